@@ -14,12 +14,14 @@ use std::time::Duration;
 
 use iceoryx2::prelude::*;
 use iceoryx2_bb_container::{
-    byte_string::FixedSizeByteString, queue::FixedSizeQueue, vec::FixedSizeVec,
+    byte_string::FixedSizeByteString, hash_map::FixedSizeHashMap, list::FixedSizeList,
+    queue::FixedSizeQueue, set::FixedSizeSet, vec::FixedSizeVec,
 };
 
 // For both data types we derive from PlacementDefault to allow in memory initialization
 // without any copy. Avoids stack overflows when data type is larger than the available stack.
-#[derive(Debug, Default, PlacementDefault)]
+// ZeroCopySend ensures that the types can be shared with other processes.
+#[derive(Debug, Default, PlacementDefault, ZeroCopySend)]
 #[repr(C)]
 pub struct ComplexData {
     name: FixedSizeByteString<4>,
@@ -28,7 +30,8 @@ pub struct ComplexData {
 
 // For both data types we derive from PlacementDefault to allow in memory initialization
 // without any copy. Avoids stack overflows when data type is larger than the available stack.
-#[derive(Debug, Default, PlacementDefault)]
+// ZeroCopySend ensures that the types can be shared with other processes.
+#[derive(Debug, Default, PlacementDefault, ZeroCopySend)]
 #[repr(C)]
 pub struct ComplexDataType {
     plain_old_data: u64,
@@ -36,6 +39,9 @@ pub struct ComplexDataType {
     vec_of_data: FixedSizeVec<u64, 4>,
     vec_of_complex_data: FixedSizeVec<ComplexData, 404857>,
     a_queue_of_things: FixedSizeQueue<FixedSizeByteString<4>, 2>,
+    a_map_of_things: FixedSizeHashMap<FixedSizeByteString<8>, u64, 16>,
+    a_set_of_things: FixedSizeSet<u64, 16>,
+    a_list_of_things: FixedSizeList<u64, 16>,
 }
 
 const CYCLE_TIME: Duration = Duration::from_secs(1);
@@ -78,6 +84,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         payload
             .a_queue_of_things
             .push(FixedSizeByteString::from_bytes(b"buh")?);
+        payload
+            .a_map_of_things
+            .insert(FixedSizeByteString::from_bytes(b"counter")?, counter)?;
+        payload.a_set_of_things.insert(counter % 3)?;
+        payload.a_list_of_things.push_front(counter);
 
         sample.send()?;
         println!("{} :: send", counter);
@@ -85,9 +96,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         // receive sample and print it
         while let Some(sample) = subscriber.receive()? {
             println!(
-                "{} :: received: {:?}",
+                "{} :: received: {:?}, map: {:?}",
                 counter,
-                sample.payload().plain_old_data
+                sample.payload().plain_old_data,
+                sample.payload().a_map_of_things
             );
         }
 
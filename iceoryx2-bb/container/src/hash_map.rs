@@ -0,0 +1,482 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A hash map with a compile-time fixed capacity that is similar to
+//! [`std::collections::HashMap`].
+//!
+//! The entries are stored inside the object and the buckets are chained via indices, therefore
+//! the [`FixedSizeHashMap`] is self-contained, can be moved and is shared memory compatible.
+//! The keys are hashed with a deterministic hash function so that every process that maps the
+//! [`FixedSizeHashMap`] finds the same entries.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+//! use iceoryx2_bb_container::hash_map::FixedSizeHashMap;
+//!
+//! const MAP_CAPACITY: usize = 123;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut my_map = FixedSizeHashMap::<FixedSizeByteString<8>, u64, MAP_CAPACITY>::new();
+//!
+//! my_map.insert(FixedSizeByteString::from_bytes(b"one")?, 1)?;
+//! my_map.insert(FixedSizeByteString::from_bytes(b"two")?, 2)?;
+//!
+//! assert_eq!(my_map.get(&FixedSizeByteString::from_bytes(b"two")?), Some(&2));
+//!
+//! for (key, value) in my_map.iter() {
+//!     println!("{} -> {}", key, value);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::MaybeUninit,
+};
+
+use iceoryx2_bb_elementary::{
+    placement_default::PlacementDefault,
+    zero_copy_send::{TypeLayoutHasher, ZeroCopySend},
+};
+use serde::{de::Visitor, Deserialize, Serialize};
+
+const INVALID_INDEX: usize = usize::MAX;
+
+/// Failures that can occur when an entry is inserted into a [`FixedSizeHashMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixedSizeHashMapInsertError {
+    /// The key is not yet contained and the map has already reached its capacity.
+    InsertWouldExceedCapacity,
+}
+
+impl std::fmt::Display for FixedSizeHashMapInsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "FixedSizeHashMapInsertError::{:?}", self)
+    }
+}
+
+impl std::error::Error for FixedSizeHashMapInsertError {}
+
+#[repr(C)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// Hash map with compile time fixed size capacity. All entries are stored inside the object,
+/// therefore it is movable and can be used in shared memory.
+#[repr(C)]
+pub struct FixedSizeHashMap<K, V, const CAPACITY: usize> {
+    len: usize,
+    buckets: [usize; CAPACITY],
+    next: [usize; CAPACITY],
+    entries: [MaybeUninit<Entry<K, V>>; CAPACITY],
+}
+
+impl<K, V, const CAPACITY: usize> PlacementDefault for FixedSizeHashMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        core::ptr::addr_of_mut!((*ptr).len).write(0);
+
+        let buckets_ptr = core::ptr::addr_of_mut!((*ptr).buckets) as *mut usize;
+        let next_ptr = core::ptr::addr_of_mut!((*ptr).next) as *mut usize;
+        for i in 0..CAPACITY {
+            buckets_ptr.add(i).write(INVALID_INDEX);
+            next_ptr.add(i).write(INVALID_INDEX);
+        }
+    }
+}
+
+impl<K, V, const CAPACITY: usize> Default for FixedSizeHashMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        let mut new_self = MaybeUninit::<Self>::uninit();
+        unsafe {
+            Self::placement_default(new_self.as_mut_ptr());
+            new_self.assume_init()
+        }
+    }
+}
+
+impl<K, V, const CAPACITY: usize> Drop for FixedSizeHashMap<K, V, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K: Debug, V: Debug, const CAPACITY: usize> Debug for FixedSizeHashMap<K, V, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, const CAPACITY: usize> PartialEq
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|v| *v == *value))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, const CAPACITY: usize> Eq for FixedSizeHashMap<K, V, CAPACITY> {}
+
+impl<K: Hash + Eq + Clone, V: Clone, const CAPACITY: usize> Clone
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn clone(&self) -> Self {
+        let mut new_self = Self::new();
+        for (key, value) in self.iter() {
+            let _ = new_self.insert(key.clone(), value.clone());
+        }
+        new_self
+    }
+}
+
+impl<K: Serialize, V: Serialize, const CAPACITY: usize> Serialize
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+struct FixedSizeHashMapVisitor<K, V, const CAPACITY: usize> {
+    _key: PhantomData<K>,
+    _value: PhantomData<V>,
+}
+
+impl<'de, K: Deserialize<'de> + Hash + Eq, V: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
+    for FixedSizeHashMapVisitor<K, V, CAPACITY>
+{
+    type Value = FixedSizeHashMap<K, V, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let str = format!(
+            "a map of at most {} entries with keys of type {} and values of type {}",
+            CAPACITY,
+            core::any::type_name::<K>(),
+            core::any::type_name::<V>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut new_map = Self::Value::new();
+
+        while let Some((key, value)) = map.next_entry()? {
+            if new_map.insert(key, value).is_err() {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the map can hold at most {} entries",
+                    CAPACITY
+                )));
+            }
+        }
+
+        Ok(new_map)
+    }
+}
+
+impl<'de, K: Deserialize<'de> + Hash + Eq, V: Deserialize<'de>, const CAPACITY: usize>
+    Deserialize<'de> for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(FixedSizeHashMapVisitor::<K, V, CAPACITY> {
+            _key: PhantomData,
+            _value: PhantomData,
+        })
+    }
+}
+
+unsafe impl<K: Send, V: Send, const CAPACITY: usize> Send for FixedSizeHashMap<K, V, CAPACITY> {}
+unsafe impl<K: Sync, V: Sync, const CAPACITY: usize> Sync for FixedSizeHashMap<K, V, CAPACITY> {}
+
+unsafe impl<K: ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn type_layout_hash() -> u64 {
+        TypeLayoutHasher::new()
+            .add_str("FixedSizeHashMap<K, V, CAPACITY>")
+            .add(core::mem::size_of::<Self>() as u64)
+            .add(core::mem::align_of::<Self>() as u64)
+            .add(CAPACITY as u64)
+            .add(K::type_layout_hash())
+            .add(V::type_layout_hash())
+            .value()
+    }
+}
+
+impl<'a, K, V, const CAPACITY: usize> IntoIterator for &'a FixedSizeHashMap<K, V, CAPACITY> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, const CAPACITY: usize> IntoIterator for &'a mut FixedSizeHashMap<K, V, CAPACITY> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    /// Creates a new empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the map
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of entries stored inside the map
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the map is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.len == CAPACITY
+    }
+
+    /// Removes all entries from the map
+    pub fn clear(&mut self) {
+        for i in (0..self.len).rev() {
+            self.len = i;
+            unsafe { self.entries[i].assume_init_drop() };
+        }
+
+        for bucket in self.buckets.iter_mut() {
+            *bucket = INVALID_INDEX;
+        }
+    }
+
+    /// Returns an iterator over all entries in an arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.as_slice().iter(),
+        }
+    }
+
+    /// Returns an iterator over all entries in an arbitrary order that allows to modify the
+    /// values.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            iter: self.as_mut_slice().iter_mut(),
+        }
+    }
+
+    /// Returns an iterator over all keys in an arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over all values in an arbitrary order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    fn as_slice(&self) -> &[Entry<K, V>] {
+        unsafe { core::slice::from_raw_parts(self.entries.as_ptr().cast(), self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [Entry<K, V>] {
+        unsafe { core::slice::from_raw_parts_mut(self.entries.as_mut_ptr().cast(), self.len) }
+    }
+
+    fn entry(&self, index: usize) -> &Entry<K, V> {
+        unsafe { self.entries[index].assume_init_ref() }
+    }
+}
+
+impl<K: Hash + Eq, V, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    /// Inserts a new entry. If the key was already contained, the value is replaced and the
+    /// old value is returned. If the key is not contained and the map is full a
+    /// [`FixedSizeHashMapInsertError`] is returned.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, FixedSizeHashMapInsertError> {
+        if let Some(index) = self.find(&key) {
+            let entry = unsafe { self.entries[index].assume_init_mut() };
+            return Ok(Some(core::mem::replace(&mut entry.value, value)));
+        }
+
+        if self.is_full() {
+            return Err(FixedSizeHashMapInsertError::InsertWouldExceedCapacity);
+        }
+
+        let bucket = Self::bucket_of(&key);
+        let index = self.len;
+        self.entries[index].write(Entry { key, value });
+        self.next[index] = self.buckets[bucket];
+        self.buckets[bucket] = index;
+        self.len += 1;
+
+        Ok(None)
+    }
+
+    /// Returns a reference to the value of the key. If the key is not contained it returns
+    /// [`None`].
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).map(|index| &self.entry(index).value)
+    }
+
+    /// Returns a mutable reference to the value of the key. If the key is not contained it
+    /// returns [`None`].
+    pub fn get_mut<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key)
+            .map(|index| unsafe { &mut self.entries[index].assume_init_mut().value })
+    }
+
+    /// Returns true if the key is contained, otherwise false.
+    pub fn contains_key<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Removes the entry of the key and returns its value. If the key is not contained it
+    /// returns [`None`].
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.find(key)?;
+        self.unlink(index);
+
+        let removed = core::mem::replace(&mut self.entries[index], MaybeUninit::uninit());
+
+        // the last entry is moved into the gap to keep the entries dense
+        let last = self.len - 1;
+        if index != last {
+            self.unlink(last);
+            let moved = core::mem::replace(&mut self.entries[last], MaybeUninit::uninit());
+            let bucket = Self::bucket_of(&unsafe { moved.assume_init_ref() }.key);
+            self.entries[index] = moved;
+            self.next[index] = self.buckets[bucket];
+            self.buckets[bucket] = index;
+        }
+        self.len -= 1;
+
+        Some(unsafe { removed.assume_init() }.value)
+    }
+
+    fn bucket_of<Q: ?Sized + Hash>(key: &Q) -> usize {
+        let mut hasher = TypeLayoutHasher::new();
+        key.hash(&mut hasher);
+        (hasher.finish() % CAPACITY as u64) as usize
+    }
+
+    fn find<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        if CAPACITY == 0 {
+            return None;
+        }
+
+        let mut index = self.buckets[Self::bucket_of(key)];
+        while index != INVALID_INDEX {
+            if self.entry(index).key.borrow() == key {
+                return Some(index);
+            }
+            index = self.next[index];
+        }
+
+        None
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let bucket = Self::bucket_of(&self.entry(index).key);
+
+        if self.buckets[bucket] == index {
+            self.buckets[bucket] = self.next[index];
+            return;
+        }
+
+        let mut current = self.buckets[bucket];
+        while self.next[current] != index {
+            current = self.next[current];
+        }
+        self.next[current] = self.next[index];
+    }
+}
+
+/// Iterator over the entries of a [`FixedSizeHashMap`], see [`FixedSizeHashMap::iter()`].
+pub struct Iter<'a, K, V> {
+    iter: core::slice::Iter<'a, Entry<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|entry| (&entry.key, &entry.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+/// Iterator over the entries of a [`FixedSizeHashMap`] that allows to modify the values, see
+/// [`FixedSizeHashMap::iter_mut()`].
+pub struct IterMut<'a, K, V> {
+    iter: core::slice::IterMut<'a, Entry<K, V>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|entry| (&entry.key, &mut entry.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
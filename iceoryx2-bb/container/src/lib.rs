@@ -87,11 +87,17 @@
 
 /// A byte string similar to [`std::string::String`] but it does not support UTF-8
 pub mod byte_string;
+/// A hash map similar to [`std::collections::HashMap`]
+pub mod hash_map;
+/// A doubly linked list similar to [`std::collections::LinkedList`]
+pub mod list;
 /// A queue similar to [`std::collections::VecDeque`]
 pub mod queue;
 /// Extends the [ByteString](crate::byte_string) so that custom string types with a semantic
 /// ruleset on their content can be realized.
 #[macro_use]
 pub mod semantic_string;
+/// A hash set similar to [`std::collections::HashSet`]
+pub mod set;
 /// A vector similar to [`std::vec::Vec`]
 pub mod vec;
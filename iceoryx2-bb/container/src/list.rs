@@ -0,0 +1,500 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A doubly linked list with a compile-time fixed capacity that is similar to
+//! [`std::collections::LinkedList`].
+//!
+//! The nodes are linked via indices into the internal storage instead of pointers. Therefore,
+//! the [`FixedSizeList`] is self-contained, can be moved and is shared memory compatible.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::list::FixedSizeList;
+//!
+//! const LIST_CAPACITY: usize = 123;
+//! let mut my_list = FixedSizeList::<u64, LIST_CAPACITY>::new();
+//!
+//! my_list.push_back(283);
+//! my_list.push_front(787);
+//!
+//! for value in my_list.iter() {
+//!     println!("value {}", value);
+//! }
+//!
+//! assert_eq!(my_list.pop_front(), Some(787));
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary::{
+    placement_default::PlacementDefault,
+    zero_copy_send::{TypeLayoutHasher, ZeroCopySend},
+};
+use serde::{de::Visitor, Deserialize, Serialize};
+
+const INVALID_INDEX: usize = usize::MAX;
+
+/// Doubly linked list with compile time fixed size capacity. All nodes are stored inside the
+/// object and are linked via indices, therefore it is movable and can be used in shared memory.
+#[repr(C)]
+pub struct FixedSizeList<T, const CAPACITY: usize> {
+    len: usize,
+    head: usize,
+    tail: usize,
+    free_head: usize,
+    next: [usize; CAPACITY],
+    prev: [usize; CAPACITY],
+    data: [MaybeUninit<T>; CAPACITY],
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeList<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        core::ptr::addr_of_mut!((*ptr).len).write(0);
+        core::ptr::addr_of_mut!((*ptr).head).write(INVALID_INDEX);
+        core::ptr::addr_of_mut!((*ptr).tail).write(INVALID_INDEX);
+        core::ptr::addr_of_mut!((*ptr).free_head).write(if CAPACITY == 0 {
+            INVALID_INDEX
+        } else {
+            0
+        });
+
+        let next_ptr = core::ptr::addr_of_mut!((*ptr).next) as *mut usize;
+        let prev_ptr = core::ptr::addr_of_mut!((*ptr).prev) as *mut usize;
+        for i in 0..CAPACITY {
+            next_ptr.add(i).write(if i + 1 < CAPACITY {
+                i + 1
+            } else {
+                INVALID_INDEX
+            });
+            prev_ptr.add(i).write(INVALID_INDEX);
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for FixedSizeList<T, CAPACITY> {
+    fn default() -> Self {
+        let mut new_self = MaybeUninit::<Self>::uninit();
+        unsafe {
+            Self::placement_default(new_self.as_mut_ptr());
+            new_self.assume_init()
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for FixedSizeList<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for FixedSizeList<T, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq for FixedSizeList<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(lhs, rhs)| lhs == rhs)
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for FixedSizeList<T, CAPACITY> {}
+
+impl<T: Clone, const CAPACITY: usize> Clone for FixedSizeList<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut new_self = Self::new();
+        for value in self.iter() {
+            new_self.push_back(value.clone());
+        }
+        new_self
+    }
+}
+
+impl<T: Serialize, const CAPACITY: usize> Serialize for FixedSizeList<T, CAPACITY> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+struct FixedSizeListVisitor<T, const CAPACITY: usize> {
+    _value: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Visitor<'de>
+    for FixedSizeListVisitor<T, CAPACITY>
+{
+    type Value = FixedSizeList<T, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let str = format!(
+            "a list of at most {} elements of type {}",
+            CAPACITY,
+            core::any::type_name::<T>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut new_list = Self::Value::new();
+
+        while let Some(element) = seq.next_element()? {
+            if !new_list.push_back(element) {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the list can hold at most {} elements",
+                    CAPACITY
+                )));
+            }
+        }
+
+        Ok(new_list)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
+    for FixedSizeList<T, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FixedSizeListVisitor::<T, CAPACITY> {
+            _value: PhantomData,
+        })
+    }
+}
+
+unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeList<T, CAPACITY> {}
+unsafe impl<T: Sync, const CAPACITY: usize> Sync for FixedSizeList<T, CAPACITY> {}
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeList<T, CAPACITY> {
+    fn type_layout_hash() -> u64 {
+        TypeLayoutHasher::new()
+            .add_str("FixedSizeList<T, CAPACITY>")
+            .add(core::mem::size_of::<Self>() as u64)
+            .add(core::mem::align_of::<Self>() as u64)
+            .add(CAPACITY as u64)
+            .add(T::type_layout_hash())
+            .value()
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a FixedSizeList<T, CAPACITY> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a mut FixedSizeList<T, CAPACITY> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const CAPACITY: usize> FixedSizeList<T, CAPACITY> {
+    /// Creates a new empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the list
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of elements stored inside the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the list is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.len == CAPACITY
+    }
+
+    /// Adds an element at the end of the list. If the list is full and the element cannot be
+    /// added it returns false, otherwise true.
+    pub fn push_back(&mut self, value: T) -> bool {
+        let index = match self.acquire_node(value) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        self.prev[index] = self.tail;
+        self.next[index] = INVALID_INDEX;
+        match self.tail {
+            INVALID_INDEX => self.head = index,
+            tail => self.next[tail] = index,
+        }
+        self.tail = index;
+
+        true
+    }
+
+    /// Adds an element at the beginning of the list. If the list is full and the element cannot
+    /// be added it returns false, otherwise true.
+    pub fn push_front(&mut self, value: T) -> bool {
+        let index = match self.acquire_node(value) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        self.next[index] = self.head;
+        self.prev[index] = INVALID_INDEX;
+        match self.head {
+            INVALID_INDEX => self.tail = index,
+            head => self.prev[head] = index,
+        }
+        self.head = index;
+
+        true
+    }
+
+    /// Removes the last element of the list and returns it to the user. If the list is empty
+    /// it returns [`None`].
+    pub fn pop_back(&mut self) -> Option<T> {
+        match self.tail {
+            INVALID_INDEX => None,
+            tail => Some(unsafe { self.remove_node(tail) }),
+        }
+    }
+
+    /// Removes the first element of the list and returns it to the user. If the list is empty
+    /// it returns [`None`].
+    pub fn pop_front(&mut self) -> Option<T> {
+        match self.head {
+            INVALID_INDEX => None,
+            head => Some(unsafe { self.remove_node(head) }),
+        }
+    }
+
+    /// Returns a reference to the first element. If the list is empty it returns [`None`].
+    pub fn front(&self) -> Option<&T> {
+        match self.head {
+            INVALID_INDEX => None,
+            head => Some(unsafe { self.data[head].assume_init_ref() }),
+        }
+    }
+
+    /// Returns a mutable reference to the first element. If the list is empty it returns
+    /// [`None`].
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        match self.head {
+            INVALID_INDEX => None,
+            head => Some(unsafe { self.data[head].assume_init_mut() }),
+        }
+    }
+
+    /// Returns a reference to the last element. If the list is empty it returns [`None`].
+    pub fn back(&self) -> Option<&T> {
+        match self.tail {
+            INVALID_INDEX => None,
+            tail => Some(unsafe { self.data[tail].assume_init_ref() }),
+        }
+    }
+
+    /// Returns a mutable reference to the last element. If the list is empty it returns
+    /// [`None`].
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        match self.tail {
+            INVALID_INDEX => None,
+            tail => Some(unsafe { self.data[tail].assume_init_mut() }),
+        }
+    }
+
+    /// Returns true if the list contains an element that is equal to the provided value.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|element| element == value)
+    }
+
+    /// Retains only the elements for which the predicate returns true, all other elements are
+    /// removed in order.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        let mut index = self.head;
+        while index != INVALID_INDEX {
+            let next = self.next[index];
+            if !predicate(unsafe { self.data[index].assume_init_ref() }) {
+                drop(unsafe { self.remove_node(index) });
+            }
+            index = next;
+        }
+    }
+
+    /// Removes all elements from the list
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+    }
+
+    /// Returns an iterator that visits all elements from the front to the back.
+    pub fn iter(&self) -> Iter<'_, T, CAPACITY> {
+        Iter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+        }
+    }
+
+    /// Returns an iterator that visits all elements from the front to the back and allows
+    /// to modify them.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, CAPACITY> {
+        IterMut {
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+            list: self,
+            _lifetime: PhantomData,
+        }
+    }
+
+    fn acquire_node(&mut self, value: T) -> Option<usize> {
+        let index = self.free_head;
+        if index == INVALID_INDEX {
+            return None;
+        }
+
+        self.free_head = self.next[index];
+        self.data[index].write(value);
+        self.len += 1;
+        Some(index)
+    }
+
+    unsafe fn remove_node(&mut self, index: usize) -> T {
+        let prev = self.prev[index];
+        let next = self.next[index];
+
+        match prev {
+            INVALID_INDEX => self.head = next,
+            prev => self.next[prev] = next,
+        }
+        match next {
+            INVALID_INDEX => self.tail = prev,
+            next => self.prev[next] = prev,
+        }
+
+        self.prev[index] = INVALID_INDEX;
+        self.next[index] = self.free_head;
+        self.free_head = index;
+        self.len -= 1;
+
+        core::mem::replace(&mut self.data[index], MaybeUninit::uninit()).assume_init()
+    }
+}
+
+/// Iterator over the elements of a [`FixedSizeList`], see [`FixedSizeList::iter()`].
+pub struct Iter<'a, T, const CAPACITY: usize> {
+    list: &'a FixedSizeList<T, CAPACITY>,
+    front: usize,
+    back: usize,
+    remaining: usize,
+}
+
+impl<'a, T, const CAPACITY: usize> Iterator for Iter<'a, T, CAPACITY> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = self.front;
+        self.front = self.list.next[index];
+        self.remaining -= 1;
+        Some(unsafe { self.list.data[index].assume_init_ref() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, const CAPACITY: usize> DoubleEndedIterator for Iter<'_, T, CAPACITY> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = self.back;
+        self.back = self.list.prev[index];
+        self.remaining -= 1;
+        Some(unsafe { self.list.data[index].assume_init_ref() })
+    }
+}
+
+impl<T, const CAPACITY: usize> ExactSizeIterator for Iter<'_, T, CAPACITY> {}
+
+/// Iterator over the mutable elements of a [`FixedSizeList`], see
+/// [`FixedSizeList::iter_mut()`].
+pub struct IterMut<'a, T, const CAPACITY: usize> {
+    list: *mut FixedSizeList<T, CAPACITY>,
+    front: usize,
+    back: usize,
+    remaining: usize,
+    _lifetime: PhantomData<&'a mut FixedSizeList<T, CAPACITY>>,
+}
+
+impl<'a, T, const CAPACITY: usize> Iterator for IterMut<'a, T, CAPACITY> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = self.front;
+        // every element is visited at most once and the list is only accessed via raw places,
+        // therefore no aliasing mutable references are created
+        self.front = unsafe { (*self.list).next[index] };
+        self.remaining -= 1;
+        Some(unsafe { (*core::ptr::addr_of_mut!((*self.list).data[index])).assume_init_mut() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, const CAPACITY: usize> DoubleEndedIterator for IterMut<'_, T, CAPACITY> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = self.back;
+        self.back = unsafe { (*self.list).prev[index] };
+        self.remaining -= 1;
+        Some(unsafe { (*core::ptr::addr_of_mut!((*self.list).data[index])).assume_init_mut() })
+    }
+}
+
+impl<T, const CAPACITY: usize> ExactSizeIterator for IterMut<'_, T, CAPACITY> {}
@@ -103,6 +103,7 @@ use iceoryx2_bb_elementary::placement_default::PlacementDefault;
 use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
 pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::{TypeLayoutHasher, ZeroCopySend};
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 use std::marker::PhantomData;
@@ -476,6 +477,18 @@ impl<T, const CAPACITY: usize> Default for FixedSizeQueue<T, CAPACITY> {
 }
 
 unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeQueue<T, CAPACITY> {}
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeQueue<T, CAPACITY> {
+    fn type_layout_hash() -> u64 {
+        TypeLayoutHasher::new()
+            .add_str("FixedSizeQueue<T, CAPACITY>")
+            .add(core::mem::size_of::<Self>() as u64)
+            .add(core::mem::align_of::<Self>() as u64)
+            .add(CAPACITY as u64)
+            .add(T::type_layout_hash())
+            .value()
+    }
+}
 unsafe impl<T: Sync, const CAPACITY: usize> Sync for FixedSizeQueue<T, CAPACITY> {}
 
 impl<T, const CAPACITY: usize> FixedSizeQueue<T, CAPACITY> {
@@ -0,0 +1,272 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A hash set with a compile-time fixed capacity that is similar to
+//! [`std::collections::HashSet`]. It is based on the
+//! [`FixedSizeHashMap`](crate::hash_map::FixedSizeHashMap) and is therefore self-contained,
+//! movable and shared memory compatible.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::set::FixedSizeSet;
+//!
+//! const SET_CAPACITY: usize = 123;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut my_set = FixedSizeSet::<u64, SET_CAPACITY>::new();
+//!
+//! assert!(my_set.insert(283)?);
+//! assert!(!my_set.insert(283)?);
+//!
+//! assert!(my_set.contains(&283));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{borrow::Borrow, fmt::Debug, hash::Hash, marker::PhantomData};
+
+use iceoryx2_bb_elementary::{
+    placement_default::PlacementDefault,
+    zero_copy_send::{TypeLayoutHasher, ZeroCopySend},
+};
+use serde::{de::Visitor, Deserialize, Serialize};
+
+use crate::hash_map::{self, FixedSizeHashMap};
+
+/// Failures that can occur when a value is inserted into a [`FixedSizeSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixedSizeSetInsertError {
+    /// The value is not yet contained and the set has already reached its capacity.
+    InsertWouldExceedCapacity,
+}
+
+impl std::fmt::Display for FixedSizeSetInsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "FixedSizeSetInsertError::{:?}", self)
+    }
+}
+
+impl std::error::Error for FixedSizeSetInsertError {}
+
+/// Hash set with compile time fixed size capacity. All values are stored inside the object,
+/// therefore it is movable and can be used in shared memory.
+#[repr(C)]
+pub struct FixedSizeSet<T, const CAPACITY: usize> {
+    map: FixedSizeHashMap<T, (), CAPACITY>,
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeSet<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let map_ptr = core::ptr::addr_of_mut!((*ptr).map);
+        FixedSizeHashMap::placement_default(map_ptr);
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for FixedSizeSet<T, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            map: FixedSizeHashMap::default(),
+        }
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for FixedSizeSet<T, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Hash + Eq, const CAPACITY: usize> PartialEq for FixedSizeSet<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Hash + Eq, const CAPACITY: usize> Eq for FixedSizeSet<T, CAPACITY> {}
+
+impl<T: Hash + Eq + Clone, const CAPACITY: usize> Clone for FixedSizeSet<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: Serialize, const CAPACITY: usize> Serialize for FixedSizeSet<T, CAPACITY> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+struct FixedSizeSetVisitor<T, const CAPACITY: usize> {
+    _value: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de> + Hash + Eq, const CAPACITY: usize> Visitor<'de>
+    for FixedSizeSetVisitor<T, CAPACITY>
+{
+    type Value = FixedSizeSet<T, CAPACITY>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let str = format!(
+            "a set of at most {} elements of type {}",
+            CAPACITY,
+            core::any::type_name::<T>()
+        );
+        formatter.write_str(&str)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut new_set = Self::Value::new();
+
+        while let Some(element) = seq.next_element()? {
+            if new_set.insert(element).is_err() {
+                return Err(<A::Error as serde::de::Error>::custom(format!(
+                    "the set can hold at most {} elements",
+                    CAPACITY
+                )));
+            }
+        }
+
+        Ok(new_set)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Hash + Eq, const CAPACITY: usize> Deserialize<'de>
+    for FixedSizeSet<T, CAPACITY>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FixedSizeSetVisitor::<T, CAPACITY> {
+            _value: PhantomData,
+        })
+    }
+}
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeSet<T, CAPACITY> {
+    fn type_layout_hash() -> u64 {
+        TypeLayoutHasher::new()
+            .add_str("FixedSizeSet<T, CAPACITY>")
+            .add(core::mem::size_of::<Self>() as u64)
+            .add(core::mem::align_of::<Self>() as u64)
+            .add(CAPACITY as u64)
+            .add(T::type_layout_hash())
+            .value()
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a FixedSizeSet<T, CAPACITY> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T, const CAPACITY: usize> FixedSizeSet<T, CAPACITY> {
+    /// Creates a new empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the capacity of the set
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns the number of elements stored inside the set
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the set is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the set is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Removes all elements from the set
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns an iterator over all elements in an arbitrary order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            iter: self.map.iter(),
+        }
+    }
+}
+
+impl<T: Hash + Eq, const CAPACITY: usize> FixedSizeSet<T, CAPACITY> {
+    /// Adds a value to the set. Returns true when the value was not yet contained, otherwise
+    /// false. If the value is not contained and the set is full a [`FixedSizeSetInsertError`]
+    /// is returned.
+    pub fn insert(&mut self, value: T) -> Result<bool, FixedSizeSetInsertError> {
+        match self.map.insert(value, ()) {
+            Ok(previous) => Ok(previous.is_none()),
+            Err(hash_map::FixedSizeHashMapInsertError::InsertWouldExceedCapacity) => {
+                Err(FixedSizeSetInsertError::InsertWouldExceedCapacity)
+            }
+        }
+    }
+
+    /// Returns true if the set contains the value, otherwise false.
+    pub fn contains<Q: ?Sized + Hash + Eq>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Removes the value from the set. Returns true if the value was contained, otherwise
+    /// false.
+    pub fn remove<Q: ?Sized + Hash + Eq>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove(value).is_some()
+    }
+}
+
+/// Iterator over the elements of a [`FixedSizeSet`], see [`FixedSizeSet::iter()`].
+pub struct Iter<'a, T> {
+    iter: hash_map::Iter<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
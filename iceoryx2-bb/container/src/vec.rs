@@ -83,6 +83,7 @@ use std::{
     sync::atomic::Ordering,
 };
 
+use iceoryx2_bb_elementary::zero_copy_send::{TypeLayoutHasher, ZeroCopySend};
 use iceoryx2_bb_elementary::{
     math::{align_to, unaligned_mem_size},
     placement_default::PlacementDefault,
@@ -467,6 +468,18 @@ impl<T: Clone, const CAPACITY: usize> Clone for FixedSizeVec<T, CAPACITY> {
 
 unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeVec<T, CAPACITY> {}
 
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeVec<T, CAPACITY> {
+    fn type_layout_hash() -> u64 {
+        TypeLayoutHasher::new()
+            .add_str("FixedSizeVec<T, CAPACITY>")
+            .add(core::mem::size_of::<Self>() as u64)
+            .add(core::mem::align_of::<Self>() as u64)
+            .add(CAPACITY as u64)
+            .add(T::type_layout_hash())
+            .value()
+    }
+}
+
 impl<T, const CAPACITY: usize> FixedSizeVec<T, CAPACITY> {
     fn initialize_state() -> RelocatableVec<T> {
         unsafe {
@@ -0,0 +1,286 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod fixed_size_hash_map {
+    use std::collections::HashMap;
+
+    use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+    use iceoryx2_bb_container::hash_map::*;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+    use iceoryx2_bb_testing::memory::RawMemory;
+    use serde_test::{assert_tokens, Token};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeHashMap<usize, usize, SUT_CAPACITY>;
+
+    #[test]
+    fn new_map_is_empty() {
+        let sut = Sut::new();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut, len 0);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.get(&0), is_none);
+        assert_that!(sut.iter().next(), is_none);
+    }
+
+    #[test]
+    fn insert_and_get_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i * 3, i * 7), eq Ok(None));
+            assert_that!(sut, len i + 1);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.get(&(i * 3)), eq Some(&(i * 7)));
+            assert_that!(sut.contains_key(&(i * 3)), eq true);
+            assert_that!(sut.contains_key(&(i * 3 + 1)), eq false);
+        }
+    }
+
+    #[test]
+    fn insert_existing_key_replaces_value() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.insert(5, 10), eq Ok(None));
+        assert_that!(sut.insert(5, 20), eq Ok(Some(10)));
+        assert_that!(sut, len 1);
+        assert_that!(sut.get(&5), eq Some(&20));
+    }
+
+    #[test]
+    fn insert_into_full_map_fails_only_for_new_keys() {
+        let mut sut = FixedSizeHashMap::<usize, usize, 4>::new();
+        for i in 0..4 {
+            assert_that!(sut.insert(i, i), is_ok);
+        }
+
+        assert_that!(sut.insert(4, 4), eq Err(FixedSizeHashMapInsertError::InsertWouldExceedCapacity));
+        assert_that!(sut.insert(2, 8), eq Ok(Some(2)));
+    }
+
+    #[test]
+    fn get_mut_modifies_value() {
+        let mut sut = Sut::new();
+        sut.insert(1, 2).unwrap();
+
+        *sut.get_mut(&1).unwrap() = 3;
+
+        assert_that!(sut.get(&1), eq Some(&3));
+        assert_that!(sut.get_mut(&2), is_none);
+    }
+
+    #[test]
+    fn remove_works() {
+        let mut sut = Sut::new();
+        for i in 0..SUT_CAPACITY {
+            sut.insert(i, i + 1).unwrap();
+        }
+
+        for i in (0..SUT_CAPACITY).step_by(2) {
+            assert_that!(sut.remove(&i), eq Some(i + 1));
+            assert_that!(sut.remove(&i), is_none);
+        }
+        assert_that!(sut, len SUT_CAPACITY / 2);
+
+        for i in 0..SUT_CAPACITY {
+            if i % 2 == 0 {
+                assert_that!(sut.get(&i), is_none);
+            } else {
+                assert_that!(sut.get(&i), eq Some(&(i + 1)));
+            }
+        }
+    }
+
+    #[test]
+    fn behaves_like_std_hash_map_with_many_collisions() {
+        const CAPACITY: usize = 7;
+        let mut sut = FixedSizeHashMap::<u64, u64, CAPACITY>::new();
+        let mut reference = HashMap::new();
+
+        let mut state: u64 = 8127391;
+        for _ in 0..10000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let key = (state >> 33) % 13;
+            let value = state >> 40;
+
+            if (state >> 20) % 3 == 0 {
+                assert_that!(sut.remove(&key), eq reference.remove(&key));
+            } else if reference.len() < CAPACITY || reference.contains_key(&key) {
+                assert_that!(sut.insert(key, value), eq Ok(reference.insert(key, value)));
+            } else {
+                assert_that!(sut.insert(key, value), is_err);
+            }
+
+            assert_that!(sut, len reference.len());
+            for (key, value) in reference.iter() {
+                assert_that!(sut.get(key), eq Some(value));
+            }
+        }
+    }
+
+    #[test]
+    fn iter_visits_all_entries() {
+        let mut sut = Sut::new();
+        for i in 0..20 {
+            sut.insert(i, i * 2).unwrap();
+        }
+
+        let mut entries: Vec<(usize, usize)> = sut.iter().map(|(k, v)| (*k, *v)).collect();
+        entries.sort();
+        let expected_entries: Vec<(usize, usize)> = (0..20).map(|i| (i, i * 2)).collect();
+        assert_that!(entries, eq expected_entries);
+
+        let mut keys: Vec<usize> = sut.keys().copied().collect();
+        keys.sort();
+        let expected_keys: Vec<usize> = (0..20).collect();
+        assert_that!(keys, eq expected_keys);
+
+        let expected_sum: usize = (0..20).map(|i| i * 2).sum();
+        assert_that!(sut.values().sum::<usize>(), eq expected_sum);
+    }
+
+    #[test]
+    fn iter_mut_modifies_all_values() {
+        let mut sut = Sut::new();
+        for i in 0..20 {
+            sut.insert(i, i).unwrap();
+        }
+
+        for (key, value) in sut.iter_mut() {
+            *value = key + 100;
+        }
+
+        for (key, value) in &sut {
+            assert_that!(*value, eq key + 100);
+        }
+    }
+
+    #[test]
+    fn borrowed_keys_can_be_used_for_lookup() {
+        let mut sut = FixedSizeHashMap::<FixedSizeByteString<16>, u64, 8>::new();
+        sut.insert(FixedSizeByteString::from_bytes(b"hello").unwrap(), 1)
+            .unwrap();
+
+        assert_that!(sut.get(&FixedSizeByteString::from_bytes(b"hello").unwrap()), eq Some(&1));
+        assert_that!(
+            sut.get(&FixedSizeByteString::from_bytes(b"world").unwrap()),
+            is_none
+        );
+    }
+
+    #[test]
+    fn valid_after_move() {
+        let mut sut = Sut::new();
+        for i in 0..SUT_CAPACITY {
+            sut.insert(i, i * 9).unwrap();
+        }
+
+        let mut sut2 = sut;
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut2.remove(&i), eq Some(i * 9));
+        }
+        assert_that!(sut2, is_empty);
+    }
+
+    #[test]
+    fn eq_and_clone_works() {
+        let mut sut = Sut::new();
+        for i in 0..12 {
+            sut.insert(i, i * 7).unwrap();
+        }
+
+        let sut2 = sut.clone();
+        assert_that!(sut == sut2, eq true);
+
+        let mut sut3 = Sut::new();
+        for i in (0..12).rev() {
+            sut3.insert(i, i * 7).unwrap();
+        }
+        assert_that!(sut == sut3, eq true);
+
+        sut.insert(0, 1).unwrap();
+        assert_that!(sut == sut2, eq false);
+        sut.remove(&0);
+        assert_that!(sut == sut2, eq false);
+    }
+
+    #[test]
+    fn drops_all_objects_with_remove_clear_and_drop() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeHashMap::<usize, LifetimeTracker, SUT_CAPACITY>::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY);
+
+        drop(sut.insert(0, LifetimeTracker::new()));
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY);
+
+        drop(sut.remove(&1));
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY - 1);
+
+        sut.clear();
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+        drop(sut);
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init()}, len 0);
+        assert_that!(unsafe { sut.assume_init_mut()}.insert(123, 456), eq Ok(None));
+        assert_that!(unsafe { sut.assume_init()}.get(&123), eq Some(&456));
+    }
+
+    #[test]
+    fn map_with_zero_capacity_works() {
+        let mut sut = FixedSizeHashMap::<usize, usize, 0>::new();
+
+        assert_that!(sut.insert(1, 1), is_err);
+        assert_that!(sut.get(&1), is_none);
+        assert_that!(sut.remove(&1), is_none);
+    }
+
+    #[test]
+    fn serialization_works() {
+        let mut sut = FixedSizeHashMap::<u64, u64, 4>::new();
+        sut.insert(44617, 1).unwrap();
+
+        assert_tokens(
+            &sut,
+            &[
+                Token::Map { len: Some(1) },
+                Token::U64(44617),
+                Token::U64(1),
+                Token::MapEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod fixed_size_list {
+    use iceoryx2_bb_container::list::*;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
+    use iceoryx2_bb_testing::memory::RawMemory;
+    use serde_test::{assert_tokens, Token};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeList<usize, SUT_CAPACITY>;
+
+    #[test]
+    fn new_list_is_empty() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut, len 0);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.front(), is_none);
+        assert_that!(sut.back(), is_none);
+        assert_that!(sut.pop_front(), is_none);
+        assert_that!(sut.pop_back(), is_none);
+    }
+
+    #[test]
+    fn push_back_pop_front_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.is_full(), eq false);
+            assert_that!(sut.push_back(i * 2 + 3), eq true);
+            assert_that!(sut, len i + 1);
+            assert_that!(*sut.back().unwrap(), eq i * 2 + 3);
+        }
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.push_back(0), eq false);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(*sut.front().unwrap(), eq i * 2 + 3);
+            assert_that!(sut.pop_front(), eq Some(i * 2 + 3));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn push_front_pop_back_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.push_front(i * 5 + 1), eq true);
+            assert_that!(*sut.front().unwrap(), eq i * 5 + 1);
+        }
+        assert_that!(sut.push_front(0), eq false);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.pop_back(), eq Some(i * 5 + 1));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn alternating_push_and_pop_reuses_nodes() {
+        let mut sut = FixedSizeList::<usize, 3>::new();
+
+        for i in 0..100 {
+            assert_that!(sut.push_back(i), eq true);
+            assert_that!(sut.push_front(i + 1000), eq true);
+            assert_that!(sut.pop_back(), eq Some(i));
+            assert_that!(sut.pop_front(), eq Some(i + 1000));
+            assert_that!(sut, is_empty);
+        }
+    }
+
+    #[test]
+    fn front_and_back_mut_modify_elements() {
+        let mut sut = Sut::new();
+        sut.push_back(1);
+        sut.push_back(2);
+
+        *sut.front_mut().unwrap() = 10;
+        *sut.back_mut().unwrap() = 20;
+
+        assert_that!(sut.pop_front(), eq Some(10));
+        assert_that!(sut.pop_front(), eq Some(20));
+    }
+
+    #[test]
+    fn iter_visits_all_elements_in_both_directions() {
+        let mut sut = Sut::new();
+        for i in 0..10 {
+            sut.push_back(i);
+        }
+
+        let forward: Vec<usize> = sut.iter().copied().collect();
+        let backward: Vec<usize> = sut.iter().rev().copied().collect();
+
+        let expected_forward: Vec<usize> = (0..10).collect();
+        let expected_backward: Vec<usize> = (0..10).rev().collect();
+
+        assert_that!(forward, eq expected_forward);
+        assert_that!(backward, eq expected_backward);
+        assert_that!(sut.iter().len(), eq 10);
+    }
+
+    #[test]
+    fn iter_mut_modifies_all_elements() {
+        let mut sut = Sut::new();
+        for i in 0..10 {
+            sut.push_back(i);
+        }
+
+        for element in sut.iter_mut() {
+            *element *= 3;
+        }
+
+        for (i, element) in (&sut).into_iter().enumerate() {
+            assert_that!(*element, eq i * 3);
+        }
+    }
+
+    #[test]
+    fn retain_removes_elements_in_order() {
+        let mut sut = Sut::new();
+        for i in 0..20 {
+            sut.push_back(i);
+        }
+
+        sut.retain(|v| v % 3 == 0);
+
+        let result: Vec<usize> = sut.iter().copied().collect();
+        assert_that!(result, eq vec![0, 3, 6, 9, 12, 15, 18]);
+        assert_that!(sut.contains(&9), eq true);
+        assert_that!(sut.contains(&10), eq false);
+
+        for i in 0..SUT_CAPACITY - result.len() {
+            assert_that!(sut.push_front(i), eq true);
+        }
+        assert_that!(sut.is_full(), eq true);
+    }
+
+    #[test]
+    fn valid_after_move() {
+        let mut sut = Sut::new();
+        for i in 0..SUT_CAPACITY {
+            sut.push_back(i);
+        }
+
+        let mut sut2 = sut;
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut2.pop_front(), eq Some(i));
+        }
+    }
+
+    #[test]
+    fn eq_and_clone_works() {
+        let mut sut = Sut::new();
+        for i in 0..12 {
+            sut.push_back(i * 7);
+        }
+
+        let sut2 = sut.clone();
+        assert_that!(sut == sut2, eq true);
+        assert_that!(Sut::new() == Sut::new(), eq true);
+
+        sut.pop_back();
+        assert_that!(sut == sut2, eq false);
+        sut.push_back(0);
+        assert_that!(sut == sut2, eq false);
+    }
+
+    #[test]
+    fn drops_all_objects_when_out_of_scope() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeList::<LifetimeTracker, SUT_CAPACITY>::new();
+
+        for _ in 0..SUT_CAPACITY {
+            assert_that!(sut.push_back(LifetimeTracker::new()), eq true);
+        }
+
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY);
+        drop(sut);
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn drops_all_objects_with_clear_and_retain() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeList::<LifetimeTracker, SUT_CAPACITY>::new();
+
+        for _ in 0..SUT_CAPACITY {
+            assert_that!(sut.push_back(LifetimeTracker::new()), eq true);
+        }
+
+        let mut counter = 0;
+        sut.retain(|_| {
+            counter += 1;
+            counter % 2 == 0
+        });
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY / 2);
+
+        sut.clear();
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init()}, len 0);
+        assert_that!(unsafe { sut.assume_init_mut()}.push_back(123), eq true);
+        assert_that!(unsafe { sut.assume_init_mut()}.push_front(456), eq true);
+
+        assert_that!(unsafe { sut.assume_init_mut()}.pop_back(), eq Some(123));
+        assert_that!(unsafe { sut.assume_init_mut()}.pop_back(), eq Some(456));
+    }
+
+    #[test]
+    fn list_with_zero_capacity_works() {
+        let mut sut = FixedSizeList::<usize, 0>::new();
+
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.push_back(1), eq false);
+        assert_that!(sut.push_front(1), eq false);
+        assert_that!(sut.pop_front(), is_none);
+    }
+
+    #[test]
+    fn serialization_works() {
+        let mut sut = Sut::new();
+        sut.push_back(44617);
+        sut.push_back(123123);
+        sut.push_front(89712);
+
+        assert_tokens(
+            &sut,
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U64(89712),
+                Token::U64(44617),
+                Token::U64(123123),
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod fixed_size_set {
+    use iceoryx2_bb_container::set::*;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::memory::RawMemory;
+    use serde_test::{assert_tokens, Token};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeSet<usize, SUT_CAPACITY>;
+
+    #[test]
+    fn new_set_is_empty() {
+        let sut = Sut::new();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut, len 0);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.contains(&0), eq false);
+    }
+
+    #[test]
+    fn insert_contains_and_remove_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i * 3), eq Ok(true));
+            assert_that!(sut.insert(i * 3), eq Ok(false));
+        }
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(1), eq Err(FixedSizeSetInsertError::InsertWouldExceedCapacity));
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.contains(&(i * 3)), eq true);
+            assert_that!(sut.remove(&(i * 3)), eq true);
+            assert_that!(sut.remove(&(i * 3)), eq false);
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn iter_visits_all_elements() {
+        let mut sut = Sut::new();
+        for i in 0..20 {
+            sut.insert(i).unwrap();
+        }
+
+        let mut elements: Vec<usize> = sut.iter().copied().collect();
+        elements.sort();
+        let expected_elements: Vec<usize> = (0..20).collect();
+        assert_that!(elements, eq expected_elements);
+        assert_that!((&sut).into_iter().len(), eq 20);
+    }
+
+    #[test]
+    fn eq_and_clone_works() {
+        let mut sut = Sut::new();
+        for i in 0..12 {
+            sut.insert(i).unwrap();
+        }
+
+        let mut sut2 = Sut::new();
+        for i in (0..12).rev() {
+            sut2.insert(i).unwrap();
+        }
+
+        assert_that!(sut == sut2, eq true);
+        assert_that!(sut == sut.clone(), eq true);
+
+        sut2.remove(&3);
+        assert_that!(sut == sut2, eq false);
+    }
+
+    #[test]
+    fn clear_removes_all_elements() {
+        let mut sut = Sut::new();
+        for i in 0..SUT_CAPACITY {
+            sut.insert(i).unwrap();
+        }
+
+        sut.clear();
+
+        assert_that!(sut, is_empty);
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.contains(&i), eq false);
+        }
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init()}, len 0);
+        assert_that!(unsafe { sut.assume_init_mut()}.insert(123), eq Ok(true));
+        assert_that!(unsafe { sut.assume_init()}.contains(&123), eq true);
+    }
+
+    #[test]
+    fn serialization_works() {
+        let mut sut = FixedSizeSet::<u64, 4>::new();
+        sut.insert(44617).unwrap();
+
+        assert_tokens(
+            &sut,
+            &[
+                Token::Seq { len: Some(1) },
+                Token::U64(44617),
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
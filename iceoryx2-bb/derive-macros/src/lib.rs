@@ -99,3 +99,151 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+fn has_stable_layout(input: &DeriveInput) -> bool {
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .any(|attr| {
+            let mut is_stable = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                    is_stable = true;
+                }
+                Ok(())
+            });
+            is_stable
+        })
+}
+
+/// Implements the [`iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend`] trait when all
+/// fields of the struct implement it. The resulting
+/// [`iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend::type_layout_hash()`] depends on the
+/// size and alignment of the struct and on the offset and layout hash of every field but not on
+/// the names of the struct or its fields.
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// #[repr(C)]
+/// struct MyType {
+///     value_1: u64,
+///     value_2: [u8; 16],
+/// }
+///
+/// #[derive(ZeroCopySend)]
+/// #[repr(C)]
+/// struct MyRenamedType {
+///     renamed_value_1: u64,
+///     renamed_value_2: [u8; 16],
+/// }
+///
+/// assert_eq!(MyType::type_layout_hash(), MyRenamedType::type_layout_hash());
+/// ```
+///
+/// The struct must be `#[repr(C)]` or `#[repr(transparent)]`, otherwise the compiler is free
+/// to reorder the fields and the same hash could describe different memory layouts.
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// struct MyType {
+///     value: u64,
+/// }
+/// ```
+///
+/// Every field must implement [`iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend`],
+/// therefore types that contain pointers, references, or heap allocated data like [`Box`] or
+/// [`Vec`] are rejected, independent of the name under which they are used.
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// type MyBox = Box<u64>;
+///
+/// #[derive(ZeroCopySend)]
+/// #[repr(C)]
+/// struct MyType {
+///     value: MyBox,
+/// }
+/// ```
+#[proc_macro_derive(ZeroCopySend)]
+pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match input.data {
+        Data::Struct(ref data_struct) => &data_struct.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "ZeroCopySend can only be derived for structs",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    if !has_stable_layout(&input) {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "ZeroCopySend can only be derived for structs with #[repr(C)] or #[repr(transparent)]",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field in fields.iter() {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ty: ZeroCopySend));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let field_layouts = fields.iter().enumerate().map(|(i, f)| {
+        let ty = &f.ty;
+        let member = match f.ident {
+            Some(ref ident) => quote! { #ident },
+            None => {
+                let index = syn::Index::from(i);
+                quote! { #index }
+            }
+        };
+        quote! {
+            .add((unsafe { core::ptr::addr_of!((*base).#member) } as usize - base as usize) as u64)
+            .add(<#ty as ZeroCopySend>::type_layout_hash())
+        }
+    });
+
+    let base_address = match fields.is_empty() {
+        true => quote! {},
+        false => quote! {
+            let value = core::mem::MaybeUninit::<Self>::uninit();
+            let base = value.as_ptr();
+        },
+    };
+
+    let expanded = quote! {
+        unsafe impl #impl_generics ZeroCopySend for #name #ty_generics #where_clause {
+            fn type_layout_hash() -> u64 {
+                #base_address
+                <Self as ZeroCopySend>::__type_layout_hasher()
+                    .add(core::mem::size_of::<Self>() as u64)
+                    .add(core::mem::align_of::<Self>() as u64)
+                    #(#field_layouts)*
+                    .value()
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
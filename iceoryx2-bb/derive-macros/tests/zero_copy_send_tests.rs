@@ -0,0 +1,127 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod zero_copy_send {
+    use iceoryx2_bb_derive_macros::ZeroCopySend;
+    use iceoryx2_bb_elementary::zero_copy_send::{TypeLayoutHasher, ZeroCopySend};
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct Fuu {
+        _a: u64,
+        _b: [u32; 4],
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct RenamedFuu {
+        _renamed_a: u64,
+        _renamed_b: [u32; 4],
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct FuuWithReorderedFields {
+        _b: [u32; 4],
+        _a: u64,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct FuuWithOtherFieldType {
+        _a: i64,
+        _b: [u32; 4],
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct TupleFuu(u64, [u32; 4]);
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct UnitFuu;
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct Bar {
+        _fuu: Fuu,
+        _value: Option<u8>,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct RenamedBar {
+        _fuu: RenamedFuu,
+        _value: Option<u8>,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    struct Generic<T> {
+        _value: T,
+    }
+
+    #[test]
+    fn zero_copy_send_hash_is_deterministic() {
+        assert_that!(Fuu::type_layout_hash(), eq Fuu::type_layout_hash());
+        assert_that!(Bar::type_layout_hash(), eq Bar::type_layout_hash());
+    }
+
+    #[test]
+    fn zero_copy_send_hash_does_not_depend_on_names() {
+        assert_that!(Fuu::type_layout_hash(), eq RenamedFuu::type_layout_hash());
+        assert_that!(Fuu::type_layout_hash(), eq TupleFuu::type_layout_hash());
+        assert_that!(Bar::type_layout_hash(), eq RenamedBar::type_layout_hash());
+    }
+
+    #[test]
+    fn zero_copy_send_hash_depends_on_field_layout() {
+        assert_that!(Fuu::type_layout_hash(), ne FuuWithReorderedFields::type_layout_hash());
+        assert_that!(Fuu::type_layout_hash(), ne FuuWithOtherFieldType::type_layout_hash());
+        assert_that!(Fuu::type_layout_hash(), ne Bar::type_layout_hash());
+        assert_that!(UnitFuu::type_layout_hash(), ne<()>::type_layout_hash());
+    }
+
+    #[test]
+    fn zero_copy_send_hash_of_generic_struct_depends_on_parameter() {
+        assert_that!(Generic::<u32>::type_layout_hash(), eq Generic::<u32>::type_layout_hash());
+        assert_that!(Generic::<u32>::type_layout_hash(), ne Generic::<i32>::type_layout_hash());
+        assert_that!(Generic::<u32>::type_layout_hash(), ne Generic::<u64>::type_layout_hash());
+    }
+
+    #[test]
+    fn zero_copy_send_hash_is_computed_with_type_layout_hasher() {
+        let expected_hash = TypeLayoutHasher::new()
+            .add(core::mem::size_of::<Fuu>() as u64)
+            .add(core::mem::align_of::<Fuu>() as u64)
+            .add(0)
+            .add(u64::type_layout_hash())
+            .add(8)
+            .add(<[u32; 4]>::type_layout_hash())
+            .value();
+
+        assert_that!(Fuu::type_layout_hash(), eq expected_hash);
+    }
+
+    #[test]
+    fn zero_copy_send_hash_of_primitives_differ() {
+        assert_that!(u32::type_layout_hash(), ne i32::type_layout_hash());
+        assert_that!(u32::type_layout_hash(), ne f32::type_layout_hash());
+        assert_that!(
+            <[u8; 4]>::type_layout_hash(),
+            ne<[u8; 5]>::type_layout_hash()
+        );
+        assert_that!(<[u8; 4]>::type_layout_hash(), ne u32::type_layout_hash());
+    }
+}
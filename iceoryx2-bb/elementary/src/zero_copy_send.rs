@@ -0,0 +1,205 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Trait that marks types which can be shared between processes via zero-copy and that
+//! provides a stable hash of their memory layout. See [`ZeroCopySend`] for example.
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::*;
+
+/// Marks types that can be stored in shared memory and accessed from another process. Such
+/// types must be self-contained, meaning that they must not contain pointers, references or
+/// any kind of heap allocated data.
+///
+/// Every type provides a [`ZeroCopySend::type_layout_hash()`] that depends only on the
+/// structure of the type, its size, alignment and the layout of all of its fields, but not on
+/// its name. Two processes can therefore verify that they share the same memory layout even
+/// when the type is defined under a different name in each of them.
+///
+/// It is recommended to use the `ZeroCopySend` derive macro of `iceoryx2_bb_derive_macros`
+/// instead of implementing the trait manually.
+///
+/// ```
+/// use iceoryx2_bb_elementary::zero_copy_send::{TypeLayoutHasher, ZeroCopySend};
+///
+/// #[repr(C)]
+/// struct MyType {
+///     value_1: u64,
+///     value_2: [u8; 16],
+/// }
+///
+/// unsafe impl ZeroCopySend for MyType {
+///     fn type_layout_hash() -> u64 {
+///         TypeLayoutHasher::new()
+///             .add(core::mem::size_of::<Self>() as u64)
+///             .add(core::mem::align_of::<Self>() as u64)
+///             .add(0)
+///             .add(u64::type_layout_hash())
+///             .add(8)
+///             .add(<[u8; 16]>::type_layout_hash())
+///             .value()
+///     }
+/// }
+///
+/// assert_ne!(MyType::type_layout_hash(), u64::type_layout_hash());
+/// ```
+///
+/// # Safety
+///
+///  * the type must not contain pointers, references or heap allocated data
+///  * [`ZeroCopySend::type_layout_hash()`] must return a different value whenever the memory
+///    layout of the type changes
+pub unsafe trait ZeroCopySend {
+    /// Returns a stable hash of the structural memory layout of the type.
+    fn type_layout_hash() -> u64;
+
+    /// Returns a new [`TypeLayoutHasher`]. Used by the `ZeroCopySend` derive macro so that the
+    /// generated code does not depend on the path under which this crate is available.
+    #[doc(hidden)]
+    fn __type_layout_hasher() -> TypeLayoutHasher {
+        TypeLayoutHasher::new()
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x00000100000001b3;
+
+/// Computes the 64-bit FNV-1a hash over a sequence of values. It is used to implement
+/// [`ZeroCopySend::type_layout_hash()`] and produces the same result on every platform and in
+/// every process. It implements [`core::hash::Hasher`] as well so that containers in shared
+/// memory can use it to hash their keys deterministically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeLayoutHasher {
+    hash: u64,
+}
+
+impl Default for TypeLayoutHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeLayoutHasher {
+    /// Creates a new [`TypeLayoutHasher`]
+    pub const fn new() -> Self {
+        Self {
+            hash: FNV_OFFSET_BASIS,
+        }
+    }
+
+    /// Adds a value to the hash
+    pub const fn add(self, value: u64) -> Self {
+        self.add_bytes(&value.to_le_bytes())
+    }
+
+    /// Adds a string to the hash, used to distinguish primitive types with the same layout
+    pub const fn add_str(self, value: &str) -> Self {
+        self.add_bytes(value.as_bytes())
+    }
+
+    /// Returns the resulting hash
+    pub const fn value(&self) -> u64 {
+        self.hash
+    }
+
+    const fn add_bytes(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.hash ^= bytes[i] as u64;
+            self.hash = self.hash.wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+        self
+    }
+}
+
+impl core::hash::Hasher for TypeLayoutHasher {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        *self = self.add_bytes(bytes);
+    }
+}
+
+macro_rules! Impl {
+    ($type:ty) => {
+        unsafe impl ZeroCopySend for $type {
+            fn type_layout_hash() -> u64 {
+                TypeLayoutHasher::new()
+                    .add_str(stringify!($type))
+                    .add(core::mem::size_of::<$type>() as u64)
+                    .add(core::mem::align_of::<$type>() as u64)
+                    .value()
+            }
+        }
+    };
+}
+
+Impl!(());
+Impl!(f32);
+Impl!(f64);
+Impl!(u8);
+Impl!(u16);
+Impl!(u32);
+Impl!(u64);
+Impl!(u128);
+Impl!(i8);
+Impl!(i16);
+Impl!(i32);
+Impl!(i64);
+Impl!(i128);
+Impl!(isize);
+Impl!(usize);
+Impl!(char);
+Impl!(bool);
+Impl!(IoxAtomicBool);
+Impl!(IoxAtomicU8);
+Impl!(IoxAtomicU16);
+Impl!(IoxAtomicU32);
+Impl!(IoxAtomicU64);
+Impl!(IoxAtomicI8);
+Impl!(IoxAtomicI16);
+Impl!(IoxAtomicI32);
+Impl!(IoxAtomicI64);
+Impl!(IoxAtomicIsize);
+Impl!(IoxAtomicUsize);
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for [T; CAPACITY] {
+    fn type_layout_hash() -> u64 {
+        TypeLayoutHasher::new()
+            .add_str("[T; CAPACITY]")
+            .add(CAPACITY as u64)
+            .add(T::type_layout_hash())
+            .value()
+    }
+}
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for Option<T> {
+    fn type_layout_hash() -> u64 {
+        TypeLayoutHasher::new()
+            .add_str("Option<T>")
+            .add(core::mem::size_of::<Self>() as u64)
+            .add(core::mem::align_of::<Self>() as u64)
+            .add(T::type_layout_hash())
+            .value()
+    }
+}
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for core::mem::MaybeUninit<T> {
+    fn type_layout_hash() -> u64 {
+        TypeLayoutHasher::new()
+            .add_str("MaybeUninit<T>")
+            .add(T::type_layout_hash())
+            .value()
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::hash::Hasher;
+
+use iceoryx2_bb_elementary::zero_copy_send::TypeLayoutHasher;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn type_layout_hasher_computes_fnv1a() {
+    assert_that!(TypeLayoutHasher::new().value(), eq 0xcbf29ce484222325);
+    assert_that!(TypeLayoutHasher::new().add_str("a").value(), eq 0xaf63dc4c8601ec8c);
+    assert_that!(TypeLayoutHasher::new().add_str("foobar").value(), eq 0x85944171f73967e8);
+}
+
+#[test]
+fn type_layout_hasher_as_hasher_is_equal_to_const_api() {
+    let mut sut = TypeLayoutHasher::new();
+    sut.write(b"foo");
+    sut.write_u64(1234);
+
+    let expected = TypeLayoutHasher::new().add_str("foo").add(1234).value();
+    assert_that!(sut.finish(), eq expected);
+}
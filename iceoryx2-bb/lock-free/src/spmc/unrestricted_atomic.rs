@@ -111,6 +111,17 @@ impl<T: Copy> UnrestrictedAtomic<T> {
         }
     }
 
+    #[doc(hidden)]
+    /// Releases the producer so that it can be acquired again. Required when the owner of a
+    /// [`Producer`] stored in shared memory died without releasing it.
+    ///
+    /// # Safety
+    ///
+    ///  * no [`Producer`] of this [`UnrestrictedAtomic`] is alive
+    pub unsafe fn __internal_release_producer(&self) {
+        self.has_producer.store(true, Ordering::Relaxed);
+    }
+
     fn store(&self, new_value: T) {
         let write_cell = self.write_cell.load(Ordering::Relaxed);
         unsafe {
@@ -0,0 +1,330 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstracts the Linux epoll API. Can be used to wait on multiple objects which implement the
+//! [`SynchronousMultiplexing`] trait. In contrast to the
+//! [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet) it is not restricted by
+//! `FD_SETSIZE` and the cost of a wait call does not depend on the number of attachments.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use iceoryx2_bb_posix::epoll::*;
+//! use iceoryx2_bb_posix::file_descriptor_set::FileEvent;
+//! use iceoryx2_bb_posix::unix_datagram_socket::*;
+//! use std::time::Duration;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! let socket_name = FilePath::new(b"some_socket").unwrap();
+//!
+//! let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+//!     .creation_mode(CreationMode::PurgeAndCreate)
+//!     .create()
+//!     .unwrap();
+//!
+//! let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+//!     .create()
+//!     .unwrap();
+//!
+//! let epoll = EpollBuilder::new().create().unwrap();
+//! let _guard = epoll.add(&sut_receiver, FileEvent::Read).unwrap();
+//! let send_data: Vec<u8> = vec![1u8, 3u8, 3u8, 7u8, 13u8, 37u8];
+//! sut_sender.try_send(send_data.as_slice()).unwrap();
+//!
+//! // in some other process
+//! epoll.timed_wait(Duration::from_secs(1),
+//!     |fd| println!("Fd was triggered {}", unsafe { fd.native_handle() })).unwrap();
+//! ```
+
+use std::{fmt::Debug, sync::atomic::Ordering, time::Duration};
+
+use crate::{
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::{FileEvent, SynchronousMultiplexing},
+    system_configuration::ProcessResourceLimit,
+};
+use iceoryx2_bb_log::warn;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollCreateError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollAddError {
+    AlreadyAttached,
+    CapacityExceeded,
+    InsufficientMemory,
+    UnsupportedFileDescriptor,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollWaitError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+/// Detaches the attached [`FileDescriptor`] from the [`Epoll`] when it goes out of scope.
+pub struct EpollGuard<'epoll, 'fd> {
+    epoll: &'epoll Epoll,
+    fd: &'fd FileDescriptor,
+}
+
+impl<'epoll, 'fd> EpollGuard<'epoll, 'fd> {
+    pub fn file_descriptor(&self) -> &'fd FileDescriptor {
+        self.fd
+    }
+}
+
+impl Drop for EpollGuard<'_, '_> {
+    fn drop(&mut self) {
+        self.epoll.remove(unsafe { self.fd.native_handle() })
+    }
+}
+
+/// Creates a new [`Epoll`].
+#[derive(Debug)]
+pub struct EpollBuilder {
+    has_close_on_exec: bool,
+}
+
+impl Default for EpollBuilder {
+    fn default() -> Self {
+        Self {
+            has_close_on_exec: true,
+        }
+    }
+}
+
+impl EpollBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines if the underlying epoll file descriptor is closed when the process calls
+    /// `exec`. Enabled by default.
+    pub fn set_close_on_exec(mut self, value: bool) -> Self {
+        self.has_close_on_exec = value;
+        self
+    }
+
+    /// Creates the [`Epoll`] or returns an [`EpollCreateError`] on failure.
+    pub fn create(self) -> Result<Epoll, EpollCreateError> {
+        let flags = if self.has_close_on_exec {
+            posix::EPOLL_CLOEXEC
+        } else {
+            0
+        };
+
+        let raw_fd = unsafe { posix::epoll_create1(flags) };
+        if let Some(epoll_fd) = FileDescriptor::new(raw_fd) {
+            return Ok(Epoll {
+                epoll_fd,
+                len: IoxAtomicUsize::new(0),
+            });
+        }
+
+        let msg = "Unable to create epoll file descriptor";
+        handle_errno!(EpollCreateError, from self,
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of file descriptors was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system wide limit of file descriptors was reached.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+/// The Linux epoll abstraction to wait on multiple objects which implement the
+/// [`SynchronousMultiplexing`] trait. The attachments are level-triggered.
+pub struct Epoll {
+    epoll_fd: FileDescriptor,
+    len: IoxAtomicUsize,
+}
+
+impl Debug for Epoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Epoll {{ epoll_fd: {:?}, len: {} }}",
+            self.epoll_fd,
+            self.len.load(Ordering::Relaxed)
+        )
+    }
+}
+
+impl FileDescriptorBased for Epoll {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.epoll_fd
+    }
+}
+
+impl Epoll {
+    /// Returns the maximum capacity of the [`Epoll`]. Every attachment is a file descriptor,
+    /// therefore the capacity is bound by the maximum number of open file descriptors of the
+    /// process.
+    pub fn capacity() -> usize {
+        ProcessResourceLimit::MaxNumberOfOpenFileDescriptors.soft_limit() as usize
+    }
+
+    /// Returns the number of attached [`FileDescriptor`]s
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if the [`Epoll`] is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Attaches an object and waits for the provided [`FileEvent`]. As long as the returned
+    /// [`EpollGuard`] lives the object stays attached.
+    pub fn add<'epoll, 'fd, F: SynchronousMultiplexing>(
+        &'epoll self,
+        fd: &'fd F,
+        event: FileEvent,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAddError> {
+        let fd = fd.file_descriptor();
+        let raw_fd = unsafe { fd.native_handle() };
+        let mut epoll_event = posix::epoll_event {
+            events: Self::to_epoll_events(event),
+            data: posix::epoll_data_t { fd: raw_fd },
+        };
+
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_ADD,
+                raw_fd,
+                &mut epoll_event,
+            )
+        } == 0
+        {
+            self.len.fetch_add(1, Ordering::Relaxed);
+            return Ok(EpollGuard { epoll: self, fd });
+        }
+
+        let msg = "Unable to add file descriptor";
+        handle_errno!(EpollAddError, from self,
+            Errno::EEXIST => (AlreadyAttached, "{} {:?} since it is already attached.", msg, fd),
+            Errno::ENOSPC => (CapacityExceeded, "{} {:?} since the system limit of epoll watches was reached.", msg, fd),
+            Errno::ENOMEM => (InsufficientMemory, "{} {:?} due to insufficient memory.", msg, fd),
+            Errno::EPERM => (UnsupportedFileDescriptor, "{} {:?} since the file descriptor does not support epoll.", msg, fd),
+            v => (UnknownError(v as i32), "{} {:?} since an unknown error occurred ({}).", msg, fd, v)
+        );
+    }
+
+    fn remove(&self, value: i32) {
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_DEL,
+                value,
+                std::ptr::null_mut(),
+            )
+        } == -1
+        {
+            warn!(from self, "Unable to remove file descriptor {} ({:?}). The file descriptor was maybe already closed.",
+                value, Errno::get());
+        }
+
+        self.len.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn to_epoll_events(event: FileEvent) -> u32 {
+        match event {
+            FileEvent::Read => posix::EPOLLIN,
+            FileEvent::Write => posix::EPOLLOUT,
+            FileEvent::Exceptional => posix::EPOLLPRI,
+            FileEvent::ReadWrite => posix::EPOLLIN | posix::EPOLLOUT,
+            FileEvent::ReadExceptional => posix::EPOLLIN | posix::EPOLLPRI,
+            FileEvent::WriteExceptional => posix::EPOLLOUT | posix::EPOLLPRI,
+            FileEvent::ReadWriteExceptional => posix::EPOLLIN | posix::EPOLLOUT | posix::EPOLLPRI,
+        }
+    }
+
+    /// Checks if an attached object was triggered and calls for every triggered
+    /// [`FileDescriptor`] the provided callback. Does not block.
+    pub fn try_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        self.wait(0, fd_callback)
+    }
+
+    /// Waits until either the timeout has passed or an attached object was triggered. For
+    /// every triggered [`FileDescriptor`] the provided callback is called.
+    pub fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout: Duration,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        // round up so that at least the provided timeout is waited
+        let timeout_in_ms = timeout.as_nanos().div_ceil(1_000_000);
+        self.wait(
+            timeout_in_ms.min(posix::int::MAX as u128) as posix::int,
+            fd_callback,
+        )
+    }
+
+    /// Blocks until an attached object was triggered. For every triggered [`FileDescriptor`]
+    /// the provided callback is called.
+    pub fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        self.wait(-1, fd_callback)
+    }
+
+    fn wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout_in_ms: posix::int,
+        mut fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        let max_events = self.len().clamp(1, posix::int::MAX as usize);
+        let mut events: Vec<posix::epoll_event> = Vec::with_capacity(max_events);
+
+        let number_of_events = unsafe {
+            posix::epoll_wait(
+                self.epoll_fd.native_handle(),
+                events.as_mut_ptr(),
+                max_events as posix::int,
+                timeout_in_ms,
+            )
+        };
+
+        if number_of_events == -1 {
+            let msg = "Failure while waiting for epoll events";
+            handle_errno!(EpollWaitError, from self,
+                fatal Errno::EBADF => ("This should never happen! {} since the epoll file descriptor is invalid.", msg),
+                Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        unsafe { events.set_len(number_of_events as usize) };
+        for event in &events {
+            let raw_fd = unsafe { event.data.fd };
+            let fd = FileDescriptor::non_owning_new(raw_fd).unwrap();
+            fd_callback(&fd);
+        }
+
+        Ok(())
+    }
+}
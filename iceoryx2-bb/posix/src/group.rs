@@ -134,6 +134,38 @@ impl Group {
         Self::from_gid(unsafe { posix::getgid() })
     }
 
+    /// Returns the gids of the supplementary groups of the process. In contrast to
+    /// [`Group::members()`] it contains also the groups that are not listed in the group
+    /// database, like the ones that are assigned by a login service.
+    pub fn supplementary_gids_of_self() -> Result<Vec<u32>, GroupError> {
+        let msg = "Unable to acquire the supplementary groups of the process";
+        let origin = "Group::supplementary_gids_of_self()";
+
+        let number_of_groups = unsafe { posix::getgroups(0, core::ptr::null_mut()) };
+        if number_of_groups < 0 {
+            fail!(from origin, with GroupError::UnknownError(Errno::get() as i32),
+                "{} since the number of groups could not be acquired ({:?}).", msg, Errno::get());
+        }
+
+        let mut gids = vec![0 as posix::gid_t; number_of_groups as usize];
+        let number_of_groups = unsafe { posix::getgroups(number_of_groups, gids.as_mut_ptr()) };
+        if number_of_groups < 0 {
+            match Errno::get() {
+                Errno::EINVAL => {
+                    fail!(from origin, with GroupError::InsufficientBufferSize,
+                        "{} since the groups changed while they were acquired.", msg);
+                }
+                v => {
+                    fail!(from origin, with GroupError::UnknownError(v as i32),
+                        "{} since an unknown error occurred ({:?}).", msg, v);
+                }
+            }
+        }
+
+        gids.truncate(number_of_groups as usize);
+        Ok(gids)
+    }
+
     /// Create an group object from a given gid. If the gid does not exist an error will be
     /// returned.
     pub fn from_gid(gid: u32) -> Result<Group, GroupError> {
@@ -45,6 +45,8 @@ pub mod udp_socket;
 #[macro_use]
 pub mod handle_errno;
 pub mod directory;
+#[cfg(target_os = "linux")]
+pub mod epoll;
 pub mod file;
 pub mod file_descriptor;
 pub mod file_descriptor_set;
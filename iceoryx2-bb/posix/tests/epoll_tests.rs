@@ -0,0 +1,157 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_os = "linux")]
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_posix::config::*;
+use iceoryx2_bb_posix::epoll::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::file_descriptor_set::{FileDescriptorSet, FileEvent};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::unix_datagram_socket::*;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::assert_that;
+use std::time::Duration;
+use std::time::Instant;
+
+static TIMEOUT: Duration = Duration::from_millis(10);
+
+fn generate_socket_name() -> FilePath {
+    let mut file = FileName::new(b"epoll_tests").unwrap();
+    file.push_bytes(
+        UniqueSystemId::new()
+            .unwrap()
+            .value()
+            .to_string()
+            .as_bytes(),
+    )
+    .unwrap();
+
+    FilePath::from_path_and_file(&test_directory(), &file).unwrap()
+}
+
+fn create_socket_pair() -> (UnixDatagramReceiver, UnixDatagramSender) {
+    let socket_name = generate_socket_name();
+
+    let receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    (receiver, sender)
+}
+
+#[test]
+fn epoll_timed_wait_blocks_at_least_timeout() {
+    let (sut_receiver, _sut_sender) = create_socket_pair();
+
+    let sut = EpollBuilder::new().create().unwrap();
+    let _guard = sut.add(&sut_receiver, FileEvent::Read).unwrap();
+
+    let start = Instant::now();
+
+    let mut result = vec![];
+    sut.timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    assert_that!(result, len 0);
+}
+
+#[test]
+fn epoll_add_and_remove_works() {
+    let (sut_receiver_1, _) = create_socket_pair();
+    let (sut_receiver_2, _) = create_socket_pair();
+
+    let sut = EpollBuilder::new().create().unwrap();
+    assert_that!(sut.is_empty(), eq true);
+
+    let guard_1 = sut.add(&sut_receiver_1, FileEvent::Read).unwrap();
+    assert_that!(sut.len(), eq 1);
+    let guard_2 = sut.add(&sut_receiver_2, FileEvent::Read).unwrap();
+    assert_that!(sut.len(), eq 2);
+
+    drop(guard_1);
+    assert_that!(sut.len(), eq 1);
+    drop(guard_2);
+    assert_that!(sut.is_empty(), eq true);
+}
+
+#[test]
+fn epoll_adding_same_file_descriptor_twice_fails() {
+    let (sut_receiver, _) = create_socket_pair();
+
+    let sut = EpollBuilder::new().create().unwrap();
+    let _guard = sut.add(&sut_receiver, FileEvent::Read).unwrap();
+
+    let result = sut.add(&sut_receiver, FileEvent::Read);
+    assert_that!(result.err(), eq Some(EpollAddError::AlreadyAttached));
+    assert_that!(sut.len(), eq 1);
+}
+
+#[test]
+fn epoll_wait_reports_only_triggered_file_descriptors() {
+    let (sut_receiver_1, sut_sender_1) = create_socket_pair();
+    let (sut_receiver_2, _sut_sender_2) = create_socket_pair();
+
+    let sut = EpollBuilder::new().create().unwrap();
+    let _guard_1 = sut.add(&sut_receiver_1, FileEvent::Read).unwrap();
+    let _guard_2 = sut.add(&sut_receiver_2, FileEvent::Read).unwrap();
+
+    sut_sender_1.try_send(&[1u8, 2u8, 3u8]).unwrap();
+
+    let mut result = vec![];
+    sut.try_wait(|fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(result, len 1);
+    assert_that!(result[0], eq unsafe { sut_receiver_1.file_descriptor().native_handle() });
+
+    let mut result = vec![];
+    sut.blocking_wait(|fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+    assert_that!(result, len 1);
+}
+
+#[test]
+fn epoll_supports_more_attachments_than_file_descriptor_set() {
+    let number_of_attachments = FileDescriptorSet::capacity() + 1;
+    if Epoll::capacity() < 2 * number_of_attachments + 64 {
+        // the sockets of the test would exceed the file descriptor limit of the process
+        return;
+    }
+
+    let mut sockets = vec![];
+    for _ in 0..number_of_attachments {
+        sockets.push(create_socket_pair());
+    }
+
+    let sut = EpollBuilder::new().create().unwrap();
+    let mut guards = vec![];
+    for (receiver, _) in &sockets {
+        guards.push(sut.add(receiver, FileEvent::Read).unwrap());
+    }
+    assert_that!(sut.len(), eq number_of_attachments);
+
+    sockets.last().unwrap().1.try_send(&[1u8]).unwrap();
+
+    let mut result = vec![];
+    sut.try_wait(|fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+    assert_that!(result, len 1);
+}
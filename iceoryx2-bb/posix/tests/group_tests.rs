@@ -54,3 +54,12 @@ fn group_as_works() {
     assert_that!(root_1.password().len(), ge 0);
     assert_that!(root_1.members().len(), ge 0);
 }
+
+#[test]
+fn group_supplementary_gids_of_self_works() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    let sut = Group::supplementary_gids_of_self();
+
+    assert_that!(sut, is_ok);
+}
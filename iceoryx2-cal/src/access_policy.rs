@@ -0,0 +1,338 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Defines which users and groups are allowed to access the underlying resources of a
+//! concept. The [`AccessPolicy`] can be set with
+//! [`NamedConceptConfiguration::access_policy()`](crate::named_concept::NamedConceptConfiguration::access_policy())
+//! and is applied by every concept that is based on file-system resources. The owner of a
+//! resource has always full access, the [`AccessPolicy`] defines who else is allowed to access it.
+//!
+//! The access rights are enforced by the file-system permissions of the resources. Resources
+//! that are created later by another user that is granted access, use the policy returned by
+//! [`AccessPolicy::applicable_by()`] since only privileged processes can assign an arbitrary
+//! owner or owning group.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_cal::access_policy::*;
+//! use iceoryx2_bb_posix::permission::Permission;
+//!
+//! // the group 1000 can read and write, additionally the user 1234 gains access
+//! let policy = AccessPolicy::new()
+//!     .group(1000)
+//!     .permission(Permission::GROUP_READ | Permission::GROUP_WRITE)
+//!     .allow_user(1234);
+//! ```
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{
+    file_descriptor::FileDescriptorManagement, group::Group, ownership::OwnershipBuilder,
+    permission::Permission, user::User,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum AccessPolicyApplyError {
+    InsufficientPermissions,
+    /// The policy grants additional users or groups access but the `acl` feature is disabled.
+    AccessControlListNotSupported,
+    InternalError,
+}
+
+impl std::fmt::Display for AccessPolicyApplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "AccessPolicyApplyError::{:?}", self)
+    }
+}
+
+impl std::error::Error for AccessPolicyApplyError {}
+
+/// An additional user or group that is granted access via an access control list.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum AclEntry {
+    /// The user with the given uid
+    User(u32),
+    /// All members of the group with the given gid
+    Group(u32),
+}
+
+/// Defines the owner, the owning group and the access rights of the resources of a concept.
+/// The default policy does not change anything, the resources are owned by the creating
+/// process and are only accessible by its user.
+///
+/// Additional users and groups via [`AccessPolicy::allow_user()`] and
+/// [`AccessPolicy::allow_group()`] are applied as access control list to the underlying
+/// resources and require the `acl` feature, without it applying such a policy fails with
+/// [`AccessPolicyApplyError::AccessControlListNotSupported`]. Concepts based on socket files,
+/// like [`crate::event::unix_datagram_socket`], apply only the permission.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AccessPolicy {
+    owner: Option<u32>,
+    group: Option<u32>,
+    #[serde(with = "permission_serde")]
+    permission: Permission,
+    acl: Vec<AclEntry>,
+}
+
+mod permission_serde {
+    use iceoryx2_bb_posix::permission::Permission;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Permission,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(value.bits() as _)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Permission, D::Error> {
+        let bits = u32::deserialize(deserializer)?;
+        Ok(Permission::from_bits_truncate(bits as _))
+    }
+}
+
+impl AccessPolicy {
+    /// Creates a new [`AccessPolicy`] that grants access only to the owner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the uid of the user that owns the resources. Only privileged processes can
+    /// transfer the ownership to another user.
+    pub fn owner(mut self, uid: u32) -> Self {
+        self.owner = Some(uid);
+        self
+    }
+
+    /// Defines the gid of the group that owns the resources. The creating user must be a
+    /// member of the group.
+    pub fn group(mut self, gid: u32) -> Self {
+        self.group = Some(gid);
+        self
+    }
+
+    /// Defines the access rights of the owning group and of others. Only the
+    /// [`Permission::GROUP_ALL`] and [`Permission::OTHERS_ALL`] bits are considered, the owner
+    /// has always full access.
+    pub fn permission(mut self, value: Permission) -> Self {
+        self.permission = value & (Permission::GROUP_ALL | Permission::OTHERS_ALL);
+        self
+    }
+
+    /// Grants the user with the given uid read and write access.
+    pub fn allow_user(mut self, uid: u32) -> Self {
+        self.acl.push(AclEntry::User(uid));
+        self
+    }
+
+    /// Grants all members of the group with the given gid read and write access.
+    pub fn allow_group(mut self, gid: u32) -> Self {
+        self.acl.push(AclEntry::Group(gid));
+        self
+    }
+
+    /// Returns the uid of the owner, if one was defined
+    pub fn get_owner(&self) -> Option<u32> {
+        self.owner
+    }
+
+    /// Returns the gid of the owning group, if one was defined
+    pub fn get_group(&self) -> Option<u32> {
+        self.group
+    }
+
+    /// Returns the access rights of the owning group and of others
+    pub fn get_permission(&self) -> Permission {
+        self.permission
+    }
+
+    /// Returns all additional users and groups that are granted access
+    pub fn get_acl(&self) -> &[AclEntry] {
+        &self.acl
+    }
+
+    /// Returns true when the policy is the default policy that does not change the
+    /// ownership or access rights of the resources.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the policy the [`User`] can apply to the resources it creates. A
+    /// non-privileged user can neither transfer the ownership of a resource to another user
+    /// nor assign a group it is not a member of. Therefore, such an owner or owning group is
+    /// removed from the policy and granted access via the access control list instead, which
+    /// requires the `acl` feature. Without it, applying the returned policy fails with
+    /// [`AccessPolicyApplyError::AccessControlListNotSupported`].
+    pub fn applicable_by(&self, user: &User) -> Self {
+        let mut policy = self.clone();
+        if user.uid() == 0 {
+            return policy;
+        }
+
+        if let Some(uid) = self.owner {
+            if uid != user.uid() {
+                policy.owner = None;
+                policy.acl.push(AclEntry::User(uid));
+            }
+        }
+
+        if let Some(gid) = self.group {
+            if !is_member_of(user, gid) {
+                policy.group = None;
+                policy.acl.push(AclEntry::Group(gid));
+            }
+        }
+
+        policy
+    }
+
+    /// Returns true when the [`User`] is granted read and write access by the policy,
+    /// otherwise false. When no owner or owning group is defined the user is not granted
+    /// access via ownership. The access control list grants access only when the `acl`
+    /// feature is enabled since it is not applied to the resources otherwise.
+    pub fn grants_access_to(&self, user: &User) -> bool {
+        let read_write = |read: Permission, write: Permission| {
+            self.permission.contains(read) && self.permission.contains(write)
+        };
+        let is_member_of = |gid: u32| is_member_of(user, gid);
+
+        if self.owner == Some(user.uid()) {
+            return true;
+        }
+
+        if read_write(Permission::OTHERS_READ, Permission::OTHERS_WRITE) {
+            return true;
+        }
+
+        if let Some(gid) = self.group {
+            if read_write(Permission::GROUP_READ, Permission::GROUP_WRITE) && is_member_of(gid) {
+                return true;
+            }
+        }
+
+        cfg!(feature = "acl")
+            && self.acl.iter().any(|entry| match entry {
+                AclEntry::User(uid) => *uid == user.uid(),
+                AclEntry::Group(gid) => is_member_of(*gid),
+            })
+    }
+
+    /// Applies the policy to a file-descriptor based resource. `owner_permission` are the
+    /// access rights the owner requires. Users and groups that are granted access receive
+    /// at most the same rights.
+    pub(crate) fn apply<T: FileDescriptorManagement>(
+        &self,
+        resource: &mut T,
+        owner_permission: Permission,
+    ) -> Result<(), AccessPolicyApplyError> {
+        let msg = "Unable to apply access policy";
+        if !cfg!(feature = "acl") && !self.acl.is_empty() {
+            fail!(from self, with AccessPolicyApplyError::AccessControlListNotSupported,
+                "{} since it grants access to {:?} via an access control list which requires the \"acl\" feature.",
+                msg, self.acl);
+        }
+
+        if self.owner.is_some() || self.group.is_some() {
+            let mut ownership = OwnershipBuilder::new();
+            if let Some(uid) = self.owner {
+                ownership = ownership.uid(uid);
+            }
+            if let Some(gid) = self.group {
+                ownership = ownership.gid(gid);
+            }
+
+            fail!(from self, when resource.set_ownership(ownership.create()),
+                map iceoryx2_bb_posix::file::FileSetOwnerError::InsufficientPermissions => AccessPolicyApplyError::InsufficientPermissions,
+                unmatched AccessPolicyApplyError::InternalError,
+                "{} since the ownership could not be changed.", msg);
+        }
+
+        fail!(from self, when resource.set_permission(self.permission_for(owner_permission)),
+            map iceoryx2_bb_posix::file::FileSetPermissionError::InsufficientPermissions => AccessPolicyApplyError::InsufficientPermissions,
+            unmatched AccessPolicyApplyError::InternalError,
+            "{} since the permissions could not be changed.", msg);
+
+        #[cfg(feature = "acl")]
+        if !self.acl.is_empty() {
+            self.apply_acl(resource, owner_permission)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the permissions of a resource whose owner has `owner_permission` under the
+    /// policy.
+    pub(crate) fn permission_for(&self, owner_permission: Permission) -> Permission {
+        let granted = owner_permission & Permission::OWNER_ALL;
+        let group = Permission::from_bits_truncate(granted.bits() >> 3);
+        let others = Permission::from_bits_truncate(granted.bits() >> 6);
+
+        owner_permission | (self.permission & (group | others))
+    }
+
+    #[cfg(feature = "acl")]
+    fn apply_acl<T: FileDescriptorManagement>(
+        &self,
+        resource: &T,
+        owner_permission: Permission,
+    ) -> Result<(), AccessPolicyApplyError> {
+        use iceoryx2_bb_posix::access_control_list::*;
+
+        let msg = "Unable to apply access control list";
+        let permission = if owner_permission.contains(Permission::OWNER_WRITE) {
+            AclPermission::ReadWrite
+        } else {
+            AclPermission::Read
+        };
+        let fd = unsafe { resource.file_descriptor().native_handle() };
+
+        let mut acl = fail!(from self, when AccessControlList::from_file_descriptor(fd),
+            with AccessPolicyApplyError::InternalError,
+            "{} since the current access control list could not be acquired.", msg);
+
+        for entry in &self.acl {
+            let result = match entry {
+                AclEntry::User(uid) => acl.add_user(*uid, permission),
+                AclEntry::Group(gid) => acl.add_group(*gid, permission),
+            };
+            fail!(from self, when result, with AccessPolicyApplyError::InternalError,
+                "{} since the entry {:?} could not be added.", msg, entry);
+        }
+
+        fail!(from self, when acl.set(Acl::MaxAccessRightsForNonOwners, permission),
+            with AccessPolicyApplyError::InternalError,
+            "{} since the maximum access rights for non owners could not be set.", msg);
+
+        fail!(from self, when acl.apply_to_file_descriptor(fd),
+            map AccessControlListApplyError::InsufficientPermissions => AccessPolicyApplyError::InsufficientPermissions,
+            unmatched AccessPolicyApplyError::InternalError,
+            "{} since it could not be applied to the resource.", msg);
+
+        Ok(())
+    }
+}
+
+fn is_member_of(user: &User, gid: u32) -> bool {
+    let is_process_member_of = || {
+        User::from_self().is_ok_and(|myself| myself.uid() == user.uid())
+            && Group::supplementary_gids_of_self().is_ok_and(|gids| gids.contains(&gid))
+    };
+
+    user.gid() == gid
+        || Group::from_gid(gid)
+            .map(|group| group.members().contains(user.name()))
+            .unwrap_or(false)
+        || is_process_member_of()
+}
@@ -136,6 +136,19 @@ pub trait DynamicStorageBuilder<'builder, T: Send + Sync, D: DynamicStorage<T>>:
     /// exist or is not initialized it fails.
     fn open(self) -> Result<D, DynamicStorageOpenError>;
 
+    /// Opens a [`DynamicStorage`] like [`DynamicStorageBuilder::open()`] but maps the underlying
+    /// memory read-only when the concept supports memory protection. Concepts without memory
+    /// protection open it with read-write access.
+    ///
+    /// # Safety
+    ///
+    ///  * The underlying value must not be modified via [`DynamicStorage::get()`], for instance
+    ///    by storing into an atomic, otherwise the process is terminated with a segmentation
+    ///    fault.
+    ///  * The ownership must not be acquired since the value is dropped in place on removal.
+    ///
+    unsafe fn open_read_only(self) -> Result<D, DynamicStorageOpenError>;
+
     /// Opens the [`DynamicStorage`] if it exists, otherwise it creates it.
     fn open_or_create(self, initial_value: T) -> Result<D, DynamicStorageOpenOrCreateError>;
 }
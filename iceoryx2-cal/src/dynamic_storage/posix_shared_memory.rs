@@ -39,6 +39,7 @@
 //! storage.get().store(456, Ordering::Relaxed);
 //!
 //! ```
+use crate::access_policy::{AccessPolicy, AccessPolicyApplyError};
 pub use crate::dynamic_storage::*;
 use crate::static_storage::file::NamedConceptConfiguration;
 use crate::static_storage::file::NamedConceptRemoveError;
@@ -47,7 +48,6 @@ use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::warn;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::directory::*;
-use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
 use iceoryx2_bb_posix::shared_memory::*;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
@@ -78,6 +78,7 @@ pub struct Configuration<T: Send + Sync + Debug> {
     suffix: FileName,
     prefix: FileName,
     path: Path,
+    access_policy: AccessPolicy,
     _data: PhantomData<T>,
 }
 
@@ -87,6 +88,7 @@ impl<T: Send + Sync + Debug> Clone for Configuration<T> {
             suffix: self.suffix,
             prefix: self.prefix,
             path: self.path,
+            access_policy: self.access_policy.clone(),
             _data: PhantomData,
         }
     }
@@ -104,6 +106,7 @@ impl<T: Send + Sync + Debug> Default for Configuration<T> {
             path: Storage::<()>::default_path_hint(),
             suffix: Storage::<()>::default_suffix(),
             prefix: Storage::<()>::default_prefix(),
+            access_policy: AccessPolicy::default(),
             _data: PhantomData,
         }
     }
@@ -139,6 +142,11 @@ impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
         &self.path
     }
 
+    fn access_policy(mut self, value: &AccessPolicy) -> Self {
+        self.access_policy = value.clone();
+        self
+    }
+
     fn path_for(&self, value: &FileName) -> iceoryx2_bb_system_types::file_path::FilePath {
         self.path_for_with_type(value)
     }
@@ -168,7 +176,7 @@ impl<'builder, T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Build
 }
 
 impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
-    fn open_impl(&self) -> Result<Storage<T>, DynamicStorageOpenError> {
+    fn open_impl(&self, access_mode: AccessMode) -> Result<Storage<T>, DynamicStorageOpenError> {
         let msg = "Failed to open ";
 
         let full_name = self.config.path_for(&self.storage_name).file_name();
@@ -178,7 +186,7 @@ impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
 
         let mut elapsed_time = Duration::ZERO;
         let shm = loop {
-            match SharedMemoryBuilder::new(&full_name).open_existing(AccessMode::ReadWrite) {
+            match SharedMemoryBuilder::new(&full_name).open_existing(access_mode) {
                 Ok(v) => break v,
                 Err(SharedMemoryCreationError::DoesNotExist) => {
                     fail!(from self, with DynamicStorageOpenError::DoesNotExist,
@@ -319,11 +327,11 @@ impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
         //////////////////////////////////////////
         unsafe { (*version_ptr).store(PackageVersion::get().to_u64(), Ordering::SeqCst) };
 
-        if let Err(e) = shm.set_permission(FINAL_PERMISSIONS) {
-            fail!(from origin, with DynamicStorageCreateError::InternalError,
-                "{} since the final permissions could not be applied to the underlying shared memory ({:?}).",
-                msg, e);
-        }
+        fail!(from origin, when self.config.access_policy.apply(&mut shm, FINAL_PERMISSIONS),
+            map AccessPolicyApplyError::InsufficientPermissions => DynamicStorageCreateError::InsufficientPermissions,
+            unmatched DynamicStorageCreateError::InternalError,
+            "{} since the access policy with the final permissions could not be applied to the underlying shared memory.",
+            msg);
 
         Ok(Storage {
             shm,
@@ -365,7 +373,11 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
     }
 
     fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
-        self.open_impl()
+        self.open_impl(AccessMode::ReadWrite)
+    }
+
+    unsafe fn open_read_only(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        self.open_impl(AccessMode::Read)
     }
 
     fn open_or_create(
@@ -373,7 +385,7 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
         initial_value: T,
     ) -> Result<Storage<T>, DynamicStorageOpenOrCreateError> {
         loop {
-            match self.open_impl() {
+            match self.open_impl(AccessMode::ReadWrite) {
                 Ok(storage) => return Ok(storage),
                 Err(DynamicStorageOpenError::DoesNotExist) => match self.create_impl() {
                     Ok(shm) => {
@@ -465,6 +465,11 @@ impl<'builder, T: Send + Sync + Debug + 'static> DynamicStorageBuilder<'builder,
         self.open_impl(&mut guard)
     }
 
+    unsafe fn open_read_only(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        // process local memory cannot be protected, it is opened with read-write access
+        self.open()
+    }
+
     fn create(mut self, initial_value: T) -> Result<Storage<T>, DynamicStorageCreateError> {
         let msg = "Failed to create dynamic storage";
         let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
@@ -12,12 +12,13 @@
 
 use std::mem::MaybeUninit;
 
+use crate::access_policy::AccessPolicy;
 pub use crate::event::*;
 use crate::static_storage::file::NamedConceptConfiguration;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::{
     file_descriptor::FileDescriptorBased, file_descriptor_set::SynchronousMultiplexing,
-    unix_datagram_socket::*,
+    permission::Permission, unix_datagram_socket::*,
 };
 pub use iceoryx2_bb_system_types::file_name::FileName;
 
@@ -28,6 +29,7 @@ pub struct Configuration {
     suffix: FileName,
     prefix: FileName,
     path: Path,
+    permission: Permission,
 }
 
 impl Default for Configuration {
@@ -36,6 +38,7 @@ impl Default for Configuration {
             path: EventImpl::default_path_hint(),
             suffix: EventImpl::default_suffix(),
             prefix: EventImpl::default_prefix(),
+            permission: Permission::OWNER_ALL,
         }
     }
 }
@@ -67,11 +70,21 @@ impl NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path
     }
+
+    // the socket file can only be restricted via its permissions, the owner and the access
+    // control list of the policy are not applied
+    fn access_policy(mut self, value: &AccessPolicy) -> Self {
+        self.permission = value.permission_for(Permission::OWNER_ALL);
+        self
+    }
 }
 
 impl From<Configuration> for crate::communication_channel::unix_datagram::Configuration {
     fn from(value: Configuration) -> Self {
-        Self::default().suffix(&value.suffix).path_hint(&value.path)
+        Self::default()
+            .prefix(&value.prefix)
+            .suffix(&value.suffix)
+            .path_hint(&value.path)
     }
 }
 
@@ -199,6 +212,10 @@ impl crate::event::NotifierBuilder<EventImpl> for NotifierBuilder {
                 fail!(from self, with NotifierCreateError::DoesNotExist,
                     "{} since the corresponding listener does not exist.", msg);
             }
+            Err(UnixDatagramSenderCreationError::ConnectionRefused) => {
+                fail!(from self, with NotifierCreateError::DoesNotExist,
+                    "{} since the corresponding listener is no longer alive and only its socket remains.", msg);
+            }
             Err(UnixDatagramSenderCreationError::InsufficientPermissions) => {
                 fail!(from self, with NotifierCreateError::InsufficientPermissions,
                     "{} due to insufficient permissions.", msg);
@@ -355,6 +372,7 @@ impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
         let full_name = self.config.path_for(&self.name);
         match UnixDatagramReceiverBuilder::new(&full_name)
             .creation_mode(CreationMode::CreateExclusive)
+            .permission(self.config.permission)
             .create()
         {
             Ok(r) => Ok(Listener {
@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod access_policy;
 pub mod communication_channel;
 pub mod dynamic_storage;
 pub mod event;
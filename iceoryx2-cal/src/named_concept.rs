@@ -12,6 +12,7 @@
 
 use std::fmt::Debug;
 
+use crate::access_policy::AccessPolicy;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_posix::directory::{Directory, DirectoryRemoveError};
@@ -70,6 +71,12 @@ pub trait NamedConceptConfiguration: Default + Clone + Debug {
     /// Returns the configurations path hint.
     fn get_path_hint(&self) -> &Path;
 
+    /// Defines the [`AccessPolicy`] that is applied to the underlying resources when they are
+    /// created. Concepts that are not based on file-system resources ignore it.
+    fn access_policy(self, _value: &AccessPolicy) -> Self {
+        self
+    }
+
     /// Returns the full path for a given value under the given configuration.
     fn path_for(&self, value: &FileName) -> FilePath {
         let mut path = *self.get_path_hint();
@@ -36,4 +36,8 @@ impl Serialize for Cdr {
                     with DeserializeError::InternalError, "Failed to deserialize object."),
         )
     }
+
+    fn unique_id() -> u8 {
+        1
+    }
 }
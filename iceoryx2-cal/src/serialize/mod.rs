@@ -38,6 +38,7 @@
 //! ```
 
 pub mod cdr;
+pub mod postcard;
 pub mod toml;
 
 /// Failure emitted by [`Serialize::serialize()`]
@@ -52,6 +53,12 @@ pub enum DeserializeError {
     InternalError,
 }
 
+/// The [`Serialize::unique_id()`]s that are reserved for the serializers of this module.
+pub const RESERVED_UNIQUE_IDS: core::ops::RangeInclusive<u8> = 0..=127;
+
+/// The [`Serialize::unique_id()`] of serializers that do not provide their own.
+pub const DEFAULT_UNIQUE_ID: u8 = u8::MAX;
+
 /// Serialize and deserialize constructs which implement [`serde::Serialize`] and
 /// [`serde::de::DeserializeOwned`]
 pub trait Serialize {
@@ -60,4 +67,12 @@ pub trait Serialize {
 
     /// Deserialize a value from a given byte slice
     fn deserialize<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T, DeserializeError>;
+
+    /// Returns the id that distinguishes the serializer from other serializers. The ids in
+    /// [`RESERVED_UNIQUE_IDS`] are reserved for the serializers of this module, a custom
+    /// serializer shall return an id outside of this range. The default implementation
+    /// returns [`DEFAULT_UNIQUE_ID`].
+    fn unique_id() -> u8 {
+        DEFAULT_UNIQUE_ID
+    }
 }
@@ -0,0 +1,42 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implements [`Serialize`] for postcard, a compact binary wire format,
+//! see: <https://docs.rs/postcard>.
+
+use crate::serialize::Serialize;
+use iceoryx2_bb_log::fail;
+
+use super::{DeserializeError, SerializeError};
+
+/// postcard [`Serialize`]
+pub struct Postcard {}
+
+impl Serialize for Postcard {
+    fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
+        Ok(
+            fail!(from "Postcard::serialize", when postcard::to_stdvec(value),
+                with SerializeError::InternalError, "Failed to serialize object" ),
+        )
+    }
+
+    fn deserialize<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T, DeserializeError> {
+        Ok(
+            fail!(from "Postcard::deserialize", when postcard::from_bytes::<T>(bytes),
+                    with DeserializeError::InternalError, "Failed to deserialize object."),
+        )
+    }
+
+    fn unique_id() -> u8 {
+        2
+    }
+}
@@ -47,4 +47,8 @@ impl Serialize for Toml {
             }
         }
     }
+
+    fn unique_id() -> u8 {
+        0
+    }
 }
@@ -17,7 +17,7 @@ use std::{alloc::Layout, fmt::Debug};
 use crate::dynamic_storage::*;
 pub use crate::shared_memory::*;
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
-use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_log::{debug, fail, warn};
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
@@ -29,6 +29,7 @@ use crate::static_storage::file::{
 
 #[doc(hidden)]
 pub mod details {
+    use crate::access_policy::AccessPolicy;
     use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
 
     use super::*;
@@ -120,6 +121,11 @@ pub mod details {
             self.dynamic_storage_config.get_path_hint()
         }
 
+        fn access_policy(mut self, value: &AccessPolicy) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.access_policy(value);
+            self
+        }
+
         fn path_for(&self, value: &FileName) -> FilePath {
             self.dynamic_storage_config.path_for(value)
         }
@@ -226,6 +232,13 @@ pub mod details {
                     "{} since the size is zero.", msg);
             }
 
+            // every offset into the payload must be encodable in a PointerOffset
+            if self.size - 1 > PointerOffset::max_offset() {
+                fail!(from self, with SharedMemoryCreateError::SizeExceedsMaxSupportedSize,
+                    "{} since the size of {} exceeds the max supported size of {}.",
+                    msg, self.size, PointerOffset::max_offset() + 1);
+            }
+
             let allocator_mgmt_size = Allocator::management_size(self.size, allocator_config);
 
             let storage = match Storage::Builder::new(&self.name)
@@ -265,19 +278,41 @@ pub mod details {
                 payload_start_address: get_payload_start_address(&storage),
                 storage,
                 name: self.name,
+                is_read_only: false,
                 _phantom: PhantomData,
             })
         }
 
         fn open(self) -> Result<Memory<Allocator, Storage>, SharedMemoryOpenError> {
+            self.open_impl(false)
+        }
+
+        fn open_read_only(self) -> Result<Memory<Allocator, Storage>, SharedMemoryOpenError> {
+            self.open_impl(true)
+        }
+    }
+
+    impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
+        Builder<Allocator, Storage>
+    {
+        fn open_impl(
+            self,
+            is_read_only: bool,
+        ) -> Result<Memory<Allocator, Storage>, SharedMemoryOpenError> {
             let msg = "Unable to open shared memory";
 
-            let storage = match Storage::Builder::new(&self.name)
+            let builder = Storage::Builder::new(&self.name)
                 .config(&self.config.dynamic_storage_config)
                 .has_ownership(false)
-                .timeout(self.timeout)
-                .open()
-            {
+                .timeout(self.timeout);
+            let storage = if is_read_only {
+                // the memory is never modified and the ownership is never acquired
+                unsafe { builder.open_read_only() }
+            } else {
+                builder.open()
+            };
+
+            let storage = match storage {
                 Ok(s) => s,
                 Err(DynamicStorageOpenError::DoesNotExist) => {
                     fail!(from self, with SharedMemoryOpenError::DoesNotExist,
@@ -314,6 +349,7 @@ pub mod details {
                 payload_start_address: get_payload_start_address(&storage),
                 name: self.name,
                 storage,
+                is_read_only,
                 _phantom: PhantomData,
             })
         }
@@ -324,6 +360,7 @@ pub mod details {
         storage: Storage,
         name: FileName,
         payload_start_address: usize,
+        is_read_only: bool,
         _phantom: PhantomData<Allocator>,
     }
 
@@ -396,7 +433,16 @@ pub mod details {
             self.storage.has_ownership()
         }
 
+        fn is_read_only(&self) -> bool {
+            self.is_read_only
+        }
+
         fn acquire_ownership(&self) {
+            if self.is_read_only {
+                warn!(from self, "The ownership of a read-only shared memory cannot be acquired.");
+                return;
+            }
+
             self.storage.acquire_ownership()
         }
 
@@ -413,6 +459,11 @@ pub mod details {
         }
 
         fn allocate(&self, layout: std::alloc::Layout) -> Result<ShmPointer, ShmAllocationError> {
+            if self.is_read_only {
+                fail!(from self, with ShmAllocationError::MemoryIsReadOnly,
+                    "Failed to allocate shared memory since it is mapped read-only.");
+            }
+
             let offset = fail!(from self, when unsafe { self.storage.get().allocator.assume_init_ref().allocate(layout) },
             "Failed to allocate shared memory due to an internal allocator failure.");
 
@@ -69,6 +69,7 @@ use iceoryx2_bb_system_types::file_name::*;
 pub enum SharedMemoryCreateError {
     AlreadyExists,
     SizeIsZero,
+    SizeExceedsMaxSupportedSize,
     InsufficientPermissions,
     InternalError,
 }
@@ -121,6 +122,12 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// Opens already existing [`SharedMemory`]. If it does not exist or the initialization is not
     /// yet finished the method will fail.
     fn open(self) -> Result<Shm, SharedMemoryOpenError>;
+
+    /// Opens already existing [`SharedMemory`] like [`SharedMemoryBuilder::open()`] but maps it
+    /// read-only when the underlying concept supports memory protection. Every write access to
+    /// the payload terminates the process with a segmentation fault and
+    /// [`SharedMemory::allocate()`] fails.
+    fn open_read_only(self) -> Result<Shm, SharedMemoryOpenError>;
 }
 
 /// Abstract concept of a memory shared between multiple processes. Can be created with the
@@ -160,6 +167,10 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// Returns true if the [`SharedMemory`] holds the ownership, otherwise false
     fn has_ownership(&self) -> bool;
 
+    /// Returns true if the [`SharedMemory`] was opened with
+    /// [`SharedMemoryBuilder::open_read_only()`], otherwise false
+    fn is_read_only(&self) -> bool;
+
     /// Acquires the ownership of the [`SharedMemory`]. When the object goes out of scope the
     /// underlying resources will be removed.
     fn acquire_ownership(&self);
@@ -20,6 +20,47 @@ use iceoryx2_bb_elementary::{allocator::BaseAllocator, enum_gen};
 
 pub trait ShmAllocatorConfig: Copy + Default {}
 
+/// Defines how a memory provider that consists of multiple shared memory segments acquires a
+/// new segment when the current one cannot serve an allocation request.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum AllocationStrategy {
+    /// The memory is never resized, allocations that do not fit fail.
+    #[default]
+    Static,
+    /// The new segment is sized to exactly fit the allocation request that did not fit.
+    BestFit,
+    /// The new segment is sized to the next power of two that fits the allocation request.
+    PowerOfTwo,
+}
+
+/// Identifies a shared memory segment of a memory provider that consists of multiple
+/// segments.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct SegmentId(u8);
+
+impl SegmentId {
+    pub const fn new(value: u8) -> Self {
+        Self(value)
+    }
+
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the largest [`SegmentId`] that can be encoded in a [`PointerOffset`].
+    pub const fn max_segment_id() -> u8 {
+        u8::MAX
+    }
+}
+
+const SEGMENT_ID_SHIFT: u32 = usize::BITS - u8::BITS;
+const OFFSET_MASK: usize = (1 << SEGMENT_ID_SHIFT) - 1;
+
+/// The offset of a chunk relative to the start of the payload of its shared memory segment.
+/// The [`SegmentId`] of the segment is encoded in the upper 8 bits, an offset created with
+/// [`PointerOffset::new()`] refers always to the first segment. Therefore, the payload of a
+/// segment cannot exceed [`PointerOffset::max_offset()`] + 1 bytes, on 32-bit targets this
+/// limits a segment to 16 MiB.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub struct PointerOffset(usize);
 
@@ -28,14 +69,35 @@ impl PointerOffset {
         Self(value)
     }
 
+    /// Returns the largest offset that can be encoded together with a [`SegmentId`].
+    pub const fn max_offset() -> usize {
+        OFFSET_MASK
+    }
+
+    pub fn from_offset_and_segment_id(offset: usize, segment_id: SegmentId) -> PointerOffset {
+        debug_assert!(offset <= OFFSET_MASK);
+        Self(offset | ((segment_id.value() as usize) << SEGMENT_ID_SHIFT))
+    }
+
+    /// Returns the raw value that contains the offset and the [`SegmentId`].
     pub fn value(&self) -> usize {
         self.0
     }
+
+    /// Returns the offset relative to the start of the payload of the segment.
+    pub fn offset(&self) -> usize {
+        self.0 & OFFSET_MASK
+    }
+
+    pub fn segment_id(&self) -> SegmentId {
+        SegmentId((self.0 >> SEGMENT_ID_SHIFT) as u8)
+    }
 }
 
 enum_gen! { ShmAllocationError
   entry:
-    ExceedsMaxSupportedAlignment
+    ExceedsMaxSupportedAlignment,
+    MemoryIsReadOnly
   mapping:
     AllocationError
 }
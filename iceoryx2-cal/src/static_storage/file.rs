@@ -47,6 +47,7 @@
 pub use crate::named_concept::*;
 pub use crate::static_storage::*;
 
+use crate::access_policy::{AccessPolicy, AccessPolicyApplyError};
 use iceoryx2_bb_log::{fail, trace, warn};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::{
@@ -55,12 +56,19 @@ use iceoryx2_bb_posix::{
 
 const FINAL_PERMISSIONS: Permission = Permission::OWNER_READ;
 
+// the access policy may grant additional rights to the group and others, therefore only the
+// owner permissions indicate whether the storage is initialized
+fn is_initialized(permission: Permission) -> bool {
+    permission & Permission::OWNER_ALL == FINAL_PERMISSIONS
+}
+
 /// The custom configuration of the [`Storage`].
 #[derive(Clone, Debug)]
 pub struct Configuration {
     path: Path,
     suffix: FileName,
     prefix: FileName,
+    access_policy: AccessPolicy,
 }
 
 impl Default for Configuration {
@@ -69,6 +77,7 @@ impl Default for Configuration {
             path: Storage::default_path_hint(),
             suffix: Storage::default_suffix(),
             prefix: Storage::default_prefix(),
+            access_policy: AccessPolicy::default(),
         }
     }
 }
@@ -100,6 +109,11 @@ impl crate::named_concept::NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path
     }
+
+    fn access_policy(mut self, value: &AccessPolicy) -> Self {
+        self.access_policy = value.clone();
+        self
+    }
 }
 
 impl crate::static_storage::StaticStorageConfiguration for Configuration {}
@@ -130,10 +144,10 @@ impl StaticStorageLocked<Storage> for Locked {
                 msg, contents.len(), bytes_written);
         }
 
-        fail!(from self, when self.static_storage.file.set_permission(FINAL_PERMISSIONS),
-                map FileSetPermissionError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
+        fail!(from self, when self.static_storage.config.access_policy.apply(&mut self.static_storage.file, FINAL_PERMISSIONS),
+                map AccessPolicyApplyError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
                 unmatched StaticStorageUnlockError::InternalError,
-                "{} due to a failure while updating the permissions to {}.", msg, FINAL_PERMISSIONS);
+                "{} due to a failure while applying the access policy with the owner permissions {}.", msg, FINAL_PERMISSIONS);
 
         self.static_storage.len = contents.len() as u64;
 
@@ -240,7 +254,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
             .iter()
             .filter(|entry| {
                 let metadata = entry.metadata();
-                metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS
+                metadata.file_type() == FileType::File && is_initialized(metadata.permission())
             })
             .filter_map(|entry| config.extract_name_from_file(entry.name()))
             .collect())
@@ -289,7 +303,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
         }
         let metadata = metadata.unwrap();
 
-        if metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS {
+        if metadata.file_type() == FileType::File && is_initialized(metadata.permission()) {
             return Ok(true);
         }
 
@@ -422,7 +436,8 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
 
         let file = fail!(from origin,
             when FileBuilder::new(&self.config.path_for(&self.storage_name)).open_existing(AccessMode::Read),
-            with StaticStorageOpenError::DoesNotExist,
+            map FileOpenError::InsufficientPermissions => StaticStorageOpenError::InsufficientPermissions,
+            unmatched StaticStorageOpenError::DoesNotExist,
             "{} due to a failure while opening the file.", msg);
 
         let mut wait_for_read_access = fail!(from self,
@@ -437,7 +452,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
             when file.metadata(), with StaticStorageOpenError::Read,
             "{} due to a failure while reading the files metadata.", msg);
 
-            if metadata.permission() != FINAL_PERMISSIONS {
+            if !is_initialized(metadata.permission()) {
                 if elapsed_time > timeout {
                     fail!(from origin,
                         with StaticStorageOpenError::InitializationNotYetFinalized,
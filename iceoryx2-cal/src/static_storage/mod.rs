@@ -37,6 +37,7 @@ pub enum StaticStorageCreateError {
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum StaticStorageOpenError {
     DoesNotExist,
+    InsufficientPermissions,
     Read,
     InitializationNotYetFinalized,
     InternalError,
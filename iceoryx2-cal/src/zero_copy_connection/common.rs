@@ -12,18 +12,23 @@
 
 #[doc(hidden)]
 pub mod details {
-    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8};
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{
+        IoxAtomicBool, IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize,
+    };
     use std::cell::UnsafeCell;
     use std::fmt::Debug;
     use std::marker::PhantomData;
     use std::sync::atomic::Ordering;
 
+    use crate::access_policy::AccessPolicy;
     use crate::dynamic_storage::{
         DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError, DynamicStorageOpenError,
         DynamicStorageOpenOrCreateError,
     };
     use crate::named_concept::*;
+    use crate::shm_allocator::SegmentId;
     pub use crate::zero_copy_connection::*;
+    use iceoryx2_bb_container::vec::RelocatableVec;
     use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
     use iceoryx2_bb_lock_free::spsc::{
         index_queue::RelocatableIndexQueue,
@@ -91,6 +96,11 @@ pub mod details {
             self.dynamic_storage_config.get_path_hint()
         }
 
+        fn access_policy(mut self, value: &AccessPolicy) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.access_policy(value);
+            self
+        }
+
         fn path_for(&self, value: &FileName) -> FilePath {
             self.dynamic_storage_config.path_for(value)
         }
@@ -154,12 +164,20 @@ pub mod details {
         submission_channel: RelocatableSafelyOverflowingIndexQueue,
         completion_channel: RelocatableIndexQueue,
         used_chunk_list: RelocatableUsedChunkList,
+        segment_sample_sizes: RelocatableVec<IoxAtomicUsize>,
         max_borrowed_samples: usize,
         sample_size: usize,
         number_of_samples: usize,
+        number_of_segments: u8,
         state: IoxAtomicU8,
+        has_receiver_left: IoxAtomicBool,
         init_state: IoxAtomicU64,
         enable_safe_overflow: bool,
+        number_of_sent_samples: IoxAtomicU64,
+        number_of_received_samples: IoxAtomicU64,
+        number_of_borrowed_samples: IoxAtomicUsize,
+        accumulated_latency: IoxAtomicU64,
+        last_latency: IoxAtomicU64,
     }
 
     impl SharedManagementData {
@@ -170,6 +188,7 @@ pub mod details {
             max_borrowed_samples: usize,
             sample_size: usize,
             number_of_samples: usize,
+            number_of_segments: u8,
         ) -> Self {
             Self {
                 submission_channel: unsafe {
@@ -180,13 +199,27 @@ pub mod details {
                 completion_channel: unsafe {
                     RelocatableIndexQueue::new_uninit(completion_channel_buffer_capacity)
                 },
-                used_chunk_list: unsafe { RelocatableUsedChunkList::new_uninit(number_of_samples) },
+                used_chunk_list: unsafe {
+                    RelocatableUsedChunkList::new_uninit(
+                        number_of_samples * number_of_segments as usize,
+                    )
+                },
+                segment_sample_sizes: unsafe {
+                    RelocatableVec::new_uninit(number_of_segments as usize)
+                },
                 state: IoxAtomicU8::new(State::None.value()),
+                has_receiver_left: IoxAtomicBool::new(false),
                 init_state: IoxAtomicU64::new(0),
                 enable_safe_overflow,
                 sample_size,
                 max_borrowed_samples,
                 number_of_samples,
+                number_of_segments,
+                number_of_sent_samples: IoxAtomicU64::new(0),
+                number_of_received_samples: IoxAtomicU64::new(0),
+                number_of_borrowed_samples: IoxAtomicUsize::new(0),
+                accumulated_latency: IoxAtomicU64::new(0),
+                last_latency: IoxAtomicU64::new(0),
             }
         }
 
@@ -194,12 +227,44 @@ pub mod details {
             submission_channel_buffer_capacity: usize,
             completion_channel_buffer_capacity: usize,
             number_of_samples: usize,
+            number_of_segments: u8,
         ) -> usize {
             RelocatableIndexQueue::const_memory_size(completion_channel_buffer_capacity)
                 + RelocatableSafelyOverflowingIndexQueue::const_memory_size(
                     submission_channel_buffer_capacity,
                 )
-                + RelocatableUsedChunkList::const_memory_size(number_of_samples)
+                + RelocatableUsedChunkList::const_memory_size(
+                    number_of_samples * number_of_segments as usize,
+                )
+                + RelocatableVec::<IoxAtomicUsize>::const_memory_size(number_of_segments as usize)
+        }
+
+        fn chunk_index(&self, ptr: PointerOffset, sample_size: usize) -> usize {
+            ptr.segment_id().value() as usize * self.number_of_samples + ptr.offset() / sample_size
+        }
+
+        fn segment_sample_size_entry(&self, segment_id: SegmentId) -> &IoxAtomicUsize {
+            unsafe { &self.segment_sample_sizes.as_slice()[segment_id.value() as usize] }
+        }
+
+        fn segment_sample_size(&self, segment_id: SegmentId) -> usize {
+            self.segment_sample_size_entry(segment_id)
+                .load(Ordering::Relaxed)
+        }
+
+        fn statistics(&self) -> ZeroCopyConnectionStatistics {
+            ZeroCopyConnectionStatistics {
+                buffer_size: self.submission_channel.capacity(),
+                number_of_queued_samples: self.submission_channel.len(),
+                max_borrowed_samples: self.max_borrowed_samples,
+                number_of_borrowed_samples: self.number_of_borrowed_samples.load(Ordering::Relaxed),
+                number_of_sent_samples: self.number_of_sent_samples.load(Ordering::Relaxed),
+                number_of_received_samples: self.number_of_received_samples.load(Ordering::Relaxed),
+                accumulated_latency: Duration::from_nanos(
+                    self.accumulated_latency.load(Ordering::Relaxed),
+                ),
+                last_latency: Duration::from_nanos(self.last_latency.load(Ordering::Relaxed)),
+            }
         }
     }
 
@@ -211,6 +276,7 @@ pub mod details {
         max_borrowed_samples: usize,
         sample_size: usize,
         number_of_samples: usize,
+        number_of_segments: u8,
         timeout: Duration,
         config: Configuration<Storage>,
     }
@@ -229,6 +295,7 @@ pub mod details {
                 self.submission_channel_size(),
                 self.completion_channel_size(),
                 self.number_of_samples,
+                self.number_of_segments,
             );
 
             let msg = "Failed to acquire underlying shared memory";
@@ -245,6 +312,11 @@ pub mod details {
                         "{} since the retrieve channel allocation failed. - This is an implementation bug!", msg);
             fatal_panic!(from self, when unsafe { data.used_chunk_list.init(allocator) },
                         "{} since the used chunk list allocation failed. - This is an implementation bug!", msg);
+            fatal_panic!(from self, when unsafe { data.segment_sample_sizes.init(allocator) },
+                        "{} since the segment details allocation failed. - This is an implementation bug!", msg);
+            for _ in 0..self.number_of_segments {
+                unsafe { data.segment_sample_sizes.push(IoxAtomicUsize::new(self.sample_size)) };
+            }
 
             true
         })
@@ -256,6 +328,7 @@ pub mod details {
                                     self.max_borrowed_samples,
                                     self.sample_size,
                                     self.number_of_samples,
+                                    self.number_of_segments,
                                 )
             );
 
@@ -319,6 +392,12 @@ pub mod details {
                         "{} since the requested number of samples is set to {} but should be set to {}.",
                         msg, self.number_of_samples, storage.get().number_of_samples);
                 }
+
+                if storage.get().number_of_segments != self.number_of_segments {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleNumberOfSegments,
+                        "{} since the requested number of segments is set to {} but should be set to {}.",
+                        msg, self.number_of_segments, storage.get().number_of_segments);
+                }
             }
 
             Ok(storage)
@@ -368,6 +447,7 @@ pub mod details {
                 max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
                 sample_size: 0,
                 number_of_samples: 0,
+                number_of_segments: DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS,
                 config: Configuration::default(),
                 timeout: Duration::ZERO,
             }
@@ -402,6 +482,11 @@ pub mod details {
             self
         }
 
+        fn max_supported_shared_memory_segments(mut self, value: u8) -> Self {
+            self.number_of_segments = value.clamp(1, u8::MAX);
+            self
+        }
+
         fn receiver_max_borrowed_samples(mut self, value: usize) -> Self {
             self.max_borrowed_samples = value.clamp(1, usize::MAX);
             self
@@ -438,6 +523,11 @@ pub mod details {
             "{} since the corresponding connection could not be created or opened", msg);
 
             self.reserve_port(storage.get(), State::Receiver.value(), msg)?;
+            // a previous receiver may have been removed while it still borrowed samples
+            storage
+                .get()
+                .number_of_borrowed_samples
+                .store(0, Ordering::Relaxed);
 
             Ok(Receiver {
                 storage,
@@ -485,8 +575,19 @@ pub mod details {
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
-        fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        fn try_send(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
             let msg = "Unable to send sample";
+            let mgmt = self.storage.get();
+
+            if mgmt.number_of_segments <= ptr.segment_id().value() {
+                fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
+                    "{} since the segment id {} exceeds the number of supported segments {}.",
+                    msg, ptr.segment_id().value(), mgmt.number_of_segments);
+            }
 
             if !self.storage.get().enable_safe_overflow
                 && self.storage.get().submission_channel.is_full()
@@ -495,29 +596,32 @@ pub mod details {
                              "{} since the receive buffer is full.", msg);
             }
 
-            if !self
-                .storage
-                .get()
+            mgmt.segment_sample_size_entry(ptr.segment_id())
+                .store(sample_size, Ordering::Relaxed);
+
+            if !mgmt
                 .used_chunk_list
-                .insert(ptr.value() / self.storage.get().sample_size)
+                .insert(mgmt.chunk_index(ptr, sample_size))
             {
                 fail!(from self, with ZeroCopySendError::UsedChunkListFull,
                     "{} since the used chunk list is full.", msg);
             }
 
-            match unsafe { self.storage.get().submission_channel.push(ptr.value()) } {
+            let overflow = unsafe { self.storage.get().submission_channel.push(ptr.value()) };
+            mgmt.number_of_sent_samples.fetch_add(1, Ordering::Relaxed);
+
+            match overflow {
                 Some(v) => {
-                    if !self
-                        .storage
-                        .get()
+                    let v = PointerOffset::new(v);
+                    if !mgmt
                         .used_chunk_list
-                        .remove(v / self.storage.get().sample_size)
+                        .remove(mgmt.chunk_index(v, mgmt.segment_sample_size(v.segment_id())))
                     {
                         fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
                         "{} since an invalid offset was returned on overflow.", msg);
                     }
 
-                    Ok(Some(PointerOffset::new(v)))
+                    Ok(Some(v))
                 }
                 None => Ok(None),
             }
@@ -526,6 +630,7 @@ pub mod details {
         fn blocking_send(
             &self,
             ptr: PointerOffset,
+            sample_size: usize,
         ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
             if !self.storage.get().enable_safe_overflow {
                 AdaptiveWaitBuilder::new()
@@ -535,33 +640,43 @@ pub mod details {
                     .unwrap();
             }
 
-            self.try_send(ptr)
+            self.try_send(ptr, sample_size)
         }
 
         fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
             match unsafe { self.storage.get().completion_channel.pop() } {
                 None => Ok(None),
                 Some(v) => {
-                    if !self
-                        .storage
-                        .get()
-                        .used_chunk_list
-                        .remove(v / self.storage.get().sample_size)
+                    let mgmt = self.storage.get();
+                    let v = PointerOffset::new(v);
+                    if mgmt.number_of_segments <= v.segment_id().value()
+                        || !mgmt
+                            .used_chunk_list
+                            .remove(mgmt.chunk_index(v, mgmt.segment_sample_size(v.segment_id())))
                     {
                         fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedOffset,
-                        "Unable to reclaim sample since the receiver returned the corrupted offset {}.", v);
+                        "Unable to reclaim sample since the receiver returned the corrupted offset {}.", v.value());
                     }
-                    Ok(Some(PointerOffset::new(v)))
+                    Ok(Some(v))
                 }
             }
         }
 
+        fn has_receiver_left(&self) -> bool {
+            self.storage.get().has_receiver_left.load(Ordering::Acquire)
+        }
+
         unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
-            let sample_size = self.storage.get().sample_size;
-            self.storage
-                .get()
-                .used_chunk_list
-                .remove_all(|index| callback(PointerOffset::new(index * sample_size)));
+            let mgmt = self.storage.get();
+            let number_of_samples = mgmt.number_of_samples;
+            mgmt.used_chunk_list.remove_all(|index| {
+                let segment_id = SegmentId::new((index / number_of_samples) as u8);
+                let sample_size = mgmt.segment_sample_size(segment_id);
+                callback(PointerOffset::from_offset_and_segment_id(
+                    (index % number_of_samples) * sample_size,
+                    segment_id,
+                ))
+            });
         }
     }
 
@@ -574,6 +689,10 @@ pub mod details {
 
     impl<Storage: DynamicStorage<SharedManagementData>> Drop for Receiver<Storage> {
         fn drop(&mut self) {
+            self.storage
+                .get()
+                .has_receiver_left
+                .store(true, Ordering::Release);
             cleanup_shared_memory(&self.storage, State::Receiver);
         }
     }
@@ -630,6 +749,11 @@ pub mod details {
                 None => Ok(None),
                 Some(v) => {
                     *self.borrow_counter() += 1;
+                    let mgmt = self.storage.get();
+                    mgmt.number_of_borrowed_samples
+                        .store(*self.borrow_counter(), Ordering::Relaxed);
+                    mgmt.number_of_received_samples
+                        .fetch_add(1, Ordering::Relaxed);
                     Ok(Some(PointerOffset::new(v)))
                 }
             }
@@ -639,6 +763,10 @@ pub mod details {
             match unsafe { self.storage.get().completion_channel.push(ptr.value()) } {
                 true => {
                     *self.borrow_counter() -= 1;
+                    self.storage
+                        .get()
+                        .number_of_borrowed_samples
+                        .store(*self.borrow_counter(), Ordering::Relaxed);
                     Ok(())
                 }
                 false => {
@@ -647,6 +775,15 @@ pub mod details {
                 }
             }
         }
+
+        fn record_latency(&self, latency: Duration) {
+            // latencies are stored in nanoseconds, u64 covers more than 500 years
+            let latency = latency.as_nanos() as u64;
+            let mgmt = self.storage.get();
+            mgmt.accumulated_latency
+                .fetch_add(latency, Ordering::Relaxed);
+            mgmt.last_latency.store(latency, Ordering::Relaxed);
+        }
     }
 
     #[derive(Debug)]
@@ -694,6 +831,98 @@ pub mod details {
         type Builder = Builder<Storage>;
         type Receiver = Receiver<Storage>;
 
+        unsafe fn remove_receiver(
+            name: &FileName,
+            config: &Self::Configuration,
+        ) -> Result<bool, ZeroCopyPortRemoveError> {
+            let origin = "ZeroCopyConnection::remove_receiver()";
+            let msg = "Unable to remove the receiver";
+            let storage = match <<Storage as DynamicStorage<SharedManagementData>>::Builder<'_> as NamedConceptBuilder<
+                Storage,
+            >>::new(name)
+            .config(&config.dynamic_storage_config)
+            .open()
+            {
+                Ok(storage) => storage,
+                Err(DynamicStorageOpenError::DoesNotExist) => return Ok(false),
+                Err(DynamicStorageOpenError::VersionMismatch) => {
+                    fail!(from origin, with ZeroCopyPortRemoveError::VersionMismatch,
+                        "{} of \"{}\" since the version of the connection does not match.", msg, name);
+                }
+                Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                    fail!(from origin, with ZeroCopyPortRemoveError::InitializationNotYetFinalized,
+                        "{} of \"{}\" since the initialization of the connection is not finalized.", msg, name);
+                }
+                Err(e) => {
+                    fail!(from origin, with ZeroCopyPortRemoveError::InternalError,
+                        "{} of \"{}\" due to an internal failure ({:?}).", msg, name, e);
+                }
+            };
+
+            let mgmt = storage.get();
+            mgmt.has_receiver_left.store(true, Ordering::Release);
+
+            let mut current_state = mgmt.state.load(Ordering::Relaxed);
+            loop {
+                if current_state & State::MarkedForDestruction.value() != 0 {
+                    return Ok(true);
+                }
+
+                // without a sender nobody is left who could remove the connection
+                let new_state = if current_state & State::Sender.value() == 0 {
+                    State::MarkedForDestruction.value()
+                } else {
+                    current_state & !State::Receiver.value()
+                };
+
+                match mgmt.state.compare_exchange(
+                    current_state,
+                    new_state,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        if new_state == State::MarkedForDestruction.value() {
+                            storage.acquire_ownership();
+                        }
+                        return Ok(true);
+                    }
+                    Err(s) => current_state = s,
+                }
+            }
+        }
+
+        fn statistics(
+            name: &FileName,
+            config: &Self::Configuration,
+        ) -> Result<Option<ZeroCopyConnectionStatistics>, ZeroCopyStatisticsError> {
+            let origin = "ZeroCopyConnection::statistics()";
+            let msg = "Unable to acquire the statistics";
+            let storage = match <<Storage as DynamicStorage<SharedManagementData>>::Builder<'_> as NamedConceptBuilder<
+                Storage,
+            >>::new(name)
+            .config(&config.dynamic_storage_config)
+            .open()
+            {
+                Ok(storage) => storage,
+                Err(DynamicStorageOpenError::DoesNotExist) => return Ok(None),
+                Err(DynamicStorageOpenError::VersionMismatch) => {
+                    fail!(from origin, with ZeroCopyStatisticsError::VersionMismatch,
+                        "{} of \"{}\" since the version of the connection does not match.", msg, name);
+                }
+                Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                    fail!(from origin, with ZeroCopyStatisticsError::InitializationNotYetFinalized,
+                        "{} of \"{}\" since the initialization of the connection is not finalized.", msg, name);
+                }
+                Err(e) => {
+                    fail!(from origin, with ZeroCopyStatisticsError::InternalError,
+                        "{} of \"{}\" due to an internal failure ({:?}).", msg, name, e);
+                }
+            };
+
+            Ok(Some(storage.get().statistics()))
+        }
+
         fn does_support_safe_overflow() -> bool {
             true
         }
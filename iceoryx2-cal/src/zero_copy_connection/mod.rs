@@ -37,6 +37,7 @@ pub enum ZeroCopyCreationError {
     IncompatibleOverflowSetting,
     IncompatibleSampleSize,
     IncompatibleNumberOfSamples,
+    IncompatibleNumberOfSegments,
 }
 
 impl std::fmt::Display for ZeroCopyCreationError {
@@ -101,15 +102,74 @@ impl std::fmt::Display for ZeroCopyReleaseError {
 
 impl std::error::Error for ZeroCopyReleaseError {}
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCopyPortRemoveError {
+    InternalError,
+    VersionMismatch,
+    InitializationNotYetFinalized,
+}
+
+impl std::fmt::Display for ZeroCopyPortRemoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ZeroCopyPortRemoveError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCopyStatisticsError {
+    InternalError,
+    VersionMismatch,
+    InitializationNotYetFinalized,
+}
+
+impl std::fmt::Display for ZeroCopyStatisticsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ZeroCopyStatisticsError {}
+
+/// A snapshot of the counters of a [`ZeroCopyConnection`] that the sender and receiver update
+/// while they communicate. Acquired with [`ZeroCopyConnection::statistics()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ZeroCopyConnectionStatistics {
+    /// The capacity of the receive buffer
+    pub buffer_size: usize,
+    /// The number of samples that were sent but not yet received
+    pub number_of_queued_samples: usize,
+    /// The maximum number of samples the receiver can borrow in parallel
+    pub max_borrowed_samples: usize,
+    /// The number of samples the receiver currently borrows
+    pub number_of_borrowed_samples: usize,
+    /// The total number of samples the sender has sent
+    pub number_of_sent_samples: u64,
+    /// The total number of samples the receiver has received
+    pub number_of_received_samples: u64,
+    /// The sum of the latencies the receiver recorded with
+    /// [`ZeroCopyReceiver::record_latency()`]
+    pub accumulated_latency: Duration,
+    /// The latency the receiver recorded last with [`ZeroCopyReceiver::record_latency()`]
+    pub last_latency: Duration,
+}
+
 pub const DEFAULT_BUFFER_SIZE: usize = 4;
 pub const DEFAULT_ENABLE_SAFE_OVERFLOW: bool = false;
 pub const DEFAULT_MAX_BORROWED_SAMPLES: usize = 4;
+pub const DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS: u8 = 1;
 
 pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<C> {
     fn buffer_size(self, value: usize) -> Self;
     fn enable_safe_overflow(self, value: bool) -> Self;
     fn receiver_max_borrowed_samples(self, value: usize) -> Self;
+    /// The number of samples per shared memory segment.
     fn number_of_samples(self, value: usize) -> Self;
+    /// The number of shared memory segments the sender may distribute samples from. The
+    /// segment of a sample is identified by the [`crate::shm_allocator::SegmentId`] that is
+    /// encoded in its [`PointerOffset`].
+    fn max_supported_shared_memory_segments(self, value: u8) -> Self;
     /// The timeout defines how long the [`ZeroCopyConnectionBuilder`] should wait for
     /// concurrent
     /// [`ZeroCopyConnectionBuilder::create_sender()`] or
@@ -129,13 +189,27 @@ pub trait ZeroCopyPortDetails {
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
-    fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+    /// Sends the `ptr` to the receiver. The `sample_size` is the size of the samples in the
+    /// shared memory segment `ptr` belongs to.
+    fn try_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
-    fn blocking_send(&self, ptr: PointerOffset)
-        -> Result<Option<PointerOffset>, ZeroCopySendError>;
+    fn blocking_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
 
+    /// Returns true when the receiver left the connection, either since it was dropped or
+    /// since it was removed with [`ZeroCopyConnection::remove_receiver()`]. The offsets it
+    /// did not return can then be reacquired with [`ZeroCopySender::acquire_used_offsets()`].
+    fn has_receiver_left(&self) -> bool;
+
     /// # Safety
     ///
     /// * must ensure that no receiver is still holding data, otherwise data races may occur on
@@ -149,6 +223,10 @@ pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
     fn has_data(&self) -> bool;
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
+
+    /// Adds the time a received sample took from being sent until it was received to the
+    /// [`ZeroCopyConnectionStatistics`] of the connection.
+    fn record_latency(&self, latency: Duration);
 }
 
 pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
@@ -166,6 +244,28 @@ pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
         false
     }
 
+    /// Removes the receiver of the connection `name` without having access to the
+    /// [`ZeroCopyReceiver`], for instance when the process that owned it died. The sender
+    /// is informed via [`ZeroCopySender::has_receiver_left()`] so that it can reacquire all
+    /// offsets the receiver still held. When no sender is connected the connection is
+    /// removed. Returns false when the connection does not exist.
+    ///
+    /// # Safety
+    ///
+    /// * the [`ZeroCopyReceiver`] of the connection must not be used anymore
+    unsafe fn remove_receiver(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, ZeroCopyPortRemoveError>;
+
+    /// Acquires the [`ZeroCopyConnectionStatistics`] of the connection `name` without
+    /// connecting to it as sender or receiver. Returns [`None`] when the connection does not
+    /// exist.
+    fn statistics(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<Option<ZeroCopyConnectionStatistics>, ZeroCopyStatisticsError>;
+
     /// The default suffix of every zero copy connection
     fn default_suffix() -> FileName {
         unsafe { FileName::new_unchecked(b".rx") }
@@ -0,0 +1,96 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::group::Group;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::user::User;
+use iceoryx2_bb_testing::{assert_that, test_requires};
+use iceoryx2_cal::access_policy::{AccessPolicy, AclEntry};
+
+const FOREIGN_UID: u32 = 54321;
+const FOREIGN_GID: u32 = 54322;
+
+#[test]
+fn access_policy_applicable_by_keeps_own_owner_and_group() {
+    let user = User::from_self().unwrap();
+    let sut = AccessPolicy::new()
+        .owner(user.uid())
+        .group(user.gid())
+        .permission(Permission::GROUP_READ | Permission::GROUP_WRITE);
+
+    let policy = sut.applicable_by(&user);
+
+    assert_that!(policy, eq sut);
+}
+
+#[test]
+fn access_policy_applicable_by_privileged_user_is_unchanged() {
+    let user = User::from_self().unwrap();
+    test_requires!(user.uid() == 0);
+
+    let sut = AccessPolicy::new()
+        .owner(FOREIGN_UID)
+        .group(FOREIGN_GID)
+        .allow_user(FOREIGN_UID + 10);
+
+    let policy = sut.applicable_by(&user);
+
+    assert_that!(policy, eq sut);
+}
+
+#[test]
+fn access_policy_applicable_by_non_privileged_user_grants_foreign_owner_via_acl() {
+    let user = User::from_self().unwrap();
+    test_requires!(user.uid() != 0);
+
+    let sut = AccessPolicy::new()
+        .owner(FOREIGN_UID)
+        .group(FOREIGN_GID)
+        .permission(Permission::GROUP_READ | Permission::GROUP_WRITE)
+        .allow_user(FOREIGN_UID + 10);
+
+    let policy = sut.applicable_by(&user);
+
+    assert_that!(policy.get_owner(), eq None);
+    assert_that!(policy.get_group(), eq None);
+    assert_that!(policy.get_permission(), eq sut.get_permission());
+    let acl = policy.get_acl().to_vec();
+    assert_that!(acl, len 3);
+    assert_that!(acl, contains AclEntry::User(FOREIGN_UID + 10));
+    assert_that!(acl, contains AclEntry::User(FOREIGN_UID));
+    assert_that!(acl, contains AclEntry::Group(FOREIGN_GID));
+}
+
+#[test]
+fn access_policy_acl_grants_access_only_with_acl_support() {
+    let user = User::from_self().unwrap();
+    let sut = AccessPolicy::new()
+        .owner(FOREIGN_UID)
+        .group(FOREIGN_GID)
+        .allow_user(user.uid());
+
+    assert_that!(sut.grants_access_to(&user), eq cfg!(feature = "acl"));
+}
+
+#[test]
+fn access_policy_grants_access_to_member_of_supplementary_group() {
+    let user = User::from_self().unwrap();
+    let gids = Group::supplementary_gids_of_self().unwrap();
+    test_requires!(!gids.is_empty());
+
+    let sut = AccessPolicy::new()
+        .owner(FOREIGN_UID)
+        .group(gids[0])
+        .permission(Permission::GROUP_READ | Permission::GROUP_WRITE);
+
+    assert_that!(sut.grants_access_to(&user), eq true);
+}
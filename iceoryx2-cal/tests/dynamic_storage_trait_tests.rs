@@ -75,6 +75,39 @@ mod dynamic_storage {
         assert_that!(sut2.get().value.load(Ordering::Relaxed), eq 456);
     }
 
+    #[test]
+    fn open_read_only_and_read_works<
+        Sut: DynamicStorage<TestData>,
+        WrongTypeSut: DynamicStorage<u64>,
+    >() {
+        let storage_name = generate_name();
+
+        let sut = Sut::Builder::new(&storage_name)
+            .create(TestData::new(123))
+            .unwrap();
+
+        let sut2 = unsafe { Sut::Builder::new(&storage_name).open_read_only() }.unwrap();
+
+        assert_that!(*sut2.name(), eq storage_name);
+        assert_that!(sut2.has_ownership(), eq false);
+        assert_that!(sut2.get().value.load(Ordering::Relaxed), eq 123);
+
+        sut.get().value.store(456, Ordering::Relaxed);
+
+        assert_that!(sut2.get().value.load(Ordering::Relaxed), eq 456);
+    }
+
+    #[test]
+    fn open_read_only_non_existing_fails<
+        Sut: DynamicStorage<TestData>,
+        WrongTypeSut: DynamicStorage<u64>,
+    >() {
+        let storage_name = generate_name();
+        let sut = unsafe { Sut::Builder::new(&storage_name).open_read_only() };
+
+        assert_that!(sut.err(), eq Some(DynamicStorageOpenError::DoesNotExist));
+    }
+
     #[test]
     fn open_non_existing_fails<Sut: DynamicStorage<TestData>, WrongTypeSut: DynamicStorage<u64>>() {
         let storage_name = generate_name();
@@ -558,4 +558,8 @@ mod reactor {
 
     #[instantiate_tests(<iceoryx2_cal::reactor::posix_select::Reactor>)]
     mod posix_select {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::reactor::linux_epoll::Reactor>)]
+    mod linux_epoll {}
 }
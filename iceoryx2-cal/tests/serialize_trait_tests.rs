@@ -38,9 +38,27 @@ mod serialize {
         assert_that!(deserialized.unwrap(), eq test_object);
     }
 
+    #[test]
+    fn unique_ids_differ<Sut: Serialize>() {
+        use iceoryx2_cal::serialize::{cdr::Cdr, postcard::Postcard, toml::Toml};
+
+        let ids = [Toml::unique_id(), Cdr::unique_id(), Postcard::unique_id()];
+        assert_that!(ids.iter().filter(|id| **id == Sut::unique_id()).count(), eq 1);
+    }
+
+    #[test]
+    fn unique_id_is_reserved<Sut: Serialize>() {
+        use iceoryx2_cal::serialize::RESERVED_UNIQUE_IDS;
+
+        assert_that!(RESERVED_UNIQUE_IDS.contains(&Sut::unique_id()), eq true);
+    }
+
     #[instantiate_tests(<iceoryx2_cal::serialize::toml::Toml>)]
     mod toml {}
 
     #[instantiate_tests(<iceoryx2_cal::serialize::cdr::Cdr>)]
     mod cdr {}
+
+    #[instantiate_tests(<iceoryx2_cal::serialize::postcard::Postcard>)]
+    mod postcard {}
 }
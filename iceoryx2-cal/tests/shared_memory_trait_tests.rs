@@ -61,6 +61,25 @@ mod shared_memory {
         assert_that!(sut_open.err().unwrap(), eq SharedMemoryOpenError::DoesNotExist);
     }
 
+    #[test]
+    fn size_exceeding_max_pointer_offset_fails<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(PointerOffset::max_offset() + 2)
+            .create(&SHM_CONFIG);
+        let sut_open = Sut::Builder::new(&name).open();
+
+        assert_that!(sut_create, is_err);
+        assert_that!(sut_open, is_err);
+
+        assert_that!(
+            sut_create.err().unwrap(), eq
+            SharedMemoryCreateError::SizeExceedsMaxSupportedSize
+        );
+        assert_that!(sut_open.err().unwrap(), eq SharedMemoryOpenError::DoesNotExist);
+    }
+
     #[test]
     fn non_zero_size_works<Sut: SharedMemory<DefaultAllocator>>() {
         let name = generate_name();
@@ -377,6 +396,66 @@ mod shared_memory {
         assert_that!(Sut::does_exist(&name), eq Ok(false));
     }
 
+    #[test]
+    fn read_only_memory_contains_data_of_creator<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        const VALUE: u64 = 0x1234_5678_9abc_def0;
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { (chunk.data_ptr as *mut u64).write_unaligned(VALUE) };
+
+        let sut_open = Sut::Builder::new(&name).open_read_only().unwrap();
+        assert_that!(sut_create.is_read_only(), eq false);
+        assert_that!(sut_open.is_read_only(), eq true);
+        assert_that!(sut_open.size(), ge DEFAULT_SIZE);
+
+        let value = unsafe {
+            ((chunk.offset.offset() + sut_open.payload_start_address()) as *const u64)
+                .read_unaligned()
+        };
+        assert_that!(value, eq VALUE);
+    }
+
+    #[test]
+    fn allocation_with_read_only_memory_fails<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+
+        let _sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).open_read_only().unwrap();
+
+        let chunk = sut_open.allocate(DEFAULT_LAYOUT);
+        assert_that!(chunk.err(), eq Some(ShmAllocationError::MemoryIsReadOnly));
+    }
+
+    #[test]
+    fn opening_non_existing_read_only_fails<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+
+        let sut_open = Sut::Builder::new(&name).open_read_only();
+        assert_that!(sut_open.err(), eq Some(SharedMemoryOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn ownership_of_read_only_memory_cannot_be_acquired<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+
+        let _sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).open_read_only().unwrap();
+
+        sut_open.acquire_ownership();
+        assert_that!(sut_open.has_ownership(), eq false);
+    }
+
     #[instantiate_tests(<iceoryx2_cal::shared_memory::posix::Memory<DefaultAllocator>>)]
     mod posix {}
 
@@ -14,10 +14,13 @@ use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_posix::config::*;
 use iceoryx2_bb_posix::directory::Directory;
 use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_testing::assert_that;
+use iceoryx2_cal::access_policy::AccessPolicy;
 use iceoryx2_cal::static_storage::file::*;
 use std::time::Duration;
 
@@ -156,3 +159,29 @@ fn static_storage_file_custom_path_and_suffix_list_storage_works() {
         File::remove(file).unwrap();
     }
 }
+
+#[test]
+fn static_storage_file_applies_access_policy_permission() {
+    let storage_name = generate_name();
+    let content = "some access controlled content".to_string();
+    let config = Configuration::default()
+        .access_policy(&AccessPolicy::new().permission(
+            Permission::GROUP_READ | Permission::GROUP_WRITE | Permission::OTHERS_READ,
+        ));
+
+    let _storage_guard = Builder::new(&storage_name)
+        .config(&config)
+        .create(content.as_bytes())
+        .unwrap();
+
+    let file = FileBuilder::new(&config.path_for(&storage_name))
+        .open_existing(AccessMode::Read)
+        .unwrap();
+    // the storage is read-only, therefore the group gains no write access
+    assert_that!(file.permission().unwrap(), eq Permission::OWNER_READ | Permission::GROUP_READ | Permission::OTHERS_READ);
+
+    let storage_reader = Builder::new(&storage_name)
+        .config(&config)
+        .open(Duration::ZERO);
+    assert_that!(storage_reader, is_ok);
+}
@@ -23,7 +23,7 @@ mod zero_copy_connection {
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_cal::named_concept::*;
     use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
-    use iceoryx2_cal::shm_allocator::PointerOffset;
+    use iceoryx2_cal::shm_allocator::{PointerOffset, SegmentId};
     use iceoryx2_cal::zero_copy_connection;
     use iceoryx2_cal::zero_copy_connection::*;
 
@@ -294,6 +294,77 @@ mod zero_copy_connection {
         assert_that!(sut_receiver, is_err);
     }
 
+    #[test]
+    fn connecting_with_incompatible_number_of_segments_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(2)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(3)
+            .create_receiver(SAMPLE_SIZE);
+
+        assert_that!(sut_receiver, is_err);
+    }
+
+    #[test]
+    fn send_receive_and_retrieval_works_with_multiple_segments<Sut: ZeroCopyConnection>() {
+        const NUMBER_OF_SEGMENTS: u8 = 4;
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        for n in 0..NUMBER_OF_SEGMENTS {
+            let sample_size = SAMPLE_SIZE * (n as usize + 1);
+            let sample_offset =
+                PointerOffset::from_offset_and_segment_id(sample_size * 3, SegmentId::new(n));
+            assert_that!(sut_sender.try_send(sample_offset, sample_size), is_ok);
+
+            let sample = sut_receiver.receive().unwrap();
+            assert_that!(sample, eq Some(sample_offset));
+            assert_that!(sample.unwrap().segment_id(), eq SegmentId::new(n));
+            assert_that!(sample.unwrap().offset(), eq sample_size * 3);
+
+            assert_that!(sut_receiver.release(sample.unwrap()), is_ok);
+            let retrieval = sut_sender.reclaim().unwrap();
+            assert_that!(retrieval, eq Some(sample_offset));
+        }
+
+        let retrieval = sut_sender.reclaim().unwrap();
+        assert_that!(retrieval, is_none);
+    }
+
+    #[test]
+    fn sending_sample_of_unsupported_segment_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(2)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        let sample_offset = PointerOffset::from_offset_and_segment_id(0, SegmentId::new(2));
+        assert_that!(
+            sut_sender.try_send(sample_offset, SAMPLE_SIZE).err(), eq
+            Some(ZeroCopySendError::ConnectionCorrupted)
+        );
+    }
+
     #[test]
     fn send_receive_and_retrieval_works<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -309,7 +380,7 @@ mod zero_copy_connection {
 
         let sample_offset = SAMPLE_SIZE * 2;
         assert_that!(
-            sut_sender.try_send(PointerOffset::new(sample_offset)),
+            sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
             is_ok
         );
         let sample = sut_receiver.receive().unwrap();
@@ -341,7 +412,7 @@ mod zero_copy_connection {
         let sample_offset = SAMPLE_SIZE * 2;
         assert_that!(sut_receiver.has_data(), eq false);
         assert_that!(
-            sut_sender.try_send(PointerOffset::new(sample_offset)),
+            sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
             is_ok
         );
 
@@ -362,12 +433,12 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
 
-        let result = sut_sender.try_send(PointerOffset::new(9));
+        let result = sut_sender.try_send(PointerOffset::new(9), SAMPLE_SIZE);
         assert_that!(result, is_err);
         assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
     }
@@ -387,7 +458,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -395,7 +466,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let overflow_sample_offset = SAMPLE_SIZE * i;
             let sample_offset = SAMPLE_SIZE * (BUFFER_SIZE + i);
-            let result = sut_sender.try_send(PointerOffset::new(sample_offset));
+            let result = sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE);
             assert_that!(result, is_ok);
             assert_that!(result.ok().unwrap().unwrap().value(), eq overflow_sample_offset);
         }
@@ -416,7 +487,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -474,7 +545,7 @@ mod zero_copy_connection {
             for _ in 0..BUFFER_SIZE {
                 sample_offset += SAMPLE_SIZE;
                 assert_that!(
-                    sut_sender.try_send(PointerOffset::new(sample_offset)),
+                    sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                     is_ok
                 );
             }
@@ -544,11 +615,11 @@ mod zero_copy_connection {
             let now = Instant::now();
 
             assert_that!(
-                sut_sender.blocking_send(PointerOffset::new(sample_offset_1)),
+                sut_sender.blocking_send(PointerOffset::new(sample_offset_1), SAMPLE_SIZE),
                 is_ok
             );
             assert_that!(
-                sut_sender.blocking_send(PointerOffset::new(sample_offset_2)),
+                sut_sender.blocking_send(PointerOffset::new(sample_offset_2), SAMPLE_SIZE),
                 is_ok
             );
             assert_that!(now.elapsed(), time_at_least TIMEOUT);
@@ -577,7 +648,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * i;
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -591,6 +662,44 @@ mod zero_copy_connection {
         }
     }
 
+    #[test]
+    fn send_samples_of_multiple_segments_can_be_acquired<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 10;
+        const NUMBER_OF_SEGMENTS: u8 = 2;
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let _sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        let mut offsets = HashSet::new();
+
+        for i in 0..BUFFER_SIZE {
+            let segment_id = SegmentId::new((i % NUMBER_OF_SEGMENTS as usize) as u8);
+            let sample_size = SAMPLE_SIZE * (segment_id.value() as usize + 1);
+            let sample_offset =
+                PointerOffset::from_offset_and_segment_id(sample_size * i, segment_id);
+            offsets.insert(sample_offset.value());
+            assert_that!(sut_sender.try_send(sample_offset, sample_size), is_ok);
+        }
+
+        unsafe {
+            sut_sender.acquire_used_offsets(|offset| {
+                assert_that!(offsets.remove(&offset.value()), eq true);
+            })
+        };
+        assert_that!(offsets, len 0);
+    }
+
     #[test]
     fn send_samples_can_be_acquired_with_overflow<Sut: ZeroCopyConnection>() {
         const BUFFER_SIZE: usize = 10;
@@ -614,7 +723,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -624,7 +733,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * (i + BUFFER_SIZE);
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -661,7 +770,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -705,7 +814,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * (i + BUFFER_SIZE);
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -725,6 +834,163 @@ mod zero_copy_connection {
         }
     }
 
+    #[test]
+    fn sender_is_informed_when_receiver_left<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        assert_that!(sut_sender.has_receiver_left(), eq false);
+        drop(sut_receiver);
+        assert_that!(sut_sender.has_receiver_left(), eq true);
+    }
+
+    #[test]
+    fn borrowed_samples_can_be_acquired_when_receiver_is_removed<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 10;
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        let mut offsets = HashSet::new();
+        for i in 0..BUFFER_SIZE {
+            let sample_offset = SAMPLE_SIZE * i;
+            offsets.insert(sample_offset);
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+
+        for _ in 0..BUFFER_SIZE / 2 {
+            assert_that!(sut_receiver.receive().unwrap(), is_some);
+        }
+
+        // emulates a receiver that died while holding samples
+        core::mem::forget(sut_receiver);
+
+        let result = unsafe { Sut::remove_receiver(&name, &Sut::Configuration::default()) };
+        assert_that!(result, eq Ok(true));
+        assert_that!(sut_sender.has_receiver_left(), eq true);
+        assert_that!(sut_sender.is_connected(), eq false);
+
+        unsafe {
+            sut_sender.acquire_used_offsets(|offset| {
+                assert_that!(offsets.remove(&offset.value()), eq true);
+            })
+        };
+        assert_that!(offsets, len 0);
+
+        drop(sut_sender);
+        assert_that!(Sut::does_exist(&name), eq Ok(false));
+    }
+
+    #[test]
+    fn remove_receiver_removes_connection_without_sender<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+        core::mem::forget(sut_receiver);
+
+        let result = unsafe { Sut::remove_receiver(&name, &Sut::Configuration::default()) };
+        assert_that!(result, eq Ok(true));
+        assert_that!(Sut::does_exist(&name), eq Ok(false));
+    }
+
+    #[test]
+    fn remove_receiver_of_non_existing_connection_returns_false<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let result = unsafe { Sut::remove_receiver(&name, &Sut::Configuration::default()) };
+        assert_that!(result, eq Ok(false));
+    }
+
+    #[test]
+    fn statistics_of_non_existing_connection_returns_none<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let result = Sut::statistics(&name, &Sut::Configuration::default());
+        assert_that!(result, eq Ok(None));
+    }
+
+    #[test]
+    fn statistics_are_updated_by_sender_and_receiver<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = Sut::Configuration::default();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .receiver_max_borrowed_samples(3)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .receiver_max_borrowed_samples(3)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        let statistics = Sut::statistics(&name, &config).unwrap().unwrap();
+        assert_that!(statistics.buffer_size, eq 4);
+        assert_that!(statistics.max_borrowed_samples, eq 3);
+        assert_that!(statistics.number_of_sent_samples, eq 0);
+
+        for i in 0..3 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(i * SAMPLE_SIZE), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+
+        let statistics = Sut::statistics(&name, &config).unwrap().unwrap();
+        assert_that!(statistics.number_of_sent_samples, eq 3);
+        assert_that!(statistics.number_of_queued_samples, eq 3);
+        assert_that!(statistics.number_of_received_samples, eq 0);
+
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sut_receiver.receive().unwrap(), is_some);
+
+        let statistics = Sut::statistics(&name, &config).unwrap().unwrap();
+        assert_that!(statistics.number_of_queued_samples, eq 1);
+        assert_that!(statistics.number_of_received_samples, eq 2);
+        assert_that!(statistics.number_of_borrowed_samples, eq 2);
+
+        assert_that!(sut_receiver.release(sample), is_ok);
+
+        let statistics = Sut::statistics(&name, &config).unwrap().unwrap();
+        assert_that!(statistics.number_of_borrowed_samples, eq 1);
+        assert_that!(statistics.accumulated_latency, eq Duration::ZERO);
+
+        sut_receiver.record_latency(Duration::from_micros(3));
+        sut_receiver.record_latency(Duration::from_micros(5));
+
+        let statistics = Sut::statistics(&name, &config).unwrap().unwrap();
+        assert_that!(statistics.accumulated_latency, eq Duration::from_micros(8));
+        assert_that!(statistics.last_latency, eq Duration::from_micros(5));
+    }
+
     #[test]
     fn list_connections_works<Sut: ZeroCopyConnection>() {
         let mut sut_names = vec![];
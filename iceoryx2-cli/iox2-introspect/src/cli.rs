@@ -0,0 +1,47 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::Parser;
+use iceoryx2_cli_utils::cli::{help_template, CommonArgs};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "iox2-introspect",
+    about = "Live view of the throughput and buffer usage of all iceoryx2 services",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    help_template = help_template("introspect", false),
+)]
+pub struct Cli {
+    #[arg(help = "Only show the service with this name")]
+    pub service: Option<String>,
+
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 0,
+        help = "Number of updates to show, 0 updates until the process is terminated"
+    )]
+    pub count: u64,
+
+    #[arg(
+        short,
+        long,
+        default_value_t = 1000,
+        help = "Interval in milliseconds in which the statistics are updated"
+    )]
+    pub rate: u64,
+
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
@@ -0,0 +1,313 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use iceoryx2::config::Config;
+use iceoryx2::prelude::*;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as StaticConfigPattern;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2_cli_utils::output::ServiceDescriptor;
+use iceoryx2_cli_utils::raw::open_raw_service;
+use serde::Serialize;
+
+/// The throughput and loan usage of a publisher.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PublisherStatisticsDescriptor {
+    pub id: String,
+    pub sent_samples: u64,
+    pub samples_per_second: f64,
+    pub loaned_samples: usize,
+    pub max_loaned_samples: usize,
+}
+
+/// The fill level of the receive buffer and the borrowed samples of a subscriber, summed up
+/// over the connections to all publishers, and the average latency in microseconds of the
+/// samples it received since the previous update.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubscriberStatisticsDescriptor {
+    pub id: String,
+    pub buffer_size: usize,
+    pub queued_samples: usize,
+    pub borrowed_samples: usize,
+    pub max_borrowed_samples: usize,
+    pub received_samples: u64,
+    pub latency_us: f64,
+}
+
+/// The notification rate of a notifier.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NotifierStatisticsDescriptor {
+    pub id: String,
+    pub notifications: u64,
+    pub notifications_per_second: f64,
+}
+
+/// The statistics of all ports of a service.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ServiceStatisticsDescriptor {
+    pub service: ServiceDescriptor,
+    pub publishers: Vec<PublisherStatisticsDescriptor>,
+    pub subscribers: Vec<SubscriberStatisticsDescriptor>,
+    pub notifiers: Vec<NotifierStatisticsDescriptor>,
+}
+
+/// Acquires the statistics of all publish-subscribe and event services and computes the rates
+/// from the counters of the previous update.
+pub struct Introspection {
+    node: Node<ipc::Service>,
+    service_name: Option<String>,
+    last_update: Option<Instant>,
+    counters: HashMap<(u128, Counter), u64>,
+}
+
+impl Introspection {
+    /// Creates a new [`Introspection`]. When a `service_name` is provided only the services
+    /// with this name are introspected.
+    pub fn new(config: &Config, service_name: Option<&str>) -> Result<Self> {
+        let node = NodeBuilder::new()
+            .config(config)
+            .create::<ipc::Service>()
+            .map_err(|e| anyhow!("Failed to create node ({:?})", e))?;
+
+        Ok(Self {
+            node,
+            service_name: service_name.map(|s| s.to_string()),
+            last_update: None,
+            counters: HashMap::new(),
+        })
+    }
+
+    /// Returns the current statistics of all services sorted by name. The rates are computed
+    /// from the difference to the previous call and are 0 on the first call.
+    pub fn update(&mut self) -> Result<Vec<ServiceStatisticsDescriptor>> {
+        let mut services = vec![];
+        ipc::Service::list(self.node.config(), |details| {
+            if self
+                .service_name
+                .as_ref()
+                .map_or(true, |name| name == details.static_details.name().as_str())
+            {
+                services.push(details.static_details);
+            }
+            CallbackProgression::Continue
+        })
+        .map_err(|e| anyhow!("Failed to list services ({:?})", e))?;
+
+        let now = Instant::now();
+        let elapsed = self.last_update.map(|last| now.duration_since(last));
+        let mut rates = Rates {
+            previous: core::mem::take(&mut self.counters),
+            current: HashMap::new(),
+            elapsed,
+        };
+
+        let mut statistics = vec![];
+        for service in &services {
+            // services that are removed or cannot be opened in the meantime are skipped
+            let entry = match service.messaging_pattern() {
+                StaticConfigPattern::PublishSubscribe(_) => {
+                    self.publish_subscribe_statistics(service, &mut rates)
+                }
+                StaticConfigPattern::Event(_) => self.event_statistics(service, &mut rates),
+                _ => continue,
+            };
+
+            if let Ok(entry) = entry {
+                statistics.push(entry);
+            }
+        }
+
+        self.counters = rates.current;
+        self.last_update = Some(now);
+
+        statistics.sort_by(|lhs, rhs| lhs.service.cmp(&rhs.service));
+        Ok(statistics)
+    }
+
+    fn publish_subscribe_statistics(
+        &self,
+        service: &StaticConfig,
+        rates: &mut Rates,
+    ) -> Result<ServiceStatisticsDescriptor> {
+        let (port_factory, _) = open_raw_service(&self.node, service.name().as_str())?;
+        let max_borrowed_samples = port_factory
+            .static_config()
+            .subscriber_max_borrowed_samples();
+
+        let mut publishers = vec![];
+        port_factory.list_publisher_statistics(|details, statistics| {
+            let id = details.publisher_id().value();
+            publishers.push(PublisherStatisticsDescriptor {
+                id: id_to_string(id),
+                sent_samples: statistics.number_of_sent_samples,
+                samples_per_second: rates.update(
+                    id,
+                    Counter::SentSamples,
+                    statistics.number_of_sent_samples,
+                ),
+                loaned_samples: statistics.number_of_loaned_samples,
+                max_loaned_samples: details.max_loaned_samples(),
+            });
+            CallbackProgression::Continue
+        });
+
+        let mut subscribers = vec![];
+        let mut subscriber_ids = vec![];
+        port_factory.dynamic_config().list_subscribers(|details| {
+            subscriber_ids.push(details.subscriber_id().value());
+            subscribers.push(SubscriberStatisticsDescriptor {
+                id: id_to_string(details.subscriber_id().value()),
+                buffer_size: details.buffer_size(),
+                queued_samples: 0,
+                borrowed_samples: 0,
+                max_borrowed_samples,
+                received_samples: 0,
+                latency_us: 0.0,
+            });
+            CallbackProgression::Continue
+        });
+
+        let mut accumulated_latencies = vec![0u64; subscribers.len()];
+        port_factory.list_connection_statistics(|statistics| {
+            let id = statistics.subscriber_id.value();
+            if let Some(index) = subscriber_ids.iter().position(|s| *s == id) {
+                let subscriber = &mut subscribers[index];
+                subscriber.queued_samples += statistics.number_of_queued_samples;
+                subscriber.borrowed_samples += statistics.number_of_borrowed_samples;
+                subscriber.received_samples += statistics.number_of_received_samples;
+                accumulated_latencies[index] += statistics.accumulated_latency.as_nanos() as u64;
+            }
+            CallbackProgression::Continue
+        });
+
+        for (subscriber, (id, accumulated_latency)) in subscribers
+            .iter_mut()
+            .zip(subscriber_ids.iter().zip(accumulated_latencies))
+        {
+            let received_samples =
+                rates.difference(*id, Counter::ReceivedSamples, subscriber.received_samples);
+            let latency = rates.difference(*id, Counter::AccumulatedLatency, accumulated_latency);
+            if let (Some(received_samples), Some(latency)) = (received_samples, latency) {
+                if received_samples != 0 {
+                    subscriber.latency_us = latency as f64 / received_samples as f64 / 1000.0;
+                }
+            }
+        }
+
+        Ok(ServiceStatisticsDescriptor {
+            service: ServiceDescriptor::from(service),
+            publishers,
+            subscribers,
+            notifiers: vec![],
+        })
+    }
+
+    fn event_statistics(
+        &self,
+        service: &StaticConfig,
+        rates: &mut Rates,
+    ) -> Result<ServiceStatisticsDescriptor> {
+        let port_factory = self
+            .node
+            .service_builder(service.name())
+            .event()
+            .open()
+            .map_err(|e| anyhow!("Failed to open service \"{}\" ({:?})", service.name(), e))?;
+
+        let mut notifiers = vec![];
+        port_factory.list_notifier_statistics(|details, statistics| {
+            let id = details.notifier_id().value();
+            notifiers.push(NotifierStatisticsDescriptor {
+                id: id_to_string(id),
+                notifications: statistics.number_of_notifications,
+                notifications_per_second: rates.update(
+                    id,
+                    Counter::Notifications,
+                    statistics.number_of_notifications,
+                ),
+            });
+            CallbackProgression::Continue
+        });
+
+        Ok(ServiceStatisticsDescriptor {
+            service: ServiceDescriptor::from(service),
+            publishers: vec![],
+            subscribers: vec![],
+            notifiers,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Counter {
+    SentSamples,
+    ReceivedSamples,
+    AccumulatedLatency,
+    Notifications,
+}
+
+struct Rates {
+    previous: HashMap<(u128, Counter), u64>,
+    current: HashMap<(u128, Counter), u64>,
+    elapsed: Option<Duration>,
+}
+
+impl Rates {
+    // returns how much the counter has grown since the previous update, none when the
+    // counter was not yet acquired in the previous update
+    fn difference(&mut self, id: u128, kind: Counter, counter: u64) -> Option<u64> {
+        self.current.insert((id, kind), counter);
+        self.previous
+            .get(&(id, kind))
+            .map(|previous| counter.saturating_sub(*previous))
+    }
+
+    fn update(&mut self, id: u128, kind: Counter, counter: u64) -> f64 {
+        match (self.difference(id, kind, counter), self.elapsed) {
+            (Some(difference), Some(elapsed)) if !elapsed.is_zero() => {
+                difference as f64 / elapsed.as_secs_f64()
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+fn id_to_string(id: u128) -> String {
+    format!("{:032x}", id)
+}
+
+/// Hands the statistics of all services to the `callback` every `rate` until `count` updates
+/// were made, or until the process is terminated when `count` is 0. Returns the number of
+/// updates.
+pub fn introspect<F: FnMut(&[ServiceStatisticsDescriptor]) -> Result<()>>(
+    config: &Config,
+    service_name: Option<&str>,
+    count: u64,
+    rate: Duration,
+    mut callback: F,
+) -> Result<u64> {
+    let mut introspection = Introspection::new(config, service_name)?;
+    // the first update only acquires the counters from which the rates are computed
+    introspection.update()?;
+
+    let mut updates = 0;
+    while count == 0 || updates < count {
+        std::thread::sleep(rate);
+        callback(&introspection.update()?)?;
+        updates += 1;
+    }
+
+    Ok(updates)
+}
@@ -10,6 +10,63 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(not(debug_assertions))]
+use human_panic::setup_panic;
+
+#[cfg(debug_assertions)]
+extern crate better_panic;
+
+mod cli;
+mod commands;
+
+use std::io::IsTerminal;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+use cli::Cli;
+use iceoryx2_bb_log::{set_log_level, LogLevel};
+
+fn run(cli: Cli) -> Result<()> {
+    let config = cli.common.config()?;
+    let format = cli.common.format;
+    let is_terminal = std::io::stdout().is_terminal();
+
+    commands::introspect(
+        &config,
+        cli.service.as_deref(),
+        cli.count,
+        Duration::from_millis(cli.rate),
+        |statistics| {
+            if is_terminal {
+                // clear the screen and move the cursor to the top left for a top-like view
+                print!("\x1B[2J\x1B[1;1H");
+            }
+            format.print(&statistics)
+        },
+    )?;
+
+    Ok(())
+}
+
 fn main() {
-    println!("Not implemented. Stay tuned!");
+    #[cfg(not(debug_assertions))]
+    {
+        setup_panic!();
+    }
+    #[cfg(debug_assertions)]
+    {
+        better_panic::Settings::debug()
+            .most_recent_first(false)
+            .lineno_suffix(true)
+            .verbosity(better_panic::Verbosity::Full)
+            .install();
+    }
+
+    set_log_level(LogLevel::Warn);
+
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("Failed to execute command: {}", e);
+        std::process::exit(1);
+    }
 }
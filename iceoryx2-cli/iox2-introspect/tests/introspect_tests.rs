@@ -0,0 +1,133 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod introspect {
+    use std::time::Duration;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iox2_introspect::commands::*;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "iox2_introspect_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn publish_subscribe_statistics_are_acquired() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(2)
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(3)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sut =
+            Introspection::new(Config::global_config(), Some(service_name.as_str())).unwrap();
+        let statistics = sut.update().unwrap();
+        assert_that!(statistics, len 1);
+        assert_that!(statistics[0].publishers[0].samples_per_second, eq 0.0);
+        assert_that!(statistics[0].subscribers[0].latency_us, eq 0.0);
+
+        for i in 0..3 {
+            publisher.send_copy(i).unwrap();
+        }
+        let _sample = subscriber.receive().unwrap().unwrap();
+        let _loan = publisher.loan().unwrap();
+
+        let statistics = sut.update().unwrap();
+        assert_that!(statistics, len 1);
+        assert_that!(statistics[0].service.name, eq service_name.as_str());
+        assert_that!(statistics[0].notifiers, len 0);
+
+        let publishers = &statistics[0].publishers;
+        assert_that!(publishers, len 1);
+        assert_that!(publishers[0].sent_samples, eq 3);
+        assert_that!(publishers[0].samples_per_second, gt 0.0);
+        assert_that!(publishers[0].loaned_samples, eq 1);
+        assert_that!(publishers[0].max_loaned_samples, eq 3);
+
+        let subscribers = &statistics[0].subscribers;
+        assert_that!(subscribers, len 1);
+        assert_that!(subscribers[0].buffer_size, eq 4);
+        assert_that!(subscribers[0].queued_samples, eq 2);
+        assert_that!(subscribers[0].borrowed_samples, eq 1);
+        assert_that!(subscribers[0].max_borrowed_samples, eq 2);
+        assert_that!(subscribers[0].received_samples, eq 1);
+        assert_that!(subscribers[0].latency_us, gt 0.0);
+    }
+
+    #[test]
+    fn event_statistics_are_acquired() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        let mut sut =
+            Introspection::new(Config::global_config(), Some(service_name.as_str())).unwrap();
+        sut.update().unwrap();
+
+        notifier.notify().unwrap();
+        notifier.notify().unwrap();
+
+        let statistics = sut.update().unwrap();
+        assert_that!(statistics, len 1);
+        assert_that!(statistics[0].publishers, len 0);
+
+        let notifiers = &statistics[0].notifiers;
+        assert_that!(notifiers, len 1);
+        assert_that!(notifiers[0].notifications, eq 2);
+        assert_that!(notifiers[0].notifications_per_second, gt 0.0);
+    }
+
+    #[test]
+    fn introspect_stops_after_count_updates() {
+        let service_name = generate_name();
+
+        let mut callbacks = 0;
+        let updates = introspect(
+            Config::global_config(),
+            Some(service_name.as_str()),
+            2,
+            Duration::from_millis(1),
+            |statistics| {
+                assert_that!(statistics, len 0);
+                callbacks += 1;
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_that!(updates, eq 2);
+        assert_that!(callbacks, eq 2);
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::{Args, Parser, Subcommand};
+use iceoryx2_cli_utils::cli::{help_template, CommonArgs};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "iox2-processes",
+    about = "Query information about iceoryx2 processes and their nodes",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = true,
+    help_template = help_template("processes", true),
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub action: Action,
+
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct DetailsOptions {
+    #[arg(help = "Id of the node")]
+    pub node: String,
+}
+
+#[derive(Args, Debug)]
+pub struct CleanupOptions {
+    #[arg(help = "Id of the dead node that shall be cleaned up, all dead nodes when omitted")]
+    pub node: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Action {
+    #[command(about = "List all existing nodes")]
+    List,
+    #[command(about = "Show the details of a node and the services it is connected to")]
+    Details(DetailsOptions),
+    #[command(about = "Remove the stale resources of dead nodes")]
+    Cleanup(CleanupOptions),
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{anyhow, Result};
+use iceoryx2::config::Config;
+use iceoryx2::node::NodeView;
+use iceoryx2::prelude::*;
+use iceoryx2_cli_utils::output::{node_id_to_string, NodeDescriptor, ServiceDescriptor};
+use serde::Serialize;
+
+/// All details of a node and the services it is connected to.
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeDetailsDescriptor {
+    pub node: NodeDescriptor,
+    pub services: Vec<ServiceDescriptor>,
+}
+
+/// The outcome of a cleanup, the ids of all dead nodes whose stale resources were removed, of
+/// those that were cleaned up concurrently by another process and of those that could not be
+/// cleaned up.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CleanupDescriptor {
+    pub cleaned_up: Vec<String>,
+    pub already_cleaned_up: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+fn node_states(config: &Config) -> Result<Vec<NodeState<ipc::Service>>> {
+    let mut nodes = vec![];
+    Node::<ipc::Service>::list(config, |state| {
+        nodes.push(state);
+        CallbackProgression::Continue
+    })
+    .map_err(|e| anyhow!("Failed to list nodes ({:?})", e))?;
+
+    Ok(nodes)
+}
+
+fn find_node(config: &Config, node_id: &str) -> Result<NodeState<ipc::Service>> {
+    node_states(config)?
+        .into_iter()
+        .find(|state| node_id_to_string(state.node_id()) == node_id)
+        .ok_or_else(|| anyhow!("The node \"{}\" does not exist", node_id))
+}
+
+/// Returns all existing nodes sorted by their id.
+pub fn list(config: &Config) -> Result<Vec<NodeDescriptor>> {
+    let mut nodes: Vec<NodeDescriptor> = node_states(config)?
+        .iter()
+        .map(NodeDescriptor::from)
+        .collect();
+
+    nodes.sort_by(|lhs, rhs| lhs.id.cmp(&rhs.id));
+    Ok(nodes)
+}
+
+/// Returns the details of the node with the given id together with all services the node is
+/// registered at.
+pub fn details(config: &Config, node_id: &str) -> Result<NodeDetailsDescriptor> {
+    let state = find_node(config, node_id)?;
+    let id = *state.node_id();
+
+    let mut services = vec![];
+    ipc::Service::list(config, |details| {
+        if let Some(dynamic_details) = &details.dynamic_details {
+            if dynamic_details.nodes.iter().any(|n| *n.node_id() == id) {
+                services.push(ServiceDescriptor::from(&details.static_details));
+            }
+        }
+        CallbackProgression::Continue
+    })
+    .map_err(|e| anyhow!("Failed to list services ({:?})", e))?;
+
+    services.sort();
+    Ok(NodeDetailsDescriptor {
+        node: NodeDescriptor::from(&state),
+        services,
+    })
+}
+
+/// Removes the stale resources of the dead node with the given id or of all dead nodes when no
+/// id is provided. Fails when the given node is not dead.
+pub fn cleanup(config: &Config, node_id: Option<&str>) -> Result<CleanupDescriptor> {
+    let states = match node_id {
+        Some(node_id) => {
+            let state = find_node(config, node_id)?;
+            if !matches!(state, NodeState::Dead(_)) {
+                return Err(anyhow!(
+                    "The node \"{}\" cannot be cleaned up since it is not dead",
+                    node_id
+                ));
+            }
+            vec![state]
+        }
+        None => node_states(config)?,
+    };
+
+    let mut result = CleanupDescriptor::default();
+    for state in states {
+        if let NodeState::Dead(view) = state {
+            let id = node_id_to_string(view.id());
+            match view.remove_stale_resources() {
+                Ok(true) => result.cleaned_up.push(id),
+                // another process acquired the cleanup of the node first
+                Ok(false) => result.already_cleaned_up.push(id),
+                Err(_) => result.failed.push(id),
+            }
+        }
+    }
+
+    Ok(result)
+}
@@ -10,6 +10,51 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(not(debug_assertions))]
+use human_panic::setup_panic;
+
+#[cfg(debug_assertions)]
+extern crate better_panic;
+
+mod cli;
+mod commands;
+
+use anyhow::Result;
+use clap::Parser;
+use cli::{Action, Cli};
+use iceoryx2_bb_log::{set_log_level, LogLevel};
+
+fn run(cli: Cli) -> Result<()> {
+    let config = cli.common.config()?;
+    let format = cli.common.format;
+
+    match cli.action {
+        Action::List => format.print(&commands::list(&config)?),
+        Action::Details(options) => format.print(&commands::details(&config, &options.node)?),
+        Action::Cleanup(options) => {
+            format.print(&commands::cleanup(&config, options.node.as_deref())?)
+        }
+    }
+}
+
 fn main() {
-    println!("Not implemented. Stay tuned!");
+    #[cfg(not(debug_assertions))]
+    {
+        setup_panic!();
+    }
+    #[cfg(debug_assertions)]
+    {
+        better_panic::Settings::debug()
+            .most_recent_first(false)
+            .lineno_suffix(true)
+            .verbosity(better_panic::Verbosity::Full)
+            .install();
+    }
+
+    set_log_level(LogLevel::Warn);
+
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("Failed to execute command: {}", e);
+        std::process::exit(1);
+    }
 }
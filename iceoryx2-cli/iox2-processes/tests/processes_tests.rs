@@ -0,0 +1,122 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod processes {
+    use iceoryx2::config::Config;
+    use iceoryx2::node::testing::__internal_node_staged_death;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::monitoring::testing::__InternalMonitoringTokenTestable;
+    use iceoryx2_cli_utils::output::{node_id_to_string, NodeStateKind};
+    use iox2_processes::commands::*;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "iox2_processes_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn config_without_cleanup() -> Config {
+        let mut config = Config::global_config().clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config
+    }
+
+    #[test]
+    fn list_contains_alive_node_with_details() {
+        let node_name = NodeName::new("the_list_node").unwrap();
+        let node = NodeBuilder::new()
+            .name(&node_name)
+            .create::<ipc::Service>()
+            .unwrap();
+        let id = node_id_to_string(node.id());
+
+        let nodes = list(Config::global_config()).unwrap();
+        let sut = nodes.iter().find(|n| n.id == id).unwrap();
+
+        assert_that!(sut.state, eq NodeStateKind::Alive);
+        assert_that!(sut.pid, eq node.id().pid().value());
+        assert_that!(sut.name, eq Some(node_name.to_string()));
+        assert_that!(sut.executable, is_some);
+    }
+
+    #[test]
+    fn details_contain_services_of_node() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let id = node_id_to_string(node.id());
+
+        let sut = details(Config::global_config(), &id).unwrap();
+
+        assert_that!(sut.node.id, eq id);
+        assert_that!(sut.services, len 1);
+        assert_that!(sut.services[0].name, eq service_name.as_str());
+    }
+
+    #[test]
+    fn details_of_non_existing_node_fails() {
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let id = node_id_to_string(node.id());
+        drop(node);
+
+        assert_that!(details(Config::global_config(), &id), is_err);
+    }
+
+    #[test]
+    fn cleanup_of_alive_node_fails() {
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let id = node_id_to_string(node.id());
+
+        assert_that!(cleanup(Config::global_config(), Some(&id)), is_err);
+    }
+
+    #[test]
+    fn cleanup_removes_dead_node() {
+        let config = config_without_cleanup();
+        let fake_node_id = ((u32::MAX - std::process::id()) as u128) << 96;
+        let fake_node_id = unsafe { core::mem::transmute::<u128, UniqueSystemId>(fake_node_id) };
+        let mut node = unsafe {
+            NodeBuilder::new()
+                .config(&config)
+                .__internal_create_with_custom_node_id::<ipc::Service>(fake_node_id)
+                .unwrap()
+        };
+        let id = node_id_to_string(node.id());
+        let monitor = unsafe { __internal_node_staged_death(&mut node) };
+        monitor.staged_death();
+        core::mem::forget(node);
+
+        let state = list(&config)
+            .unwrap()
+            .into_iter()
+            .find(|n| n.id == id)
+            .unwrap()
+            .state;
+        assert_that!(state, eq NodeStateKind::Dead);
+
+        let sut = cleanup(&config, Some(&id)).unwrap();
+        assert_that!(sut.cleaned_up, eq vec![id.clone()]);
+        assert_that!(sut.already_cleaned_up, len 0);
+        assert_that!(sut.failed, len 0);
+
+        assert_that!(list(&config).unwrap().iter().any(|n| n.id == id), eq false);
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::path::PathBuf;
+
+use clap::{ArgGroup, Parser};
+use iceoryx2_cli_utils::cli::{help_template, CommonArgs};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "iox2-pub",
+    about = "Publish raw bytes to an existing publish-subscribe service",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = true,
+    help_template = help_template("pub", false),
+    group(ArgGroup::new("input").required(true).args(["hex", "base64", "file"])),
+)]
+pub struct Cli {
+    #[arg(help = "Name of the service")]
+    pub service: String,
+
+    #[arg(long, help = "Payload as hexadecimal string")]
+    pub hex: Option<String>,
+
+    #[arg(long, help = "Payload as base64 string")]
+    pub base64: Option<String>,
+
+    #[arg(long, help = "File whose content is published as payload")]
+    pub file: Option<PathBuf>,
+
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 1,
+        help = "Number of samples to publish, 0 publishes until the process is terminated"
+    )]
+    pub count: u64,
+
+    #[arg(
+        short,
+        long,
+        default_value_t = 1000,
+        help = "Interval in milliseconds after every published sample in which subscribers can connect"
+    )]
+    pub rate: u64,
+
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
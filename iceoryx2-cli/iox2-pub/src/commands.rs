@@ -0,0 +1,87 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use iceoryx2::config::Config;
+use iceoryx2::prelude::*;
+use iceoryx2_cli_utils::raw::{number_of_elements, open_raw_service, Encoding};
+
+/// The source of the raw payload bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input<'a> {
+    Encoded(Encoding, &'a str),
+    File(&'a Path),
+}
+
+impl Input<'_> {
+    /// Reads the raw payload bytes.
+    pub fn read(&self) -> Result<Vec<u8>> {
+        match self {
+            Input::Encoded(encoding, value) => encoding.decode(value),
+            Input::File(path) => std::fs::read(path)
+                .with_context(|| format!("Failed to read payload from \"{}\"", path.display())),
+        }
+    }
+}
+
+/// Publishes the raw payload `count` times, or until the process is terminated when
+/// `count` is 0, to the service and waits `rate` after every sample. Returns the number of
+/// published samples.
+pub fn publish(
+    config: &Config,
+    service_name: &str,
+    payload: &[u8],
+    count: u64,
+    rate: Duration,
+) -> Result<u64> {
+    let node = NodeBuilder::new()
+        .config(config)
+        .create::<ipc::Service>()
+        .map_err(|e| anyhow!("Failed to create node ({:?})", e))?;
+
+    let (service, type_details) = open_raw_service(&node, service_name)?;
+    let slice_len = number_of_elements(&type_details, payload)?;
+
+    let publisher = service
+        .publisher_builder()
+        .max_slice_len(slice_len)
+        .create()
+        .map_err(|e| anyhow!("Failed to create publisher ({:?})", e))?;
+
+    let mut published = 0;
+    while count == 0 || published < count {
+        let mut sample = publisher
+            .loan_slice_uninit(slice_len)
+            .map_err(|e| anyhow!("Failed to loan sample ({:?})", e))?;
+
+        // the user header is unknown, it is zeroed so that no stale data is sent
+        unsafe {
+            (sample.user_header_mut() as *mut _ as *mut u8)
+                .write_bytes(0, type_details.user_header.size)
+        };
+
+        sample
+            .write_from_fn(|n| payload[n])
+            .send()
+            .map_err(|e| anyhow!("Failed to send sample ({:?})", e))?;
+        published += 1;
+
+        // subscribers connect to the publisher when they check for new samples, therefore
+        // the publisher stays connected for one interval after every sample
+        std::thread::sleep(rate);
+    }
+
+    Ok(published)
+}
@@ -10,6 +10,63 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(not(debug_assertions))]
+use human_panic::setup_panic;
+
+#[cfg(debug_assertions)]
+extern crate better_panic;
+
+mod cli;
+mod commands;
+
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+use cli::Cli;
+use commands::Input;
+use iceoryx2_bb_log::{set_log_level, LogLevel};
+use iceoryx2_cli_utils::raw::Encoding;
+
+fn run(cli: Cli) -> Result<()> {
+    let config = cli.common.config()?;
+    let input = match (&cli.hex, &cli.base64, &cli.file) {
+        (Some(value), _, _) => Input::Encoded(Encoding::Hex, value),
+        (_, Some(value), _) => Input::Encoded(Encoding::Base64, value),
+        (_, _, Some(path)) => Input::File(path),
+        _ => unreachable!("clap requires one of the input arguments"),
+    };
+
+    let payload = input.read()?;
+    commands::publish(
+        &config,
+        &cli.service,
+        &payload,
+        cli.count,
+        Duration::from_millis(cli.rate),
+    )?;
+
+    Ok(())
+}
+
 fn main() {
-    println!("Not implemented. Stay tuned!");
+    #[cfg(not(debug_assertions))]
+    {
+        setup_panic!();
+    }
+    #[cfg(debug_assertions)]
+    {
+        better_panic::Settings::debug()
+            .most_recent_first(false)
+            .lineno_suffix(true)
+            .verbosity(better_panic::Verbosity::Full)
+            .install();
+    }
+
+    set_log_level(LogLevel::Warn);
+
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("Failed to execute command: {}", e);
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,145 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod publish {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iox2_pub::commands::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const RATE: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "iox2_pub_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn publish_in_background(
+        service_name: &ServiceName,
+        payload: Vec<u8>,
+        count: u64,
+    ) -> std::thread::JoinHandle<u64> {
+        let service_name = service_name.as_str().to_string();
+        std::thread::spawn(move || {
+            publish(
+                Config::global_config(),
+                &service_name,
+                &payload,
+                count,
+                RATE,
+            )
+            .unwrap()
+        })
+    }
+
+    #[test]
+    fn published_raw_bytes_are_received_as_typed_payload() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let value: u64 = 0x0123456789abcdef;
+        let publisher = publish_in_background(&service_name, value.to_ne_bytes().to_vec(), 2);
+
+        let start = Instant::now();
+        let mut received = 0;
+        while received < 2 && start.elapsed() < TIMEOUT {
+            if let Some(sample) = subscriber.receive().unwrap() {
+                assert_that!(*sample, eq value);
+                received += 1;
+            }
+        }
+
+        assert_that!(publisher.join().unwrap(), eq 2);
+        assert_that!(received, eq 2);
+    }
+
+    #[test]
+    fn published_raw_bytes_are_received_as_typed_slice() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let values: [u16; 3] = [1, 2, 3];
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+        let publisher = publish_in_background(&service_name, bytes, 1);
+
+        let start = Instant::now();
+        let mut sample = None;
+        while sample.is_none() && start.elapsed() < TIMEOUT {
+            sample = subscriber.receive().unwrap();
+        }
+        publisher.join().unwrap();
+
+        assert_that!(sample, is_some);
+        let sample = sample.unwrap();
+        assert_that!(sample.payload(), eq & values[..]);
+    }
+
+    #[test]
+    fn publishing_payload_with_wrong_size_fails() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let result = publish(
+            Config::global_config(),
+            service_name.as_str(),
+            &[1, 2, 3],
+            1,
+            Duration::ZERO,
+        );
+        assert_that!(result, is_err);
+    }
+
+    #[test]
+    fn publishing_to_non_existing_service_fails() {
+        let service_name = generate_name();
+
+        let result = publish(
+            Config::global_config(),
+            service_name.as_str(),
+            &[0; 8],
+            1,
+            Duration::ZERO,
+        );
+        assert_that!(result, is_err);
+    }
+
+    #[test]
+    fn encoded_input_is_decoded() {
+        let sut = Input::Encoded(iceoryx2_cli_utils::raw::Encoding::Hex, "0102");
+        assert_that!(sut.read().unwrap(), eq vec![1u8, 2]);
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use iceoryx2::prelude::*;
+use iceoryx2::service::query::{ServiceNamePattern, ServiceQuery};
+use iceoryx2_cli_utils::cli::{help_template, CommonArgs};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "iox2-services",
+    about = "Query information about iceoryx2 services",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = true,
+    help_template = help_template("services", true),
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub action: Action,
+
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MessagingPatternFilter {
+    PublishSubscribe,
+    Event,
+    RequestResponse,
+    Blackboard,
+}
+
+impl From<MessagingPatternFilter> for MessagingPattern {
+    fn from(value: MessagingPatternFilter) -> Self {
+        match value {
+            MessagingPatternFilter::PublishSubscribe => MessagingPattern::PublishSubscribe,
+            MessagingPatternFilter::Event => MessagingPattern::Event,
+            MessagingPatternFilter::RequestResponse => MessagingPattern::RequestResponse,
+            MessagingPatternFilter::Blackboard => MessagingPattern::Blackboard,
+        }
+    }
+}
+
+#[derive(Args, Debug, Default)]
+pub struct ListOptions {
+    #[arg(
+        long,
+        help = "Only services whose name matches the glob pattern, '*' does not match '/', '**' does"
+    )]
+    pub name: Option<String>,
+
+    #[arg(
+        long,
+        conflicts_with = "name",
+        help = "Only services whose name starts with the prefix"
+    )]
+    pub prefix: Option<String>,
+
+    #[arg(long, value_enum, help = "Only services with the messaging pattern")]
+    pub messaging_pattern: Option<MessagingPatternFilter>,
+
+    #[arg(
+        long = "attribute",
+        value_name = "KEY[=VALUE]",
+        help = "Only services that define the attribute key or key-value pair, can be repeated"
+    )]
+    pub attributes: Vec<String>,
+}
+
+impl ListOptions {
+    /// Returns the [`ServiceQuery`] that selects the services of the options.
+    pub fn query(&self) -> ServiceQuery {
+        let mut query = ServiceQuery::new();
+
+        if let Some(name) = &self.name {
+            query = query.name(ServiceNamePattern::Glob(name.clone()));
+        } else if let Some(prefix) = &self.prefix {
+            query = query.name(ServiceNamePattern::Prefix(prefix.clone()));
+        }
+
+        if let Some(messaging_pattern) = self.messaging_pattern {
+            query = query.messaging_pattern(messaging_pattern.into());
+        }
+
+        let mut attributes = AttributeVerifier::new();
+        for attribute in &self.attributes {
+            attributes = match attribute.split_once('=') {
+                Some((key, value)) => attributes.require(key, value),
+                None => attributes.require_key(attribute),
+            };
+        }
+
+        query.attributes(&attributes)
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct DetailsOptions {
+    #[arg(help = "Name of the service")]
+    pub service: String,
+}
+
+#[derive(Args, Debug)]
+pub struct DiscoveryOptions {
+    #[arg(
+        short,
+        long,
+        default_value_t = 100,
+        help = "Interval in milliseconds in which the services are checked for changes when no discovery listener can be created"
+    )]
+    pub rate: u64,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Action {
+    #[command(about = "List all existing services, optionally filtered")]
+    List(ListOptions),
+    #[command(about = "Show the details of a service")]
+    Details(DetailsOptions),
+    #[command(about = "Watch for services that are created or removed")]
+    Discovery(DiscoveryOptions),
+}
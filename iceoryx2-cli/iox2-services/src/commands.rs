@@ -0,0 +1,193 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use iceoryx2::config::Config;
+use iceoryx2::discovery::{
+    self, DiscoveryListener, DiscoveryListenerBuilder, DiscoveryListenerWaitError,
+};
+use iceoryx2::prelude::*;
+use iceoryx2::service::query::ServiceQuery;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2_cli_utils::format::Format;
+use iceoryx2_cli_utils::output::{NodeDescriptor, PortDescriptor, ServiceDescriptor};
+use serde::Serialize;
+
+const MESSAGING_PATTERNS: [MessagingPattern; 4] = [
+    MessagingPattern::PublishSubscribe,
+    MessagingPattern::Event,
+    MessagingPattern::RequestResponse,
+    MessagingPattern::Blackboard,
+];
+
+/// All details of a service, the static configuration with attributes, QoS and type details
+/// and the nodes and ports that are connected to it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceDetailsDescriptor {
+    pub static_details: StaticConfig,
+    pub nodes: Option<Vec<NodeDescriptor>>,
+    pub ports: Option<Vec<PortDescriptor>>,
+}
+
+/// A change in the set of existing services.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum DiscoveryEvent {
+    Added(ServiceDescriptor),
+    Removed(ServiceDescriptor),
+}
+
+/// Returns all existing services sorted by name.
+pub fn list(config: &Config) -> Result<Vec<ServiceDescriptor>> {
+    query(config, &ServiceQuery::new())
+}
+
+/// Returns all existing services that match the [`ServiceQuery`] sorted by name.
+pub fn query(config: &Config, query: &ServiceQuery) -> Result<Vec<ServiceDescriptor>> {
+    let mut services = vec![];
+    ipc::Service::query(config, query, |details| {
+        services.push(ServiceDescriptor::from(&details.static_details));
+        CallbackProgression::Continue
+    })
+    .map_err(|e| anyhow!("Failed to list services ({:?})", e))?;
+
+    services.sort();
+    Ok(services)
+}
+
+/// Returns the details of all services with the given name, one for every messaging pattern
+/// under which the service exists.
+pub fn details(config: &Config, service_name: &str) -> Result<Vec<ServiceDetailsDescriptor>> {
+    let name = ServiceName::new(service_name)
+        .map_err(|e| anyhow!("Invalid service name \"{}\" ({:?})", service_name, e))?;
+
+    let mut services = vec![];
+    for pattern in MESSAGING_PATTERNS {
+        let details = ipc::Service::details(&name, config, pattern).map_err(|e| {
+            anyhow!(
+                "Failed to acquire details of service \"{}\" ({:?})",
+                service_name,
+                e
+            )
+        })?;
+
+        if let Some(details) = details {
+            services.push(ServiceDetailsDescriptor {
+                nodes: details
+                    .dynamic_details
+                    .as_ref()
+                    .map(|d| d.nodes.iter().map(NodeDescriptor::from).collect()),
+                ports: details
+                    .dynamic_details
+                    .as_ref()
+                    .map(|d| d.ports.iter().map(PortDescriptor::from).collect()),
+                static_details: details.static_details,
+            });
+        }
+    }
+
+    if services.is_empty() {
+        return Err(anyhow!("The service \"{}\" does not exist", service_name));
+    }
+
+    Ok(services)
+}
+
+/// Compares the currently existing services with the `known` services, updates them and
+/// returns the changes.
+pub fn discovery_update(
+    config: &Config,
+    known: &mut BTreeSet<ServiceDescriptor>,
+) -> Result<Vec<DiscoveryEvent>> {
+    let current: BTreeSet<ServiceDescriptor> = list(config)?.into_iter().collect();
+
+    let mut events: Vec<DiscoveryEvent> = known
+        .difference(&current)
+        .cloned()
+        .map(DiscoveryEvent::Removed)
+        .collect();
+    events.extend(
+        current
+            .difference(known)
+            .cloned()
+            .map(DiscoveryEvent::Added),
+    );
+
+    *known = current;
+    Ok(events)
+}
+
+/// Blocks until services were created or removed and returns the changes. The first call
+/// returns all existing services. Can return without any change when only nodes changed.
+pub fn discovery_wait(listener: &DiscoveryListener<ipc::Service>) -> Result<Vec<DiscoveryEvent>> {
+    let mut events = vec![];
+    listener
+        .blocking_wait(|event| match event {
+            discovery::DiscoveryEvent::ServiceAdded(service) => {
+                events.push(DiscoveryEvent::Added(ServiceDescriptor::from(&service)))
+            }
+            discovery::DiscoveryEvent::ServiceRemoved(service) => {
+                events.push(DiscoveryEvent::Removed(ServiceDescriptor::from(&service)))
+            }
+            discovery::DiscoveryEvent::NodeJoined(_) | discovery::DiscoveryEvent::NodeLeft(_) => {}
+        })
+        .map_err(|e| {
+            anyhow::Error::new(e).context(format!("Failed to wait for service changes ({:?})", e))
+        })?;
+
+    Ok(events)
+}
+
+/// Prints every change in the set of existing services until the process is terminated. The
+/// changes are reported by a [`DiscoveryListener`], only when it cannot be created the
+/// services are checked for changes with the given `rate`.
+pub fn discovery(config: &Config, rate: Duration, format: Format) -> Result<()> {
+    let listener = match DiscoveryListenerBuilder::new()
+        .config(config)
+        .create::<ipc::Service>()
+    {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!(
+                "Unable to create a discovery listener ({:?}), checking for changes every {:?}.",
+                e, rate
+            );
+            return discovery_with_polling(config, rate, format);
+        }
+    };
+
+    loop {
+        match discovery_wait(&listener) {
+            Ok(events) => {
+                for event in events {
+                    format.print(&event)?;
+                }
+            }
+            Err(e) => match e.downcast_ref::<DiscoveryListenerWaitError>() {
+                Some(DiscoveryListenerWaitError::InterruptSignal) => return Ok(()),
+                _ => return Err(e),
+            },
+        }
+    }
+}
+
+fn discovery_with_polling(config: &Config, rate: Duration, format: Format) -> Result<()> {
+    let mut known = BTreeSet::new();
+    loop {
+        for event in discovery_update(config, &mut known)? {
+            format.print(&event)?;
+        }
+        std::thread::sleep(rate);
+    }
+}
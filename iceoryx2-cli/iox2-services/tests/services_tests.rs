@@ -0,0 +1,198 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod services {
+    use std::collections::BTreeSet;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::discovery::DiscoveryListenerBuilder;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as StaticConfigPattern;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cli_utils::output::{
+        node_id_to_string, NodeStateKind, PortKind, ServiceDescriptor,
+    };
+    use iox2_services::cli::{ListOptions, MessagingPatternFilter};
+    use iox2_services::commands::*;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "iox2_services_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn list_contains_existing_services() {
+        let service_name = generate_name();
+        let config = Config::global_config();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let _pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let services: Vec<ServiceDescriptor> = list(config)
+            .unwrap()
+            .into_iter()
+            .filter(|s| s.name == service_name.as_str())
+            .collect();
+
+        assert_that!(services, len 2);
+        assert_that!(services.iter().any(|s| s.messaging_pattern == "PublishSubscribe"), eq true);
+        assert_that!(services.iter().any(|s| s.messaging_pattern == "Event"), eq true);
+    }
+
+    #[test]
+    fn query_returns_only_services_matching_the_list_options() {
+        let service_name = generate_name();
+        let config = Config::global_config();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let _lidar = node
+            .service_builder(&ServiceName::new(&format!("{}/lidar", service_name)).unwrap())
+            .publish_subscribe::<u64>()
+            .create_with_attributes(&AttributeSpecifier::new().define("sensor", "lidar"))
+            .unwrap();
+        let _camera = node
+            .service_builder(&ServiceName::new(&format!("{}/camera", service_name)).unwrap())
+            .event()
+            .create_with_attributes(&AttributeSpecifier::new().define("sensor", "camera"))
+            .unwrap();
+
+        let options = ListOptions {
+            prefix: Some(format!("{}/", service_name)),
+            ..Default::default()
+        };
+        assert_that!(query(config, &options.query()).unwrap(), len 2);
+
+        let options = ListOptions {
+            name: Some(format!("{}/*", service_name)),
+            attributes: vec!["sensor=lidar".into()],
+            ..Default::default()
+        };
+        let services = query(config, &options.query()).unwrap();
+        assert_that!(services, len 1);
+        assert_that!(services[0].messaging_pattern, eq "PublishSubscribe");
+
+        let options = ListOptions {
+            prefix: Some(format!("{}/", service_name)),
+            messaging_pattern: Some(MessagingPatternFilter::Event),
+            attributes: vec!["sensor".into()],
+            ..Default::default()
+        };
+        let services = query(config, &options.query()).unwrap();
+        assert_that!(services, len 1);
+        assert_that!(services[0].messaging_pattern, eq "Event");
+    }
+
+    #[test]
+    fn details_contain_static_config_and_nodes() {
+        let service_name = generate_name();
+        let config = Config::global_config();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(3)
+            .create()
+            .unwrap();
+        let _publisher = pubsub.publisher_builder().create().unwrap();
+
+        let services = details(config, service_name.as_str()).unwrap();
+        assert_that!(services, len 1);
+
+        let service = &services[0];
+        assert_that!(*service.static_details.name(), eq service_name);
+        match service.static_details.messaging_pattern() {
+            StaticConfigPattern::PublishSubscribe(c) => assert_that!(c.max_publishers(), eq 3),
+            _ => assert_that!(true, eq false),
+        }
+
+        let nodes = service.nodes.as_ref().unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].id, eq node_id_to_string(node.id()));
+        assert_that!(nodes[0].state, eq NodeStateKind::Alive);
+
+        let ports = service.ports.as_ref().unwrap();
+        assert_that!(ports, len 1);
+        assert_that!(ports[0].kind, eq PortKind::Publisher);
+        assert_that!(ports[0].node_id, eq node_id_to_string(node.id()));
+    }
+
+    #[test]
+    fn details_of_non_existing_service_fails() {
+        let service_name = generate_name();
+        let config = Config::global_config();
+
+        assert_that!(details(config, service_name.as_str()), is_err);
+    }
+
+    #[test]
+    fn discovery_reports_added_and_removed_services() {
+        let service_name = generate_name();
+        let config = Config::global_config();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+
+        let mut known = BTreeSet::new();
+        discovery_update(config, &mut known).unwrap();
+
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let events = discovery_update(config, &mut known).unwrap();
+        assert_that!(events.iter().any(|e| matches!(e, DiscoveryEvent::Added(s) if s.name == service_name.as_str())), eq true);
+
+        drop(event);
+
+        let events = discovery_update(config, &mut known).unwrap();
+        assert_that!(events.iter().any(|e| matches!(e, DiscoveryEvent::Removed(s) if s.name == service_name.as_str())), eq true);
+    }
+
+    #[test]
+    fn discovery_wait_reports_added_and_removed_services() {
+        let service_name = generate_name();
+        let config = Config::global_config();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+
+        let listener = DiscoveryListenerBuilder::new()
+            .config(config)
+            .create::<ipc::Service>()
+            .unwrap();
+        discovery_wait(&listener).unwrap();
+
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let events = discovery_wait(&listener).unwrap();
+        assert_that!(events.iter().any(|e| matches!(e, DiscoveryEvent::Added(s) if s.name == service_name.as_str())), eq true);
+
+        drop(event);
+
+        let events = discovery_wait(&listener).unwrap();
+        assert_that!(events.iter().any(|e| matches!(e, DiscoveryEvent::Removed(s) if s.name == service_name.as_str())), eq true);
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use iceoryx2_cli_utils::cli::{help_template, CommonArgs};
+use iceoryx2_cli_utils::raw::Encoding;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "iox2-sub",
+    about = "Receive raw bytes from an existing publish-subscribe service",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = true,
+    help_template = help_template("sub", false),
+)]
+pub struct Cli {
+    #[arg(help = "Name of the service")]
+    pub service: String,
+
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value_t = Encoding::Hex,
+        help = "Encoding in which the received payloads are printed"
+    )]
+    pub encoding: Encoding,
+
+    #[arg(
+        short,
+        long,
+        help = "File to which the raw bytes of every received payload are appended"
+    )]
+    pub output: Option<PathBuf>,
+
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 0,
+        help = "Number of samples to receive, 0 receives until the process is terminated"
+    )]
+    pub count: u64,
+
+    #[arg(
+        short,
+        long,
+        default_value_t = 10,
+        help = "Interval in milliseconds in which the subscriber checks for new samples"
+    )]
+    pub rate: u64,
+
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use iceoryx2::config::Config;
+use iceoryx2::prelude::*;
+use iceoryx2_cli_utils::raw::{open_raw_service, Encoding};
+use serde::Serialize;
+
+/// A received sample with its encoded payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SampleDescriptor {
+    pub size: usize,
+    pub payload: String,
+}
+
+impl SampleDescriptor {
+    /// Creates a [`SampleDescriptor`] from the raw payload bytes.
+    pub fn new(payload: &[u8], encoding: Encoding) -> Self {
+        Self {
+            size: payload.len(),
+            payload: encoding.encode(payload),
+        }
+    }
+}
+
+/// Receives raw payloads from the service until `count` samples were received, or until the
+/// process is terminated when `count` is 0, and checks for new samples every `rate`. Every
+/// payload is handed to the `callback`. Returns the number of received samples.
+pub fn subscribe<F: FnMut(&[u8]) -> Result<()>>(
+    config: &Config,
+    service_name: &str,
+    count: u64,
+    rate: Duration,
+    mut callback: F,
+) -> Result<u64> {
+    let node = NodeBuilder::new()
+        .config(config)
+        .create::<ipc::Service>()
+        .map_err(|e| anyhow!("Failed to create node ({:?})", e))?;
+
+    let (service, _) = open_raw_service(&node, service_name)?;
+    let subscriber = service
+        .subscriber_builder()
+        .create()
+        .map_err(|e| anyhow!("Failed to create subscriber ({:?})", e))?;
+
+    let mut received = 0;
+    while count == 0 || received < count {
+        match subscriber
+            .receive()
+            .map_err(|e| anyhow!("Failed to receive sample ({:?})", e))?
+        {
+            Some(sample) => {
+                callback(sample.payload())?;
+                received += 1;
+            }
+            None => std::thread::sleep(rate),
+        }
+    }
+
+    Ok(received)
+}
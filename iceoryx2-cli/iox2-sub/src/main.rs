@@ -10,6 +10,75 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(not(debug_assertions))]
+use human_panic::setup_panic;
+
+#[cfg(debug_assertions)]
+extern crate better_panic;
+
+mod cli;
+mod commands;
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use cli::Cli;
+use commands::SampleDescriptor;
+use iceoryx2_bb_log::{set_log_level, LogLevel};
+
+fn run(cli: Cli) -> Result<()> {
+    let config = cli.common.config()?;
+    let format = cli.common.format;
+
+    let mut output = match &cli.output {
+        Some(path) => Some(
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open \"{}\"", path.display()))?,
+        ),
+        None => None,
+    };
+
+    commands::subscribe(
+        &config,
+        &cli.service,
+        cli.count,
+        Duration::from_millis(cli.rate),
+        |payload| {
+            if let Some(file) = &mut output {
+                file.write_all(payload)
+                    .context("Failed to write payload to file")?;
+            }
+            format.print(&SampleDescriptor::new(payload, cli.encoding))
+        },
+    )?;
+
+    Ok(())
+}
+
 fn main() {
-    println!("Not implemented. Stay tuned!");
+    #[cfg(not(debug_assertions))]
+    {
+        setup_panic!();
+    }
+    #[cfg(debug_assertions)]
+    {
+        better_panic::Settings::debug()
+            .most_recent_first(false)
+            .lineno_suffix(true)
+            .verbosity(better_panic::Verbosity::Full)
+            .install();
+    }
+
+    set_log_level(LogLevel::Warn);
+
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("Failed to execute command: {}", e);
+        std::process::exit(1);
+    }
 }
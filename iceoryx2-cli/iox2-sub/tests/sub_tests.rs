@@ -0,0 +1,98 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod subscribe {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cli_utils::raw::Encoding;
+    use iox2_sub::commands::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "iox2_sub_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn typed_payload_is_received_as_raw_bytes() {
+        const VALUE: u32 = 0x11223344;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u32>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let name = service_name.as_str().to_string();
+        let subscriber = std::thread::spawn(move || {
+            let mut payloads = vec![];
+            let received = subscribe(
+                Config::global_config(),
+                &name,
+                2,
+                Duration::from_millis(1),
+                |payload| {
+                    payloads.push(payload.to_vec());
+                    Ok(())
+                },
+            )
+            .unwrap();
+            (received, payloads)
+        });
+
+        let start = Instant::now();
+        while !subscriber.is_finished() && start.elapsed() < TIMEOUT {
+            publisher.send_copy(VALUE).unwrap();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_that!(subscriber.is_finished(), eq true);
+        let (received, payloads) = subscriber.join().unwrap();
+        assert_that!(received, eq 2);
+        for payload in payloads {
+            assert_that!(payload, eq VALUE.to_ne_bytes().to_vec());
+        }
+    }
+
+    #[test]
+    fn subscribing_to_non_existing_service_fails() {
+        let service_name = generate_name();
+
+        let result = subscribe(
+            Config::global_config(),
+            service_name.as_str(),
+            1,
+            Duration::from_millis(1),
+            |_| Ok(()),
+        );
+        assert_that!(result, is_err);
+    }
+
+    #[test]
+    fn sample_descriptor_contains_encoded_payload() {
+        let sut = SampleDescriptor::new(&[0xde, 0xad], Encoding::Hex);
+
+        assert_that!(sut.size, eq 2);
+        assert_that!(sut.payload, eq "dead");
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{anyhow, Result};
+use clap::Args;
+use colored::*;
+use iceoryx2::config::Config;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+
+use crate::format::Format;
+
+/// The arguments that select the iceoryx2 setup a command operates on and how its results are
+/// printed.
+#[derive(Args, Debug, Clone)]
+pub struct CommonArgs {
+    #[arg(
+        short,
+        long,
+        value_enum,
+        global = true,
+        default_value_t = Format::Human,
+        help = "The output format"
+    )]
+    pub format: Format,
+
+    #[arg(
+        short,
+        long,
+        global = true,
+        help = "The iceoryx2 config file, the global config is used when not provided"
+    )]
+    pub config: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        global = true,
+        help = "The domain in which the command operates, overrides the prefix of the config"
+    )]
+    pub domain: Option<String>,
+}
+
+impl CommonArgs {
+    /// Returns the [`Config`] that is defined by the `--config` and `--domain` arguments.
+    pub fn config(&self) -> Result<Config> {
+        let mut config = match &self.config {
+            Some(path) => {
+                let path = FilePath::new(path.as_bytes())
+                    .map_err(|e| anyhow!("Invalid config file path \"{}\" ({:?})", path, e))?;
+                Config::from_file(&path)
+                    .map_err(|e| anyhow!("Unable to load config file \"{}\" ({:?})", path, e))?
+            }
+            None => Config::global_config().clone(),
+        };
+
+        if let Some(domain) = &self.domain {
+            config.global.prefix = FileName::new(domain.as_bytes())
+                .map_err(|e| anyhow!("Invalid domain name \"{}\" ({:?})", domain, e))?;
+        }
+
+        Ok(config)
+    }
+}
+
+/// The help template that all iceoryx2 command line tools share.
+pub fn help_template(cli_name: &str, has_subcommands: bool) -> String {
+    let mut template = format!(
+        "{}{}{}\n\n{}\n{{options}}",
+        "Usage: ".bright_green().bold(),
+        format!("iox2 {} ", cli_name).bold(),
+        if has_subcommands {
+            "[OPTIONS] <COMMAND>"
+        } else {
+            "[OPTIONS]"
+        },
+        "Options:".bright_green().bold(),
+    );
+
+    if has_subcommands {
+        template.push_str(&format!(
+            "\n\n{}\n{{subcommands}}",
+            "Commands:".bright_green().bold()
+        ));
+    }
+
+    template
+}
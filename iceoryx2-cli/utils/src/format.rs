@@ -0,0 +1,97 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The output formats of the iceoryx2 command line tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Indented key-value listing for humans
+    Human,
+    /// JSON, one document per output
+    Json,
+    /// Pretty printed RON
+    Ron,
+}
+
+impl Format {
+    /// Converts the value into a [`String`] in the given [`Format`].
+    pub fn to_string<T: Serialize>(&self, value: &T) -> Result<String> {
+        match self {
+            Format::Human => {
+                let value = serde_json::to_value(value).context("Failed to serialize value")?;
+                let mut output = String::new();
+                write_human(&mut output, &value, 0);
+                Ok(output.trim_end().to_string())
+            }
+            Format::Json => {
+                serde_json::to_string_pretty(value).context("Failed to serialize value to JSON")
+            }
+            Format::Ron => ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+                .context("Failed to serialize value to RON"),
+        }
+    }
+
+    /// Prints the value in the given [`Format`] to stdout.
+    pub fn print<T: Serialize>(&self, value: &T) -> Result<()> {
+        println!("{}", self.to_string(value)?);
+        Ok(())
+    }
+}
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => Some("-".to_string()),
+        Value::Bool(v) => Some(v.to_string()),
+        Value::Number(v) => Some(v.to_string()),
+        Value::String(v) => Some(v.clone()),
+        Value::Array(v) if v.is_empty() => Some("[]".to_string()),
+        Value::Object(v) if v.is_empty() => Some("{}".to_string()),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+fn write_human(output: &mut String, value: &Value, indent: usize) {
+    let prefix = " ".repeat(indent);
+    match value {
+        Value::Object(entries) if !entries.is_empty() => {
+            for (key, entry) in entries {
+                match scalar_to_string(entry) {
+                    Some(v) => output.push_str(&format!("{}{}: {}\n", prefix, key, v)),
+                    None => {
+                        output.push_str(&format!("{}{}:\n", prefix, key));
+                        write_human(output, entry, indent + 2);
+                    }
+                }
+            }
+        }
+        Value::Array(entries) if !entries.is_empty() => {
+            for entry in entries {
+                match scalar_to_string(entry) {
+                    Some(v) => output.push_str(&format!("{}- {}\n", prefix, v)),
+                    None => {
+                        output.push_str(&format!("{}-\n", prefix));
+                        write_human(output, entry, indent + 2);
+                    }
+                }
+            }
+        }
+        scalar => {
+            if let Some(v) = scalar_to_string(scalar) {
+                output.push_str(&format!("{}{}\n", prefix, v));
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::node::{NodeId, NodeState, NodeView};
+use iceoryx2::service::dynamic_config::PortDetails;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::Service;
+use serde::Serialize;
+
+/// The liveness of a [`Node`](iceoryx2::node::Node), see [`NodeState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum NodeStateKind {
+    /// The process of the [`Node`](iceoryx2::node::Node) is alive.
+    Alive,
+    /// The process of the [`Node`](iceoryx2::node::Node) died and left stale resources behind.
+    Dead,
+    /// The state cannot be acquired due to missing permissions.
+    Inaccessible,
+    /// The resources of the [`Node`](iceoryx2::node::Node) are inconsistent.
+    Undefined,
+}
+
+/// The printable representation of a [`Node`](iceoryx2::node::Node).
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeDescriptor {
+    /// The [`NodeId`] as hex string
+    pub id: String,
+    /// The process id of the owning process
+    pub pid: i32,
+    /// The liveness of the [`Node`](iceoryx2::node::Node)
+    pub state: NodeStateKind,
+    /// The name of the [`Node`](iceoryx2::node::Node), when the details are accessible
+    pub name: Option<String>,
+    /// The executable of the owning process, when the details are accessible
+    pub executable: Option<String>,
+}
+
+/// Converts a [`NodeId`] into the representation used by all command line tools.
+pub fn node_id_to_string(id: &NodeId) -> String {
+    format!("{:032x}", id.value())
+}
+
+impl<S: Service> From<&NodeState<S>> for NodeDescriptor {
+    fn from(state: &NodeState<S>) -> Self {
+        let (kind, details) = match state {
+            NodeState::Alive(view) => (NodeStateKind::Alive, view.details().as_ref()),
+            NodeState::Dead(view) => (NodeStateKind::Dead, view.details().as_ref()),
+            NodeState::Inaccessible(_) => (NodeStateKind::Inaccessible, None),
+            NodeState::Undefined(_) => (NodeStateKind::Undefined, None),
+        };
+
+        Self {
+            id: node_id_to_string(state.node_id()),
+            pid: state.node_id().pid().value(),
+            state: kind,
+            name: details.map(|d| d.name().to_string()),
+            executable: details.map(|d| d.executable().to_string()),
+        }
+    }
+}
+
+/// The short description of a service that is shown in listings.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ServiceDescriptor {
+    /// The name of the service
+    pub name: String,
+    /// The messaging pattern of the service
+    pub messaging_pattern: String,
+    /// The unique id of the service
+    pub id: String,
+}
+
+impl From<&StaticConfig> for ServiceDescriptor {
+    fn from(config: &StaticConfig) -> Self {
+        Self {
+            name: config.name().to_string(),
+            messaging_pattern: config.messaging_pattern().to_string(),
+            id: config.service_id().as_str().to_string(),
+        }
+    }
+}
+
+/// The type of a port that is connected to a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum PortKind {
+    Publisher,
+    Subscriber,
+    Notifier,
+    Listener,
+    Client,
+    Server,
+    Reader,
+    Writer,
+}
+
+/// The printable representation of a port that is connected to a service.
+#[derive(Debug, Clone, Serialize)]
+pub struct PortDescriptor {
+    /// The type of the port
+    pub kind: PortKind,
+    /// The unique port id as hex string
+    pub id: String,
+    /// The [`NodeId`] of the owning [`Node`](iceoryx2::node::Node) as hex string
+    pub node_id: String,
+}
+
+impl From<&PortDetails> for PortDescriptor {
+    fn from(details: &PortDetails) -> Self {
+        let (kind, id) = match details {
+            PortDetails::Publisher(d) => (PortKind::Publisher, d.publisher_id().value()),
+            PortDetails::Subscriber(d) => (PortKind::Subscriber, d.subscriber_id().value()),
+            PortDetails::Notifier(d) => (PortKind::Notifier, d.notifier_id().value()),
+            PortDetails::Listener(d) => (PortKind::Listener, d.listener_id().value()),
+            PortDetails::Client(d) => (PortKind::Client, d.client_id().value()),
+            PortDetails::Server(d) => (PortKind::Server, d.server_id().value()),
+            PortDetails::Reader(d) => (PortKind::Reader, d.reader_id().value()),
+            PortDetails::Writer(d) => (PortKind::Writer, d.writer_id().value()),
+        };
+
+        Self {
+            kind,
+            id: format!("{:032x}", id),
+            node_id: node_id_to_string(details.node_id()),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Access to publish-subscribe services whose payload type is unknown at compile time. The
+//! payload is treated as raw bytes with the size and alignment recorded in the
+//! [`StaticConfig`](iceoryx2::service::static_config::StaticConfig) of the service.
+
+use anyhow::{anyhow, Context, Result};
+use base64::Engine;
+use clap::ValueEnum;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe::CustomHeaderMarker;
+use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+use iceoryx2::service::static_config::message_type_details::{MessageTypeDetails, TypeVariant};
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as StaticConfigPattern;
+
+/// A publish-subscribe service that is opened with raw byte payloads.
+pub type RawPortFactory = PortFactory<ipc::Service, [u8], CustomHeaderMarker>;
+
+/// The encodings in which raw payloads are read from and written to the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Encoding {
+    /// Hexadecimal, two lower case digits per byte
+    Hex,
+    /// Standard base64 with padding
+    Base64,
+}
+
+impl Encoding {
+    /// Encodes the bytes into a [`String`].
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Hex => bytes.iter().map(|b| format!("{:02x}", b)).collect(),
+            Encoding::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+        }
+    }
+
+    /// Decodes the bytes from a [`String`]. Whitespace in hex input is ignored.
+    pub fn decode(&self, value: &str) -> Result<Vec<u8>> {
+        match self {
+            Encoding::Hex => {
+                let digits: Vec<u8> = value.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+                if digits.len() % 2 != 0 {
+                    return Err(anyhow!("The hex input has an odd number of digits"));
+                }
+
+                digits
+                    .chunks(2)
+                    .map(|pair| {
+                        core::str::from_utf8(pair)
+                            .ok()
+                            .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                            .ok_or_else(|| anyhow!("The hex input contains invalid digits"))
+                    })
+                    .collect()
+            }
+            Encoding::Base64 => base64::engine::general_purpose::STANDARD
+                .decode(value.trim())
+                .context("The base64 input is invalid"),
+        }
+    }
+}
+
+/// Opens the existing publish-subscribe service with the given name with the payload and user
+/// header type details stored in its static config and returns them together with the
+/// service.
+pub fn open_raw_service(
+    node: &Node<ipc::Service>,
+    service_name: &str,
+) -> Result<(RawPortFactory, MessageTypeDetails)> {
+    let name = ServiceName::new(service_name)
+        .map_err(|e| anyhow!("Invalid service name \"{}\" ({:?})", service_name, e))?;
+
+    let details = ipc::Service::details(&name, node.config(), MessagingPattern::PublishSubscribe)
+        .map_err(|e| {
+            anyhow!(
+                "Failed to acquire details of service \"{}\" ({:?})",
+                service_name,
+                e
+            )
+        })?
+        .ok_or_else(|| {
+            anyhow!(
+                "The publish-subscribe service \"{}\" does not exist",
+                service_name
+            )
+        })?;
+
+    let type_details = match details.static_details.messaging_pattern() {
+        StaticConfigPattern::PublishSubscribe(c) => c.message_type_details().clone(),
+        _ => {
+            return Err(anyhow!(
+                "The service \"{}\" is not a publish-subscribe service",
+                service_name
+            ))
+        }
+    };
+
+    let service = node
+        .service_builder(&name)
+        .publish_subscribe_with_type_details(&type_details.payload)
+        .and_then(|builder| builder.user_header_type_details(&type_details.user_header))
+        .map_err(|e| {
+            anyhow!(
+                "The service \"{}\" has invalid type details ({:?})",
+                service_name,
+                e
+            )
+        })?
+        .open()
+        .map_err(|e| anyhow!("Failed to open service \"{}\" ({:?})", service_name, e))?;
+
+    Ok((service, type_details))
+}
+
+/// Returns the number of payload elements that the raw bytes represent. Fails when the number
+/// of bytes does not match the payload type of the service.
+pub fn number_of_elements(type_details: &MessageTypeDetails, bytes: &[u8]) -> Result<usize> {
+    let payload = &type_details.payload;
+    match payload.variant {
+        TypeVariant::FixedSize if bytes.len() == payload.size => Ok(1),
+        TypeVariant::FixedSize => Err(anyhow!(
+            "The payload has {} bytes but the type \"{}\" requires exactly {} bytes",
+            bytes.len(),
+            payload.type_name,
+            payload.size
+        )),
+        TypeVariant::Dynamic if payload.size != 0 && bytes.len() % payload.size == 0 => {
+            Ok(bytes.len() / payload.size)
+        }
+        TypeVariant::Dynamic => Err(anyhow!(
+            "The payload has {} bytes which is not a multiple of the {} bytes of the type \"{}\"",
+            bytes.len(),
+            payload.size,
+            payload.type_name
+        )),
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod format {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cli_utils::format::Format;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Inner {
+        value: u64,
+    }
+
+    #[derive(Serialize)]
+    struct Outer {
+        name: String,
+        inner: Inner,
+        list: Vec<u32>,
+        empty: Vec<u32>,
+        nothing: Option<u32>,
+    }
+
+    fn sut() -> Outer {
+        Outer {
+            name: "funky".to_string(),
+            inner: Inner { value: 42 },
+            list: vec![1, 2],
+            empty: vec![],
+            nothing: None,
+        }
+    }
+
+    #[test]
+    fn human_format_is_indented_key_value_listing() {
+        let output = Format::Human.to_string(&sut()).unwrap();
+
+        assert_that!(
+            output,
+            eq "name: funky\ninner:\n  value: 42\nlist:\n  - 1\n  - 2\nempty: []\nnothing: -"
+        );
+    }
+
+    #[test]
+    fn json_format_can_be_parsed_again() {
+        let output = Format::Json.to_string(&sut()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_that!(value["name"], eq "funky");
+        assert_that!(value["inner"]["value"], eq 42);
+        assert_that!(value["list"][1], eq 2);
+    }
+
+    #[test]
+    fn ron_format_can_be_parsed_again() {
+        let output = Format::Ron.to_string(&sut()).unwrap();
+        let value: ron::Value = ron::from_str(&output).unwrap();
+
+        assert_that!(format!("{:?}", value).contains("funky"), eq true);
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod raw {
+    use iceoryx2::service::static_config::message_type_details::{
+        MessageTypeDetails, TypeDetail, TypeVariant,
+    };
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cli_utils::raw::*;
+
+    fn type_details(variant: TypeVariant, size: usize) -> MessageTypeDetails {
+        MessageTypeDetails {
+            payload: TypeDetail {
+                variant,
+                type_name: "SomeType".to_string(),
+                size,
+                alignment: 1,
+                type_layout_hash: None,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn hex_encoding_roundtrip_works() {
+        let bytes = [0x00u8, 0x1f, 0xab, 0xff];
+
+        assert_that!(Encoding::Hex.encode(&bytes), eq "001fabff");
+        assert_that!(Encoding::Hex.decode("00 1F ab ff").unwrap(), eq bytes.to_vec());
+    }
+
+    #[test]
+    fn base64_encoding_roundtrip_works() {
+        let bytes = b"iceoryx2";
+        let encoded = Encoding::Base64.encode(bytes);
+
+        assert_that!(encoded, eq "aWNlb3J5eDI=");
+        assert_that!(Encoding::Base64.decode(&encoded).unwrap(), eq bytes.to_vec());
+    }
+
+    #[test]
+    fn decoding_invalid_input_fails() {
+        assert_that!(Encoding::Hex.decode("abc"), is_err);
+        assert_that!(Encoding::Hex.decode("zz"), is_err);
+        assert_that!(Encoding::Base64.decode("!!!"), is_err);
+    }
+
+    #[test]
+    fn number_of_elements_requires_exact_size_for_fixed_size_types() {
+        let details = type_details(TypeVariant::FixedSize, 8);
+
+        assert_that!(number_of_elements(&details, &[0; 8]).unwrap(), eq 1);
+        assert_that!(number_of_elements(&details, &[0; 7]), is_err);
+        assert_that!(number_of_elements(&details, &[0; 16]), is_err);
+    }
+
+    #[test]
+    fn number_of_elements_requires_multiple_of_size_for_dynamic_types() {
+        let details = type_details(TypeVariant::Dynamic, 4);
+
+        assert_that!(number_of_elements(&details, &[0; 12]).unwrap(), eq 3);
+        assert_that!(number_of_elements(&details, &[0; 6]), is_err);
+    }
+}
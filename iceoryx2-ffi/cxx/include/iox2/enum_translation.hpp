@@ -316,6 +316,10 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleNotifyOnSendBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleDeadline;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -376,6 +380,10 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR:
+        return iox2::PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE:
+        return iox2::PublishSubscribeOpenError::IncompatibleDeadline;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenError::InsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -423,6 +431,8 @@ constexpr auto from<int, iox2::NotifierCreateError>(const int value) noexcept ->
     switch (error) {
     case iox2_notifier_create_error_e_EXCEEDS_MAX_SUPPORTED_NOTIFIERS:
         return iox2::NotifierCreateError::ExceedsMaxSupportedNotifiers;
+    case iox2_notifier_create_error_e_UNABLE_TO_CREATE_STATISTICS:
+        return iox2::NotifierCreateError::UnableToCreateStatistics;
     }
 
     IOX_UNREACHABLE();
@@ -475,6 +485,10 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::ExceedsMaxSupportedPublishers;
     case iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT:
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
+    case iox2_publisher_create_error_e_UNABLE_TO_CREATE_NOTIFIER:
+        return iox2::PublisherCreateError::UnableToCreateNotifier;
+    case iox2_publisher_create_error_e_UNABLE_TO_CREATE_STATISTICS:
+        return iox2::PublisherCreateError::UnableToCreateStatistics;
     }
 
     IOX_UNREACHABLE();
@@ -488,6 +502,10 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService;
     case iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS:
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
+    case iox2_subscriber_create_error_e_UNABLE_TO_CREATE_LISTENER:
+        return iox2::SubscriberCreateError::UnableToCreateListener;
+    case iox2_subscriber_create_error_e_HISTORY_SIZE_EXCEEDS_HISTORY_SIZE_OF_SERVICE:
+        return iox2::SubscriberCreateError::HistorySizeExceedsHistorySizeOfService;
     }
 
     IOX_UNREACHABLE();
@@ -526,6 +544,10 @@ constexpr auto from<int, iox2::SubscriberReceiveError>(const int value) noexcept
         return iox2::SubscriberReceiveError::UnableToMapPublishersDataSegment;
     case iox2_subscriber_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES:
         return iox2::SubscriberReceiveError::ExceedsMaxBorrowedSamples;
+    case iox2_subscriber_receive_error_e_NOTIFY_ON_SEND_IS_DISABLED:
+        return iox2::SubscriberReceiveError::NotifyOnSendIsDisabled;
+    case iox2_subscriber_receive_error_e_INTERNAL_FAILURE:
+        return iox2::SubscriberReceiveError::InternalFailure;
     }
 
     IOX_UNREACHABLE();
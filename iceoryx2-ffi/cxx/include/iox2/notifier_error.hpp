@@ -25,6 +25,9 @@ enum class NotifierCreateError : uint8_t {
     /// [`Notifier`]s
     /// can be created for a specific [`Service`].
     ExceedsMaxSupportedNotifiers,
+    /// The statistics the [`Notifier`] updates while it is in use could not
+    /// be created.
+    UnableToCreateStatistics,
 };
 
 /// Defines the failures that can occur while a [`Notifier::notify()`] call.
@@ -31,6 +31,10 @@ class PortFactorySubscriber {
     /// Defines the required buffer size of the [`Subscriber`]. Smallest possible value is `1`.
     IOX_BUILDER_OPTIONAL(uint64_t, buffer_size);
 
+    /// Defines how many samples of the history the [`Subscriber`] receives when it connects
+    /// to a publisher. Must not exceed the history size of the service.
+    IOX_BUILDER_OPTIONAL(uint64_t, history_size);
+
   public:
     PortFactorySubscriber(const PortFactorySubscriber&) = delete;
     PortFactorySubscriber(PortFactorySubscriber&&) = default;
@@ -63,6 +67,8 @@ PortFactorySubscriber<S, Payload, UserHeader>::create() && -> iox::expected<Subs
     auto* ref_handle = iox2_cast_port_factory_subscriber_builder_ref_h(m_handle);
     m_buffer_size.and_then(
         [&](auto value) { iox2_port_factory_subscriber_builder_set_buffer_size(ref_handle, value); });
+    m_history_size.and_then(
+        [&](auto value) { iox2_port_factory_subscriber_builder_set_history_size(ref_handle, value); });
 
     iox2_subscriber_h sub_handle {};
     auto result = iox2_port_factory_subscriber_builder_create(m_handle, nullptr, &sub_handle);
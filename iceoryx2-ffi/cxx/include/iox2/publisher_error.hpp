@@ -29,6 +29,12 @@ enum class PublisherCreateError : uint8_t {
     /// The datasegment in which the payload of the [`Publisher`] is stored,
     /// could not be created.
     UnableToCreateDataSegment,
+    /// The [`Service`] has notify on send enabled but the [`Notifier`] of the
+    /// companion event [`Service`] could not be created.
+    UnableToCreateNotifier,
+    /// The statistics the [`Publisher`] updates while it is in use could not
+    /// be created.
+    UnableToCreateStatistics,
 };
 
 /// Defines a failure that can occur in [`Publisher::loan()`] and
@@ -48,6 +48,10 @@ enum class PublishSubscribeOpenError : uint8_t {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required notify on send behavior is not compatible.
+    IncompatibleNotifyOnSendBehavior,
+    /// The [`Service`] required deadline is not compatible.
+    IncompatibleDeadline,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -133,6 +137,10 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     OpenDoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     OpenIncompatibleOverflowBehavior,
+    /// The [`Service`] required notify on send behavior is not compatible.
+    OpenIncompatibleNotifyOnSendBehavior,
+    /// The [`Service`] required deadline is not compatible.
+    OpenIncompatibleDeadline,
     /// The process has not enough permissions to open the [`Service`]
     OpenInsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -27,7 +27,15 @@ enum class SubscriberReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapPublishersDataSegment
+    UnableToMapPublishersDataSegment,
+
+    /// A blocking or timed receive was called but the [`Service`] does not
+    /// notify on send.
+    NotifyOnSendIsDisabled,
+
+    /// Errors that indicate either an implementation issue or a wrongly
+    /// configured system.
+    InternalFailure
 };
 
 /// Describes the failures when a new [`Subscriber`] is created via the
@@ -42,6 +50,14 @@ enum class SubscriberCreateError : uint8_t {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`] offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+
+    /// The [`Service`] has notify on send enabled but the [`Listener`] of the
+    /// companion event [`Service`] could not be created.
+    UnableToCreateListener,
+
+    /// When the [`Subscriber`] requests more historical samples than the
+    /// [`Service`] stores the creation will fail.
+    HistorySizeExceedsHistorySizeOfService,
 };
 } // namespace iox2
 
@@ -59,4 +59,18 @@ auto HeaderPublishSubscribe::payload_type_layout() const -> iox::Layout {
 
     return iox::Layout::create(size, alignment).expect("Payload layout is always valid.");
 }
+
+auto HeaderPublishSubscribe::sequence_number() const -> uint64_t {
+    auto* handle_ref = iox2_cast_publish_subscribe_header_ref_h(m_handle);
+    return iox2_publish_subscribe_header_sequence_number(handle_ref);
+}
+
+auto HeaderPublishSubscribe::timestamp() const -> iox::units::Duration {
+    auto* handle_ref = iox2_cast_publish_subscribe_header_ref_h(m_handle);
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+    iox2_publish_subscribe_header_timestamp(handle_ref, &seconds, &nanoseconds);
+
+    return iox::units::Duration::fromSeconds(seconds) + iox::units::Duration::fromNanoseconds(nanoseconds);
+}
 } // namespace iox2
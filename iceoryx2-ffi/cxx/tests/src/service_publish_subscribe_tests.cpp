@@ -380,6 +380,31 @@ TYPED_TEST(ServicePublishSubscribeTest, send_receive_with_user_header_works) {
     }
 }
 
+TYPED_TEST(ServicePublishSubscribeTest, sample_header_contains_consecutive_sequence_numbers) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+
+    const auto* name_value = "I am floating through the galaxy of my brain. Oh the colors!";
+    const auto service_name = ServiceName::create(name_value).expect("");
+
+    auto node = NodeBuilder().create<SERVICE_TYPE>().expect("");
+    auto service = node.service_builder(service_name).template publish_subscribe<uint64_t>().create().expect("");
+
+    auto sut_publisher = service.publisher_builder().create().expect("");
+    auto sut_subscriber = service.subscriber_builder().create().expect("");
+
+    constexpr uint64_t NUMBER_OF_SAMPLES = 3;
+    for (uint64_t idx = 0; idx < NUMBER_OF_SAMPLES; ++idx) {
+        sut_publisher.send_copy(idx).expect("");
+    }
+
+    for (uint64_t idx = 0; idx < NUMBER_OF_SAMPLES; ++idx) {
+        auto sample = sut_subscriber.receive().expect("");
+        ASSERT_TRUE(sample.has_value());
+        ASSERT_THAT(sample->header().sequence_number(), Eq(idx));
+        ASSERT_THAT(sample->header().timestamp(), Ne(iox::units::Duration::zero()));
+    }
+}
+
 TYPED_TEST(ServicePublishSubscribeTest, has_sample_works) {
     constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
 
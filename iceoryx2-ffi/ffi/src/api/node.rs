@@ -64,6 +64,9 @@ impl IntoCInt for NodeEvent {
             NodeEvent::Tick => iox2_node_event_e::TICK,
             NodeEvent::TerminationRequest => iox2_node_event_e::TERMINATION_REQUEST,
             NodeEvent::InterruptSignal => iox2_node_event_e::INTERRUPT_SIGNAL,
+            // objects cannot be attached to a node via the C API, therefore a node never
+            // reports an attachment event to C
+            NodeEvent::Attachment(_) => iox2_node_event_e::TICK,
         }) as c_int
     }
 }
@@ -89,7 +92,7 @@ impl NodeUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<NodeUnion>
 pub struct iox2_node_storage_t {
-    internal: [u8; 16], // magic number obtained with size_of::<Option<NodeUnion>>()
+    internal: [u8; 24], // magic number obtained with size_of::<Option<NodeUnion>>()
 }
 
 #[repr(C)]
@@ -32,6 +32,7 @@ use core::mem::ManuallyDrop;
 #[derive(Copy, Clone)]
 pub enum iox2_notifier_create_error_e {
     EXCEEDS_MAX_SUPPORTED_NOTIFIERS = IOX2_OK as isize + 1,
+    UNABLE_TO_CREATE_STATISTICS,
 }
 
 impl IntoCInt for NotifierCreateError {
@@ -40,6 +41,9 @@ impl IntoCInt for NotifierCreateError {
             NotifierCreateError::ExceedsMaxSupportedNotifiers => {
                 iox2_notifier_create_error_e::EXCEEDS_MAX_SUPPORTED_NOTIFIERS
             }
+            NotifierCreateError::UnableToCreateStatistics => {
+                iox2_notifier_create_error_e::UNABLE_TO_CREATE_STATISTICS
+            }
         }) as c_int
     }
 }
@@ -33,6 +33,8 @@ use core::mem::ManuallyDrop;
 pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
+    UNABLE_TO_CREATE_NOTIFIER,
+    UNABLE_TO_CREATE_STATISTICS,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -44,6 +46,12 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::UnableToCreateDataSegment => {
                 iox2_publisher_create_error_e::UNABLE_TO_CREATE_DATA_SEGMENT
             }
+            PublisherCreateError::UnableToCreateNotifier => {
+                iox2_publisher_create_error_e::UNABLE_TO_CREATE_NOTIFIER
+            }
+            PublisherCreateError::UnableToCreateStatistics => {
+                iox2_publisher_create_error_e::UNABLE_TO_CREATE_STATISTICS
+            }
         }) as c_int
     }
 }
@@ -108,7 +116,7 @@ impl IntoCInt for UnableToDeliverStrategy {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryPublisherBuilderUnion>
 pub struct iox2_port_factory_publisher_builder_storage_t {
-    internal: [u8; 128], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
+    internal: [u8; 144], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -33,6 +33,8 @@ use core::mem::ManuallyDrop;
 pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
+    UNABLE_TO_CREATE_LISTENER,
+    HISTORY_SIZE_EXCEEDS_HISTORY_SIZE_OF_SERVICE,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -44,6 +46,12 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService => {
                 iox2_subscriber_create_error_e::BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE
             }
+            SubscriberCreateError::UnableToCreateListener => {
+                iox2_subscriber_create_error_e::UNABLE_TO_CREATE_LISTENER
+            }
+            SubscriberCreateError::HistorySizeExceedsHistorySizeOfService => {
+                iox2_subscriber_create_error_e::HISTORY_SIZE_EXCEEDS_HISTORY_SIZE_OF_SERVICE
+            }
         }) as c_int
     }
 }
@@ -73,7 +81,7 @@ impl PortFactorySubscriberBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactorySubscriberBuilderUnion>
 pub struct iox2_port_factory_subscriber_builder_storage_t {
-    internal: [u8; 112], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
+    internal: [u8; 128], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -185,6 +193,44 @@ pub unsafe extern "C" fn iox2_port_factory_subscriber_builder_set_buffer_size(
     }
 }
 
+/// Sets the number of historical samples the subscriber receives when it connects
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_subscriber_builder_ref_h`]
+///   obtained by [`iox2_port_factory_pub_sub_subscriber_builder`](crate::iox2_port_factory_pub_sub_subscriber_builder) and
+///   casted by [`iox2_cast_port_factory_subscriber_builder_ref_h`].
+/// * `value` - The value to set the history size to
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_subscriber_builder_set_history_size(
+    port_factory_handle: iox2_port_factory_subscriber_builder_ref_h,
+    value: c_size_t,
+) {
+    debug_assert!(!port_factory_handle.is_null());
+
+    let port_factory_struct = unsafe { &mut *port_factory_handle.as_type() };
+    match port_factory_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let port_factory = ManuallyDrop::take(&mut port_factory_struct.value.as_mut().ipc);
+
+            port_factory_struct.set(PortFactorySubscriberBuilderUnion::new_ipc(
+                port_factory.history_size(value),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let port_factory = ManuallyDrop::take(&mut port_factory_struct.value.as_mut().local);
+
+            port_factory_struct.set(PortFactorySubscriberBuilderUnion::new_local(
+                port_factory.history_size(value),
+            ));
+        }
+    }
+}
+
 // TODO [#210] add all the other setter methods
 
 /// Creates a subscriber and consumes the builder
@@ -26,7 +26,7 @@ use super::HandleToType;
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 32], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 56], // core::mem::size_of::<Option<Header>>()
 }
 
 #[repr(C)]
@@ -199,4 +199,55 @@ pub unsafe extern "C" fn iox2_publish_subscribe_header_payload_type_alignment(
     header.value.as_ref().payload_type_layout().align()
 }
 
+/// Returns the sequence number of the sample.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///    [`iox2_sample_header()`](crate::iox2_sample_header)
+///
+/// # Safety
+///
+/// * `header_handle` is valid, non-null and was obtained via [`iox2_cast_publish_subscribe_header_ref_h`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_sequence_number(
+    header_handle: iox2_publish_subscribe_header_ref_h,
+) -> u64 {
+    debug_assert!(!header_handle.is_null());
+
+    let header = &mut *header_handle.as_type();
+
+    header.value.as_ref().sequence_number()
+}
+
+/// Acquires the time at which the sample was sent.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///    [`iox2_sample_header()`](crate::iox2_sample_header)
+/// * `seconds` - valid pointer to store the seconds of the timestamp
+/// * `nanoseconds` - valid pointer to store the nanoseconds of the timestamp
+///
+/// # Safety
+///
+/// * `header_handle` is valid, non-null and was obtained via [`iox2_cast_publish_subscribe_header_ref_h`]
+/// * `seconds` and `nanoseconds` are valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_timestamp(
+    header_handle: iox2_publish_subscribe_header_ref_h,
+    seconds: *mut u64,
+    nanoseconds: *mut u32,
+) {
+    debug_assert!(!header_handle.is_null());
+    debug_assert!(!seconds.is_null());
+    debug_assert!(!nanoseconds.is_null());
+
+    let header = &mut *header_handle.as_type();
+    let timestamp = header.value.as_ref().timestamp();
+
+    *seconds = timestamp.seconds();
+    *nanoseconds = timestamp.nanoseconds();
+}
+
 // END C API
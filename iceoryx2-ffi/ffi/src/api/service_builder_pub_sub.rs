@@ -50,6 +50,8 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_SUBSCRIBERS,
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+    O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR,
+    O_INCOMPATIBLE_DEADLINE,
     O_INSUFFICIENT_PERMISSIONS,
     O_SERVICE_IN_CORRUPTED_STATE,
     O_HANGS_IN_CREATION,
@@ -102,6 +104,12 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleOverflowBehavior => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR
          }
+         PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR
+         }
+         PublishSubscribeOpenError::IncompatibleDeadline => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_DEADLINE
+         }
          PublishSubscribeOpenError::InsufficientPermissions => {
              iox2_pub_sub_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
          }
@@ -250,6 +258,7 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_user_header_type_detai
         type_name,
         size,
         alignment,
+        type_layout_hash: None,
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
@@ -328,6 +337,7 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_payload_type_details(
         type_name,
         size,
         alignment,
+        type_layout_hash: None,
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
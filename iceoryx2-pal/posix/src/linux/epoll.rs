@@ -0,0 +1,78 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+use crate::posix::Struct;
+
+pub const EPOLL_CLOEXEC: int = 0o2000000;
+
+pub const EPOLL_CTL_ADD: int = 1;
+pub const EPOLL_CTL_DEL: int = 2;
+pub const EPOLL_CTL_MOD: int = 3;
+
+pub const EPOLLIN: u32 = 0x001;
+pub const EPOLLPRI: u32 = 0x002;
+pub const EPOLLOUT: u32 = 0x004;
+pub const EPOLLERR: u32 = 0x008;
+pub const EPOLLHUP: u32 = 0x010;
+pub const EPOLLRDHUP: u32 = 0x2000;
+pub const EPOLLET: u32 = 1u32 << 31;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union epoll_data_t {
+    pub ptr: *mut void,
+    pub fd: int,
+    pub u32: u32,
+    pub u64: u64,
+}
+
+// glibc declares the struct with __attribute__((packed)) on x86_64 only
+#[repr(C)]
+#[cfg_attr(target_arch = "x86_64", repr(packed))]
+#[derive(Copy, Clone)]
+pub struct epoll_event {
+    pub events: u32,
+    pub data: epoll_data_t,
+}
+impl Struct for epoll_event {}
+
+pub unsafe fn epoll_create1(flags: int) -> int {
+    internal::epoll_create1(flags)
+}
+
+pub unsafe fn epoll_ctl(epfd: int, op: int, fd: int, event: *mut epoll_event) -> int {
+    internal::epoll_ctl(epfd, op, fd, event)
+}
+
+pub unsafe fn epoll_wait(epfd: int, events: *mut epoll_event, maxevents: int, timeout: int) -> int {
+    internal::epoll_wait(epfd, events, maxevents, timeout)
+}
+
+mod internal {
+    use super::*;
+
+    #[cfg_attr(target_os = "linux", link(name = "c"))]
+    extern "C" {
+        pub(super) fn epoll_create1(flags: int) -> int;
+        pub(super) fn epoll_ctl(epfd: int, op: int, fd: int, event: *mut epoll_event) -> int;
+        pub(super) fn epoll_wait(
+            epfd: int,
+            events: *mut epoll_event,
+            maxevents: int,
+            timeout: int,
+        ) -> int;
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("received request: {:?}", *active_request);
+//!
+//!     // send a stream of responses
+//!     active_request.send_copy(1)?;
+//!
+//!     let response = active_request.loan_uninit()?;
+//!     response.write_payload(2).send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, mem::MaybeUninit, ops::Deref, sync::Arc};
+
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_cal::{
+    shm_allocator::PointerOffset,
+    zero_copy_connection::{ZeroCopyReceiver, ZeroCopyReleaseError},
+};
+
+use crate::{
+    port::{
+        details::client_connections::Connection, port_identifiers::UniqueClientId,
+        server::ServerSharedState, LoanError, SendError,
+    },
+    raw_sample::{RawSample, RawSampleMut},
+    response_mut::ResponseMut,
+    service::header::request_response::{RequestHeader, ResponseHeader},
+};
+
+/// Represents a request that was received by a [`Server`](crate::port::server::Server) via
+/// [`Server::receive()`](crate::port::server::Server::receive()). It provides read access to
+/// the request and can be used to send an arbitrary number of responses back to the
+/// [`Client`](crate::port::client::Client) that sent the request. The request is released as
+/// soon as the [`ActiveRequest`] goes out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases the request in the underlying connection of
+/// the [`Server`](crate::port::server::Server) and the
+/// [`Server`](crate::port::server::Server) is not thread-safe!
+pub struct ActiveRequest<
+    Service: crate::service::Service,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    pub(crate) ptr: RawSample<RequestHeader, (), RequestPayload>,
+    pub(crate) shared_state: Arc<ServerSharedState<Service>>,
+    pub(crate) connection: Arc<Connection<Service>>,
+    pub(crate) connection_index: usize,
+    pub(crate) offset: PointerOffset,
+    pub(crate) _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Debug
+    for ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ActiveRequest<{}, {}, {}> {{ connection: {:?}, connection_index: {}, offset: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<ResponsePayload>(),
+            self.connection,
+            self.connection_index,
+            self.offset
+        )
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Deref
+    for ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    type Target = RequestPayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        match self.connection.request_receiver.release(self.offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The clients retrieve channel is full and the request cannot be returned.");
+            }
+        }
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    /// Returns a reference to the payload of the received request.
+    pub fn payload(&self) -> &RequestPayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the received request.
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`](crate::port::client::Client) that
+    /// sent the request.
+    pub fn origin(&self) -> UniqueClientId {
+        self.connection.client_id
+    }
+
+    /// Loans uninitialized memory for a [`ResponseMut`] where the user can write its payload
+    /// to. The response is delivered to the [`Client`](crate::port::client::Client) that sent
+    /// the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let response = active_request.loan_uninit()?;
+    ///     response.write_payload(*active_request + 1).send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<ResponseMut<Service, MaybeUninit<ResponsePayload>>, LoanError> {
+        let type_details = self
+            .shared_state
+            .static_config()
+            .response_message_type_details();
+        let (offset, header_address) = self.shared_state.allocate(type_details.sample_layout(1))?;
+
+        let header_ptr = header_address as *mut ResponseHeader;
+        unsafe {
+            header_ptr.write(ResponseHeader::new(
+                self.shared_state.server_id(),
+                self.header().request_id(),
+            ))
+        };
+
+        let user_header_ptr = type_details
+            .user_header_ptr_from_header(header_ptr.cast())
+            .cast_mut()
+            .cast();
+        let payload_ptr = type_details
+            .payload_ptr_from_header(header_ptr.cast())
+            .cast_mut()
+            .cast();
+
+        Ok(ResponseMut::new(
+            &self.shared_state,
+            &self.connection,
+            self.connection_index,
+            unsafe { RawSampleMut::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
+            offset,
+        ))
+    }
+
+    /// Copies the input `value` into a [`ResponseMut`] and sends it to the
+    /// [`Client`](crate::port::client::Client) that sent the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     active_request.send_copy(1234)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(&self, value: ResponsePayload) -> Result<(), SendError> {
+        self.loan_uninit()?.write_payload(value).send()
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug + Default>
+    ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    /// Loans default initialized memory for a [`ResponseMut`] where the user can write its
+    /// payload to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let mut response = active_request.loan()?;
+    ///     *response.payload_mut() = 789;
+    ///     response.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan(&self) -> Result<ResponseMut<Service, ResponsePayload>, LoanError> {
+        Ok(self
+            .loan_uninit()?
+            .write_payload(ResponsePayload::default()))
+    }
+}
@@ -125,6 +125,12 @@ pub struct Service {
     pub connection_suffix: FileName,
     /// The suffix of a one-to-one connection
     pub event_connection_suffix: FileName,
+    /// The suffix of the events that notify the
+    /// [`DiscoveryListener`](crate::discovery::DiscoveryListener)s about created and removed
+    /// services and nodes
+    pub discovery_suffix: FileName,
+    /// The suffix of the statistics a port updates while it is in use
+    pub port_statistics_suffix: FileName,
 }
 
 /// All configurable settings of a [`crate::node::Node`].
@@ -215,6 +221,10 @@ pub struct Defaults {
     pub publish_subscribe: PublishSubscribe,
     /// Default settings for the messaging pattern event
     pub event: Event,
+    /// Default settings for the messaging pattern request-response
+    pub request_response: RequestResponse,
+    /// Default settings for the messaging pattern blackboard
+    pub blackboard: Blackboard,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
@@ -274,6 +284,58 @@ pub struct Event {
     pub event_id_max_value: usize,
 }
 
+/// Default settings for the request-response messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RequestResponse {
+    /// The maximum amount of supported [`crate::port::client::Client`]
+    pub max_clients: usize,
+    /// The maximum amount of supported [`crate::port::server::Server`]
+    pub max_servers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+    /// The maximum amount of requests a [`crate::port::client::Client`] can have in flight
+    /// at the same time.
+    pub max_active_requests_per_client: usize,
+    /// The maximum amount of responses that can be buffered for a single
+    /// [`crate::pending_response::PendingResponse`] per [`crate::port::server::Server`].
+    pub max_response_buffer_size: usize,
+    /// The maximum amount of [`crate::response::Response`]s a
+    /// [`crate::pending_response::PendingResponse`] can hold at the same time per
+    /// [`crate::port::server::Server`].
+    pub max_borrowed_responses_per_pending_response: usize,
+    /// Defines how the request buffer of the [`crate::port::server::Server`] behaves when it
+    /// is full. When safe overflow is activated, the [`crate::port::client::Client`] will
+    /// replace the oldest request with the newest one.
+    pub enable_safe_overflow_for_requests: bool,
+    /// Defines how the response buffer of the [`crate::port::client::Client`] behaves when it
+    /// is full. When safe overflow is activated, the [`crate::port::server::Server`] will
+    /// replace the oldest response with the newest one.
+    pub enable_safe_overflow_for_responses: bool,
+    /// The maximum amount of [`crate::request_mut::RequestMut`]s a
+    /// [`crate::port::client::Client`] can loan at the same time.
+    pub client_max_loaned_requests: usize,
+    /// The maximum amount of [`crate::response_mut::ResponseMut`]s a
+    /// [`crate::port::server::Server`] can loan at the same time.
+    pub server_max_loaned_responses: usize,
+}
+
+/// Default settings for the blackboard messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Blackboard {
+    /// The maximum amount of supported [`crate::port::reader::Reader`]
+    pub max_readers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+}
+
 /// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
 /// the [Global] settings, which must align with the iceoryx2 instance the application intends to
 /// join, and the [Defaults] for communication within that iceoryx2 instance. The user has the
@@ -305,6 +367,8 @@ impl Default for Config {
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: FileName::new(b".connection").unwrap(),
                     event_connection_suffix: FileName::new(b".event").unwrap(),
+                    discovery_suffix: FileName::new(b".discovery").unwrap(),
+                    port_statistics_suffix: FileName::new(b".statistics").unwrap(),
                 },
                 node: Node {
                     directory: Path::new(b"nodes").unwrap(),
@@ -334,6 +398,22 @@ impl Default for Config {
                     max_nodes: 36,
                     event_id_max_value: 32,
                 },
+                request_response: RequestResponse {
+                    max_clients: 8,
+                    max_servers: 2,
+                    max_nodes: 20,
+                    max_active_requests_per_client: 4,
+                    max_response_buffer_size: 2,
+                    max_borrowed_responses_per_pending_response: 2,
+                    enable_safe_overflow_for_requests: true,
+                    enable_safe_overflow_for_responses: true,
+                    client_max_loaned_requests: 2,
+                    server_max_loaned_responses: 2,
+                },
+                blackboard: Blackboard {
+                    max_readers: 8,
+                    max_nodes: 20,
+                },
             },
         }
     }
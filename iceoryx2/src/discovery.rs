@@ -0,0 +1,558 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ## Watch For Services And Nodes
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::discovery::{DiscoveryEvent, DiscoveryListenerBuilder};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let discovery = DiscoveryListenerBuilder::new()
+//!     // only report publish-subscribe services ...
+//!     .messaging_pattern(MessagingPattern::PublishSubscribe)
+//!     // ... that were created with the attribute "camera" = "front"
+//!     .attributes(&AttributeVerifier::new().require("camera", "front"))
+//!     .create::<ipc::Service>()?;
+//!
+//! loop {
+//!     // the first call reports all services and nodes that already exist
+//!     discovery.blocking_wait(|event| match event {
+//!         DiscoveryEvent::ServiceAdded(service) => println!("added: {}", service.name()),
+//!         DiscoveryEvent::ServiceRemoved(service) => println!("removed: {}", service.name()),
+//!         DiscoveryEvent::NodeJoined(node_id) => println!("joined: {:?}", node_id),
+//!         DiscoveryEvent::NodeLeft(node_id) => println!("left: {:?}", node_id),
+//!     })?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Attach To A WaitSet
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::discovery::DiscoveryListenerBuilder;
+//! use iceoryx2::waitset::WaitSetBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let discovery = DiscoveryListenerBuilder::new().create::<ipc::Service>()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! let guard = waitset.attach_notification(&discovery)?;
+//!
+//! // report the already existing services and nodes
+//! discovery.try_wait(|event| println!("{:?}", event))?;
+//!
+//! waitset.wait_and_process(|attachment_id| {
+//!     if attachment_id.has_event_from(&guard) {
+//!         discovery.try_wait(|event| println!("{:?}", event)).unwrap();
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeSet, HashMap},
+    fmt::Debug,
+    sync::Arc,
+    time::Duration,
+};
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::{
+    directory::{Directory, DirectoryCreateError},
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    permission::Permission,
+    unique_system_id::UniqueSystemId,
+};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::event::{
+    Event, Listener, ListenerBuilder, ListenerCreateError, ListenerWaitError, NamedConceptBuilder,
+    NamedConceptMgmt, Notifier, NotifierBuilder, NotifierCreateError, NotifierNotifyError,
+    TriggerId,
+};
+
+use crate::{
+    config::Config,
+    node::{Node, NodeId, NodeListFailure, SharedNode},
+    service::{
+        self,
+        attribute::AttributeVerifier,
+        config_scheme::discovery_config,
+        messaging_pattern::MessagingPattern,
+        query::{ServiceNamePattern, ServiceQuery},
+        service_id::ServiceId,
+        static_config::StaticConfig,
+        ServiceListError,
+    },
+};
+
+/// Defines the failures that can occur when a [`DiscoveryListener`] is created with the
+/// [`DiscoveryListenerBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoveryListenerCreateError {
+    /// The process has insufficient permissions to create the underlying event.
+    InsufficientPermissions,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl std::fmt::Display for DiscoveryListenerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "DiscoveryListenerCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for DiscoveryListenerCreateError {}
+
+/// Defines the failures that can occur when a [`DiscoveryListener`] waits for changes with
+/// [`DiscoveryListener::try_wait()`] or one of its variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoveryListenerWaitError {
+    /// The process has insufficient permissions to list all services or nodes.
+    InsufficientPermissions,
+    /// An interrupt signal was received while waiting.
+    InterruptSignal,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl std::fmt::Display for DiscoveryListenerWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "DiscoveryListenerWaitError::{:?}", self)
+    }
+}
+
+impl std::error::Error for DiscoveryListenerWaitError {}
+
+/// A change in the system that is reported by the [`DiscoveryListener`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscoveryEvent {
+    /// A [`Service`](crate::service::Service) was created.
+    ServiceAdded(StaticConfig),
+    /// A [`Service`](crate::service::Service) was removed.
+    ServiceRemoved(StaticConfig),
+    /// A [`Node`] was created.
+    NodeJoined(NodeId),
+    /// A [`Node`] was dropped or its dead remains were cleaned up.
+    NodeLeft(NodeId),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(usize)]
+pub(crate) enum DiscoveryTrigger {
+    ServicesChanged = 0,
+    NodesChanged = 1,
+}
+
+/// Wakes up all [`DiscoveryListener`]s under the given [`Config`]. Must be called after the
+/// change is visible to [`Service::list()`](crate::service::Service::list()) or
+/// [`Node::list()`].
+pub(crate) fn notify_discovery_listeners<S: service::Service>(
+    config: &Config,
+    trigger: DiscoveryTrigger,
+) {
+    let origin = "notify_discovery_listeners()";
+    let discovery_config = discovery_config::<S>(config);
+
+    let listeners = match <S::Event as NamedConceptMgmt>::list_cfg(&discovery_config) {
+        Ok(listeners) => listeners,
+        Err(e) => {
+            debug!(from origin,
+                "Unable to notify the discovery listeners about {:?} since they could not be listed ({:?}).",
+                trigger, e);
+            return;
+        }
+    };
+
+    for name in listeners {
+        match <S::Event as Event>::NotifierBuilder::new(&name)
+            .config(&discovery_config)
+            .open()
+        {
+            Ok(notifier) => match notifier.notify(TriggerId::new(trigger as usize)) {
+                Ok(()) => (),
+                Err(NotifierNotifyError::Disconnected) => {
+                    remove_stale_discovery_listener::<S>(&name, &discovery_config)
+                }
+                Err(e) => {
+                    debug!(from origin,
+                        "Unable to notify the discovery listener \"{}\" about {:?} ({:?}).",
+                        name, trigger, e);
+                }
+            },
+            Err(NotifierCreateError::DoesNotExist) => {
+                remove_stale_discovery_listener::<S>(&name, &discovery_config)
+            }
+            Err(e) => {
+                debug!(from origin,
+                    "Unable to open the discovery listener \"{}\" to notify it about {:?} ({:?}).",
+                    name, trigger, e);
+            }
+        }
+    }
+}
+
+/// Removes the remains of a [`DiscoveryListener`] whose process crashed. Every
+/// [`DiscoveryListener`] has a unique name, therefore a listed listener that cannot be
+/// reached anymore is never recreated and it is safe to remove it.
+fn remove_stale_discovery_listener<S: service::Service>(
+    name: &FileName,
+    discovery_config: &<S::Event as NamedConceptMgmt>::Configuration,
+) {
+    let origin = "remove_stale_discovery_listener()";
+    match unsafe { <S::Event as NamedConceptMgmt>::remove_cfg(name, discovery_config) } {
+        Ok(true) => {
+            debug!(from origin, "Removed the stale discovery listener \"{}\".", name);
+        }
+        Ok(false) => (),
+        Err(e) => {
+            debug!(from origin,
+                "Unable to remove the stale discovery listener \"{}\" ({:?}).", name, e);
+        }
+    }
+}
+
+/// Notifies all [`DiscoveryListener`]s about a removed [`Service`](crate::service::Service)
+/// when it goes out of scope. It must be the last member of the
+/// [`ServiceState`](crate::service::ServiceState) so that the notification is sent after all
+/// underlying resources of the [`Service`](crate::service::Service) are removed.
+#[derive(Debug)]
+pub(crate) struct ServiceRemovalNotifier<S: service::Service> {
+    shared_node: Option<Arc<SharedNode<S>>>,
+}
+
+impl<S: service::Service> ServiceRemovalNotifier<S> {
+    pub(crate) fn new() -> Self {
+        Self { shared_node: None }
+    }
+
+    pub(crate) fn arm(&mut self, shared_node: Arc<SharedNode<S>>) {
+        self.shared_node = Some(shared_node);
+    }
+}
+
+impl<S: service::Service> Drop for ServiceRemovalNotifier<S> {
+    fn drop(&mut self) {
+        if let Some(shared_node) = self.shared_node.take() {
+            notify_discovery_listeners::<S>(
+                shared_node.config(),
+                DiscoveryTrigger::ServicesChanged,
+            );
+        }
+    }
+}
+
+/// Creates a [`DiscoveryListener`] that reports the [`Service`](crate::service::Service)s and
+/// [`Node`]s that are created or removed under a specific [`Config`].
+#[derive(Debug, Default)]
+pub struct DiscoveryListenerBuilder {
+    config: Option<Config>,
+    query: ServiceQuery,
+}
+
+impl DiscoveryListenerBuilder {
+    /// Creates a new [`DiscoveryListenerBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`Config`] under which the changes are observed. If it is not set, the
+    /// [`Config::global_config()`] is used.
+    pub fn config(mut self, config: &Config) -> Self {
+        self.config = Some(config.clone());
+        self
+    }
+
+    /// Reports only [`Service`](crate::service::Service)s whose
+    /// [`ServiceName`](crate::service::service_name::ServiceName) fits the
+    /// [`ServiceNamePattern`]. By default, all names are reported.
+    pub fn name(mut self, pattern: ServiceNamePattern) -> Self {
+        self.query = self.query.name(pattern);
+        self
+    }
+
+    /// Reports only [`Service`](crate::service::Service)s with the given
+    /// [`MessagingPattern`]. By default, all messaging patterns are reported.
+    pub fn messaging_pattern(mut self, messaging_pattern: MessagingPattern) -> Self {
+        self.query = self.query.messaging_pattern(messaging_pattern);
+        self
+    }
+
+    /// Reports only [`Service`](crate::service::Service)s whose
+    /// [`AttributeSet`](crate::service::attribute::AttributeSet) satisfies the
+    /// [`AttributeVerifier`]. By default, the attributes are not considered.
+    pub fn attributes(mut self, attributes: &AttributeVerifier) -> Self {
+        self.query = self.query.attributes(attributes);
+        self
+    }
+
+    /// Creates the [`DiscoveryListener`].
+    pub fn create<S: service::Service>(
+        self,
+    ) -> Result<DiscoveryListener<S>, DiscoveryListenerCreateError> {
+        let msg = "Unable to create discovery listener";
+        let config = self
+            .config
+            .clone()
+            .unwrap_or_else(|| Config::global_config().clone());
+
+        let id = fail!(from self, when UniqueSystemId::new(),
+                    with DiscoveryListenerCreateError::InternalError,
+                    "{} since the unique id could not be generated.", msg);
+        let name = fail!(from self, when FileName::new(format!("{:x}", id.value()).as_bytes()),
+                    with DiscoveryListenerCreateError::InternalError,
+                    "{} since the unique id is not a valid file name.", msg);
+
+        // the discovery listener may be created before any service or node created the root path
+        let root_path = config.global.root_path();
+        if !fail!(from self, when Directory::does_exist(root_path),
+                    with DiscoveryListenerCreateError::InternalError,
+                    "{} since it could not be determined if the root path \"{}\" exists.", msg, root_path)
+        {
+            match Directory::create(root_path, Permission::OWNER_ALL | Permission::GROUP_ALL) {
+                Ok(_) | Err(DirectoryCreateError::DirectoryAlreadyExists) => (),
+                Err(DirectoryCreateError::InsufficientPermissions) => {
+                    fail!(from self, with DiscoveryListenerCreateError::InsufficientPermissions,
+                        "{} due to insufficient permissions to create the root path \"{}\".", msg, root_path);
+                }
+                Err(e) => {
+                    fail!(from self, with DiscoveryListenerCreateError::InternalError,
+                        "{} since the root path \"{}\" could not be created ({:?}).", msg, root_path, e);
+                }
+            }
+        }
+
+        let listener = match <S::Event as Event>::ListenerBuilder::new(&name)
+            .config(&discovery_config::<S>(&config))
+            .trigger_id_max(TriggerId::new(DiscoveryTrigger::NodesChanged as usize))
+            .create()
+        {
+            Ok(listener) => listener,
+            Err(ListenerCreateError::InsufficientPermissions) => {
+                fail!(from self, with DiscoveryListenerCreateError::InsufficientPermissions,
+                    "{} due to insufficient permissions to create the underlying event.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with DiscoveryListenerCreateError::InternalError,
+                    "{} since the underlying event could not be created ({:?}).", msg, e);
+            }
+        };
+
+        Ok(DiscoveryListener {
+            listener,
+            config,
+            query: self.query,
+            services: RefCell::new(HashMap::new()),
+            nodes: RefCell::new(BTreeSet::new()),
+            services_changed: Cell::new(true),
+            nodes_changed: Cell::new(true),
+        })
+    }
+}
+
+/// Reports the [`Service`](crate::service::Service)s and [`Node`]s that are created or
+/// removed under a specific [`Config`] as [`DiscoveryEvent`]s.
+///
+/// Every [`Service`](crate::service::Service) and [`Node`] wakes up all
+/// [`DiscoveryListener`]s when it is created or removed. Only then the [`DiscoveryListener`]
+/// compares the existing [`Service`](crate::service::Service)s and [`Node`]s with the ones it
+/// already knows, there is no periodic polling. The first call of
+/// [`DiscoveryListener::try_wait()`] or one of its variants reports all
+/// [`Service`](crate::service::Service)s and [`Node`]s that already exist.
+///
+/// It can be attached to a [`WaitSet`](crate::waitset::WaitSet) when the underlying event is
+/// file descriptor based, like in the [`ipc::Service`](crate::service::ipc::Service).
+#[derive(Debug)]
+pub struct DiscoveryListener<S: service::Service> {
+    listener: <S::Event as Event>::Listener,
+    config: Config,
+    query: ServiceQuery,
+    services: RefCell<HashMap<ServiceId, StaticConfig>>,
+    nodes: RefCell<BTreeSet<NodeId>>,
+    services_changed: Cell<bool>,
+    nodes_changed: Cell<bool>,
+}
+
+impl<S: service::Service> DiscoveryListener<S> {
+    /// Returns the [`Config`] under which the changes are observed.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Non-blocking check for changes. Calls the provided callback for every
+    /// [`DiscoveryEvent`] that occurred since the last call.
+    pub fn try_wait<F: FnMut(DiscoveryEvent)>(
+        &self,
+        callback: F,
+    ) -> Result<(), DiscoveryListenerWaitError> {
+        self.wait(|listener| listener.try_wait_one(), callback)
+    }
+
+    /// Blocking wait for changes until the provided timeout has passed. Calls the provided
+    /// callback for every [`DiscoveryEvent`] that occurred since the last call.
+    /// Sporadic wakeups can occur, the callback is then not called at all.
+    pub fn timed_wait<F: FnMut(DiscoveryEvent)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), DiscoveryListenerWaitError> {
+        self.wait(|listener| listener.timed_wait_one(timeout), callback)
+    }
+
+    /// Blocking wait for changes. Calls the provided callback for every [`DiscoveryEvent`]
+    /// that occurred since the last call.
+    /// Sporadic wakeups can occur, the callback is then not called at all.
+    pub fn blocking_wait<F: FnMut(DiscoveryEvent)>(
+        &self,
+        callback: F,
+    ) -> Result<(), DiscoveryListenerWaitError> {
+        self.wait(|listener| listener.blocking_wait_one(), callback)
+    }
+
+    fn wait<
+        W: FnOnce(&<S::Event as Event>::Listener) -> Result<Option<TriggerId>, ListenerWaitError>,
+        F: FnMut(DiscoveryEvent),
+    >(
+        &self,
+        wait_call: W,
+        mut callback: F,
+    ) -> Result<(), DiscoveryListenerWaitError> {
+        let msg = "Unable to wait for discovery events";
+        let map_error = |e| match e {
+            ListenerWaitError::InterruptSignal => DiscoveryListenerWaitError::InterruptSignal,
+            _ => DiscoveryListenerWaitError::InternalError,
+        };
+
+        if !self.services_changed.get() && !self.nodes_changed.get() {
+            if let Some(trigger) = fail!(from self, when wait_call(&self.listener).map_err(map_error),
+                    "{} since the underlying event could not be waited on.", msg)
+            {
+                self.handle_trigger(trigger);
+            }
+        }
+
+        fail!(from self, when self.listener.try_wait_all(|trigger| self.handle_trigger(trigger))
+                    .map_err(map_error),
+            "{} since the pending notifications of the underlying event could not be acquired.", msg);
+
+        if self.services_changed.get() {
+            for event in self.update_services()? {
+                callback(event);
+            }
+        }
+
+        if self.nodes_changed.get() {
+            for event in self.update_nodes()? {
+                callback(event);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_trigger(&self, trigger: TriggerId) {
+        match trigger.as_value() {
+            v if v == DiscoveryTrigger::ServicesChanged as usize => self.services_changed.set(true),
+            v if v == DiscoveryTrigger::NodesChanged as usize => self.nodes_changed.set(true),
+            _ => {
+                self.services_changed.set(true);
+                self.nodes_changed.set(true);
+            }
+        }
+    }
+
+    fn update_services(&self) -> Result<Vec<DiscoveryEvent>, DiscoveryListenerWaitError> {
+        let msg = "Unable to update the discovered services";
+        let mut current = HashMap::new();
+        fail!(from self, when S::list(&self.config, |details| {
+                if self.query.matches(&details.static_details) {
+                    current.insert(
+                        details.static_details.service_id().clone(),
+                        details.static_details,
+                    );
+                }
+                iceoryx2_bb_elementary::CallbackProgression::Continue
+            }),
+            map ServiceListError::InsufficientPermissions => DiscoveryListenerWaitError::InsufficientPermissions,
+            unmatched DiscoveryListenerWaitError::InternalError,
+            "{} since the services could not be listed.", msg);
+
+        let mut known = self.services.borrow_mut();
+        let mut events: Vec<DiscoveryEvent> = known
+            .iter()
+            .filter(|(id, service)| current.get(*id) != Some(*service))
+            .map(|(_, service)| DiscoveryEvent::ServiceRemoved(service.clone()))
+            .collect();
+        events.extend(
+            current
+                .iter()
+                .filter(|(id, service)| known.get(*id) != Some(*service))
+                .map(|(_, service)| DiscoveryEvent::ServiceAdded(service.clone())),
+        );
+
+        *known = current;
+        self.services_changed.set(false);
+        Ok(events)
+    }
+
+    fn update_nodes(&self) -> Result<Vec<DiscoveryEvent>, DiscoveryListenerWaitError> {
+        let msg = "Unable to update the discovered nodes";
+        let mut current = BTreeSet::new();
+        fail!(from self, when Node::<S>::list(&self.config, |node_state| {
+                // a dead node is present until its remains are cleaned up
+                current.insert(*node_state.node_id());
+                iceoryx2_bb_elementary::CallbackProgression::Continue
+            }),
+            map NodeListFailure::InsufficientPermissions => DiscoveryListenerWaitError::InsufficientPermissions;
+                NodeListFailure::Interrupt => DiscoveryListenerWaitError::InterruptSignal,
+            unmatched DiscoveryListenerWaitError::InternalError,
+            "{} since the nodes could not be listed.", msg);
+
+        let mut known = self.nodes.borrow_mut();
+        let mut events: Vec<DiscoveryEvent> = known
+            .difference(&current)
+            .map(|id| DiscoveryEvent::NodeLeft(*id))
+            .collect();
+        events.extend(
+            current
+                .difference(&known)
+                .map(|id| DiscoveryEvent::NodeJoined(*id)),
+        );
+
+        *known = current;
+        self.nodes_changed.set(false);
+        Ok(events)
+    }
+}
+
+impl<S: service::Service> FileDescriptorBased for DiscoveryListener<S>
+where
+    <S::Event as Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<S: service::Service> SynchronousMultiplexing for DiscoveryListener<S> where
+    <S::Event as Event>::Listener: SynchronousMultiplexing
+{
+}
@@ -22,9 +22,9 @@
 //!
 //! - Publish-Subscribe
 //! - Events
-//! - Request-Response (planned)
+//! - Request-Response
 //! - Pipeline (planned)
-//! - Blackboard (planned)
+//! - Blackboard
 //!
 //! For a comprehensive list of all planned features, please refer to the
 //! [GitHub Roadmap](https://github.com/eclipse-iceoryx/iceoryx2/blob/main/ROADMAP.md).
@@ -289,14 +289,35 @@ mod compiletests;
 /// Handles iceoryx2s global configuration
 pub mod config;
 
+/// The request that is received by a [`Server`](crate::port::server::Server). It can be used
+/// to send responses back to the [`Client`](crate::port::client::Client).
+pub mod active_request;
+
+/// Notifies about created and removed [`Service`](crate::service::Service)s and
+/// [`Node`](crate::node::Node)s so that they can be discovered without polling.
+pub mod discovery;
+
 /// Central instance that owns all service entities and can handle incoming event in an event loop
 pub mod node;
 
 /// The ports or communication endpoints of iceoryx2
 pub mod port;
 
+/// The handle of a sent request that is used by the [`Client`](crate::port::client::Client)
+/// to receive the corresponding responses.
+pub mod pending_response;
+
 pub(crate) mod raw_sample;
 
+/// The request that is sent by a [`Client`](crate::port::client::Client).
+pub mod request_mut;
+
+/// The response that is received by a [`Client`](crate::port::client::Client).
+pub mod response;
+
+/// The response that is sent by a [`Server`](crate::port::server::Server).
+pub mod response_mut;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
@@ -307,5 +328,9 @@ pub mod sample_mut;
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
 
+/// Allows to wait on multiple events, like notifications, deadlines and intervals, in a
+/// single call and dispatches them to a user callback.
+pub mod waitset;
+
 /// Loads a meaninful subset to cover 90% of the iceoryx2 communication use cases.
 pub mod prelude;
@@ -105,6 +105,9 @@
 //!         NodeEvent::InterruptSignal => {
 //!             println!("Someone send an interrupt signal ...");
 //!         }
+//!         NodeEvent::Attachment(id) => {
+//!             println!("attachment {:?} has a pending event", id);
+//!         }
 //!     }
 //! }
 //! # Ok(())
@@ -117,11 +120,15 @@ pub mod node_name;
 #[doc(hidden)]
 pub mod testing;
 
+use crate::discovery::{notify_discovery_listeners, DiscoveryTrigger};
 use crate::node::node_name::NodeName;
+use crate::port::port_identifiers::UniqueSubscriberId;
+use crate::port::subscriber::Subscriber;
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
     node_details_path, node_monitoring_config, service_tag_config,
 };
+use crate::service::header::publish_subscribe::Header;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, remove_service_tag};
@@ -131,20 +138,25 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
 use iceoryx2_bb_posix::clock::{nanosleep, NanosleepError, Time};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_bb_posix::process::{Process, ProcessId};
 use iceoryx2_bb_posix::signal::SignalHandler;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::named_concept::{NamedConceptPathHintRemoveError, NamedConceptRemoveError};
+use iceoryx2_cal::reactor::{
+    Reactor, ReactorAttachError, ReactorBuilder, ReactorCreateError, ReactorWaitError,
+};
 use iceoryx2_cal::{
     monitoring::*, named_concept::NamedConceptListError, serialize::*, static_storage::*,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 use std::cell::UnsafeCell;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::sync::atomic::Ordering;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 
 /// A complete list of all events that can occur in the main event loop, [`Node::wait()`].
@@ -156,6 +168,129 @@ pub enum NodeEvent {
     TerminationRequest,
     /// SIGINT signal was received
     InterruptSignal,
+    /// An object attached with [`Node::attach()`] or [`Node::attach_subscriber()`] has a
+    /// pending event. The [`NodeAttachmentId`] identifies the attachment.
+    Attachment(NodeAttachmentId),
+}
+
+/// Defines the failures that can occur when an object is attached to the [`Node`] with
+/// [`Node::attach()`] or [`Node::attach_subscriber()`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum NodeAttachmentError {
+    /// The capacity of the underlying reactor is exceeded.
+    InsufficientCapacity,
+    /// The object is already attached to the [`Node`].
+    AlreadyAttached,
+    /// The [`Subscriber`] cannot be attached since its service was not created with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notify_on_send()`].
+    NotifyOnSendIsDisabled,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl std::fmt::Display for NodeAttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "NodeAttachmentError::{:?}", self)
+    }
+}
+
+impl std::error::Error for NodeAttachmentError {}
+
+/// Identifies an object that was attached to the [`Node`] with [`Node::attach()`]. It is
+/// contained in [`NodeEvent::Attachment`] when the attachment has a pending event.
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+pub struct NodeAttachmentId(i32);
+
+impl NodeAttachmentId {
+    /// Returns true when the [`NodeAttachmentId`] belongs to the attachment of the provided
+    /// [`NodeAttachmentGuard`], otherwise false.
+    pub fn is_from<Service: service::Service>(&self, guard: &NodeAttachmentGuard<Service>) -> bool {
+        *self == guard.id()
+    }
+}
+
+/// Is returned by [`Node::attach()`]. As long as the guard lives the attachment is considered
+/// in [`Node::wait()`]. When it goes out of scope the attachment is detached.
+pub struct NodeAttachmentGuard<'node, 'attachment, Service: service::Service>
+where
+    Service::Reactor: 'node,
+{
+    node: &'node Node<Service>,
+    reactor_guard: Option<<Service::Reactor as Reactor>::Guard<'node, 'attachment>>,
+    id: NodeAttachmentId,
+}
+
+impl<Service: service::Service> Debug for NodeAttachmentGuard<'_, '_, Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "NodeAttachmentGuard<{}> {{ id: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.id
+        )
+    }
+}
+
+impl<Service: service::Service> NodeAttachmentGuard<'_, '_, Service> {
+    /// Returns the [`NodeAttachmentId`] of the attachment.
+    pub fn id(&self) -> NodeAttachmentId {
+        self.id
+    }
+}
+
+impl<Service: service::Service> Drop for NodeAttachmentGuard<'_, '_, Service> {
+    fn drop(&mut self) {
+        self.node.attachments.remove(self.id);
+
+        // detaches the file descriptor from the reactor
+        self.reactor_guard.take();
+    }
+}
+
+struct NodeAttachments<Service: service::Service> {
+    // created on the first attachment so that nodes without attachments do not acquire the
+    // resources of a reactor
+    reactor: OnceLock<Service::Reactor>,
+    file_descriptors: Mutex<BTreeSet<i32>>,
+    pending_events: Mutex<VecDeque<i32>>,
+}
+
+impl<Service: service::Service> Debug for NodeAttachments<Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "NodeAttachments {{ file_descriptors: {:?}, pending_events: {:?} }}",
+            self.file_descriptors, self.pending_events
+        )
+    }
+}
+
+impl<Service: service::Service> NodeAttachments<Service> {
+    fn reactor(&self) -> Result<&Service::Reactor, ReactorCreateError> {
+        if let Some(reactor) = self.reactor.get() {
+            return Ok(reactor);
+        }
+
+        let reactor = <Service::Reactor as Reactor>::Builder::new().create()?;
+        Ok(self.reactor.get_or_init(|| reactor))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.file_descriptors.lock().unwrap().is_empty()
+    }
+
+    fn remove(&self, id: NodeAttachmentId) {
+        self.file_descriptors.lock().unwrap().remove(&id.0);
+        self.pending_events.lock().unwrap().retain(|fd| *fd != id.0);
+    }
+
+    fn next_pending_event(&self) -> Option<NodeAttachmentId> {
+        self.pending_events
+            .lock()
+            .unwrap()
+            .pop_front()
+            .map(NodeAttachmentId)
+    }
 }
 
 /// The system-wide unique id of a [`Node`]
@@ -491,6 +626,7 @@ impl<Service: service::Service> DeadNodeView<Service> {
             Ok(_) => {
                 drop(cleaner);
                 IN_CLEANUP_SECTION.store(false, Ordering::Relaxed);
+                notify_discovery_listeners::<Service>(config, DiscoveryTrigger::NodesChanged);
                 Ok(true)
             }
             Err(e) => {
@@ -670,12 +806,81 @@ impl RegisteredServices {
     }
 }
 
+/// The history of a dropped publisher.
+#[derive(Debug)]
+pub(crate) struct RetainedHistory {
+    /// Copies of the raw samples, oldest first.
+    pub(crate) samples: Vec<Vec<u8>>,
+    /// The subscribers that were connected to the publisher and received the history already.
+    pub(crate) served_subscribers: Vec<UniqueSubscriberId>,
+}
+
+/// The histories of dropped publishers. A publisher that is created later for the same
+/// service with the same node takes them over.
+#[derive(Debug)]
+pub(crate) struct RetainedHistories {
+    data: Mutex<HashMap<ServiceId, RetainedHistory>>,
+}
+
+impl RetainedHistories {
+    /// Merges the history into the already retained history of the service so that dropping
+    /// multiple publishers of the same service does not lose any history. Only the latest
+    /// `history_size` samples are kept.
+    pub(crate) fn store(
+        &self,
+        service_id: &ServiceId,
+        history: RetainedHistory,
+        history_size: usize,
+    ) {
+        let mut data = self.data.lock().unwrap();
+        let mut retained_history = match data.remove(service_id) {
+            Some(mut retained_history) => {
+                retained_history.samples.extend(history.samples);
+                retained_history
+                    .samples
+                    .sort_by_key(|sample| Self::timestamp_of(sample));
+                // a subscriber that was not connected to every publisher did not receive the
+                // complete merged history
+                retained_history
+                    .served_subscribers
+                    .retain(|id| history.served_subscribers.contains(id));
+                retained_history
+            }
+            None => history,
+        };
+
+        let number_of_samples = retained_history.samples.len();
+        if history_size < number_of_samples {
+            retained_history
+                .samples
+                .drain(..number_of_samples - history_size);
+        }
+
+        data.insert(service_id.clone(), retained_history);
+    }
+
+    pub(crate) fn take(&self, service_id: &ServiceId) -> Option<RetainedHistory> {
+        self.data.lock().unwrap().remove(service_id)
+    }
+
+    pub(crate) fn remove(&self, service_id: &ServiceId) {
+        self.data.lock().unwrap().remove(service_id);
+    }
+
+    fn timestamp_of(sample: &[u8]) -> Duration {
+        unsafe { core::ptr::read_unaligned(sample.as_ptr() as *const Header) }
+            .timestamp()
+            .as_duration()
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct SharedNode<Service: service::Service> {
     id: NodeId,
     details: NodeDetails,
     monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
     registered_services: RegisteredServices,
+    retained_histories: RetainedHistories,
     _details_storage: Service::StaticStorage,
 }
 
@@ -694,6 +899,10 @@ impl<Service: service::Service> SharedNode<Service> {
     pub(crate) fn registered_services(&self) -> &RegisteredServices {
         &self.registered_services
     }
+
+    pub(crate) fn retained_histories(&self) -> &RetainedHistories {
+        &self.retained_histories
+    }
 }
 
 impl<Service: service::Service> Drop for SharedNode<Service> {
@@ -705,6 +914,10 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
 
             warn!(from self, when remove_node::<Service>(self.id, self.details.config()),
                 "Unable to remove node resources.");
+
+            // the node is listed as long as the monitoring token exists
+            drop(self.monitoring_token.get_mut().take());
+            notify_discovery_listeners::<Service>(self.config(), DiscoveryTrigger::NodesChanged);
         }
     }
 }
@@ -718,6 +931,7 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
 #[derive(Debug)]
 pub struct Node<Service: service::Service> {
     shared: Arc<SharedNode<Service>>,
+    attachments: Box<NodeAttachments<Service>>,
 }
 
 unsafe impl<Service: service::Service> Send for Node<Service> {}
@@ -795,13 +1009,202 @@ impl<Service: service::Service> Node<Service> {
         (*self.shared.monitoring_token.get()).take().unwrap()
     }
 
+    /// Attaches an object to the [`Node`] so that [`Node::wait()`] wakes up as soon as the
+    /// object has a pending event and returns [`NodeEvent::Attachment`]. The object must
+    /// implement [`SynchronousMultiplexing`], like a
+    /// [`Listener`](crate::port::listener::Listener), and cannot be attached twice.
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let event = node.service_builder(&"MyEventName".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    /// let listener = event.listener_builder().create()?;
+    ///
+    /// let guard = node.attach(&listener)?;
+    ///
+    /// loop {
+    ///     match node.wait(Duration::from_secs(1)) {
+    ///         NodeEvent::Attachment(id) if id.is_from(&guard) => {
+    ///             while let Some(event_id) = listener.try_wait_one()? {
+    ///                 println!("received {:?}", event_id);
+    ///             }
+    ///         }
+    ///         NodeEvent::Tick | NodeEvent::Attachment(_) => (),
+    ///         NodeEvent::TerminationRequest | NodeEvent::InterruptSignal => break,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach<'node, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'node self,
+        attachment: &'attachment T,
+    ) -> Result<NodeAttachmentGuard<'node, 'attachment, Service>, NodeAttachmentError> {
+        let msg = "Unable to attach object to node";
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+        if self
+            .attachments
+            .file_descriptors
+            .lock()
+            .unwrap()
+            .contains(&fd)
+        {
+            fail!(from self, with NodeAttachmentError::AlreadyAttached,
+                "{} {:?} since it is already attached.", msg, attachment);
+        }
+
+        let reactor = match self.attachments.reactor() {
+            Ok(reactor) => reactor,
+            Err(e) => {
+                fail!(from self, with NodeAttachmentError::InternalError,
+                    "{} {:?} since the reactor could not be created ({:?}).", msg, attachment, e);
+            }
+        };
+
+        let reactor_guard = match reactor.attach(attachment) {
+            Ok(guard) => guard,
+            Err(ReactorAttachError::CapacityExceeded) => {
+                fail!(from self, with NodeAttachmentError::InsufficientCapacity,
+                    "{} {:?} since it would exceed the capacity of {}.",
+                    msg, attachment, Service::Reactor::capacity());
+            }
+            Err(ReactorAttachError::UnknownError(e)) => {
+                fail!(from self, with NodeAttachmentError::InternalError,
+                    "{} {:?} due to an internal error ({}).", msg, attachment, e);
+            }
+        };
+
+        self.attachments.file_descriptors.lock().unwrap().insert(fd);
+
+        Ok(NodeAttachmentGuard {
+            node: self,
+            reactor_guard: Some(reactor_guard),
+            id: NodeAttachmentId(fd),
+        })
+    }
+
+    /// Attaches a [`Subscriber`] to the [`Node`] so that [`Node::wait()`] wakes up as soon as
+    /// a [`Publisher`](crate::port::publisher::Publisher) sent a sample and returns
+    /// [`NodeEvent::Attachment`]. The service of the [`Subscriber`] must be created with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notify_on_send()`]. The
+    /// event is consumed when [`Subscriber::receive()`] finds no more samples.
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"MyServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .enable_notify_on_send(true)
+    ///     .open_or_create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let guard = node.attach_subscriber(&subscriber)?;
+    ///
+    /// loop {
+    ///     match node.wait(Duration::from_secs(1)) {
+    ///         NodeEvent::Attachment(id) if id.is_from(&guard) => {
+    ///             while let Some(sample) = subscriber.receive()? {
+    ///                 println!("received {:?}", *sample);
+    ///             }
+    ///         }
+    ///         NodeEvent::Tick | NodeEvent::Attachment(_) => (),
+    ///         NodeEvent::TerminationRequest | NodeEvent::InterruptSignal => break,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_subscriber<'node, 'attachment, Payload: Debug + ?Sized, UserHeader: Debug>(
+        &'node self,
+        subscriber: &'attachment Subscriber<Service, Payload, UserHeader>,
+    ) -> Result<NodeAttachmentGuard<'node, 'attachment, Service>, NodeAttachmentError>
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    {
+        match subscriber.listener() {
+            Some(listener) => self.attach(listener),
+            None => {
+                fail!(from self, with NodeAttachmentError::NotifyOnSendIsDisabled,
+                    "Unable to attach subscriber {:?} to node since the service was not created with notify on send enabled.",
+                    subscriber.id());
+            }
+        }
+    }
+
     /// Waits until an event was received. It returns
     /// [`NodeEvent::Tick`] when the `cycle_time` has passed, otherwise event that occurred.
+    ///
+    /// If objects are attached with [`Node::attach()`] it returns [`NodeEvent::Attachment`] as
+    /// soon as one of them has a pending event. When multiple attachments have pending events
+    /// they are returned one after another in consecutive calls. The user has to consume the
+    /// events of the attachment, otherwise it will be reported again in the next wait call.
     pub fn wait(&self, cycle_time: Duration) -> NodeEvent {
         if SignalHandler::termination_requested() {
             return NodeEvent::TerminationRequest;
         }
 
+        if let Some(id) = self.attachments.next_pending_event() {
+            return NodeEvent::Attachment(id);
+        }
+
+        if self.attachments.is_empty() {
+            return self.sleep(cycle_time);
+        }
+
+        let mut triggered_fds = vec![];
+        let reactor = match self.attachments.reactor.get() {
+            Some(reactor) => reactor,
+            None => return self.sleep(cycle_time),
+        };
+
+        let wait_result = reactor.timed_wait(
+            |fd| triggered_fds.push(unsafe { fd.native_handle() }),
+            cycle_time,
+        );
+
+        match wait_result {
+            Ok(()) => (),
+            Err(ReactorWaitError::Interrupt) => {
+                if SignalHandler::termination_requested() {
+                    return NodeEvent::TerminationRequest;
+                }
+                return NodeEvent::InterruptSignal;
+            }
+            Err(v) => {
+                fatal_panic!(from self,
+                    "Failed to wait with cycle time {:?} on the attachments in main event loop, caused by ({:?}).",
+                    cycle_time, v);
+            }
+        }
+
+        if SignalHandler::termination_requested() {
+            return NodeEvent::TerminationRequest;
+        }
+
+        {
+            let file_descriptors = self.attachments.file_descriptors.lock().unwrap();
+            self.attachments.pending_events.lock().unwrap().extend(
+                triggered_fds
+                    .into_iter()
+                    .filter(|fd| file_descriptors.contains(fd)),
+            );
+        }
+
+        match self.attachments.next_pending_event() {
+            Some(id) => NodeEvent::Attachment(id),
+            None => NodeEvent::Tick,
+        }
+    }
+
+    fn sleep(&self, cycle_time: Duration) -> NodeEvent {
         match nanosleep(cycle_time) {
             Ok(()) => {
                 if SignalHandler::termination_requested() {
@@ -964,7 +1367,9 @@ impl<Service: service::Service> Node<Service> {
         let origin = format!("open_node_storage({:?}, {:?})", config, node_id);
 
         match result.err().unwrap() {
-            StaticStorageOpenError::DoesNotExist => Ok(None),
+            // nodes of other users are not accessible and therefore treated as non-existing
+            StaticStorageOpenError::DoesNotExist
+            | StaticStorageOpenError::InsufficientPermissions => Ok(None),
             StaticStorageOpenError::Read => {
                 fail!(from origin, with NodeReadStorageFailure::ReadError,
                     "{} since the node config storage could not be read.", msg);
@@ -990,22 +1395,18 @@ impl<Service: service::Service> Node<Service> {
             return Ok(None);
         };
 
-        let mut read_content =
-            String::from_utf8(vec![b' '; node_storage.len() as usize]).expect("");
+        let mut read_content = vec![0u8; node_storage.len() as usize];
 
         let origin = format!("get_node_details({:?}, {:?})", config, node_id);
         let msg = "Unable to read node details";
 
-        if node_storage
-            .read(unsafe { read_content.as_mut_vec() }.as_mut_slice())
-            .is_err()
-        {
+        if node_storage.read(read_content.as_mut_slice()).is_err() {
             fail!(from origin, with NodeReadStorageFailure::ReadError,
                 "{} since the content of the node config storage could not be read.", msg);
         }
 
         let node_details = fail!(from origin,
-                    when Service::ConfigSerializer::deserialize::<NodeDetails>(unsafe { read_content.as_mut_vec()}),
+                    when Service::ConfigSerializer::deserialize::<NodeDetails>(&read_content),
                     with NodeReadStorageFailure::Corrupted,
                 "{} since the contents of the node config storage is corrupted.", msg);
 
@@ -1119,18 +1520,28 @@ impl NodeBuilder {
         let (details_storage, details) =
             self.create_node_details_storage::<Service>(&config, &NodeId(node_id))?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
-
-        Ok(Node {
+        let node = Node {
             shared: Arc::new(SharedNode {
                 id: NodeId(node_id),
                 monitoring_token: UnsafeCell::new(Some(monitoring_token)),
                 registered_services: RegisteredServices {
                     data: Mutex::new(HashMap::new()),
                 },
+                retained_histories: RetainedHistories {
+                    data: Mutex::new(HashMap::new()),
+                },
                 _details_storage: details_storage,
                 details,
             }),
-        })
+            attachments: Box::new(NodeAttachments {
+                reactor: OnceLock::new(),
+                file_descriptors: Mutex::new(BTreeSet::new()),
+                pending_events: Mutex::new(VecDeque::new()),
+            }),
+        };
+
+        notify_discovery_listeners::<Service>(&config, DiscoveryTrigger::NodesChanged);
+        Ok(node)
     }
 
     fn create_token<Service: service::Service>(
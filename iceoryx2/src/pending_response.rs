@@ -0,0 +1,140 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//!
+//! let pending_response = client.send_copy(1234)?;
+//! println!("request was delivered to {} servers",
+//!     pending_response.number_of_server_connections());
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response {} from server {:?}", *response, response.origin());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use crate::{
+    port::{client::ClientSharedState, update_connections::ConnectionFailure, ReceiveError},
+    response::Response,
+};
+
+/// Represents an active request that was sent by a [`Client`](crate::port::client::Client).
+/// It receives the stream of responses that the [`Server`](crate::port::server::Server)s send
+/// for this particular request. As soon as the [`PendingResponse`] goes out of scope, the
+/// request is no longer active and all further responses are discarded.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it shares the state of the
+/// [`Client`](crate::port::client::Client) and the [`Client`](crate::port::client::Client)
+/// is not thread-safe!
+pub struct PendingResponse<
+    Service: crate::service::Service,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    shared_state: Arc<ClientSharedState<Service>>,
+    request_id: u64,
+    number_of_server_connections: usize,
+    _request_payload: PhantomData<RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Debug
+    for PendingResponse<Service, RequestPayload, ResponsePayload>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PendingResponse<{}, {}, {}> {{ request_id: {}, number_of_server_connections: {} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<ResponsePayload>(),
+            self.request_id,
+            self.number_of_server_connections
+        )
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for PendingResponse<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.shared_state.complete_request(self.request_id);
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    PendingResponse<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        shared_state: &Arc<ClientSharedState<Service>>,
+        request_id: u64,
+        number_of_server_connections: usize,
+    ) -> Self {
+        Self {
+            shared_state: Arc::clone(shared_state),
+            request_id,
+            number_of_server_connections,
+            _request_payload: PhantomData,
+            _response_payload: PhantomData,
+        }
+    }
+
+    /// Returns the id of the request that is unique for every request of a
+    /// [`Client`](crate::port::client::Client).
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+
+    /// Returns the number of [`Server`](crate::port::server::Server)s the request was
+    /// delivered to.
+    pub fn number_of_server_connections(&self) -> usize {
+        self.number_of_server_connections
+    }
+
+    /// Returns true if there are responses in the buffer that can be received with
+    /// [`PendingResponse::receive()`]. Responses of other requests of the same
+    /// [`Client`](crate::port::client::Client) are not considered.
+    pub fn has_response(&self) -> Result<bool, ConnectionFailure> {
+        self.shared_state.has_response(self.request_id)
+    }
+
+    /// Receives a [`Response`] from one of the [`Server`](crate::port::server::Server)s that
+    /// received the request. If no response could be received [`None`] is returned. If a
+    /// failure occurs [`ReceiveError`] is returned, e.g. [`ReceiveError::ExceedsMaxBorrows`]
+    /// when the [`PendingResponse`] already borrows
+    /// [`StaticConfig::max_borrowed_responses_per_pending_response()`](crate::service::static_config::request_response::StaticConfig::max_borrowed_responses_per_pending_response())
+    /// [`Response`]s.
+    pub fn receive(&self) -> Result<Option<Response<Service, ResponsePayload>>, ReceiveError> {
+        self.shared_state.receive_response(self.request_id)
+    }
+}
@@ -0,0 +1,959 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service
+//!     .client_builder()
+//!     // defines how many requests can be loaned in parallel
+//!     .max_loaned_requests(5)
+//!     .create()?;
+//!
+//! // loan some initialized memory and send it
+//! let mut request = client.loan()?;
+//! *request.payload_mut() = 1337;
+//! let pending_response = request.send()?;
+//!
+//! // loan some uninitialized memory and send it
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1337);
+//! let other_pending_response = request.send()?;
+//!
+//! // send a copy of the value
+//! let yet_another_pending_response = client.send_copy(313)?;
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response: {}", *response);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{debug, error, fail, fatal_panic, warn};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::NamedConceptMgmt;
+use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptRemoveError};
+use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver, ZeroCopyReleaseError,
+    ZeroCopySendError, ZeroCopySender,
+};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
+
+use super::details::sample_pool::SamplePool;
+use super::details::server_connections::{Connection, ServerConnections};
+use super::port_identifiers::UniqueClientId;
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use super::{LoanError, ReceiveError, SendError};
+use crate::config;
+use crate::pending_response::PendingResponse;
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::request_mut::RequestMut;
+use crate::response::Response;
+use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::naming_scheme::{client_data_segment_name, is_port_part_of_connection};
+use crate::service::static_config::request_response::StaticConfig;
+use crate::service::{self, port_factory::client::LocalClientConfig};
+
+/// Describes the failures when a new [`Client`] is created via the
+/// [`crate::service::port_factory::client::PortFactoryClient`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientCreateError {
+    /// The maximum amount of [`Client`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Client`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedClients,
+    /// The datasegment in which the requests of the [`Client`] are stored, could not be created.
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ClientCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ClientCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ClientCreateError {}
+
+/// Failure that can be emitted when a [`RequestMut`] is sent via [`RequestMut::send()`] or
+/// [`Client::send_copy()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum RequestSendError {
+    /// The [`Client`] has already the maximum amount of active requests. A request is active
+    /// as long as its [`PendingResponse`] exists. The limit is defined in
+    /// [`crate::service::static_config::request_response::StaticConfig::max_active_requests_per_client()`].
+    ExceedsMaxActiveRequests,
+    /// A failure occurred while delivering the request to the
+    /// [`Server`](crate::port::server::Server)s.
+    SendError(SendError),
+}
+
+impl From<SendError> for RequestSendError {
+    fn from(value: SendError) -> Self {
+        RequestSendError::SendError(value)
+    }
+}
+
+impl From<LoanError> for RequestSendError {
+    fn from(value: LoanError) -> Self {
+        RequestSendError::SendError(SendError::LoanError(value))
+    }
+}
+
+impl From<ConnectionFailure> for RequestSendError {
+    fn from(value: ConnectionFailure) -> Self {
+        RequestSendError::SendError(SendError::ConnectionError(value))
+    }
+}
+
+impl std::fmt::Display for RequestSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "RequestSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for RequestSendError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(crate) enum RemoveRequestResponsePortFromAllConnectionsError {
+    InsufficientPermissions,
+    InternalError,
+}
+
+#[derive(Debug)]
+struct StashedResponse<Service: service::Service> {
+    request_id: u64,
+    connection: Arc<Connection<Service>>,
+    offset: PointerOffset,
+}
+
+/// The state of a [`Client`] that is shared with all of its [`RequestMut`]s and
+/// [`PendingResponse`]s.
+#[derive(Debug)]
+pub(crate) struct ClientSharedState<Service: service::Service> {
+    sample_pool: SamplePool<Service>,
+    server_connections: ServerConnections<Service>,
+    server_list_state: UnsafeCell<ContainerState<ServerDetails>>,
+    config: LocalClientConfig,
+    loan_counter: IoxAtomicUsize,
+    request_id_counter: IoxAtomicU64,
+    active_requests: UnsafeCell<Vec<u64>>,
+    stashed_responses: UnsafeCell<Vec<StashedResponse<Service>>>,
+    borrowed_responses: UnsafeCell<Vec<u64>>,
+    is_active: IoxAtomicBool,
+}
+
+impl<Service: service::Service> ClientSharedState<Service> {
+    fn static_config(&self) -> &StaticConfig {
+        &self.server_connections.static_config
+    }
+
+    fn retrieve_returned_requests(&self) {
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                loop {
+                    match connection.request_sender.reclaim() {
+                        Ok(Some(offset)) => self.sample_pool.release_sample(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim requests from connection {:?} due to {:?}. This may lead to a situation where no more requests will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_request_active(&self, request_id: u64) -> bool {
+        unsafe { &*self.active_requests.get() }.contains(&request_id)
+    }
+
+    fn number_of_stashed_responses(
+        &self,
+        request_id: u64,
+        connection: &Connection<Service>,
+    ) -> usize {
+        unsafe { &*self.stashed_responses.get() }
+            .iter()
+            .filter(|r| r.request_id == request_id && std::ptr::eq(&*r.connection, connection))
+            .count()
+    }
+
+    // a connection is blocked when a stashed response exceeds the response buffer of its
+    // request, no further responses are received from it so that the server experiences the
+    // back-pressure of the full buffer
+    fn is_connection_blocked(&self, connection: &Connection<Service>) -> bool {
+        unsafe { &*self.active_requests.get() }
+            .iter()
+            .any(|request_id| {
+                self.static_config().max_response_buffer_size
+                    < self.number_of_stashed_responses(*request_id, connection)
+            })
+    }
+
+    // returns false when no further responses shall be received from the connection
+    fn stash_or_release_response(
+        &self,
+        connection: &Arc<Connection<Service>>,
+        offset: PointerOffset,
+    ) -> bool {
+        let request_id = self.response_header(connection, offset).request_id();
+        if !self.is_request_active(request_id) {
+            release_response(connection, offset);
+            return true;
+        }
+
+        // the stash acts as response buffer of every pending response, limiting it keeps the
+        // borrowed responses of a connection below the maximum of
+        // StaticConfig::max_borrowed_responses() so that unconsumed responses of one request
+        // never block the responses of another request
+        let stashed_responses = unsafe { &mut *self.stashed_responses.get() };
+        let mut keep_receiving = true;
+        if self.static_config().max_response_buffer_size
+            <= self.number_of_stashed_responses(request_id, connection)
+        {
+            if self.static_config().enable_safe_overflow_for_responses {
+                if let Some(pos) = stashed_responses.iter().position(|r| {
+                    r.request_id == request_id && Arc::ptr_eq(&r.connection, connection)
+                }) {
+                    let oldest = stashed_responses.remove(pos);
+                    debug!(from self, "The response buffer of request {} is full, the oldest response is discarded.",
+                        request_id);
+                    release_response(&oldest.connection, oldest.offset);
+                }
+            } else {
+                debug!(from self, "The response buffer of request {} is full, the server {:?} is blocked until the responses are consumed.",
+                    request_id, connection.server_id);
+                keep_receiving = false;
+            }
+        }
+
+        stashed_responses.push(StashedResponse {
+            request_id,
+            connection: connection.clone(),
+            offset,
+        });
+
+        keep_receiving
+    }
+
+    fn receive_into_stash(&self, connection: &Arc<Connection<Service>>) {
+        if self.is_connection_blocked(connection) {
+            return;
+        }
+
+        while let Ok(Some(offset)) = connection.response_receiver.receive() {
+            if !self.stash_or_release_response(connection, offset) {
+                break;
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.server_connections.get(i) {
+            // keep all responses for still active requests, the server is gone but its
+            // responses are still valid
+            while let Ok(Some(offset)) = connection.response_receiver.receive() {
+                self.stash_or_release_response(connection, offset);
+            }
+
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered requests
+            unsafe {
+                connection
+                    .request_sender
+                    .acquire_used_offsets(|offset| self.sample_pool.release_sample(offset))
+            };
+        }
+
+        self.server_connections.remove(i);
+    }
+
+    fn populate_server_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.server_connections.capacity(), None);
+
+        unsafe {
+            (*self.server_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut ret_val = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match self.server_connections.get(i) {
+                        None => true,
+                        Some(connection) => connection.server_id != details.server_id,
+                    };
+
+                    if create_connection {
+                        self.remove_connection(i);
+
+                        if let Err(e) = self.server_connections.create(i, details) {
+                            warn!(from self, "Unable to establish connection to new server {:?}.", details.server_id);
+                            ret_val = Err(e);
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        ret_val
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.server_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .servers
+                .update_state(&mut *self.server_list_state.get())
+        } {
+            fail!(from self, when self.populate_server_channels(),
+                "Connections were updated only partially since at least one connection to a server failed.");
+        }
+
+        Ok(())
+    }
+
+    fn allocate(&self, layout: Layout) -> Result<(PointerOffset, usize), LoanError> {
+        let msg = "Unable to allocate request with";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_requests {
+            fail!(from self, with LoanError::ExceedsMaxLoans,
+                "{} {:?} since already {} requests were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned request to loan another request.",
+                msg, layout, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_requests);
+        }
+
+        self.retrieve_returned_requests();
+
+        match self.sample_pool.allocate() {
+            Ok(chunk) => {
+                self.loan_counter.fetch_add(1, Ordering::Relaxed);
+                Ok((chunk.offset, chunk.data_ptr as usize))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} {:?} since the underlying shared memory is out of memory.", msg, layout);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} {:?} since an internal failure occurred ({:?}).", msg, layout, v);
+            }
+        }
+    }
+
+    pub(crate) fn return_loaned_request(&self, offset: PointerOffset) {
+        self.sample_pool.release_sample(offset);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn send_request(
+        &self,
+        offset: PointerOffset,
+        request_id: u64,
+    ) -> Result<usize, RequestSendError> {
+        let msg = "Unable to send request";
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self, with RequestSendError::SendError(SendError::ConnectionBrokenSinceSenderNoLongerExists),
+                "{} since the corresponding client is already disconnected.", msg);
+        }
+
+        let number_of_active_requests = unsafe { &*self.active_requests.get() }.len();
+        if self.static_config().max_active_requests_per_client <= number_of_active_requests {
+            fail!(from self, with RequestSendError::ExceedsMaxActiveRequests,
+                "{} since it would exceed the maximum of {} active requests.",
+                msg, self.static_config().max_active_requests_per_client);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_requests();
+
+        let mut number_of_recipients = 0;
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                match connection
+                    .request_sender
+                    .try_send(offset, self.sample_pool.sample_size())
+                {
+                    Ok(overflow) => {
+                        self.sample_pool.borrow_sample(offset);
+                        number_of_recipients += 1;
+
+                        if let Some(old) = overflow {
+                            self.sample_pool.release_sample(old);
+                        }
+                    }
+                    Err(ZeroCopySendError::ReceiveBufferFull)
+                    | Err(ZeroCopySendError::UsedChunkListFull) => {
+                        debug!(from self, "The request {} could not be delivered to server {:?} since its buffer is full.",
+                            request_id, connection.server_id);
+                    }
+                    Err(ZeroCopySendError::ConnectionCorrupted) => {
+                        error!(from self, "While delivering the request {} a corrupted connection was detected with server {:?}.",
+                            request_id, connection.server_id);
+                    }
+                }
+            }
+        }
+
+        // a request that reached no server will never receive a response
+        if number_of_recipients > 0 {
+            unsafe { &mut *self.active_requests.get() }.push(request_id);
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    pub(crate) fn response_header(
+        &self,
+        connection: &Connection<Service>,
+        offset: PointerOffset,
+    ) -> &ResponseHeader {
+        let header_ptr = (offset.value() + connection.data_segment.payload_start_address())
+            as *const ResponseHeader;
+        unsafe { &*header_ptr }
+    }
+
+    pub(crate) fn receive_response<ResponsePayload: Debug>(
+        self: &Arc<Self>,
+        request_id: u64,
+    ) -> Result<Option<Response<Service, ResponsePayload>>, ReceiveError> {
+        let msg = "Unable to receive response";
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        if self
+            .static_config()
+            .max_borrowed_responses_per_pending_response
+            <= self.number_of_borrowed_responses(request_id)
+        {
+            fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+                "{} since it would exceed the maximum {} of borrowed responses of the pending response.",
+                msg, self.static_config().max_borrowed_responses_per_pending_response);
+        }
+
+        let stashed_response = {
+            let stashed_responses = unsafe { &mut *self.stashed_responses.get() };
+            stashed_responses
+                .iter()
+                .position(|r| r.request_id == request_id)
+                .map(|pos| stashed_responses.remove(pos))
+        };
+
+        if let Some(stashed) = stashed_response {
+            return Ok(Some(self.create_response(
+                request_id,
+                stashed.connection,
+                stashed.offset,
+            )));
+        }
+
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                if self.is_connection_blocked(connection) {
+                    continue;
+                }
+
+                loop {
+                    match connection.response_receiver.receive() {
+                        Ok(None) => break,
+                        Ok(Some(offset)) => {
+                            if self.response_header(connection, offset).request_id() == request_id {
+                                return Ok(Some(self.create_response(
+                                    request_id,
+                                    connection.clone(),
+                                    offset,
+                                )));
+                            }
+
+                            if !self.stash_or_release_response(connection, offset) {
+                                break;
+                            }
+                        }
+                        Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                            fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+                                "{} since it would exceed the maximum {} of borrowed responses.",
+                                msg, connection.response_receiver.max_borrowed_samples());
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn number_of_borrowed_responses(&self, request_id: u64) -> usize {
+        unsafe { &*self.borrowed_responses.get() }
+            .iter()
+            .filter(|id| **id == request_id)
+            .count()
+    }
+
+    pub(crate) fn release_borrowed_response(
+        &self,
+        request_id: u64,
+        connection: &Connection<Service>,
+        offset: PointerOffset,
+    ) {
+        let borrowed_responses = unsafe { &mut *self.borrowed_responses.get() };
+        if let Some(pos) = borrowed_responses.iter().position(|id| *id == request_id) {
+            borrowed_responses.remove(pos);
+        }
+
+        release_response(connection, offset);
+    }
+
+    fn create_response<ResponsePayload: Debug>(
+        self: &Arc<Self>,
+        request_id: u64,
+        connection: Arc<Connection<Service>>,
+        offset: PointerOffset,
+    ) -> Response<Service, ResponsePayload> {
+        let header_ptr = (offset.value() + connection.data_segment.payload_start_address())
+            as *const ResponseHeader;
+        let type_details = self.static_config().response_message_type_details();
+        let user_header_ptr = type_details
+            .user_header_ptr_from_header(header_ptr.cast())
+            .cast();
+        let payload_ptr = type_details
+            .payload_ptr_from_header(header_ptr.cast())
+            .cast();
+
+        unsafe { &mut *self.borrowed_responses.get() }.push(request_id);
+
+        Response {
+            ptr: unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
+            client_shared_state: Arc::clone(self),
+            request_id,
+            connection,
+            offset,
+        }
+    }
+
+    pub(crate) fn has_response(&self, request_id: u64) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Some responses are not being received since not all connections to servers could be established.");
+
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                self.receive_into_stash(connection);
+            }
+        }
+
+        let stashed_responses = unsafe { &*self.stashed_responses.get() };
+        Ok(stashed_responses.iter().any(|r| r.request_id == request_id))
+    }
+
+    pub(crate) fn complete_request(&self, request_id: u64) {
+        let active_requests = unsafe { &mut *self.active_requests.get() };
+        active_requests.retain(|id| *id != request_id);
+
+        let stashed_responses = unsafe { &mut *self.stashed_responses.get() };
+        stashed_responses.retain(|r| {
+            if r.request_id == request_id {
+                release_response(&r.connection, r.offset);
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+pub(crate) fn release_response<Service: service::Service>(
+    connection: &Connection<Service>,
+    offset: PointerOffset,
+) {
+    match connection.response_receiver.release(offset) {
+        Ok(()) => (),
+        Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+            fatal_panic!(from connection, "This should never happen! The servers retrieve channel is full and the response cannot be returned.");
+        }
+    }
+}
+
+/// Sending endpoint of a request-response based communication. Sends requests to all
+/// connected [`Server`](crate::port::server::Server)s and receives their responses via the
+/// [`PendingResponse`] that is returned for every request.
+#[derive(Debug)]
+pub struct Client<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> {
+    pub(crate) shared_state: Arc<ClientSharedState<Service>>,
+    dynamic_client_handle: Option<ContainerHandle>,
+    _request_payload: PhantomData<RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for Client<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.shared_state.is_active.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.dynamic_client_handle {
+            self.shared_state
+                .server_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .release_client_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    Client<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalClientConfig,
+    ) -> Result<Self, ClientCreateError> {
+        let msg = "Unable to create Client port";
+        let origin = "Client::new()";
+        let client_id = UniqueClientId::new();
+        let server_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .servers;
+
+        let number_of_requests =
+            static_config.required_amount_of_requests_per_data_segment(config.max_loaned_requests);
+
+        let sample_pool = fail!(from origin,
+                when SamplePool::create(&client_data_segment_name(&client_id),
+                                        service.__internal_state().shared_node.config(),
+                                        &service.__internal_state().port_access_policy,
+                                        static_config.request_message_type_details().sample_layout(1),
+                                        number_of_requests),
+                with ClientCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let shared_state = Arc::new(ClientSharedState {
+            sample_pool,
+            server_connections: ServerConnections::new(
+                server_list.capacity(),
+                client_id,
+                service.__internal_state().clone(),
+                static_config,
+                number_of_requests,
+            ),
+            server_list_state: unsafe { UnsafeCell::new(server_list.get_state()) },
+            config,
+            loan_counter: IoxAtomicUsize::new(0),
+            request_id_counter: IoxAtomicU64::new(0),
+            active_requests: UnsafeCell::new(Vec::with_capacity(
+                static_config.max_active_requests_per_client,
+            )),
+            stashed_responses: UnsafeCell::new(Vec::new()),
+            borrowed_responses: UnsafeCell::new(Vec::new()),
+            is_active: IoxAtomicBool::new(true),
+        });
+
+        let mut new_self = Self {
+            shared_state,
+            dynamic_client_handle: None,
+            _request_payload: PhantomData,
+            _response_payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.shared_state.populate_server_channels() {
+            warn!(from new_self, "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a client is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_client_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_client_id(ClientDetails {
+                client_id,
+                node_id: *service.__internal_state().shared_node.id(),
+                number_of_samples: number_of_requests,
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ClientCreateError::ExceedsMaxSupportedClients,
+                    "{} since it would exceed the maximum supported amount of clients of {}.",
+                    msg, static_config.max_clients);
+            }
+        };
+
+        new_self.dynamic_client_handle = Some(dynamic_client_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`]
+    pub fn id(&self) -> UniqueClientId {
+        self.shared_state.server_connections.client_id()
+    }
+
+    /// Loans/allocates a [`RequestMut`] from the underlying data segment of the [`Client`].
+    /// The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(42);
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<RequestMut<Service, MaybeUninit<RequestPayload>, ResponsePayload>, LoanError> {
+        let type_details = self
+            .shared_state
+            .static_config()
+            .request_message_type_details();
+        let (offset, header_address) = self.shared_state.allocate(type_details.sample_layout(1))?;
+        let request_id = self
+            .shared_state
+            .request_id_counter
+            .fetch_add(1, Ordering::Relaxed);
+
+        let header_ptr = header_address as *mut RequestHeader;
+        unsafe { header_ptr.write(RequestHeader::new(self.id(), request_id)) };
+
+        let user_header_ptr = type_details
+            .user_header_ptr_from_header(header_ptr.cast())
+            .cast_mut()
+            .cast();
+        let payload_ptr = type_details
+            .payload_ptr_from_header(header_ptr.cast())
+            .cast_mut()
+            .cast();
+
+        Ok(RequestMut::new(
+            &self.shared_state,
+            unsafe { RawSampleMut::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
+            offset,
+        ))
+    }
+
+    /// Copies the input `value` into a [`RequestMut`] and sends it to all connected
+    /// [`Server`](crate::port::server::Server)s. Returns the [`PendingResponse`] that can be
+    /// used to receive the responses of the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let pending_response = client.send_copy(1234)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(
+        &self,
+        value: RequestPayload,
+    ) -> Result<PendingResponse<Service, RequestPayload, ResponsePayload>, RequestSendError> {
+        let request = self.loan_uninit()?;
+        request.write_payload(value).send()
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug + Default, ResponsePayload: Debug>
+    Client<Service, RequestPayload, ResponsePayload>
+{
+    /// Loans/allocates a [`RequestMut`] from the underlying data segment of the [`Client`]
+    /// and initializes it with the default value. This can be a performance hit and
+    /// [`Client::loan_uninit()`] can be used to loan a [`core::mem::MaybeUninit<RequestPayload>`].
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let mut request = client.loan()?;
+    /// *request.payload_mut() = 42;
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan(&self) -> Result<RequestMut<Service, RequestPayload, ResponsePayload>, LoanError> {
+        Ok(self.loan_uninit()?.write_payload(RequestPayload::default()))
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> UpdateConnections
+    for Client<Service, RequestPayload, ResponsePayload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.shared_state.update_connections()
+    }
+}
+
+pub(crate) unsafe fn remove_data_segment_of_client<Service: service::Service>(
+    port_id: &UniqueClientId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_data_segment_of_client::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &client_data_segment_name(port_id),
+            &data_segment_config::<Service>(config),
+        ), "Unable to remove the clients data segment."
+    );
+
+    Ok(())
+}
+
+fn connections<Service: service::Service>(
+    origin: &str,
+    msg: &str,
+    config: &<Service::Connection as NamedConceptMgmt>::Configuration,
+) -> Result<Vec<FileName>, RemoveRequestResponsePortFromAllConnectionsError> {
+    match <Service::Connection as NamedConceptMgmt>::list_cfg(config) {
+        Ok(list) => Ok(list),
+        Err(NamedConceptListError::InsufficientPermissions) => {
+            fail!(from origin, with RemoveRequestResponsePortFromAllConnectionsError::InsufficientPermissions,
+                    "{} due to insufficient permissions to list all connections.", msg);
+        }
+        Err(NamedConceptListError::InternalError) => {
+            fail!(from origin, with RemoveRequestResponsePortFromAllConnectionsError::InternalError,
+                "{} due to an internal error while listing all connections.", msg);
+        }
+    }
+}
+
+/// Removes all request and response connections of a [`Client`] or a
+/// [`Server`](crate::port::server::Server). The port is identified by the value of its
+/// [`UniqueClientId`] or [`UniqueServerId`](crate::port::port_identifiers::UniqueServerId).
+pub(crate) unsafe fn remove_request_response_port_from_all_connections<
+    Service: service::Service,
+>(
+    port_id_value: u128,
+    config: &config::Config,
+) -> Result<(), RemoveRequestResponsePortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_request_response_port_from_all_connections::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id_value
+    );
+    let msg = "Unable to remove the request-response port from all connections";
+
+    let connection_config = connection_config::<Service>(config);
+    let connection_list = connections::<Service>(&origin, msg, &connection_config)?;
+
+    let mut ret_val = Ok(());
+    for connection in connection_list {
+        if is_port_part_of_connection(&connection, port_id_value) {
+            match <Service::Connection as NamedConceptMgmt>::remove_cfg(
+                &connection,
+                &connection_config,
+            ) {
+                Ok(_) => (),
+                Err(NamedConceptRemoveError::InsufficientPermissions) => {
+                    debug!(from origin, "{} due to insufficient permissions to remove the connection ({:?}).", msg, connection);
+                    ret_val = Err(
+                        RemoveRequestResponsePortFromAllConnectionsError::InsufficientPermissions,
+                    );
+                }
+                Err(NamedConceptRemoveError::InternalError) => {
+                    debug!(from origin, "{} due to an internal error while removing the connection ({:?}).", msg, connection);
+                    ret_val = Err(RemoveRequestResponsePortFromAllConnectionsError::InternalError);
+                }
+            }
+        }
+    }
+
+    ret_val
+}
@@ -10,5 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub(crate) mod client_connections;
+pub(crate) mod port_statistics;
 pub(crate) mod publisher_connections;
+pub(crate) mod sample_pool;
+pub(crate) mod server_connections;
 pub(crate) mod subscriber_connections;
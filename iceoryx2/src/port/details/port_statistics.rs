@@ -0,0 +1,98 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fmt::Debug;
+use std::time::Duration;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::access_policy::AccessPolicy;
+use iceoryx2_cal::dynamic_storage::{
+    DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError, DynamicStorageOpenError,
+};
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt, NamedConceptRemoveError,
+};
+
+use crate::config;
+use crate::service;
+use crate::service::config_scheme::port_statistics_config;
+use crate::service::naming_scheme::port_statistics_name;
+
+/// The counters a port updates while it is in use. They are stored in a segment that is created
+/// by the port and removed when it goes out of scope. Every other process maps the segment
+/// read-only, therefore only the process of the port can modify them.
+#[derive(Debug)]
+pub(crate) struct PortStatistics<Service: service::Service, T: Send + Sync + Debug + 'static> {
+    storage: Service::PortStatisticsStorage<T>,
+}
+
+impl<Service: service::Service, T: Send + Sync + Debug + 'static> PortStatistics<Service, T> {
+    pub(crate) fn create(
+        port_id: &UniqueSystemId,
+        global_config: &config::Config,
+        access_policy: &AccessPolicy,
+        initial_value: T,
+    ) -> Result<Self, DynamicStorageCreateError> {
+        let storage = fail!(from "PortStatistics::create()",
+            when <Service::PortStatisticsStorage<T> as DynamicStorage<T>>::Builder::new(&port_statistics_name(port_id))
+                .config(&port_statistics_config::<Service, T>(global_config).access_policy(access_policy))
+                .has_ownership(true)
+                .create(initial_value),
+            "Unable to create the port statistics.");
+
+        Ok(Self { storage })
+    }
+
+    /// Opens the statistics of another port. The returned [`PortStatistics`] must only be
+    /// read since the underlying memory is mapped read-only.
+    pub(crate) fn open_read_only(
+        port_id: &UniqueSystemId,
+        global_config: &config::Config,
+        timeout: Duration,
+    ) -> Result<Self, DynamicStorageOpenError> {
+        let storage = fail!(from "PortStatistics::open_read_only()",
+            when unsafe {
+                <Service::PortStatisticsStorage<T> as DynamicStorage<T>>::Builder::new(&port_statistics_name(port_id))
+                    .config(&port_statistics_config::<Service, T>(global_config))
+                    .timeout(timeout)
+                    .open_read_only()
+            },
+            "Unable to open the port statistics.");
+
+        Ok(Self { storage })
+    }
+
+    pub(crate) fn get(&self) -> &T {
+        self.storage.get()
+    }
+
+    /// Removes the statistics of a port that is no longer available, for instance since its
+    /// process died.
+    ///
+    /// # Safety
+    ///
+    ///  * The port must not be used anymore.
+    ///
+    pub(crate) unsafe fn remove(
+        port_id: &UniqueSystemId,
+        global_config: &config::Config,
+    ) -> Result<(), NamedConceptRemoveError> {
+        fail!(from "PortStatistics::remove()",
+            when <Service::PortStatisticsStorage<T> as NamedConceptMgmt>::remove_cfg(
+                &port_statistics_name(port_id),
+                &port_statistics_config::<Service, T>(global_config)),
+            "Unable to remove the port statistics.");
+
+        Ok(())
+    }
+}
@@ -10,14 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::{cell::UnsafeCell, sync::Arc};
+use std::{cell::UnsafeCell, sync::Arc, time::Duration};
 
 use crate::{
+    config,
+    port::details::port_statistics::PortStatistics,
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service::{
         self,
-        config_scheme::{connection_config, data_segment_config},
-        dynamic_config::publish_subscribe::PublisherDetails,
+        config_scheme::{connection_config, data_segment_config, node_monitoring_config},
+        dynamic_config::publish_subscribe::{
+            PublisherDetails, PublisherStatistics, PublisherStatisticsEntry,
+        },
         naming_scheme::{connection_name, data_segment_name},
         static_config::publish_subscribe::StaticConfig,
         ServiceState,
@@ -25,18 +29,39 @@ use crate::{
 };
 
 use crate::port::update_connections::ConnectionFailure;
-use iceoryx2_bb_log::fail;
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
+use iceoryx2_cal::monitoring::{Monitoring, MonitoringBuilder, MonitoringMonitor, State};
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+};
 use iceoryx2_cal::{
     shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder,
-    shm_allocator::pool_allocator::PoolAllocator, zero_copy_connection::*,
+    shared_memory::SharedMemoryOpenError, shm_allocator::pool_allocator::PoolAllocator,
+    shm_allocator::SegmentId, zero_copy_connection::*,
 };
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
+use std::sync::atomic::Ordering;
 
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
-    pub(crate) data_segment: Service::SharedMemory,
+    // data segments that are not yet mapped are mapped on first use
+    data_segments: UnsafeCell<Vec<Option<Service::SharedMemory>>>,
+    data_segment_config: <Service::SharedMemory as NamedConceptMgmt>::Configuration,
+    // the statistics of the publisher are mapped read-only on first use
+    statistics: UnsafeCell<Option<PortStatistics<Service, PublisherStatisticsEntry>>>,
+    creation_timeout: Duration,
     pub(crate) publisher_id: UniquePublisherId,
+    // sequence number of the next expected sample, 0 when no sample was received so far
+    pub(crate) next_sequence_number: IoxAtomicU64,
+    // quality of service violations are reported only once until the publisher recovers
+    deadline_missed: IoxAtomicBool,
+    liveliness_lost: IoxAtomicBool,
+    // monitors the node of a publisher with a liveliness lease to detect a dead process
+    // without waiting for the lease to expire
+    node_monitor: Option<<Service::Monitoring as Monitoring>::Monitor>,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -52,29 +77,163 @@ impl<Service: service::Service> Connection<Service> {
         let receiver = fail!(from this,
                         when <Service::Connection as ZeroCopyConnection>::
                             Builder::new( &connection_name(details.publisher_id, this.subscriber_id))
-                                    .config(&connection_config::<Service>(this.service_state.shared_node.config()))
+                                    .config(&connection_config::<Service>(this.service_state.shared_node.config()).access_policy(&this.service_state.port_access_policy))
                                     .buffer_size(this.buffer_size)
                                     .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                     .enable_safe_overflow(this.static_config.enable_safe_overflow)
                                     .number_of_samples(details.number_of_samples)
+                                    .max_supported_shared_memory_segments(details.max_number_of_segments)
                                     .timeout(this.service_state.shared_node.config().global.service.creation_timeout)
                                     .create_receiver(this.static_config.message_type_details().sample_layout(details.max_slice_len).size()),
                         "{} since the zero copy connection could not be established.", msg);
 
-        let data_segment = fail!(from this,
-                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
-                                Builder::new(&data_segment_name(&details.publisher_id))
-                                .config(&data_segment_config::<Service>(this.service_state.shared_node.config()))
-                                .timeout(this.service_state.shared_node.config().global.service.creation_timeout)
-                                .open(),
-                            "{} since the publishers data segment could not be opened.", msg);
+        let data_segment_config =
+            data_segment_config::<Service>(this.service_state.shared_node.config());
+        let creation_timeout = this
+            .service_state
+            .shared_node
+            .config()
+            .global
+            .service
+            .creation_timeout;
+
+        // a publisher with multiple segments may have already released its first segment,
+        // therefore only the data segment of a publisher with a single segment is mapped
+        // right away
+        let mut data_segments = vec![];
+        if details.max_number_of_segments == 1 {
+            data_segments.push(Some(fail!(from this,
+                            when Self::open_data_segment(&data_segment_config, creation_timeout, &details.publisher_id, SegmentId::new(0)),
+                            "{} since the publishers data segment could not be opened.", msg)));
+        }
+
+        let node_monitor = match details.liveliness_lease_duration {
+            Some(_) => Self::open_node_monitor(this, details),
+            None => None,
+        };
 
         Ok(Self {
             receiver,
-            data_segment,
+            data_segments: UnsafeCell::new(data_segments),
+            data_segment_config,
+            statistics: UnsafeCell::new(None),
+            creation_timeout,
             publisher_id: details.publisher_id,
+            next_sequence_number: IoxAtomicU64::new(0),
+            deadline_missed: IoxAtomicBool::new(false),
+            liveliness_lost: IoxAtomicBool::new(false),
+            node_monitor,
         })
     }
+
+    // the monitoring concept cannot detect the state of its own process, therefore
+    // publishers of the same process are not monitored
+    fn open_node_monitor(
+        this: &PublisherConnections<Service>,
+        details: &PublisherDetails,
+    ) -> Option<<Service::Monitoring as Monitoring>::Monitor> {
+        if details.node_id.pid() == Process::from_self().id() {
+            return None;
+        }
+
+        let config = node_monitoring_config::<Service>(this.service_state.shared_node.config());
+        match <Service::Monitoring as Monitoring>::Builder::new(&details.node_id.as_file_name())
+            .config(&config)
+            .monitor()
+        {
+            Ok(monitor) => Some(monitor),
+            Err(e) => {
+                debug!(from this,
+                    "Unable to monitor the node of publisher {:?} ({:?}). Its liveliness is tracked by its lease duration only.",
+                    details.publisher_id, e);
+                None
+            }
+        }
+    }
+
+    /// Returns true when the node of the publisher is known to be dead or does no longer
+    /// exist.
+    pub(crate) fn is_publisher_node_dead(&self) -> bool {
+        match &self.node_monitor {
+            Some(monitor) => matches!(monitor.state(), Ok(State::Dead) | Ok(State::DoesNotExist)),
+            None => false,
+        }
+    }
+
+    /// Stores whether the publisher missed its deadline and returns true when the deadline
+    /// was missed for the first time since it was last met.
+    pub(crate) fn update_deadline_missed(&self, is_missed: bool) -> bool {
+        !self.deadline_missed.swap(is_missed, Ordering::Relaxed) && is_missed
+    }
+
+    /// Stores whether the publisher lost its liveliness and returns true when the liveliness
+    /// was lost for the first time since it was last asserted.
+    pub(crate) fn update_liveliness_lost(&self, is_lost: bool) -> bool {
+        !self.liveliness_lost.swap(is_lost, Ordering::Relaxed) && is_lost
+    }
+
+    // the subscriber only reads samples, the data segment is mapped read-only so that a
+    // misbehaving subscriber cannot corrupt the data of the publisher
+    fn open_data_segment(
+        config: &<Service::SharedMemory as NamedConceptMgmt>::Configuration,
+        timeout: Duration,
+        publisher_id: &UniquePublisherId,
+        segment_id: SegmentId,
+    ) -> Result<Service::SharedMemory, SharedMemoryOpenError> {
+        <Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder::new(&data_segment_name(
+            publisher_id,
+            segment_id,
+        ))
+        .config(config)
+        .timeout(timeout)
+        .open_read_only()
+    }
+
+    /// Returns the current [`PublisherStatistics`] of the publisher and maps them when they are
+    /// not yet mapped.
+    pub(crate) fn publisher_statistics(
+        &self,
+        global_config: &config::Config,
+    ) -> Result<PublisherStatistics, DynamicStorageOpenError> {
+        let statistics = unsafe { &mut *self.statistics.get() };
+        match statistics {
+            Some(statistics) => Ok(statistics.get().load()),
+            None => {
+                let publisher_statistics: PortStatistics<Service, PublisherStatisticsEntry> = fail!(from self,
+                    when PortStatistics::open_read_only(&self.publisher_id.0, global_config, self.creation_timeout),
+                    "Unable to map the statistics of publisher {:?}.", self.publisher_id);
+                let value = publisher_statistics.get().load();
+                *statistics = Some(publisher_statistics);
+                Ok(value)
+            }
+        }
+    }
+
+    /// Returns the payload start address of the publishers data segment with the provided
+    /// [`SegmentId`] and maps the data segment when it is not yet mapped.
+    pub(crate) fn data_segment_start_address(
+        &self,
+        segment_id: SegmentId,
+    ) -> Result<usize, SharedMemoryOpenError> {
+        let data_segments = unsafe { &mut *self.data_segments.get() };
+        let index = segment_id.value() as usize;
+        if data_segments.len() <= index {
+            data_segments.resize_with(index + 1, || None);
+        }
+
+        match &data_segments[index] {
+            Some(data_segment) => Ok(data_segment.payload_start_address()),
+            None => {
+                let data_segment = fail!(from self,
+                    when Self::open_data_segment(&self.data_segment_config, self.creation_timeout, &self.publisher_id, segment_id),
+                    "Unable to map the data segment {} of publisher {:?}.",
+                    segment_id.value(), self.publisher_id);
+                let payload_start_address = data_segment.payload_start_address();
+                data_segments[index] = Some(data_segment);
+                Ok(payload_start_address)
+            }
+        }
+    }
 }
 #[derive(Debug)]
 pub(crate) struct PublisherConnections<Service: service::Service> {
@@ -0,0 +1,118 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::alloc::Layout;
+use std::sync::atomic::Ordering;
+
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::access_policy::AccessPolicy;
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptConfiguration};
+use iceoryx2_cal::shared_memory::{
+    SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
+};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use crate::config;
+use crate::service;
+use crate::service::config_scheme::data_segment_config;
+
+/// Reference counted pool of equally sized samples that is stored in the data segment of a port.
+/// A sample is returned to the pool as soon as its last reference was released.
+#[derive(Debug)]
+pub(crate) struct SamplePool<Service: service::Service> {
+    memory: Service::SharedMemory,
+    sample_reference_counter: Vec<IoxAtomicU64>,
+    sample_layout: Layout,
+}
+
+impl<Service: service::Service> SamplePool<Service> {
+    pub(crate) fn create(
+        name: &FileName,
+        global_config: &config::Config,
+        access_policy: &AccessPolicy,
+        sample_layout: Layout,
+        number_of_samples: usize,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: sample_layout,
+        };
+
+        let memory = fail!(from "SamplePool::create()",
+            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(name)
+                .config(&data_segment_config::<Service>(global_config).access_policy(access_policy))
+                .size(sample_layout.size() * number_of_samples + sample_layout.align() - 1)
+                .create(&allocator_config),
+            "Unable to create the data segment.");
+
+        Ok(Self {
+            memory,
+            sample_reference_counter: (0..number_of_samples)
+                .map(|_| IoxAtomicU64::new(0))
+                .collect(),
+            sample_layout,
+        })
+    }
+
+    pub(crate) fn sample_size(&self) -> usize {
+        self.sample_layout.size()
+    }
+
+    pub(crate) fn payload_start_address(&self) -> usize {
+        self.memory.payload_start_address()
+    }
+
+    /// Returns true when no sample of the pool is loaned, in flight or borrowed.
+    pub(crate) fn is_unused(&self) -> bool {
+        self.sample_reference_counter
+            .iter()
+            .all(|counter| counter.load(Ordering::Relaxed) == 0)
+    }
+
+    fn sample_index(&self, distance_to_chunk: usize) -> usize {
+        distance_to_chunk / self.sample_layout.size()
+    }
+
+    pub(crate) fn allocate(&self) -> Result<ShmPointer, ShmAllocationError> {
+        let ptr = self.memory.allocate(self.sample_layout)?;
+        if self.sample_reference_counter[self.sample_index(ptr.offset.value())]
+            .fetch_add(1, Ordering::Relaxed)
+            != 0
+        {
+            fatal_panic!(from self,
+                "Unable to allocate sample since the allocated sample is already in use! This should never happen!");
+        }
+
+        Ok(ptr)
+    }
+
+    pub(crate) fn borrow_sample(&self, distance_to_chunk: PointerOffset) {
+        self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn release_sample(&self, distance_to_chunk: PointerOffset) {
+        if self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                self.memory
+                    .deallocate(distance_to_chunk, self.sample_layout);
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{cell::UnsafeCell, sync::Arc};
+
+use crate::{
+    port::port_identifiers::{UniqueClientId, UniqueServerId},
+    service::{
+        self,
+        config_scheme::{connection_config, data_segment_config},
+        dynamic_config::request_response::ServerDetails,
+        naming_scheme::{
+            request_connection_name, response_connection_name, server_data_segment_name,
+        },
+        static_config::request_response::StaticConfig,
+        ServiceState,
+    },
+};
+
+use crate::port::update_connections::ConnectionFailure;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptConfiguration};
+use iceoryx2_cal::{
+    shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder,
+    shm_allocator::pool_allocator::PoolAllocator, zero_copy_connection::*,
+};
+
+/// The connection of a [`Client`](crate::port::client::Client) to a single
+/// [`Server`](crate::port::server::Server).
+#[derive(Debug)]
+pub(crate) struct Connection<Service: service::Service> {
+    pub(crate) request_sender: <Service::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) response_receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) server_id: UniqueServerId,
+}
+
+impl<Service: service::Service> Connection<Service> {
+    fn new(
+        this: &ServerConnections<Service>,
+        details: &ServerDetails,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to server {:?} from client {:?}.",
+            details.server_id, this.client_id
+        );
+        let global_config = this.service_state.shared_node.config();
+
+        let request_sender = fail!(from this,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new( &request_connection_name(this.client_id, details.server_id))
+                                    .config(&connection_config::<Service>(global_config).access_policy(&this.service_state.port_access_policy))
+                                    .buffer_size(this.static_config.request_buffer_size())
+                                    .receiver_max_borrowed_samples(this.static_config.max_borrowed_requests())
+                                    .enable_safe_overflow(this.static_config.enable_safe_overflow_for_requests)
+                                    .number_of_samples(this.number_of_requests)
+                                    .timeout(global_config.global.service.creation_timeout)
+                                    .create_sender(this.static_config.request_message_type_details().sample_layout(1).size()),
+                        "{} since the request connection could not be established.", msg);
+
+        let response_receiver = fail!(from this,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new( &response_connection_name(details.server_id, this.client_id))
+                                    .config(&connection_config::<Service>(global_config).access_policy(&this.service_state.port_access_policy))
+                                    .buffer_size(this.static_config.response_buffer_size())
+                                    .receiver_max_borrowed_samples(this.static_config.max_borrowed_responses())
+                                    .enable_safe_overflow(this.static_config.enable_safe_overflow_for_responses)
+                                    .number_of_samples(details.number_of_samples)
+                                    .timeout(global_config.global.service.creation_timeout)
+                                    .create_receiver(this.static_config.response_message_type_details().sample_layout(1).size()),
+                        "{} since the response connection could not be established.", msg);
+
+        let data_segment = fail!(from this,
+                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                                Builder::new(&server_data_segment_name(&details.server_id))
+                                .config(&data_segment_config::<Service>(global_config))
+                                .timeout(global_config.global.service.creation_timeout)
+                                .open_read_only(),
+                            "{} since the servers data segment could not be opened.", msg);
+
+        Ok(Self {
+            request_sender,
+            response_receiver,
+            data_segment,
+            server_id: details.server_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ServerConnections<Service: service::Service> {
+    connections: Vec<UnsafeCell<Option<Arc<Connection<Service>>>>>,
+    client_id: UniqueClientId,
+    pub(crate) service_state: Arc<ServiceState<Service>>,
+    pub(crate) static_config: StaticConfig,
+    number_of_requests: usize,
+}
+
+impl<Service: service::Service> ServerConnections<Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        client_id: UniqueClientId,
+        service_state: Arc<ServiceState<Service>>,
+        static_config: &StaticConfig,
+        number_of_requests: usize,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            client_id,
+            service_state,
+            static_config: static_config.clone(),
+            number_of_requests,
+        }
+    }
+
+    pub(crate) fn client_id(&self) -> UniqueClientId {
+        self.client_id
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Arc<Connection<Service>>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<Arc<Connection<Service>>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        details: &ServerDetails,
+    ) -> Result<(), ConnectionFailure> {
+        *self.get_mut(index) = Some(Arc::new(Connection::new(self, details)?));
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, index: usize) {
+        *self.get_mut(index) = None;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
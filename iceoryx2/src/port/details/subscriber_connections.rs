@@ -14,7 +14,8 @@ use std::cell::UnsafeCell;
 use std::sync::Arc;
 
 use iceoryx2_bb_log::fail;
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::access_policy::AccessPolicy;
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptConfiguration};
 use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
 };
@@ -39,6 +40,7 @@ impl<Service: service::Service> Connection<Service> {
         this: &SubscriberConnections<Service>,
         subscriber_details: SubscriberDetails,
         number_of_samples: usize,
+        max_number_of_segments: u8,
         max_slice_len: usize,
     ) -> Result<Self, ZeroCopyCreationError> {
         let msg = format!(
@@ -53,11 +55,12 @@ impl<Service: service::Service> Connection<Service> {
 
         let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
                         Builder::new( &connection_name(this.port_id, subscriber_details.subscriber_id))
-                                .config(&connection_config::<Service>(this.shared_node.config()))
+                                .config(&connection_config::<Service>(this.shared_node.config()).access_policy(&this.access_policy))
                                 .buffer_size(subscriber_details.buffer_size)
                                 .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                 .enable_safe_overflow(this.static_config.enable_safe_overflow)
                                 .number_of_samples(number_of_samples)
+                                .max_supported_shared_memory_segments(max_number_of_segments)
                                 .timeout(this.shared_node.config().global.service.creation_timeout)
                                 .create_sender(this.static_config.message_type_details().sample_layout(max_slice_len).size()),
                         "{}.", msg);
@@ -75,7 +78,9 @@ pub(crate) struct SubscriberConnections<Service: service::Service> {
     port_id: UniquePublisherId,
     shared_node: Arc<SharedNode<Service>>,
     pub(crate) static_config: StaticConfig,
+    access_policy: AccessPolicy,
     number_of_samples: usize,
+    max_number_of_segments: u8,
 }
 
 impl<Service: service::Service> SubscriberConnections<Service> {
@@ -84,14 +89,18 @@ impl<Service: service::Service> SubscriberConnections<Service> {
         shared_node: Arc<SharedNode<Service>>,
         port_id: UniquePublisherId,
         static_config: &StaticConfig,
+        access_policy: &AccessPolicy,
         number_of_samples: usize,
+        max_number_of_segments: u8,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             shared_node,
             port_id,
             static_config: static_config.clone(),
+            access_policy: access_policy.clone(),
             number_of_samples,
+            max_number_of_segments,
         }
     }
 
@@ -122,6 +131,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
             self,
             subscriber_details,
             self.number_of_samples,
+            self.max_number_of_segments,
             max_slice_len,
         )?);
 
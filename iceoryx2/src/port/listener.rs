@@ -60,9 +60,13 @@
 
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, NamedConceptMgmt, TriggerId};
-use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptConfiguration, NamedConceptRemoveError,
+};
 
 use crate::config::Config;
 use crate::service::config_scheme::event_config;
@@ -125,7 +129,8 @@ impl<Service: service::Service> Listener<Service> {
         let listener_id = UniqueListenerId::new();
 
         let event_name = event_concept_name(&listener_id);
-        let event_config = event_config::<Service>(service.__internal_state().shared_node.config());
+        let event_config = event_config::<Service>(service.__internal_state().shared_node.config())
+            .access_policy(&service.__internal_state().port_access_policy);
 
         let listener = fail!(from origin,
                              when <Service::Event as iceoryx2_cal::event::Event>::ListenerBuilder::new(&event_name).config(&event_config)
@@ -239,6 +244,20 @@ impl<Service: service::Service> Listener<Service> {
     }
 }
 
+impl<Service: service::Service> FileDescriptorBased for Listener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<Service: service::Service> SynchronousMultiplexing for Listener<Service> where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing
+{
+}
+
 pub(crate) unsafe fn remove_connection_of_listener<Service: service::Service>(
     listener_id: &UniqueListenerId,
     config: &Config,
@@ -16,6 +16,8 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// Sending endpoint (port) for request-response based communication
+pub mod client;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
 /// Receiving endpoint (port) for event based communication
@@ -26,15 +28,110 @@ pub mod notifier;
 pub mod port_identifiers;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Receiving endpoint (port) for blackboard based communication
+pub mod reader;
+/// Sending endpoint (port) for publish-subscribe based communication with serialized payloads
+pub mod serialized_publisher;
+/// Receiving endpoint (port) for publish-subscribe based communication with serialized payloads
+pub mod serialized_subscriber;
+/// Receiving endpoint (port) for request-response based communication
+pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
 /// Interface to perform cyclic updates to the ports. Required to deliver history to new
 /// participants or to perform other management tasks.
 pub mod update_connections;
+/// Sending endpoint (port) for blackboard based communication
+pub mod writer;
 
 use crate::port::port_identifiers::*;
+use crate::port::update_connections::ConnectionFailure;
 use crate::service;
 
+/// Defines a failure that can occur when a [`Client`](crate::port::client::Client) loans a
+/// [`RequestMut`](crate::request_mut::RequestMut) or an
+/// [`ActiveRequest`](crate::active_request::ActiveRequest) loans a
+/// [`ResponseMut`](crate::response_mut::ResponseMut).
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum LoanError {
+    /// The data segment of the port does not have any more memory left
+    OutOfMemory,
+    /// The maximum amount of parallel loans of the port is exceeded. Release or send a loaned
+    /// sample to loan another one.
+    ExceedsMaxLoans,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl std::fmt::Display for LoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "LoanError::{:?}", self)
+    }
+}
+
+impl std::error::Error for LoanError {}
+
+/// Failure that can be emitted when a request or a response is sent.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SendError {
+    /// The data shall be sent but the sending port went already out of scope.
+    ConnectionBrokenSinceSenderNoLongerExists,
+    /// The data shall be sent but the receiving port does no longer exist.
+    ConnectionBrokenSinceReceiverNoLongerExists,
+    /// A connection between the sending and the receiving port is corrupted.
+    ConnectionCorrupted,
+    /// The buffer of the receiving port is full and it does not safely overflow.
+    ReceiveBufferFull,
+    /// A failure occurred while acquiring memory for the payload
+    LoanError(LoanError),
+    /// A failure occurred while establishing a connection to the receiving port
+    ConnectionError(ConnectionFailure),
+}
+
+impl From<LoanError> for SendError {
+    fn from(value: LoanError) -> Self {
+        SendError::LoanError(value)
+    }
+}
+
+impl From<ConnectionFailure> for SendError {
+    fn from(value: ConnectionFailure) -> Self {
+        SendError::ConnectionError(value)
+    }
+}
+
+impl std::fmt::Display for SendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "SendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SendError {}
+
+/// Failure that can be emitted when a [`Server`](crate::port::server::Server) receives a request
+/// or a [`PendingResponse`](crate::pending_response::PendingResponse) receives a response.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ReceiveError {
+    /// The maximum amount of data a port can borrow in parallel is exceeded.
+    ExceedsMaxBorrows,
+    /// Failures when establishing a connection to a sending port.
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl From<ConnectionFailure> for ReceiveError {
+    fn from(value: ConnectionFailure) -> Self {
+        ReceiveError::ConnectionFailure(value)
+    }
+}
+
+impl std::fmt::Display for ReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ReceiveError {}
+
 /// Defines the action a port shall take when an internal failure occurs. Can happen when the
 /// system is corrupted and files are modified by non-iceoryx2 instances. Is used as return value of
 /// the [`DegrationCallback`] to define a custom behavior.
@@ -37,11 +37,12 @@
 
 use super::{event_id::EventId, port_identifiers::UniqueListenerId};
 use crate::{
-    port::port_identifiers::UniqueNotifierId,
+    config,
+    port::{details::port_statistics::PortStatistics, port_identifiers::UniqueNotifierId},
     service::{
         self,
         config_scheme::event_config,
-        dynamic_config::event::{ListenerDetails, NotifierDetails},
+        dynamic_config::event::{ListenerDetails, NotifierDetails, NotifierStatisticsEntry},
         naming_scheme::event_concept_name,
         ServiceState,
     },
@@ -50,7 +51,10 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, fail, warn};
 use iceoryx2_cal::{dynamic_storage::DynamicStorage, event::NotifierBuilder};
-use iceoryx2_cal::{event::Event, named_concept::NamedConceptBuilder};
+use iceoryx2_cal::{
+    event::Event,
+    named_concept::{NamedConceptBuilder, NamedConceptRemoveError},
+};
 use std::{
     cell::UnsafeCell,
     sync::{atomic::Ordering, Arc},
@@ -65,6 +69,8 @@ pub enum NotifierCreateError {
     /// defined in [`crate::config::Config`]. When this is exceeded no more [`Notifier`]s
     /// can be created for a specific [`Service`](crate::service::Service).
     ExceedsMaxSupportedNotifiers,
+    /// The statistics the [`Notifier`] updates while it is in use could not be created.
+    UnableToCreateStatistics,
 }
 
 impl std::fmt::Display for NotifierCreateError {
@@ -179,6 +185,7 @@ pub struct Notifier<Service: service::Service> {
     listener_list_state: UnsafeCell<ContainerState<ListenerDetails>>,
     default_event_id: EventId,
     event_id_max_value: usize,
+    statistics: PortStatistics<Service, NotifierStatisticsEntry>,
     dynamic_notifier_handle: Option<ContainerHandle>,
     notifier_id: UniqueNotifierId,
 }
@@ -212,6 +219,11 @@ impl<Service: service::Service> Notifier<Service> {
             .event()
             .listeners;
 
+        let statistics = fail!(from origin,
+                when PortStatistics::create(&notifier_id.0, service.__internal_state().shared_node.config(), &service.__internal_state().port_access_policy, NotifierStatisticsEntry::new()),
+                with NotifierCreateError::UnableToCreateStatistics,
+                "{} since the notifier statistics could not be created.", msg);
+
         let mut new_self = Self {
             listener_connections: ListenerConnections::new(
                 listener_list.capacity(),
@@ -224,6 +236,7 @@ impl<Service: service::Service> Notifier<Service> {
                 .static_config
                 .event()
                 .event_id_max_value,
+            statistics,
             dynamic_notifier_handle: None,
             notifier_id,
         };
@@ -339,6 +352,8 @@ impl<Service: service::Service> Notifier<Service> {
                             msg, value, self.event_id_max_value);
         }
 
+        self.statistics.get().increment_notifications();
+
         for i in 0..self.listener_connections.len() {
             match self.listener_connections.get(i) {
                 Some(ref connection) => match connection.notifier.notify(value) {
@@ -360,3 +375,10 @@ impl<Service: service::Service> Notifier<Service> {
         Ok(number_of_triggered_listeners)
     }
 }
+
+pub(crate) unsafe fn remove_statistics_of_notifier<Service: service::Service>(
+    port_id: &UniqueNotifierId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    PortStatistics::<Service, NotifierStatisticsEntry>::remove(&port_id.0, config)
+}
@@ -103,17 +103,26 @@
 
 use super::port_identifiers::UniquePublisherId;
 use super::UniqueSubscriberId;
+use crate::node::RetainedHistory;
+use crate::port::details::port_statistics::PortStatistics;
+use crate::port::details::sample_pool::SamplePool;
 use crate::port::details::subscriber_connections::*;
+use crate::port::notifier::Notifier;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::port::DegrationAction;
 use crate::raw_sample::RawSampleMut;
+use crate::service::builder::publish_subscribe::open_or_create_notify_on_send_service;
 use crate::service::config_scheme::{connection_config, data_segment_config};
-use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::dynamic_config::publish_subscribe::{
+    PublisherDetails, PublisherStatisticsEntry, SubscriberDetails,
+};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::{
     data_segment_name, extract_publisher_id_from_connection, extract_subscriber_id_from_connection,
 };
-use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
+use crate::service::port_factory::publisher::{
+    AllocationStrategy, LocalPublisherConfig, UnableToDeliverStrategy,
+};
 use crate::service::static_config::publish_subscribe::{self};
 use crate::service::{self, ServiceState};
 use crate::{config, sample_mut::SampleMut};
@@ -122,21 +131,20 @@ use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::access_policy::AccessPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::NamedConceptMgmt;
-use iceoryx2_cal::named_concept::{
-    NamedConceptBuilder, NamedConceptListError, NamedConceptRemoveError,
-};
-use iceoryx2_cal::shared_memory::{
-    SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
-};
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
-use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptRemoveError};
+use iceoryx2_cal::shared_memory::{SharedMemoryCreateError, ShmPointer};
+use iceoryx2_cal::shm_allocator::{PointerOffset, SegmentId, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{
+    IoxAtomicBool, IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize,
+};
 use std::cell::UnsafeCell;
 use std::fmt::Debug;
 use std::sync::atomic::Ordering;
@@ -154,6 +162,12 @@ pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     /// The datasegment in which the payload of the [`Publisher`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The [`Service`](crate::service::Service) has notify on send enabled but the
+    /// [`Notifier`] of the companion event [`Service`](crate::service::Service) could not be
+    /// created.
+    UnableToCreateNotifier,
+    /// The statistics the [`Publisher`] updates while it is in use could not be created.
+    UnableToCreateStatistics,
 }
 
 impl std::fmt::Display for PublisherCreateError {
@@ -233,12 +247,32 @@ pub(crate) enum RemovePubSubPortFromAllConnectionsError {
     InternalError,
 }
 
+/// The maximum number of data segments a [`Publisher`] with an [`AllocationStrategy`] other
+/// than [`AllocationStrategy::Static`] can create during its lifetime.
+const MAX_NUMBER_OF_DATA_SEGMENTS: u8 = 32;
+
+#[derive(Debug)]
+struct Segment<Service: service::Service> {
+    sample_pool: SamplePool<Service>,
+    max_slice_len: usize,
+}
+
+#[derive(Debug, Default)]
+struct RestoredHistory {
+    // number of the oldest history entries that were taken over from a previous publisher
+    len: usize,
+    // subscribers that already received these entries from the previous publisher
+    served_subscribers: Vec<UniqueSubscriberId>,
+}
+
 #[derive(Debug)]
 pub(crate) struct DataSegment<Service: service::Service> {
-    sample_reference_counter: Vec<IoxAtomicU64>,
-    memory: Service::SharedMemory,
-    payload_size: usize,
-    payload_type_layout: Layout,
+    // never exceeds its initial capacity, references to the segments stay valid while new
+    // segments are added
+    segments: UnsafeCell<Vec<Option<Segment<Service>>>>,
+    current_segment_id: IoxAtomicU8,
+    max_number_of_segments: u8,
+    number_of_samples: usize,
     port_id: UniquePublisherId,
     config: LocalPublisherConfig,
     service_state: Arc<ServiceState<Service>>,
@@ -246,63 +280,144 @@ pub(crate) struct DataSegment<Service: service::Service> {
     subscriber_connections: SubscriberConnections<Service>,
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<usize>>>,
+    restored_history: UnsafeCell<RestoredHistory>,
     static_config: crate::service::static_config::StaticConfig,
     loan_counter: IoxAtomicUsize,
     is_active: IoxAtomicBool,
+    sequence_number: IoxAtomicU64,
+    notifier: Option<Notifier<Service>>,
+    statistics: PortStatistics<Service, PublisherStatisticsEntry>,
+    dynamic_publisher_handle: UnsafeCell<Option<ContainerHandle>>,
 }
 
 impl<Service: service::Service> DataSegment<Service> {
-    fn sample_index(&self, distance_to_chunk: usize) -> usize {
-        distance_to_chunk / self.payload_size
+    fn segment(&self, segment_id: SegmentId) -> &Segment<Service> {
+        match unsafe { &*self.segments.get() }.get(segment_id.value() as usize) {
+            Some(Some(segment)) => segment,
+            _ => fatal_panic!(from self,
+                    "This should never happen! The data segment {} is no longer available.",
+                    segment_id.value()),
+        }
+    }
+
+    fn update_statistics<F: FnOnce(&PublisherStatisticsEntry)>(&self, update: F) {
+        update(self.statistics.get());
+    }
+
+    fn current_segment_id(&self) -> SegmentId {
+        SegmentId::new(self.current_segment_id.load(Ordering::Relaxed))
     }
 
-    fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
+    fn max_slice_len(&self) -> usize {
+        self.segment(self.current_segment_id()).max_slice_len
+    }
+
+    fn sample_size(&self, offset: PointerOffset) -> usize {
+        self.segment(offset.segment_id()).sample_pool.sample_size()
+    }
+
+    fn allocate(&self) -> Result<ShmPointer, ShmAllocationError> {
         self.retrieve_returned_samples();
 
-        let msg = "Unable to allocate Sample";
-        let ptr = self.memory.allocate(layout)?;
-        if self.sample_reference_counter[self.sample_index(ptr.offset.value())]
-            .fetch_add(1, Ordering::Relaxed)
-            != 0
-        {
-            fatal_panic!(from self,
-                "{} since the allocated sample is already in use! This should never happen!", msg);
-        }
+        let segment_id = self.current_segment_id();
+        let ptr = self.segment(segment_id).sample_pool.allocate()?;
 
-        Ok(ptr)
+        Ok(ShmPointer {
+            offset: PointerOffset::from_offset_and_segment_id(ptr.offset.value(), segment_id),
+            data_ptr: ptr.data_ptr,
+        })
     }
 
-    fn borrow_sample(&self, distance_to_chunk: usize) {
-        self.sample_reference_counter[self.sample_index(distance_to_chunk)]
-            .fetch_add(1, Ordering::Relaxed);
+    fn create_segment(&self, slice_len: usize) -> Result<(), PublisherLoanError> {
+        let msg = "Unable to create a new data segment";
+        let max_slice_len = match self.config.allocation_strategy {
+            AllocationStrategy::Static => {
+                fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                    "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
+                    slice_len, self.max_slice_len());
+            }
+            AllocationStrategy::BestFit => slice_len,
+            AllocationStrategy::PowerOfTwo => slice_len.next_power_of_two(),
+        };
+
+        let old_segment_id = self.current_segment_id();
+        if self.max_number_of_segments <= old_segment_id.value() + 1 {
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                "{} for a slice with {} elements since all {} data segments of the publisher were already created.",
+                msg, slice_len, self.max_number_of_segments);
+        }
+
+        let new_segment_id = SegmentId::new(old_segment_id.value() + 1);
+        let sample_pool = fail!(from self,
+            when create_data_segment::<Service>(
+                &self.port_id,
+                new_segment_id,
+                self.service_state.shared_node.config(),
+                &self.service_state.port_access_policy,
+                self.number_of_samples,
+                &self.subscriber_connections.static_config,
+                max_slice_len),
+            with PublisherLoanError::InternalFailure,
+            "{} for a slice with {} elements since the underlying shared memory could not be created.",
+            msg, slice_len);
+
+        unsafe {
+            (*self.segments.get()).push(Some(Segment {
+                sample_pool,
+                max_slice_len,
+            }))
+        };
+        self.current_segment_id
+            .store(new_segment_id.value(), Ordering::Relaxed);
+        self.release_segment_when_unused(old_segment_id);
+
+        Ok(())
     }
 
-    fn release_sample(&self, distance_to_chunk: PointerOffset) {
-        if self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
-            .fetch_sub(1, Ordering::Relaxed)
-            == 1
+    fn release_segment_when_unused(&self, segment_id: SegmentId) {
+        if segment_id != self.current_segment_id()
+            && self.segment(segment_id).sample_pool.is_unused()
         {
-            unsafe {
-                self.memory
-                    .deallocate(distance_to_chunk, self.payload_type_layout);
-            }
+            let segments = unsafe { &mut *self.segments.get() };
+            segments[segment_id.value() as usize] = None;
         }
     }
 
+    fn borrow_sample(&self, offset: PointerOffset) {
+        self.segment(offset.segment_id())
+            .sample_pool
+            .borrow_sample(PointerOffset::new(offset.offset()));
+    }
+
+    fn release_sample(&self, offset: PointerOffset) {
+        self.segment(offset.segment_id())
+            .sample_pool
+            .release_sample(PointerOffset::new(offset.offset()));
+        self.release_segment_when_unused(offset.segment_id());
+    }
+
     fn retrieve_returned_samples(&self) {
         for i in 0..self.subscriber_connections.len() {
             match self.subscriber_connections.get(i) {
-                Some(ref connection) => loop {
-                    match connection.sender.reclaim() {
-                        Ok(Some(ptr_dist)) => {
-                            self.release_sample(ptr_dist);
-                        }
-                        Ok(None) => break,
-                        Err(e) => {
-                            warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
+                Some(ref connection) => {
+                    loop {
+                        match connection.sender.reclaim() {
+                            Ok(Some(ptr_dist)) => {
+                                self.release_sample(ptr_dist);
+                            }
+                            Ok(None) => break,
+                            Err(e) => {
+                                warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
+                            }
                         }
                     }
-                },
+
+                    // the subscriber was dropped or removed by the cleanup of its dead node,
+                    // the samples it still held can be recovered right away
+                    if connection.sender.has_receiver_left() {
+                        self.remove_connection(i);
+                    }
+                }
                 None => (),
             }
         }
@@ -324,7 +439,8 @@ impl<Service: service::Service> DataSegment<Service> {
 
     pub(crate) fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
         self.release_sample(distance_to_chunk);
-        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+        let number_of_loans = self.loan_counter.fetch_sub(1, Ordering::Relaxed) - 1;
+        self.update_statistics(|statistics| statistics.set_loaned_samples(number_of_loans));
     }
 
     fn add_sample_to_history(&self, address_to_chunk: usize) {
@@ -332,10 +448,14 @@ impl<Service: service::Service> DataSegment<Service> {
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
-                self.borrow_sample(address_to_chunk);
+                self.borrow_sample(PointerOffset::new(address_to_chunk));
                 match history.push_with_overflow(address_to_chunk) {
                     None => (),
-                    Some(old) => self.release_sample(PointerOffset::new(old)),
+                    Some(old) => {
+                        let restored_history = unsafe { &mut *self.restored_history.get() };
+                        restored_history.len = restored_history.len.saturating_sub(1);
+                        self.release_sample(PointerOffset::new(old))
+                    }
                 }
             }
         }
@@ -353,11 +473,13 @@ impl<Service: service::Service> DataSegment<Service> {
             }
         };
 
+        let offset = PointerOffset::new(address_to_chunk);
+        let sample_size = self.sample_size(offset);
         let mut number_of_recipients = 0;
         for i in 0..self.subscriber_connections.len() {
             match self.subscriber_connections.get(i) {
                 Some(ref connection) => {
-                    match deliver_call(&connection.sender, PointerOffset::new(address_to_chunk)) {
+                    match deliver_call(&connection.sender, offset, sample_size) {
                         Err(ZeroCopySendError::ReceiveBufferFull)
                         | Err(ZeroCopySendError::UsedChunkListFull) => {
                             /* causes no problem
@@ -392,7 +514,7 @@ impl<Service: service::Service> DataSegment<Service> {
                             }
                         }
                         Ok(overflow) => {
-                            self.borrow_sample(address_to_chunk);
+                            self.borrow_sample(offset);
                             number_of_recipients += 1;
 
                             if let Some(old) = overflow {
@@ -440,7 +562,10 @@ impl<Service: service::Service> DataSegment<Service> {
                             self.config.max_slice_len,
                         ) {
                             Ok(()) => match &self.subscriber_connections.get(i) {
-                                Some(connection) => self.deliver_sample_history(connection),
+                                Some(connection) => self.deliver_sample_history(
+                                    connection,
+                                    subscriber_details.history_size,
+                                ),
                                 None => {
                                     fatal_panic!(from self, "This should never happen! Unable to acquire previously created subscriber connection.")
                                 }
@@ -495,16 +620,25 @@ impl<Service: service::Service> DataSegment<Service> {
         Ok(())
     }
 
-    fn deliver_sample_history(&self, connection: &Connection<Service>) {
+    fn deliver_sample_history(&self, connection: &Connection<Service>, history_size: usize) {
         match &self.history {
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
-                for i in 0..history.len() {
-                    let ptr_distance = unsafe { history.get_unchecked(i) };
+                let restored_history = unsafe { &*self.restored_history.get() };
+                let mut start = history.len().saturating_sub(history_size);
+                if restored_history
+                    .served_subscribers
+                    .contains(&connection.subscriber_id)
+                {
+                    start = start.max(restored_history.len);
+                }
 
-                    match connection.sender.try_send(PointerOffset::new(ptr_distance)) {
-                        Ok(_) => self.borrow_sample(ptr_distance),
+                for i in start..history.len() {
+                    let offset = PointerOffset::new(unsafe { history.get_unchecked(i) });
+
+                    match connection.sender.try_send(offset, self.sample_size(offset)) {
+                        Ok(_) => self.borrow_sample(offset),
                         Err(e) => {
                             warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
                         }
@@ -524,8 +658,152 @@ impl<Service: service::Service> DataSegment<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
+        let timestamp = self.stamp_sample(address_to_chunk);
         self.add_sample_to_history(address_to_chunk);
-        self.deliver_sample(address_to_chunk)
+        let number_of_recipients = self.deliver_sample(address_to_chunk)?;
+        self.update_statistics(|statistics| statistics.increment_sent_samples(&timestamp));
+        self.notify_subscribers();
+        Ok(number_of_recipients)
+    }
+
+    fn notify_subscribers(&self) {
+        if let Some(notifier) = &self.notifier {
+            if let Err(e) = notifier.notify() {
+                warn!(from self, "Unable to notify the subscribers about the sent sample ({:?}).", e);
+            }
+        }
+    }
+
+    fn stamp_sample(&self, address_to_chunk: usize) -> Time {
+        let timestamp = match Time::now() {
+            Ok(timestamp) => timestamp,
+            Err(e) => {
+                warn!(from self, "Unable to acquire the send timestamp of the sample ({:?}). The timestamp will be zero.", e);
+                Time::default()
+            }
+        };
+        let sequence_number = self.sequence_number.fetch_add(1, Ordering::Relaxed);
+
+        let header = self.sample_address(PointerOffset::new(address_to_chunk)) as *mut Header;
+        unsafe { (*header).set_send_details(sequence_number, timestamp) };
+        timestamp
+    }
+
+    fn sample_address(&self, offset: PointerOffset) -> usize {
+        self.segment(offset.segment_id())
+            .sample_pool
+            .payload_start_address()
+            + offset.offset()
+    }
+
+    fn number_of_elements(&self, header: &Header) -> usize {
+        header.payload_type_layout().size()
+            / self
+                .subscriber_connections
+                .static_config
+                .message_type_details
+                .payload
+                .size
+                .max(1)
+    }
+
+    /// Stores a copy of the history in the node so that the next [`Publisher`] of the same
+    /// node and service can take it over.
+    fn retain_history(&self) {
+        let history = match &self.history {
+            Some(history) => unsafe { &*history.get() },
+            None => return,
+        };
+
+        let mut samples = Vec::with_capacity(history.len());
+        for i in 0..history.len() {
+            let address =
+                self.sample_address(PointerOffset::new(unsafe { history.get_unchecked(i) }));
+            let number_of_elements =
+                self.number_of_elements(unsafe { &*(address as *const Header) });
+            let sample_size = self
+                .subscriber_connections
+                .static_config
+                .message_type_details
+                .sample_layout(number_of_elements)
+                .size();
+            samples.push(
+                unsafe { core::slice::from_raw_parts(address as *const u8, sample_size) }.to_vec(),
+            );
+        }
+
+        if samples.is_empty() {
+            return;
+        }
+
+        let mut served_subscribers = vec![];
+        for i in 0..self.subscriber_connections.len() {
+            if let Some(connection) = self.subscriber_connections.get(i) {
+                served_subscribers.push(connection.subscriber_id);
+            }
+        }
+
+        self.service_state.shared_node.retained_histories().store(
+            self.static_config.service_id(),
+            RetainedHistory {
+                samples,
+                served_subscribers,
+            },
+            self.subscriber_connections.static_config.history_size,
+        );
+    }
+
+    /// Copies the history a previous [`Publisher`] of the same node and service retained into
+    /// the data segment and adds it to the history. It is only delivered to subscribers that
+    /// were not connected to the previous [`Publisher`].
+    fn restore_history(&self) {
+        let retained_history = match self
+            .service_state
+            .shared_node
+            .retained_histories()
+            .take(self.static_config.service_id())
+        {
+            Some(retained_history) => retained_history,
+            None => return,
+        };
+
+        unsafe {
+            (*self.restored_history.get()).served_subscribers = retained_history.served_subscribers
+        };
+
+        for sample in retained_history.samples {
+            let old_header = unsafe { core::ptr::read_unaligned(sample.as_ptr() as *const Header) };
+            let number_of_elements = self.number_of_elements(&old_header);
+            if self.max_slice_len() < number_of_elements {
+                if let Err(e) = self.create_segment(number_of_elements) {
+                    warn!(from self, "Unable to restore a sample of the retained history ({:?}).", e);
+                    continue;
+                }
+            }
+
+            let ptr = match self.allocate() {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    warn!(from self, "Unable to restore the retained history since the sample could not be allocated ({:?}).", e);
+                    return;
+                }
+            };
+
+            let mut header = Header::new(self.port_id, old_header.payload_type_layout());
+            header.set_send_details(
+                self.sequence_number.fetch_add(1, Ordering::Relaxed),
+                old_header.timestamp(),
+            );
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(sample.as_ptr(), ptr.data_ptr, sample.len());
+                (ptr.data_ptr as *mut Header).write(header);
+            }
+
+            self.add_sample_to_history(ptr.offset.value());
+            self.release_sample(ptr.offset);
+            unsafe { (*self.restored_history.get()).len += 1 };
+        }
     }
 }
 
@@ -533,7 +811,6 @@ impl<Service: service::Service> DataSegment<Service> {
 #[derive(Debug)]
 pub struct Publisher<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
     pub(crate) data_segment: Arc<DataSegment<Service>>,
-    dynamic_publisher_handle: Option<ContainerHandle>,
     payload_size: usize,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -543,7 +820,9 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
     for Publisher<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        if let Some(handle) = self.dynamic_publisher_handle {
+        self.data_segment.retain_history();
+
+        if let Some(handle) = unsafe { *self.data_segment.dynamic_publisher_handle.get() } {
             self.data_segment
                 .service_state
                 .dynamic_storage
@@ -578,29 +857,48 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .messaging_pattern
             .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
 
-        let data_segment = fail!(from origin,
-                when Self::create_data_segment(&port_id, service.__internal_state().shared_node.config(), number_of_samples, static_config, &config),
+        let sample_pool = fail!(from origin,
+                when create_data_segment::<Service>(&port_id, SegmentId::new(0), service.__internal_state().shared_node.config(), &service.__internal_state().port_access_policy, number_of_samples, static_config, config.max_slice_len),
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
+        let max_number_of_segments = match config.allocation_strategy {
+            AllocationStrategy::Static => 1,
+            _ => MAX_NUMBER_OF_DATA_SEGMENTS,
+        };
+
+        let notifier = match static_config.enable_notify_on_send {
+            true => {
+                let event = fail!(from origin,
+                    when open_or_create_notify_on_send_service(service),
+                    with PublisherCreateError::UnableToCreateNotifier,
+                    "{} since the companion event service could not be opened or created.", msg);
+                Some(fail!(from origin,
+                    when event.notifier_builder().create(),
+                    with PublisherCreateError::UnableToCreateNotifier,
+                    "{} since the notifier of the companion event service could not be created.", msg))
+            }
+            false => None,
+        };
+
+        let statistics = fail!(from origin,
+                when PortStatistics::create(&port_id.0, service.__internal_state().shared_node.config(), &service.__internal_state().port_access_policy, PublisherStatisticsEntry::new()),
+                with PublisherCreateError::UnableToCreateStatistics,
+                "{} since the publisher statistics could not be created.", msg);
 
         let max_slice_len = config.max_slice_len;
         let data_segment = Arc::new(DataSegment {
             is_active: IoxAtomicBool::new(true),
-            memory: data_segment,
-            payload_size: static_config
-                .message_type_details()
-                .sample_layout(config.max_slice_len)
-                .size(),
-            payload_type_layout: static_config
-                .message_type_details()
-                .payload_layout(config.max_slice_len),
-            sample_reference_counter: {
-                let mut v = Vec::with_capacity(number_of_samples);
-                for _ in 0..number_of_samples {
-                    v.push(IoxAtomicU64::new(0));
-                }
-                v
-            },
+            segments: UnsafeCell::new({
+                let mut segments = Vec::with_capacity(max_number_of_segments as usize);
+                segments.push(Some(Segment {
+                    sample_pool,
+                    max_slice_len,
+                }));
+                segments
+            }),
+            current_segment_id: IoxAtomicU8::new(0),
+            max_number_of_segments,
+            number_of_samples,
             service_state: service.__internal_state().clone(),
             port_id,
             subscriber_connections: SubscriberConnections::new(
@@ -608,7 +906,9 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 service.__internal_state().shared_node.clone(),
                 port_id,
                 static_config,
+                &service.__internal_state().port_access_policy,
                 number_of_samples,
+                max_number_of_segments,
             ),
             config,
             subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
@@ -616,8 +916,13 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 true => None,
                 false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
             },
+            restored_history: UnsafeCell::new(RestoredHistory::default()),
             static_config: service.__internal_state().static_config.clone(),
             loan_counter: IoxAtomicUsize::new(0),
+            sequence_number: IoxAtomicU64::new(0),
+            notifier,
+            statistics,
+            dynamic_publisher_handle: UnsafeCell::new(None),
         });
 
         let payload_size = data_segment
@@ -627,14 +932,15 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .payload
             .size;
 
-        let mut new_self = Self {
+        let new_self = Self {
             data_segment,
-            dynamic_publisher_handle: None,
             payload_size,
             _payload: PhantomData,
             _user_header: PhantomData,
         };
 
+        new_self.data_segment.restore_history();
+
         if let Err(e) = new_self.data_segment.populate_subscriber_channels() {
             warn!(from new_self, "The new Publisher port is unable to connect to every Subscriber port, caused by {:?}.", e);
         }
@@ -652,7 +958,10 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 publisher_id: port_id,
                 number_of_samples,
                 max_slice_len,
+                max_number_of_segments,
+                max_loaned_samples: new_self.data_segment.config.max_loaned_samples,
                 node_id: *service.__internal_state().shared_node.id(),
+                liveliness_lease_duration: new_self.data_segment.config.liveliness_lease_duration,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -662,33 +971,13 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             }
         };
 
-        new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
+        unsafe {
+            *new_self.data_segment.dynamic_publisher_handle.get() = Some(dynamic_publisher_handle)
+        };
 
         Ok(new_self)
     }
 
-    fn create_data_segment(
-        port_id: &UniquePublisherId,
-        global_config: &config::Config,
-        number_of_samples: usize,
-        static_config: &publish_subscribe::StaticConfig,
-        config: &LocalPublisherConfig,
-    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
-        let l = static_config
-            .message_type_details
-            .sample_layout(config.max_slice_len);
-        let allocator_config = shm_allocator::pool_allocator::Config { bucket_layout: l };
-
-        Ok(fail!(from "Publisher::create_data_segment()",
-            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
-            Service::SharedMemory,
-                >>::new(&data_segment_name(port_id))
-                .config(&data_segment_config::<Service>(global_config))
-                .size(l.size() * number_of_samples + l.align() - 1)
-                .create(&allocator_config),
-            "Unable to create the data segment."))
-    }
-
     /// Returns the [`UniquePublisherId`] of the [`Publisher`]
     pub fn id(&self) -> UniquePublisherId {
         self.data_segment.port_id
@@ -700,6 +989,16 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.data_segment.config.unable_to_deliver_strategy
     }
 
+    /// Signals the connected [`Subscriber`](crate::port::subscriber::Subscriber)s that the
+    /// [`Publisher`] is still alive even when it has no [`SampleMut`] to send. Must be called
+    /// at least once within the
+    /// [`PortFactoryPublisher::liveliness_lease_duration()`](crate::service::port_factory::publisher::PortFactoryPublisher::liveliness_lease_duration())
+    /// when no samples are sent.
+    pub fn assert_liveliness(&self) {
+        self.data_segment
+            .update_statistics(|statistics| statistics.assert_liveliness());
+    }
+
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, PublisherLoanError> {
         let msg = "Unable to allocate Sample with";
 
@@ -711,11 +1010,15 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 msg, layout, self.data_segment.loan_counter.load(Ordering::Relaxed), self.data_segment.config.max_loaned_samples);
         }
 
-        match self.data_segment.allocate(layout) {
+        match self.data_segment.allocate() {
             Ok(chunk) => {
-                self.data_segment
+                let number_of_loans = self
+                    .data_segment
                     .loan_counter
-                    .fetch_add(1, Ordering::Relaxed);
+                    .fetch_add(1, Ordering::Relaxed)
+                    + 1;
+                self.data_segment
+                    .update_statistics(|statistics| statistics.set_loaned_samples(number_of_loans));
                 Ok(chunk)
             }
             Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
@@ -978,11 +1281,8 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         &self,
         slice_len: usize,
     ) -> Result<SampleMut<Service, [MaybeUninit<Payload>], UserHeader>, PublisherLoanError> {
-        let max_slice_len = self.data_segment.config.max_slice_len;
-        if max_slice_len < slice_len {
-            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
-                "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
-                slice_len, max_slice_len);
+        if self.data_segment.max_slice_len() < slice_len {
+            self.data_segment.create_segment(slice_len)?;
         }
 
         let sample_layout = self.sample_layout(slice_len);
@@ -1025,7 +1325,7 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
 // END: sliced API
 ////////////////////////
 
-impl<Service: service::Service, Payload: Debug, UserHeader: Debug> UpdateConnections
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> UpdateConnections
     for Publisher<Service, Payload, UserHeader>
 {
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
@@ -1033,6 +1333,25 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug> UpdateConnect
     }
 }
 
+fn create_data_segment<Service: service::Service>(
+    port_id: &UniquePublisherId,
+    segment_id: SegmentId,
+    global_config: &config::Config,
+    access_policy: &AccessPolicy,
+    number_of_samples: usize,
+    static_config: &publish_subscribe::StaticConfig,
+    max_slice_len: usize,
+) -> Result<SamplePool<Service>, SharedMemoryCreateError> {
+    Ok(fail!(from "Publisher::create_data_segment()",
+        when SamplePool::create(
+            &data_segment_name(port_id, segment_id),
+            global_config,
+            access_policy,
+            static_config.message_type_details.sample_layout(max_slice_len),
+            number_of_samples),
+        "Unable to create the data segment."))
+}
+
 pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>(
     port_id: &UniquePublisherId,
     config: &config::Config,
@@ -1043,15 +1362,24 @@ pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>
         port_id
     );
 
-    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
-            &data_segment_name(port_id),
-            &data_segment_config::<Service>(config),
-        ), "Unable to remove the publishers data segment."
-    );
+    for segment_id in 0..MAX_NUMBER_OF_DATA_SEGMENTS {
+        fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+                &data_segment_name(port_id, SegmentId::new(segment_id)),
+                &data_segment_config::<Service>(config),
+            ), "Unable to remove the publishers data segment {}.", segment_id
+        );
+    }
 
     Ok(())
 }
 
+pub(crate) unsafe fn remove_statistics_of_publisher<Service: service::Service>(
+    port_id: &UniquePublisherId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    PortStatistics::<Service, PublisherStatisticsEntry>::remove(&port_id.0, config)
+}
+
 fn connections<Service: service::Service>(
     origin: &str,
     msg: &str,
@@ -1126,6 +1454,18 @@ pub(crate) unsafe fn remove_subscriber_from_all_connections<Service: service::Se
     for connection in connection_list {
         let subscriber_id = extract_subscriber_id_from_connection(&connection);
         if subscriber_id == *port_id {
+            // removing only the receiver informs a living publisher so that it can recover all
+            // samples the dead subscriber still held
+            match <Service::Connection as ZeroCopyConnection>::remove_receiver(
+                &connection,
+                &connection_config,
+            ) {
+                Ok(_) => continue,
+                Err(e) => {
+                    debug!(from origin, "Unable to remove the receiver of the connection ({:?}) due to ({:?}), the connection will be removed instead.", connection, e);
+                }
+            }
+
             match <Service::Connection as NamedConceptMgmt>::remove_cfg(
                 &connection,
                 &connection_config,
@@ -0,0 +1,231 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .create()?;
+//!
+//! let reader = blackboard.reader_builder().create()?;
+//!
+//! let entry = reader.entry::<i32>(&0)?;
+//! println!("current value: {}", entry.get());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::port::port_identifiers::UniqueReaderId;
+use crate::service::dynamic_config::blackboard::ReaderDetails;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use crate::service::{self, ServiceState};
+
+/// Defines a failure that can occur when a [`Reader`] is created with
+/// [`crate::service::port_factory::reader::PortFactoryReader`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ReaderCreateError {
+    /// The maximum amount of [`Reader`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Reader`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedReaders,
+}
+
+impl std::fmt::Display for ReaderCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ReaderCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ReaderCreateError {}
+
+/// Defines a failure that can occur when an [`EntryHandle`] is acquired with
+/// [`Reader::entry()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EntryHandleError {
+    /// The blackboard has no entry with the provided key.
+    EntryDoesNotExist,
+    /// The entry exists but stores a value of a different type.
+    IncompatibleValueType,
+}
+
+impl std::fmt::Display for EntryHandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "EntryHandleError::{:?}", self)
+    }
+}
+
+impl std::error::Error for EntryHandleError {}
+
+/// Reads the entries of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+#[derive(Debug)]
+pub struct Reader<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static> {
+    dynamic_reader_handle: Option<ContainerHandle>,
+    service_state: Arc<ServiceState<Service>>,
+    reader_id: UniqueReaderId,
+    _key: PhantomData<KeyType>,
+}
+
+impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static> Drop
+    for Reader<Service, KeyType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_reader_handle {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .release_reader_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static>
+    Reader<Service, KeyType>
+{
+    pub(crate) fn new(service: &Service) -> Result<Self, ReaderCreateError> {
+        let msg = "Failed to create Reader port";
+        let origin = "Reader::new()";
+        let reader_id = UniqueReaderId::new();
+
+        let mut new_self = Self {
+            dynamic_reader_handle: None,
+            service_state: service.__internal_state().clone(),
+            reader_id,
+            _key: PhantomData,
+        };
+
+        let dynamic_reader_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .add_reader_id(ReaderDetails {
+                reader_id,
+                node_id: *service.__internal_state().shared_node.id(),
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ReaderCreateError::ExceedsMaxSupportedReaders,
+                    "{} since it would exceed the maximum supported amount of readers of {}.",
+                    msg, service.__internal_state().static_config.blackboard().max_readers());
+            }
+        };
+
+        new_self.dynamic_reader_handle = Some(dynamic_reader_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueReaderId`] of the [`Reader`]
+    pub fn id(&self) -> UniqueReaderId {
+        self.reader_id
+    }
+
+    /// Acquires an [`EntryHandle`] to read the value of the entry with the provided key.
+    pub fn entry<ValueType: Copy + Debug + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandle<'_, Service, KeyType, ValueType>, EntryHandleError> {
+        let msg = "Unable to acquire entry handle";
+        let static_config = self.service_state.static_config.blackboard();
+        let payload_start_address = self
+            .service_state
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .payload_start_address();
+
+        let index = match unsafe { static_config.entry_index(payload_start_address, key) } {
+            Some(index) => index,
+            None => {
+                fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                    "{} since no entry with the key {:?} exists.", msg, key);
+            }
+        };
+
+        let details = &static_config.entries[index];
+        let required_type = TypeDetail::__internal_new::<ValueType>(TypeVariant::FixedSize);
+        if details.value_type_details != required_type {
+            fail!(from self, with EntryHandleError::IncompatibleValueType,
+                "{} since the entry with the key {:?} stores the type {:?} but {:?} was requested.",
+                msg, key, details.value_type_details, required_type);
+        }
+
+        Ok(EntryHandle {
+            atomic: unsafe {
+                &*(payload_start_address.add(details.value_offset)
+                    as *const UnrestrictedAtomic<ValueType>)
+            },
+            _reader: PhantomData,
+        })
+    }
+}
+
+/// Reads the latest value of a single entry of the blackboard. Acquired with
+/// [`Reader::entry()`].
+pub struct EntryHandle<
+    'reader,
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+    ValueType: Copy + Debug + 'static,
+> {
+    atomic: &'reader UnrestrictedAtomic<ValueType>,
+    _reader: PhantomData<&'reader Reader<Service, KeyType>>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > Debug for EntryHandle<'_, Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandle<{}, {}, {}> {{ value: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<KeyType>(),
+            core::any::type_name::<ValueType>(),
+            self.atomic.load()
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > EntryHandle<'_, Service, KeyType, ValueType>
+{
+    /// Returns a copy of the latest value of the entry.
+    pub fn get(&self) -> ValueType {
+        self.atomic.load()
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::builder::publish_subscribe_serialized::serialize::cdr::Cdr;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<Vec<String>, Cdr>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//!
+//! publisher.send(&vec!["hello".to_string(), "world".to_string()])?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::Serialize;
+
+use crate::port::port_identifiers::UniquePublisherId;
+use crate::port::publisher::{Publisher, PublisherLoanError, PublisherSendError};
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service;
+use crate::service::builder::publish_subscribe::CustomHeaderMarker;
+
+/// Defines a failure that can occur in [`SerializedPublisher::send()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerializedPublisherSendError {
+    /// The payload could not be serialized with the serializer of the
+    /// [`Service`](crate::service::Service), e.g. since the serializer does not support
+    /// the data model of the payload like maps in CDR or non-table values in TOML.
+    SerializationFailed,
+    /// The memory for the serialized payload could not be loaned.
+    LoanError(PublisherLoanError),
+    /// The serialized payload could not be delivered.
+    SendError(PublisherSendError),
+}
+
+impl From<PublisherLoanError> for SerializedPublisherSendError {
+    fn from(value: PublisherLoanError) -> Self {
+        SerializedPublisherSendError::LoanError(value)
+    }
+}
+
+impl From<PublisherSendError> for SerializedPublisherSendError {
+    fn from(value: PublisherSendError) -> Self {
+        SerializedPublisherSendError::SendError(value)
+    }
+}
+
+impl std::fmt::Display for SerializedPublisherSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "SerializedPublisherSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SerializedPublisherSendError {}
+
+/// Sending endpoint of a publish-subscribe based communication whose payload is serialized
+/// with the `Serializer` into the shared memory instead of being constructed in place. It
+/// supports arbitrary [`serde::Serialize`] types like [`String`], [`Vec`] or
+/// [`std::collections::HashMap`] at the cost of one serialization and copy per sample.
+pub struct SerializedPublisher<
+    Service: service::Service,
+    Payload: Debug + serde::Serialize,
+    Serializer: Serialize,
+> {
+    publisher: Publisher<Service, [u8], CustomHeaderMarker>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<Service: service::Service, Payload: Debug + serde::Serialize, Serializer: Serialize> Debug
+    for SerializedPublisher<Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SerializedPublisher<{}, {}, {}> {{ publisher: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Serializer>(),
+            self.publisher
+        )
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + serde::Serialize, Serializer: Serialize>
+    SerializedPublisher<Service, Payload, Serializer>
+{
+    pub(crate) fn new(publisher: Publisher<Service, [u8], CustomHeaderMarker>) -> Self {
+        Self {
+            publisher,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`SerializedPublisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.publisher.id()
+    }
+
+    /// Serializes the value into a loaned sample and delivers it to all connected
+    /// [`SerializedSubscriber`](crate::port::serialized_subscriber::SerializedSubscriber)s.
+    /// On success the number of recipients is returned, otherwise a
+    /// [`SerializedPublisherSendError`] describing the failure.
+    pub fn send(&self, value: &Payload) -> Result<usize, SerializedPublisherSendError> {
+        let bytes = fail!(from self, when Serializer::serialize(value),
+                with SerializedPublisherSendError::SerializationFailed,
+                "Unable to send the payload since it could not be serialized.");
+
+        let sample = fail!(from self, when self.publisher.loan_slice_uninit(bytes.len()),
+                "Unable to send the payload since the memory for the serialized payload of {} bytes could not be loaned.",
+                bytes.len());
+
+        let sample = sample.write_from_fn(|n| bytes[n]);
+        Ok(fail!(from self, when sample.send(),
+                "Unable to deliver the serialized payload."))
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + serde::Serialize, Serializer: Serialize>
+    UpdateConnections for SerializedPublisher<Service, Payload, Serializer>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.publisher.update_connections()
+    }
+}
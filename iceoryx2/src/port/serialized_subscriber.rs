@@ -0,0 +1,162 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::builder::publish_subscribe_serialized::serialize::cdr::Cdr;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<Vec<String>, Cdr>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! while let Some(payload) = subscriber.receive()? {
+//!     println!("received: {:?}", payload);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::Serialize;
+
+use crate::port::port_identifiers::UniqueSubscriberId;
+use crate::port::subscriber::{Subscriber, SubscriberReceiveError};
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service;
+use crate::service::builder::publish_subscribe::CustomHeaderMarker;
+
+/// Defines a failure that can occur in [`SerializedSubscriber::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerializedSubscriberReceiveError {
+    /// The received payload could not be deserialized with the serializer of the
+    /// [`Service`](crate::service::Service). The sample is discarded.
+    DeserializationFailed,
+    /// The serialized payload could not be received.
+    ReceiveError(SubscriberReceiveError),
+}
+
+impl From<SubscriberReceiveError> for SerializedSubscriberReceiveError {
+    fn from(value: SubscriberReceiveError) -> Self {
+        SerializedSubscriberReceiveError::ReceiveError(value)
+    }
+}
+
+impl std::fmt::Display for SerializedSubscriberReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "SerializedSubscriberReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SerializedSubscriberReceiveError {}
+
+/// Receiving endpoint of a publish-subscribe based communication whose payload was serialized
+/// by a [`SerializedPublisher`](crate::port::serialized_publisher::SerializedPublisher). The
+/// payload is deserialized into an owned value when it is received and the underlying sample
+/// is released right away.
+pub struct SerializedSubscriber<
+    Service: service::Service,
+    Payload: Debug + serde::de::DeserializeOwned,
+    Serializer: Serialize,
+> {
+    subscriber: Subscriber<Service, [u8], CustomHeaderMarker>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::de::DeserializeOwned,
+        Serializer: Serialize,
+    > Debug for SerializedSubscriber<Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SerializedSubscriber<{}, {}, {}> {{ subscriber: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Serializer>(),
+            self.subscriber
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::de::DeserializeOwned,
+        Serializer: Serialize,
+    > SerializedSubscriber<Service, Payload, Serializer>
+{
+    pub(crate) fn new(subscriber: Subscriber<Service, [u8], CustomHeaderMarker>) -> Self {
+        Self {
+            subscriber,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the [`SerializedSubscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    /// Returns the internal buffer size of the [`SerializedSubscriber`].
+    pub fn buffer_size(&self) -> usize {
+        self.subscriber.buffer_size()
+    }
+
+    /// Returns true if the [`SerializedSubscriber`] has samples in the buffer that can be
+    /// received with [`SerializedSubscriber::receive`].
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        self.subscriber.has_samples()
+    }
+
+    /// Receives and deserializes a payload from a
+    /// [`SerializedPublisher`](crate::port::serialized_publisher::SerializedPublisher). If no
+    /// sample could be received [`None`] is returned. If a failure occurs
+    /// [`SerializedSubscriberReceiveError`] is returned.
+    pub fn receive(&self) -> Result<Option<Payload>, SerializedSubscriberReceiveError> {
+        let sample = match fail!(from self, when self.subscriber.receive(),
+                                "Unable to receive the serialized payload.")
+        {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        Ok(Some(
+            fail!(from self, when Serializer::deserialize(sample.payload()),
+                with SerializedSubscriberReceiveError::DeserializationFailed,
+                "Unable to deserialize the received payload of {} bytes.", sample.payload().len()),
+        ))
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::de::DeserializeOwned,
+        Serializer: Serialize,
+    > UpdateConnections for SerializedSubscriber<Service, Payload, Serializer>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.subscriber.update_connections()
+    }
+}
@@ -0,0 +1,518 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service
+//!     .server_builder()
+//!     // defines how many responses can be loaned in parallel
+//!     .max_loaned_responses(5)
+//!     .create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("received request: {}", *active_request);
+//!
+//!     // loan some initialized memory and send it
+//!     let mut response = active_request.loan()?;
+//!     *response.payload_mut() = 1337;
+//!     response.send()?;
+//!
+//!     // loan some uninitialized memory and send it
+//!     let response = active_request.loan_uninit()?;
+//!     let response = response.write_payload(1337);
+//!     response.send()?;
+//!
+//!     // send a copy of the value
+//!     active_request.send_copy(313)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::NamedConceptMgmt;
+use iceoryx2_cal::named_concept::NamedConceptRemoveError;
+use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver, ZeroCopySendError, ZeroCopySender,
+};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+
+use super::details::client_connections::{ClientConnections, Connection};
+use super::details::sample_pool::SamplePool;
+use super::port_identifiers::UniqueServerId;
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use super::{LoanError, ReceiveError, SendError};
+use crate::active_request::ActiveRequest;
+use crate::config;
+use crate::raw_sample::RawSample;
+use crate::service::config_scheme::data_segment_config;
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header::request_response::RequestHeader;
+use crate::service::naming_scheme::server_data_segment_name;
+use crate::service::static_config::request_response::StaticConfig;
+use crate::service::{self, port_factory::server::LocalServerConfig};
+
+/// Describes the failures when a new [`Server`] is created via the
+/// [`crate::service::port_factory::server::PortFactoryServer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerCreateError {
+    /// The maximum amount of [`Server`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Server`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedServers,
+    /// The datasegment in which the responses of the [`Server`] are stored, could not be created.
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ServerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ServerCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServerCreateError {}
+
+/// The state of a [`Server`] that is shared with all of its [`ActiveRequest`]s and
+/// [`ResponseMut`](crate::response_mut::ResponseMut)s.
+#[derive(Debug)]
+pub(crate) struct ServerSharedState<Service: service::Service> {
+    sample_pool: SamplePool<Service>,
+    client_connections: ClientConnections<Service>,
+    client_list_state: UnsafeCell<ContainerState<ClientDetails>>,
+    config: LocalServerConfig,
+    loan_counter: IoxAtomicUsize,
+    is_active: IoxAtomicBool,
+}
+
+impl<Service: service::Service> ServerSharedState<Service> {
+    pub(crate) fn static_config(&self) -> &StaticConfig {
+        &self.client_connections.static_config
+    }
+
+    pub(crate) fn server_id(&self) -> UniqueServerId {
+        self.client_connections.server_id()
+    }
+
+    fn retrieve_returned_responses(&self) {
+        for i in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(i) {
+                loop {
+                    match connection.response_sender.reclaim() {
+                        Ok(Some(offset)) => self.sample_pool.release_sample(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim responses from connection {:?} due to {:?}. This may lead to a situation where no more responses will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.client_connections.get(i) {
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered responses
+            unsafe {
+                connection
+                    .response_sender
+                    .acquire_used_offsets(|offset| self.sample_pool.release_sample(offset))
+            };
+        }
+
+        self.client_connections.remove(i);
+    }
+
+    fn populate_client_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.client_connections.capacity(), None);
+
+        unsafe {
+            (*self.client_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut ret_val = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match self.client_connections.get(i) {
+                        None => true,
+                        Some(connection) => connection.client_id != details.client_id,
+                    };
+
+                    if create_connection {
+                        self.remove_connection(i);
+
+                        if let Err(e) = self.client_connections.create(i, details) {
+                            warn!(from self, "Unable to establish connection to new client {:?}.", details.client_id);
+                            ret_val = Err(e);
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        ret_val
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.client_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .clients
+                .update_state(&mut *self.client_list_state.get())
+        } {
+            fail!(from self, when self.populate_client_channels(),
+                "Connections were updated only partially since at least one connection to a client failed.");
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn allocate(&self, layout: Layout) -> Result<(PointerOffset, usize), LoanError> {
+        let msg = "Unable to allocate response with";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_responses {
+            fail!(from self, with LoanError::ExceedsMaxLoans,
+                "{} {:?} since already {} responses were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned response to loan another response.",
+                msg, layout, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_responses);
+        }
+
+        self.retrieve_returned_responses();
+
+        match self.sample_pool.allocate() {
+            Ok(chunk) => {
+                self.loan_counter.fetch_add(1, Ordering::Relaxed);
+                Ok((chunk.offset, chunk.data_ptr as usize))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} {:?} since the underlying shared memory is out of memory.", msg, layout);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} {:?} since an internal failure occurred ({:?}).", msg, layout, v);
+            }
+        }
+    }
+
+    pub(crate) fn return_loaned_response(&self, offset: PointerOffset) {
+        self.sample_pool.release_sample(offset);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn send_response(
+        &self,
+        offset: PointerOffset,
+        connection: &Arc<Connection<Service>>,
+        connection_index: usize,
+    ) -> Result<(), SendError> {
+        let msg = "Unable to send response";
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self, with SendError::ConnectionBrokenSinceSenderNoLongerExists,
+                "{} since the corresponding server is already disconnected.", msg);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        let is_connected = match self.client_connections.get(connection_index) {
+            Some(current) => Arc::ptr_eq(current, connection),
+            None => false,
+        };
+
+        if !is_connected {
+            fail!(from self, with SendError::ConnectionBrokenSinceReceiverNoLongerExists,
+                "{} since the client {:?} is no longer connected.", msg, connection.client_id);
+        }
+
+        self.retrieve_returned_responses();
+
+        match connection
+            .response_sender
+            .try_send(offset, self.sample_pool.sample_size())
+        {
+            Ok(overflow) => {
+                self.sample_pool.borrow_sample(offset);
+
+                if let Some(old) = overflow {
+                    self.sample_pool.release_sample(old);
+                }
+            }
+            Err(ZeroCopySendError::ReceiveBufferFull) => {
+                fail!(from self, with SendError::ReceiveBufferFull,
+                    "{} since the response buffer of client {:?} is full and does not safely overflow.",
+                    msg, connection.client_id);
+            }
+            Err(ZeroCopySendError::UsedChunkListFull) => {
+                debug!(from self, "The response could not be delivered to client {:?} since its buffer is full.",
+                    connection.client_id);
+            }
+            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                fail!(from self, with SendError::ConnectionCorrupted,
+                    "{} since a corrupted connection was detected with client {:?}.",
+                    msg, connection.client_id);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Receiving endpoint of a request-response based communication. Receives the requests of all
+/// connected [`Client`](crate::port::client::Client)s as [`ActiveRequest`] which can be used
+/// to send a stream of responses back to the [`Client`](crate::port::client::Client).
+#[derive(Debug)]
+pub struct Server<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> {
+    pub(crate) shared_state: Arc<ServerSharedState<Service>>,
+    dynamic_server_handle: Option<ContainerHandle>,
+    _request_payload: PhantomData<RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for Server<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.shared_state.is_active.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.dynamic_server_handle {
+            self.shared_state
+                .client_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .release_server_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    Server<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalServerConfig,
+    ) -> Result<Self, ServerCreateError> {
+        let msg = "Unable to create Server port";
+        let origin = "Server::new()";
+        let server_id = UniqueServerId::new();
+        let client_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .clients;
+
+        let number_of_responses = static_config
+            .required_amount_of_responses_per_data_segment(config.max_loaned_responses);
+
+        let sample_pool = fail!(from origin,
+                when SamplePool::create(&server_data_segment_name(&server_id),
+                                        service.__internal_state().shared_node.config(),
+                                        &service.__internal_state().port_access_policy,
+                                        static_config.response_message_type_details().sample_layout(1),
+                                        number_of_responses),
+                with ServerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let shared_state = Arc::new(ServerSharedState {
+            sample_pool,
+            client_connections: ClientConnections::new(
+                client_list.capacity(),
+                server_id,
+                service.__internal_state().clone(),
+                static_config,
+                number_of_responses,
+            ),
+            client_list_state: unsafe { UnsafeCell::new(client_list.get_state()) },
+            config,
+            loan_counter: IoxAtomicUsize::new(0),
+            is_active: IoxAtomicBool::new(true),
+        });
+
+        let mut new_self = Self {
+            shared_state,
+            dynamic_server_handle: None,
+            _request_payload: PhantomData,
+            _response_payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.shared_state.populate_client_channels() {
+            warn!(from new_self, "The new Server port is unable to connect to every Client port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a server is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_server_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_server_id(ServerDetails {
+                server_id,
+                node_id: *service.__internal_state().shared_node.id(),
+                number_of_samples: number_of_responses,
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ServerCreateError::ExceedsMaxSupportedServers,
+                    "{} since it would exceed the maximum supported amount of servers of {}.",
+                    msg, static_config.max_servers);
+            }
+        };
+
+        new_self.dynamic_server_handle = Some(dynamic_server_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`]
+    pub fn id(&self) -> UniqueServerId {
+        self.shared_state.server_id()
+    }
+
+    /// Returns true if the [`Server`] has requests in the buffer that can be received with
+    /// [`Server::receive()`].
+    pub fn has_requests(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.shared_state.update_connections(),
+                "Some requests are not being received since not all connections to clients could be established.");
+
+        for i in 0..self.shared_state.client_connections.len() {
+            if let Some(ref connection) = self.shared_state.client_connections.get(i) {
+                if connection.request_receiver.has_data() {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Receives an [`ActiveRequest`] from a [`Client`](crate::port::client::Client). If no
+    /// request could be received [`None`] is returned. If a failure occurs [`ReceiveError`]
+    /// is returned.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<ActiveRequest<Service, RequestPayload, ResponsePayload>>, ReceiveError> {
+        let msg = "Unable to receive another request";
+        fail!(from self, when self.shared_state.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        for i in 0..self.shared_state.client_connections.len() {
+            if let Some(ref connection) = self.shared_state.client_connections.get(i) {
+                match connection.request_receiver.receive() {
+                    Ok(None) => (),
+                    Ok(Some(offset)) => {
+                        let header_ptr = (offset.value()
+                            + connection.data_segment.payload_start_address())
+                            as *const RequestHeader;
+                        let type_details = self
+                            .shared_state
+                            .static_config()
+                            .request_message_type_details();
+                        let user_header_ptr = type_details
+                            .user_header_ptr_from_header(header_ptr.cast())
+                            .cast();
+                        let payload_ptr = type_details
+                            .payload_ptr_from_header(header_ptr.cast())
+                            .cast();
+
+                        return Ok(Some(ActiveRequest {
+                            ptr: unsafe {
+                                RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr)
+                            },
+                            shared_state: self.shared_state.clone(),
+                            connection: connection.clone(),
+                            connection_index: i,
+                            offset,
+                            _response_payload: PhantomData,
+                        }));
+                    }
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+                            "{} since it would exceed the maximum {} of borrowed requests.",
+                            msg, connection.request_receiver.max_borrowed_samples());
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> UpdateConnections
+    for Server<Service, RequestPayload, ResponsePayload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.shared_state.update_connections()
+    }
+}
+
+pub(crate) unsafe fn remove_data_segment_of_server<Service: service::Service>(
+    port_id: &UniqueServerId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_data_segment_of_server::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &server_data_segment_name(port_id),
+            &data_segment_config::<Service>(config),
+        ), "Unable to remove the servers data segment."
+    );
+
+    Ok(())
+}
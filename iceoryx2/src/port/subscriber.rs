@@ -36,16 +36,22 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
-use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::{shared_memory::*, zero_copy_connection::*};
+use iceoryx2_cal::event::ListenerWaitError;
+use iceoryx2_cal::zero_copy_connection::*;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
+use crate::port::listener::Listener;
 use crate::port::DegrationAction;
 use crate::sample::SampleDetails;
+use crate::service::builder::publish_subscribe::open_or_create_notify_on_send_service;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::subscriber::SubscriberConfig;
@@ -53,7 +59,7 @@ use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
 use super::details::publisher_connections::{Connection, PublisherConnections};
-use super::port_identifiers::UniqueSubscriberId;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::{ConnectionFailure, UpdateConnections};
 use super::DegrationCallback;
 
@@ -68,6 +74,14 @@ pub enum SubscriberReceiveError {
     /// Occurs when a [`Subscriber`] is unable to connect to a corresponding
     /// [`Publisher`](crate::port::publisher::Publisher).
     ConnectionFailure(ConnectionFailure),
+
+    /// [`Subscriber::blocking_receive()`] or [`Subscriber::timed_receive()`] was called but the
+    /// [`Service`](crate::service::Service) was not created with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notify_on_send()`].
+    NotifyOnSendIsDisabled,
+
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
 }
 
 impl std::fmt::Display for SubscriberReceiveError {
@@ -90,6 +104,13 @@ pub enum SubscriberCreateError {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`](crate::service::Service) offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// When the [`Subscriber`] requests more historical samples than the
+    /// [`Service`](crate::service::Service) stores the creation will fail.
+    HistorySizeExceedsHistorySizeOfService,
+    /// The [`Service`](crate::service::Service) has notify on send enabled but the
+    /// [`Listener`] of the companion event [`Service`](crate::service::Service) could not be
+    /// created.
+    UnableToCreateListener,
 }
 
 impl std::fmt::Display for SubscriberCreateError {
@@ -100,6 +121,22 @@ impl std::fmt::Display for SubscriberCreateError {
 
 impl std::error::Error for SubscriberCreateError {}
 
+/// Quality of service violations of a connected [`Publisher`](crate::port::publisher::Publisher)
+/// that are reported by [`Subscriber::publisher_qos_events()`]. Every violation is reported
+/// once and again only after the [`Publisher`](crate::port::publisher::Publisher) recovered.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PublisherQosEvent {
+    /// The [`Publisher`](crate::port::publisher::Publisher) did not send a sample within the
+    /// deadline defined with
+    /// [`crate::service::builder::publish_subscribe::Builder::deadline()`].
+    DeadlineMissed(UniquePublisherId),
+    /// The [`Publisher`](crate::port::publisher::Publisher) neither sent a sample nor asserted
+    /// its liveliness within its
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::liveliness_lease_duration()`]
+    /// or its process died.
+    LivelinessLost(UniquePublisherId),
+}
+
 /// The receiving endpoint of a publish-subscribe communication.
 #[derive(Debug)]
 pub struct Subscriber<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
@@ -110,6 +147,8 @@ pub struct Subscriber<Service: service::Service, Payload: Debug + ?Sized, UserHe
     degration_callback: Option<DegrationCallback<'static>>,
 
     publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    number_of_missed_samples: IoxAtomicU64,
+    listener: Option<Listener<Service>>,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -160,6 +199,32 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             None => static_config.subscriber_max_buffer_size,
         };
 
+        let history_size = match config.history_size {
+            Some(history_size) => {
+                if static_config.history_size < history_size {
+                    fail!(from origin, with SubscriberCreateError::HistorySizeExceedsHistorySizeOfService,
+                        "{} since the requested history size {} exceeds the history size {} of the service.",
+                        msg, history_size, static_config.history_size);
+                }
+                history_size
+            }
+            None => static_config.history_size,
+        };
+
+        let listener = match static_config.enable_notify_on_send {
+            true => {
+                let event = fail!(from origin,
+                    when open_or_create_notify_on_send_service(service),
+                    with SubscriberCreateError::UnableToCreateListener,
+                    "{} since the companion event service could not be opened or created.", msg);
+                Some(fail!(from origin,
+                    when event.listener_builder().create(),
+                    with SubscriberCreateError::UnableToCreateListener,
+                    "{} since the listener of the companion event service could not be created.", msg))
+            }
+            false => None,
+        };
+
         let publisher_connections = PublisherConnections::new(
             publisher_list.capacity(),
             subscriber_id,
@@ -183,6 +248,8 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             dynamic_subscriber_handle: None,
             static_config: service.__internal_state().static_config.clone(),
+            number_of_missed_samples: IoxAtomicU64::new(0),
+            listener,
             _payload: PhantomData,
             _user_header: PhantomData,
         };
@@ -203,6 +270,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .add_subscriber_id(SubscriberDetails {
                 subscriber_id,
                 buffer_size,
+                history_size,
                 node_id: *service.__internal_state().shared_node.id(),
             }) {
             Some(unique_index) => unique_index,
@@ -299,8 +367,22 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             Ok(data) => match data {
                 None => Ok(None),
                 Some(offset) => {
-                    let absolute_address =
-                        offset.value() + connection.data_segment.payload_start_address();
+                    let data_segment_start_address = match connection
+                        .data_segment_start_address(offset.segment_id())
+                    {
+                        Ok(address) => address,
+                        Err(e) => {
+                            if let Err(e) = connection.receiver.release(offset) {
+                                warn!(from self, "Unable to return the sample of an unmappable data segment to the publisher ({:?}).", e);
+                            }
+                            fail!(from self, with SubscriberReceiveError::ConnectionFailure(e.into()),
+                                    "{} since the data segment {} of publisher {:?} could not be mapped.",
+                                    msg, offset.segment_id().value(), connection.publisher_id);
+                        }
+                    };
+                    let absolute_address = offset.offset() + data_segment_start_address;
+                    self.detect_missed_samples(connection, absolute_address as *const Header);
+                    Self::record_latency(connection, absolute_address as *const Header);
 
                     let details = SampleDetails {
                         publisher_connection: connection.clone(),
@@ -319,6 +401,48 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         }
     }
 
+    fn detect_missed_samples(&self, connection: &Connection<Service>, header: *const Header) {
+        let sequence_number = unsafe { (*header).sequence_number() };
+        let next_sequence_number = connection
+            .next_sequence_number
+            .swap(sequence_number + 1, Ordering::Relaxed);
+
+        // the first received sample of a publisher cannot reveal any gap
+        if next_sequence_number != 0 && next_sequence_number < sequence_number {
+            let number_of_missed_samples = sequence_number - next_sequence_number;
+            self.number_of_missed_samples
+                .fetch_add(number_of_missed_samples, Ordering::Relaxed);
+            debug!(from self,
+                "Detected {} missed samples from publisher {:?} (sequence numbers {}..{}).",
+                number_of_missed_samples, connection.publisher_id, next_sequence_number, sequence_number);
+        }
+    }
+
+    fn record_latency(connection: &Connection<Service>, header: *const Header) {
+        let timestamp = unsafe { (*header).timestamp() }.as_duration();
+        // samples without a valid send timestamp do not contribute to the statistics
+        if timestamp.is_zero() {
+            return;
+        }
+
+        if let Ok(now) = Time::now() {
+            connection
+                .receiver
+                .record_latency(now.as_duration().saturating_sub(timestamp));
+        }
+    }
+
+    /// Returns the total number of samples that were missed since the [`Subscriber`] was
+    /// created. Samples are missed when they were sent but never received, for instance when
+    /// they were discarded from a full buffer with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_safe_overflow()`] or when
+    /// the [`Publisher`](crate::port::publisher::Publisher) was unable to deliver them.
+    /// Gaps are detected with the [`Header::sequence_number()`] when the next sample of the
+    /// same [`Publisher`](crate::port::publisher::Publisher) is received.
+    pub fn number_of_missed_samples(&self) -> u64 {
+        self.number_of_missed_samples.load(Ordering::Relaxed)
+    }
+
     /// Returns the [`UniqueSubscriberId`] of the [`Subscriber`]
     pub fn id(&self) -> UniqueSubscriberId {
         self.publisher_connections.subscriber_id()
@@ -348,8 +472,170 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         Ok(false)
     }
 
+    /// Checks the deadline and liveliness of all connected
+    /// [`Publisher`](crate::port::publisher::Publisher)s and calls the provided callback for
+    /// every new [`PublisherQosEvent`] until it returns [`CallbackProgression::Stop`].
+    /// The check is performed on every call, therefore it shall be called periodically to
+    /// detect violations in time, for instance when the attachment of
+    /// [`WaitSet::attach_publisher_qos()`](crate::waitset::WaitSet::attach_publisher_qos())
+    /// wakes up the [`WaitSet`](crate::waitset::WaitSet). The events can be forwarded to a
+    /// [`Notifier`](crate::port::notifier::Notifier) to wake up a [`Listener`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::subscriber::PublisherQosEvent;
+    /// # use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .deadline(Duration::from_millis(100))
+    ///     .open_or_create()?;
+    /// let qos_events = node.service_builder(&"My/Funk/QosEvents".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber_builder().create()?;
+    /// let notifier = qos_events.notifier_builder().create()?;
+    ///
+    /// subscriber.publisher_qos_events(|event| {
+    ///     let event_id = match event {
+    ///         PublisherQosEvent::DeadlineMissed(_) => EventId::new(1),
+    ///         PublisherQosEvent::LivelinessLost(_) => EventId::new(2),
+    ///     };
+    ///     notifier.notify_with_custom_event_id(event_id).ok();
+    ///     CallbackProgression::Continue
+    /// })?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn publisher_qos_events<F: FnMut(PublisherQosEvent) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+                "Some publishers are not checked since not all connections to publishers could be established.");
+
+        let now = match Time::now() {
+            Ok(now) => now.as_duration(),
+            Err(e) => {
+                warn!(from self, "Unable to check the quality of service of the publishers since the current time could not be acquired ({:?}).", e);
+                return Ok(());
+            }
+        };
+        // a zero timestamp belongs to a publisher that was unable to acquire the current time
+        let elapsed_since = |timestamp: Time| match timestamp.as_duration() {
+            Duration::ZERO => Duration::ZERO,
+            timestamp => now.saturating_sub(timestamp),
+        };
+
+        let deadline = self.static_config.publish_subscribe().deadline();
+        let global_config = self
+            .publisher_connections
+            .service_state
+            .shared_node
+            .config();
+
+        unsafe {
+            (*self.publisher_list_state.get()).for_each(|handle, details| {
+                let connection = match self.publisher_connections.get(handle.index() as usize) {
+                    Some(connection) if connection.publisher_id == details.publisher_id => {
+                        connection
+                    }
+                    _ => return CallbackProgression::Continue,
+                };
+                // the deadline and the lease of a publisher whose statistics cannot be mapped
+                // are not checked, its liveliness is tracked by its node only
+                let statistics = connection.publisher_statistics(global_config).ok();
+
+                if let (Some(deadline), Some(statistics)) = (deadline, &statistics) {
+                    let is_missed = elapsed_since(statistics.last_sample_timestamp) > deadline;
+                    if connection.update_deadline_missed(is_missed)
+                        && callback(PublisherQosEvent::DeadlineMissed(details.publisher_id))
+                            == CallbackProgression::Stop
+                    {
+                        return CallbackProgression::Stop;
+                    }
+                }
+
+                if let Some(lease_duration) = details.liveliness_lease_duration {
+                    let is_lost = connection.is_publisher_node_dead()
+                        || statistics.is_some_and(|statistics| {
+                            elapsed_since(statistics.last_liveliness_timestamp) > lease_duration
+                        });
+                    if connection.update_liveliness_lost(is_lost)
+                        && callback(PublisherQosEvent::LivelinessLost(details.publisher_id))
+                            == CallbackProgression::Stop
+                    {
+                        return CallbackProgression::Stop;
+                    }
+                }
+
+                CallbackProgression::Continue
+            })
+        };
+
+        Ok(())
+    }
+
+    /// Returns the period in which [`Subscriber::publisher_qos_events()`] has to be called so
+    /// that every violation is reported at the latest one period after it occurred. It is the
+    /// smallest of the deadline of the service and the liveliness lease durations of the
+    /// currently connected [`Publisher`](crate::port::publisher::Publisher)s or [`None`] when
+    /// none of them is defined.
+    pub fn publisher_qos_check_interval(&self) -> Option<Duration> {
+        let mut interval = self.static_config.publish_subscribe().deadline();
+        let publishers = unsafe {
+            self.publisher_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .publishers
+                .get_state()
+        };
+
+        publishers.for_each(|_, details| {
+            if let Some(lease_duration) = details.liveliness_lease_duration {
+                interval = Some(interval.map_or(lease_duration, |v| v.min(lease_duration)));
+            }
+            CallbackProgression::Continue
+        });
+
+        interval
+    }
+
+    pub(crate) fn listener(&self) -> Option<&Listener<Service>> {
+        self.listener.as_ref()
+    }
+
     fn receive_impl(
         &self,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        if let Some(sample) = self.receive_from_connections()? {
+            return Ok(Some(sample));
+        }
+
+        // consume the notifications of the companion event service when no samples are left,
+        // otherwise an attached subscriber would wake up the node over and over again. Samples
+        // that were delivered in between are acquired with the second attempt.
+        match &self.listener {
+            Some(listener) => {
+                if let Err(e) = listener.try_wait_all(|_| {}) {
+                    warn!(from self, "Unable to consume the notifications of the companion event service ({:?}).", e);
+                }
+                self.receive_from_connections()
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn receive_from_connections(
+        &self,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
         if let Err(e) = self.update_connections() {
             fail!(from self,
@@ -383,6 +669,49 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         Ok(None)
     }
 
+    fn wait_and_receive<T, F: Fn(&Self) -> Result<Option<T>, SubscriberReceiveError>>(
+        &self,
+        receive: F,
+        timeout: Option<Duration>,
+    ) -> Result<Option<T>, SubscriberReceiveError> {
+        let msg = "Unable to wait for samples";
+        let listener = match &self.listener {
+            Some(listener) => listener,
+            None => {
+                fail!(from self, with SubscriberReceiveError::NotifyOnSendIsDisabled,
+                    "{} since the service was not created with notify on send enabled.", msg);
+            }
+        };
+
+        let start = Instant::now();
+        loop {
+            if let Some(sample) = receive(self)? {
+                return Ok(Some(sample));
+            }
+
+            let wait_result = match timeout {
+                Some(timeout) => {
+                    let elapsed = start.elapsed();
+                    if timeout <= elapsed {
+                        return Ok(None);
+                    }
+                    listener.timed_wait_all(|_| {}, timeout - elapsed)
+                }
+                None => listener.blocking_wait_all(|_| {}),
+            };
+
+            match wait_result {
+                Ok(()) => (),
+                Err(ListenerWaitError::InterruptSignal) => return Ok(None),
+                Err(e) => {
+                    fail!(from self, with SubscriberReceiveError::InternalFailure,
+                        "{} since the listener of the companion event service failed to wait ({:?}).",
+                        msg, e);
+                }
+            }
+        }
+    }
+
     fn payload_ptr(&self, header: *const Header) -> *const u8 {
         self.publisher_connections
             .static_config
@@ -424,6 +753,31 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Upda
 impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     Subscriber<Service, Payload, UserHeader>
 {
+    /// Blocks until a [`crate::sample::Sample`] was received from a
+    /// [`crate::port::publisher::Publisher`]. Requires a [`Service`](crate::service::Service)
+    /// that was created with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notify_on_send()`].
+    /// If the call was interrupted by a signal [`None`] is returned. If a failure occurs
+    /// [`SubscriberReceiveError`] is returned.
+    pub fn blocking_receive(
+        &self,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.wait_and_receive(Self::receive, None)
+    }
+
+    /// Waits until either a [`crate::sample::Sample`] was received from a
+    /// [`crate::port::publisher::Publisher`] or the timeout has passed. Requires a
+    /// [`Service`](crate::service::Service) that was created with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notify_on_send()`].
+    /// If no sample was received in time or the call was interrupted by a signal [`None`] is
+    /// returned. If a failure occurs [`SubscriberReceiveError`] is returned.
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.wait_and_receive(Self::receive, Some(timeout))
+    }
+
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
     pub fn receive(
@@ -444,6 +798,31 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
 impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     Subscriber<Service, [Payload], UserHeader>
 {
+    /// Blocks until a [`crate::sample::Sample`] was received from a
+    /// [`crate::port::publisher::Publisher`]. Requires a [`Service`](crate::service::Service)
+    /// that was created with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notify_on_send()`].
+    /// If the call was interrupted by a signal [`None`] is returned. If a failure occurs
+    /// [`SubscriberReceiveError`] is returned.
+    pub fn blocking_receive(
+        &self,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        self.wait_and_receive(Self::receive, None)
+    }
+
+    /// Waits until either a [`crate::sample::Sample`] was received from a
+    /// [`crate::port::publisher::Publisher`] or the timeout has passed. Requires a
+    /// [`Service`](crate::service::Service) that was created with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notify_on_send()`].
+    /// If no sample was received in time or the call was interrupted by a signal [`None`] is
+    /// returned. If a failure occurs [`SubscriberReceiveError`] is returned.
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        self.wait_and_receive(Self::receive, Some(timeout))
+    }
+
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
     pub fn receive(
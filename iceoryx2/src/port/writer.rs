@@ -0,0 +1,278 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .create()?;
+//!
+//! let writer = blackboard.writer_builder().create()?;
+//!
+//! let entry = writer.entry::<i32>(&0)?;
+//! entry.update_with_copy(1234);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::sync::{atomic::Ordering, Arc};
+
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::{Producer, UnrestrictedAtomic};
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use crate::port::port_identifiers::UniqueWriterId;
+use crate::service::dynamic_config::blackboard::WriterDetails;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use crate::service::{self, ServiceState};
+
+/// Defines a failure that can occur when a [`Writer`] is created with
+/// [`crate::service::port_factory::writer::PortFactoryWriter`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum WriterCreateError {
+    /// A blackboard supports only one [`Writer`]. When it already exists no further [`Writer`]
+    /// can be created.
+    ExceedsMaxSupportedWriters,
+}
+
+impl std::fmt::Display for WriterCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WriterCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WriterCreateError {}
+
+/// Defines a failure that can occur when an [`EntryHandleMut`] is acquired with
+/// [`Writer::entry()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EntryHandleMutError {
+    /// The blackboard has no entry with the provided key.
+    EntryDoesNotExist,
+    /// The entry exists but stores a value of a different type.
+    IncompatibleValueType,
+    /// There exists already an [`EntryHandleMut`] for the entry.
+    HandleAlreadyExists,
+}
+
+impl std::fmt::Display for EntryHandleMutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "EntryHandleMutError::{:?}", self)
+    }
+}
+
+impl std::error::Error for EntryHandleMutError {}
+
+/// Updates the entries of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service. Every blackboard has at most one [`Writer`].
+#[derive(Debug)]
+pub struct Writer<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static> {
+    dynamic_writer_handle: Option<ContainerHandle>,
+    service_state: Arc<ServiceState<Service>>,
+    writer_id: UniqueWriterId,
+    entry_handles: Vec<IoxAtomicBool>,
+    _key: PhantomData<KeyType>,
+}
+
+impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static> Drop
+    for Writer<Service, KeyType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_writer_handle {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .release_writer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static>
+    Writer<Service, KeyType>
+{
+    pub(crate) fn new(service: &Service) -> Result<Self, WriterCreateError> {
+        let msg = "Failed to create Writer port";
+        let origin = "Writer::new()";
+        let writer_id = UniqueWriterId::new();
+        let static_config = service.__internal_state().static_config.blackboard();
+
+        let mut new_self = Self {
+            dynamic_writer_handle: None,
+            service_state: service.__internal_state().clone(),
+            writer_id,
+            entry_handles: (0..static_config.number_of_entries())
+                .map(|_| IoxAtomicBool::new(false))
+                .collect(),
+            _key: PhantomData,
+        };
+
+        let dynamic_writer_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .add_writer_id(WriterDetails {
+                writer_id,
+                node_id: *service.__internal_state().shared_node.id(),
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with WriterCreateError::ExceedsMaxSupportedWriters,
+                    "{} since it would exceed the maximum supported amount of writers of {}.",
+                    msg, static_config.max_writers());
+            }
+        };
+
+        new_self.dynamic_writer_handle = Some(dynamic_writer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueWriterId`] of the [`Writer`]
+    pub fn id(&self) -> UniqueWriterId {
+        self.writer_id
+    }
+
+    /// Acquires an [`EntryHandleMut`] for the entry with the provided key. There can be at most
+    /// one [`EntryHandleMut`] per entry at the same time.
+    pub fn entry<ValueType: Copy + Debug + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandleMut<'_, Service, KeyType, ValueType>, EntryHandleMutError> {
+        let msg = "Unable to acquire entry handle";
+        let static_config = self.service_state.static_config.blackboard();
+        let payload_start_address = self
+            .service_state
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .payload_start_address();
+
+        let index = match unsafe { static_config.entry_index(payload_start_address, key) } {
+            Some(index) => index,
+            None => {
+                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                    "{} since no entry with the key {:?} exists.", msg, key);
+            }
+        };
+
+        let details = &static_config.entries[index];
+        let required_type = TypeDetail::__internal_new::<ValueType>(TypeVariant::FixedSize);
+        if details.value_type_details != required_type {
+            fail!(from self, with EntryHandleMutError::IncompatibleValueType,
+                "{} since the entry with the key {:?} stores the type {:?} but {:?} was requested.",
+                msg, key, details.value_type_details, required_type);
+        }
+
+        if self.entry_handles[index]
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            fail!(from self, with EntryHandleMutError::HandleAlreadyExists,
+                "{} since a handle for the entry with the key {:?} already exists.", msg, key);
+        }
+
+        let atomic = unsafe {
+            &*(payload_start_address.add(details.value_offset)
+                as *const UnrestrictedAtomic<ValueType>)
+        };
+
+        let producer = match atomic.acquire_producer() {
+            Some(producer) => producer,
+            None => {
+                // only one writer can exist and it owns no handle for this entry, therefore
+                // the producer was not released by a previous writer that died
+                unsafe { atomic.__internal_release_producer() };
+                fatal_panic!(from self, when atomic.acquire_producer().ok_or(()),
+                    "This should never happen! {} since the producer of the entry with the key {:?} could not be acquired.",
+                    msg, key)
+            }
+        };
+
+        Ok(EntryHandleMut {
+            producer: ManuallyDrop::new(producer),
+            writer: self,
+            index,
+        })
+    }
+}
+
+/// Writes new values into a single entry of the blackboard. Acquired with [`Writer::entry()`].
+pub struct EntryHandleMut<
+    'writer,
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+    ValueType: Copy + Debug + 'static,
+> {
+    producer: ManuallyDrop<Producer<'writer, ValueType>>,
+    writer: &'writer Writer<Service, KeyType>,
+    index: usize,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > Debug for EntryHandleMut<'_, Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandleMut<{}, {}, {}> {{ writer_id: {:?}, index: {} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<KeyType>(),
+            core::any::type_name::<ValueType>(),
+            self.writer.writer_id,
+            self.index
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > Drop for EntryHandleMut<'_, Service, KeyType, ValueType>
+{
+    fn drop(&mut self) {
+        // the producer must be released before the entry is marked as free, otherwise a new
+        // handle could try to acquire the producer while it is still in use
+        unsafe { ManuallyDrop::drop(&mut self.producer) };
+        self.writer.entry_handles[self.index].store(false, Ordering::Release);
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+        ValueType: Copy + Debug + 'static,
+    > EntryHandleMut<'_, Service, KeyType, ValueType>
+{
+    /// Replaces the value of the entry with a copy of the provided value. All
+    /// [`Reader`](crate::port::reader::Reader)s will read the new value from now on.
+    pub fn update_with_copy(&self, value: ValueType) {
+        self.producer.store(value);
+    }
+}
@@ -16,10 +16,12 @@ pub use crate::port::event_id::EventId;
 pub use crate::service::messaging_pattern::MessagingPattern;
 pub use crate::service::{
     attribute::AttributeSet, attribute::AttributeSpecifier, attribute::AttributeVerifier, ipc,
-    local, port_factory::publisher::UnableToDeliverStrategy, port_factory::PortFactory,
+    local, port_factory::publisher::AllocationStrategy,
+    port_factory::publisher::UnableToDeliverStrategy, port_factory::PortFactory,
     service_name::ServiceName, Service,
 };
-pub use iceoryx2_bb_derive_macros::PlacementDefault;
+pub use iceoryx2_bb_derive_macros::{PlacementDefault, ZeroCopySend};
 pub use iceoryx2_bb_elementary::alignment::Alignment;
 pub use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+pub use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 pub use iceoryx2_bb_elementary::CallbackProgression;
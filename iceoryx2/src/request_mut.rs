@@ -0,0 +1,249 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//!
+//! let mut request = client.loan()?;
+//! *request.payload_mut() = 1234;
+//! println!("request id: {}", request.header().request_id());
+//!
+//! let pending_response = request.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    mem::MaybeUninit,
+    sync::Arc,
+};
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    pending_response::PendingResponse,
+    port::client::{ClientSharedState, RequestSendError},
+    raw_sample::RawSampleMut,
+    service::header::request_response::RequestHeader,
+};
+
+/// Acquired by a [`Client`](crate::port::client::Client) via
+///  * [`Client::loan()`](crate::port::client::Client::loan())
+///  * [`Client::loan_uninit()`](crate::port::client::Client::loan_uninit())
+///
+/// It stores the payload of the request that will be sent to all connected
+/// [`Server`](crate::port::server::Server)s. If the [`RequestMut`] is not sent it will
+/// release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent requests in the
+/// [`Client`](crate::port::client::Client) and the [`Client`](crate::port::client::Client)
+/// is not thread-safe!
+///
+/// The generic parameter `M` is either a `RequestPayload` or a
+/// [`core::mem::MaybeUninit<RequestPayload>`], depending which API is used to obtain the request.
+pub struct RequestMut<Service: crate::service::Service, M: Debug, ResponsePayload: Debug> {
+    shared_state: Arc<ClientSharedState<Service>>,
+    ptr: RawSampleMut<RequestHeader, (), M>,
+    offset_to_chunk: PointerOffset,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: crate::service::Service, M: Debug, ResponsePayload: Debug> Debug
+    for RequestMut<Service, M, ResponsePayload>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RequestMut<{}, {}, {}> {{ shared_state: {:?}, offset_to_chunk: {:?} }}",
+            core::any::type_name::<M>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<Service>(),
+            self.shared_state,
+            self.offset_to_chunk
+        )
+    }
+}
+
+impl<Service: crate::service::Service, M: Debug, ResponsePayload: Debug> Drop
+    for RequestMut<Service, M, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.shared_state
+            .return_loaned_request(self.offset_to_chunk);
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    RequestMut<Service, MaybeUninit<RequestPayload>, ResponsePayload>
+{
+    pub(crate) fn new(
+        shared_state: &Arc<ClientSharedState<Service>>,
+        ptr: RawSampleMut<RequestHeader, (), MaybeUninit<RequestPayload>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            shared_state: Arc::clone(shared_state),
+            ptr,
+            offset_to_chunk,
+            _response_payload: PhantomData,
+        }
+    }
+
+    /// Writes the payload to the request and labels the request as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(1234);
+    ///
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(
+        mut self,
+        value: RequestPayload,
+    ) -> RequestMut<Service, RequestPayload, ResponsePayload> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<RequestPayload>`] container and
+    /// labels the request as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<RequestPayload>`] really is
+    /// initialized. Calling this when the content is not fully initialized causes immediate
+    /// undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let mut request = client.loan_uninit()?;
+    /// request.payload_mut().write(1234);
+    /// let request = unsafe { request.assume_init() };
+    ///
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> RequestMut<Service, RequestPayload, ResponsePayload> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        M: Debug, // `M` is either a `RequestPayload` or a `MaybeUninit<RequestPayload>`
+        ResponsePayload: Debug,
+    > RequestMut<Service, M, ResponsePayload>
+{
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the request.
+    pub fn payload(&self) -> &M {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the request.
+    pub fn payload_mut(&mut self) -> &mut M {
+        self.ptr.as_payload_mut()
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    RequestMut<Service, RequestPayload, ResponsePayload>
+{
+    /// Sends the request to all connected [`Server`](crate::port::server::Server)s and returns
+    /// the [`PendingResponse`] that receives the corresponding responses. On failure a
+    /// [`RequestSendError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let mut request = client.loan()?;
+    /// *request.payload_mut() = 4567;
+    ///
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send(
+        self,
+    ) -> Result<PendingResponse<Service, RequestPayload, ResponsePayload>, RequestSendError> {
+        let request_id = self.header().request_id();
+        let number_of_server_connections = self
+            .shared_state
+            .send_request(self.offset_to_chunk, request_id)?;
+
+        Ok(PendingResponse::new(
+            &self.shared_state,
+            request_id,
+            number_of_server_connections,
+        ))
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//! let pending_response = client.send_copy(1234)?;
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received: {:?}", *response);
+//!     println!("header: {:?}", response.header());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::{fmt::Debug, ops::Deref};
+
+use iceoryx2_cal::zero_copy_connection::PointerOffset;
+
+use crate::port::client::ClientSharedState;
+use crate::port::details::server_connections::Connection;
+use crate::port::port_identifiers::UniqueServerId;
+use crate::raw_sample::RawSample;
+use crate::service::header::request_response::ResponseHeader;
+
+/// It stores the payload of a response and is acquired by the
+/// [`PendingResponse`](crate::pending_response::PendingResponse) via
+/// [`PendingResponse::receive()`](crate::pending_response::PendingResponse::receive()).
+/// The response is released as soon as the [`Response`] goes out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases the response in the underlying connection of
+/// the [`Client`](crate::port::client::Client) and the
+/// [`Client`](crate::port::client::Client) is not thread-safe!
+pub struct Response<Service: crate::service::Service, ResponsePayload: Debug> {
+    pub(crate) ptr: RawSample<ResponseHeader, (), ResponsePayload>,
+    pub(crate) client_shared_state: Arc<ClientSharedState<Service>>,
+    pub(crate) request_id: u64,
+    pub(crate) connection: Arc<Connection<Service>>,
+    pub(crate) offset: PointerOffset,
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Debug
+    for Response<Service, ResponsePayload>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Response<{}, {}> {{ connection: {:?}, offset: {:?} }}",
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<Service>(),
+            self.connection,
+            self.offset
+        )
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Deref
+    for Response<Service, ResponsePayload>
+{
+    type Target = ResponsePayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Drop
+    for Response<Service, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.client_shared_state.release_borrowed_response(
+            self.request_id,
+            &self.connection,
+            self.offset,
+        );
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Response<Service, ResponsePayload> {
+    /// Returns a reference to the payload of the [`Response`]
+    pub fn payload(&self) -> &ResponsePayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a reference to the [`ResponseHeader`] of the [`Response`].
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`](crate::port::server::Server) that sent
+    /// the [`Response`].
+    pub fn origin(&self) -> UniqueServerId {
+        self.connection.server_id
+    }
+}
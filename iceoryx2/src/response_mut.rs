@@ -0,0 +1,244 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     let mut response = active_request.loan()?;
+//!     *response.payload_mut() = 1234;
+//!     println!("response for request: {}", response.header().request_id());
+//!
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt::{Debug, Formatter},
+    mem::MaybeUninit,
+    sync::Arc,
+};
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    port::{details::client_connections::Connection, server::ServerSharedState, SendError},
+    raw_sample::RawSampleMut,
+    service::header::request_response::ResponseHeader,
+};
+
+/// Acquired by an [`ActiveRequest`](crate::active_request::ActiveRequest) via
+///  * [`ActiveRequest::loan()`](crate::active_request::ActiveRequest::loan())
+///  * [`ActiveRequest::loan_uninit()`](crate::active_request::ActiveRequest::loan_uninit())
+///
+/// It stores the payload of the response that will be sent to the
+/// [`Client`](crate::port::client::Client) that sent the request. If the [`ResponseMut`] is
+/// not sent it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent responses in the
+/// [`Server`](crate::port::server::Server) and the [`Server`](crate::port::server::Server)
+/// is not thread-safe!
+///
+/// The generic parameter `M` is either a `ResponsePayload` or a
+/// [`core::mem::MaybeUninit<ResponsePayload>`], depending which API is used to obtain the
+/// response.
+pub struct ResponseMut<Service: crate::service::Service, M: Debug> {
+    shared_state: Arc<ServerSharedState<Service>>,
+    connection: Arc<Connection<Service>>,
+    connection_index: usize,
+    ptr: RawSampleMut<ResponseHeader, (), M>,
+    offset_to_chunk: PointerOffset,
+}
+
+impl<Service: crate::service::Service, M: Debug> Debug for ResponseMut<Service, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ResponseMut<{}, {}> {{ connection: {:?}, connection_index: {}, offset_to_chunk: {:?} }}",
+            core::any::type_name::<M>(),
+            core::any::type_name::<Service>(),
+            self.connection,
+            self.connection_index,
+            self.offset_to_chunk
+        )
+    }
+}
+
+impl<Service: crate::service::Service, M: Debug> Drop for ResponseMut<Service, M> {
+    fn drop(&mut self) {
+        self.shared_state
+            .return_loaned_response(self.offset_to_chunk);
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug>
+    ResponseMut<Service, MaybeUninit<ResponsePayload>>
+{
+    pub(crate) fn new(
+        shared_state: &Arc<ServerSharedState<Service>>,
+        connection: &Arc<Connection<Service>>,
+        connection_index: usize,
+        ptr: RawSampleMut<ResponseHeader, (), MaybeUninit<ResponsePayload>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            shared_state: Arc::clone(shared_state),
+            connection: Arc::clone(connection),
+            connection_index,
+            ptr,
+            offset_to_chunk,
+        }
+    }
+
+    /// Writes the payload to the response and labels the response as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let response = active_request.loan_uninit()?;
+    ///     let response = response.write_payload(1234);
+    ///     response.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(
+        mut self,
+        value: ResponsePayload,
+    ) -> ResponseMut<Service, ResponsePayload> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<ResponsePayload>`] container and
+    /// labels the response as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<ResponsePayload>`] really is
+    /// initialized. Calling this when the content is not fully initialized causes immediate
+    /// undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let mut response = active_request.loan_uninit()?;
+    ///     response.payload_mut().write(1234);
+    ///     let response = unsafe { response.assume_init() };
+    ///     response.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> ResponseMut<Service, ResponsePayload> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        M: Debug, // `M` is either a `ResponsePayload` or a `MaybeUninit<ResponsePayload>`
+    > ResponseMut<Service, M>
+{
+    /// Returns a reference to the [`ResponseHeader`] of the response.
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the response.
+    pub fn payload(&self) -> &M {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the response.
+    pub fn payload_mut(&mut self) -> &mut M {
+        self.ptr.as_payload_mut()
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug>
+    ResponseMut<Service, ResponsePayload>
+{
+    /// Sends the response to the [`Client`](crate::port::client::Client) that sent the
+    /// corresponding request. On failure a [`SendError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let mut response = active_request.loan()?;
+    ///     *response.payload_mut() = 4567;
+    ///     response.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send(self) -> Result<(), SendError> {
+        self.shared_state.send_response(
+            self.offset_to_chunk,
+            &self.connection,
+            self.connection_index,
+        )
+    }
+}
@@ -150,7 +150,7 @@ impl AttributeSpecifier {
 
 /// Represents the set of [`Attribute`]s that are required when the [`crate::service::Service`]
 /// is opened.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AttributeVerifier {
     attribute_set: AttributeSet,
     required_keys: Vec<String>,
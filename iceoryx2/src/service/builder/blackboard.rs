@@ -0,0 +1,622 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     // the entries are only used when the service is created
+//!     .add::<i32>(0, -1)
+//!     .add::<u64>(1, 0)
+//!     .max_readers(4)
+//!     .open_or_create()?;
+//!
+//! let writer = blackboard.writer_builder().create()?;
+//! let reader = blackboard.reader_builder().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::discovery::{notify_discovery_listeners, DiscoveryTrigger};
+use crate::service;
+use crate::service::builder::OpenDynamicStorageFailure;
+use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
+use crate::service::port_factory::blackboard;
+use crate::service::static_config::blackboard::EntryDetails;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::*;
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
+
+use self::attribute::{AttributeSpecifier, AttributeVerifier};
+
+use super::ServiceState;
+
+/// Failures that can occur when an existing [`MessagingPattern::Blackboard`] [`Service`] shall
+/// be opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackboardOpenError {
+    /// The [`Service`] does not exist.
+    DoesNotExist,
+    /// The process has not enough permissions to open the [`Service`]
+    InsufficientPermissions,
+    /// Some underlying resources of the [`Service`] do not exist which indicate a corrupted
+    /// [`Service`]state.
+    ServiceInCorruptedState,
+    /// The [`Service`] has the wrong messaging pattern.
+    IncompatibleMessagingPattern,
+    /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
+    IncompatibleAttributes,
+    /// The [`Service`] uses a different key type.
+    IncompatibleKeyType,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+    /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
+    /// by a process that crashed during [`Service`] creation.
+    HangsInCreation,
+    /// The [`Service`] supports less [`Reader`](crate::port::reader::Reader)s than requested.
+    DoesNotSupportRequestedAmountOfReaders,
+    /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
+    DoesNotSupportRequestedAmountOfNodes,
+    /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
+    ExceedsMaxNumberOfNodes,
+    /// The [`Service`] is marked for destruction and currently cleaning up since no one is using it anymore.
+    /// When the call creation call is repeated with a little delay the [`Service`] should be
+    /// recreatable.
+    IsMarkedForDestruction,
+}
+
+impl std::fmt::Display for BlackboardOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "BlackboardOpenError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenError {}
+
+impl From<ServiceState> for BlackboardOpenError {
+    fn from(value: ServiceState) -> Self {
+        match value {
+            ServiceState::IncompatibleMessagingPattern => {
+                BlackboardOpenError::IncompatibleMessagingPattern
+            }
+            ServiceState::InsufficientPermissions => BlackboardOpenError::InsufficientPermissions,
+            ServiceState::HangsInCreation => BlackboardOpenError::HangsInCreation,
+            ServiceState::Corrupted => BlackboardOpenError::ServiceInCorruptedState,
+        }
+    }
+}
+
+/// Failures that can occur when a new [`MessagingPattern::Blackboard`] [`Service`] shall be
+/// created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackboardCreateError {
+    /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
+    ServiceInCorruptedState,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+    /// Multiple processes are trying to create the same [`Service`].
+    IsBeingCreatedByAnotherInstance,
+    /// The [`Service`] already exists.
+    AlreadyExists,
+    /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
+    /// by a process that crashed during [`Service`] creation.
+    HangsInCreation,
+    /// The process has insufficient permissions to create the [`Service`].
+    InsufficientPermissions,
+    /// No entries were added with [`Builder::add()`]. A blackboard requires at least one entry.
+    NoEntriesProvided,
+}
+
+impl std::fmt::Display for BlackboardCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "BlackboardCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BlackboardCreateError {}
+
+impl From<ServiceState> for BlackboardCreateError {
+    fn from(value: ServiceState) -> Self {
+        match value {
+            ServiceState::IncompatibleMessagingPattern => BlackboardCreateError::AlreadyExists,
+            ServiceState::InsufficientPermissions => BlackboardCreateError::InsufficientPermissions,
+            ServiceState::HangsInCreation => BlackboardCreateError::HangsInCreation,
+            ServiceState::Corrupted => BlackboardCreateError::ServiceInCorruptedState,
+        }
+    }
+}
+
+/// Failures that can occur when a [`MessagingPattern::Blackboard`] [`Service`] shall be opened
+/// or created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BlackboardOpenOrCreateError {
+    /// Failures that can occur when a blackboard [`Service`] is opened.
+    BlackboardOpenError(BlackboardOpenError),
+    /// Failures that can occur when a blackboard [`Service`] is created.
+    BlackboardCreateError(BlackboardCreateError),
+}
+
+impl From<BlackboardOpenError> for BlackboardOpenOrCreateError {
+    fn from(value: BlackboardOpenError) -> Self {
+        BlackboardOpenOrCreateError::BlackboardOpenError(value)
+    }
+}
+
+impl From<BlackboardCreateError> for BlackboardOpenOrCreateError {
+    fn from(value: BlackboardCreateError) -> Self {
+        BlackboardOpenOrCreateError::BlackboardCreateError(value)
+    }
+}
+
+impl std::fmt::Display for BlackboardOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "BlackboardOpenOrCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenOrCreateError {}
+
+impl From<ServiceState> for BlackboardOpenOrCreateError {
+    fn from(value: ServiceState) -> Self {
+        BlackboardOpenOrCreateError::BlackboardOpenError(value.into())
+    }
+}
+
+struct BuilderEntry<KeyType> {
+    key: KeyType,
+    value_type_details: TypeDetail,
+    value_layout: Layout,
+    value_writer: Box<dyn Fn(*mut u8)>,
+}
+
+impl<KeyType: Debug> Debug for BuilderEntry<KeyType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BuilderEntry {{ key: {:?}, value_type_details: {:?}, value_layout: {:?} }}",
+            self.key, self.value_type_details, self.value_layout
+        )
+    }
+}
+
+/// Builder to create new [`MessagingPattern::Blackboard`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<
+    KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+    ServiceType: service::Service,
+> {
+    base: builder::BuilderWithServiceType<ServiceType>,
+    entries: Vec<BuilderEntry<KeyType>>,
+    verify_max_readers: bool,
+    verify_max_nodes: bool,
+    _key: PhantomData<KeyType>,
+}
+
+impl<KeyType: Send + Sync + Eq + Copy + Debug + 'static, ServiceType: service::Service>
+    Builder<KeyType, ServiceType>
+{
+    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            entries: vec![],
+            verify_max_readers: false,
+            verify_max_nodes: false,
+            _key: PhantomData,
+        };
+
+        new_self.base.service_config.messaging_pattern = MessagingPattern::Blackboard(
+            static_config::blackboard::StaticConfig::new(new_self.base.shared_node.config()),
+        );
+        new_self.config_details().key_type_details =
+            TypeDetail::__internal_new::<KeyType>(TypeVariant::FixedSize);
+
+        new_self
+    }
+
+    fn config_details(&mut self) -> &mut static_config::blackboard::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
+            }
+        }
+    }
+
+    /// Adds an entry with the provided key and the initial value to the blackboard. If an
+    /// entry with the same key was already added, it is replaced. The entries are only used
+    /// when the [`Service`] is created, when an existing [`Service`] is opened they are ignored.
+    pub fn add<ValueType: Copy + Debug + 'static>(
+        mut self,
+        key: KeyType,
+        initial_value: ValueType,
+    ) -> Self {
+        self.entries.retain(|entry| entry.key != key);
+        self.entries.push(BuilderEntry {
+            key,
+            value_type_details: TypeDetail::__internal_new::<ValueType>(TypeVariant::FixedSize),
+            value_layout: Layout::new::<UnrestrictedAtomic<ValueType>>(),
+            value_writer: Box::new(move |ptr: *mut u8| unsafe {
+                (ptr as *mut UnrestrictedAtomic<ValueType>)
+                    .write(UnrestrictedAtomic::new(initial_value))
+            }),
+        });
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`Node`](crate::node::Node)s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.config_details().max_nodes = value;
+        self.verify_max_nodes = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::reader::Reader`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::reader::Reader`] must be at least supported.
+    pub fn max_readers(mut self, value: usize) -> Self {
+        self.config_details().max_readers = value;
+        self.verify_max_readers = true;
+        self
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes. If the [`Service`] already exists all attribute
+    /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
+    /// does not exist the required attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        let msg = "Unable to open or create blackboard service";
+
+        loop {
+            match self.base.is_service_available(msg)? {
+                Some(_) => return Ok(self.open_with_attributes(required_attributes)?),
+                None => {
+                    match self.create_impl(&AttributeSpecifier(
+                        required_attributes.attributes().clone(),
+                    )) {
+                        Ok(factory) => return Ok(factory),
+                        Err(BlackboardCreateError::AlreadyExists)
+                        | Err(BlackboardCreateError::IsBeingCreatedByAnotherInstance) => {
+                            continue;
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
+        const OPEN_RETRY_LIMIT: usize = 5;
+        let msg = "Unable to open blackboard service";
+
+        let mut service_open_retry_count = 0;
+        loop {
+            match self.base.is_service_available(msg)? {
+                None => {
+                    fail!(from self, with BlackboardOpenError::DoesNotExist,
+                        "{} since the blackboard does not exist.", msg);
+                }
+                Some((static_config, static_storage)) => {
+                    let blackboard_static_config =
+                        self.verify_service_attributes(&static_config, required_attributes)?;
+
+                    let service_tag = self
+                        .base
+                        .create_node_service_tag(msg, BlackboardOpenError::InternalFailure)?;
+
+                    let dynamic_config = match self.base.open_dynamic_config_storage() {
+                        Ok(v) => v,
+                        Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
+                            fail!(from self, with BlackboardOpenError::IsMarkedForDestruction,
+                                "{} since the service is marked for destruction.", msg);
+                        }
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with BlackboardOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(e) => {
+                            if self.base.is_service_available(msg)?.is_none() {
+                                fail!(from self, with BlackboardOpenError::DoesNotExist,
+                                    "{} since the blackboard does not exist.", msg);
+                            }
+
+                            service_open_retry_count += 1;
+
+                            if OPEN_RETRY_LIMIT < service_open_retry_count {
+                                fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                                "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
+                                msg, e);
+                            }
+
+                            continue;
+                        }
+                    };
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::Blackboard(blackboard_static_config);
+
+                    if let Some(mut service_tag) = service_tag {
+                        service_tag.release_ownership();
+                    }
+
+                    return Ok(blackboard::PortFactory::new(
+                        ServiceType::__internal_from_state(service::ServiceState::new(
+                            static_config,
+                            self.base.shared_node,
+                            dynamic_config,
+                            static_storage,
+                        )),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.create_impl(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.create_impl(attributes)
+    }
+
+    fn create_impl(
+        &mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.adjust_attributes_to_meaningful_values();
+
+        let msg = "Unable to create blackboard service";
+
+        if self.entries.is_empty() {
+            fail!(from self, with BlackboardCreateError::NoEntriesProvided,
+                "{} since no entries were added to the blackboard.", msg);
+        }
+
+        match self.base.is_service_available(msg)? {
+            None => {
+                let service_tag = self
+                    .base
+                    .create_node_service_tag(msg, BlackboardCreateError::InternalFailure)?;
+
+                let static_config = match self.base.create_static_config_storage() {
+                    Ok(c) => c,
+                    Err(StaticStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with BlackboardCreateError::AlreadyExists,
+                           "{} since the service already exists.", msg);
+                    }
+                    Err(StaticStorageCreateError::Creation) => {
+                        fail!(from self, with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                            "{} since the service is being created by another instance.", msg);
+                    }
+                    Err(StaticStorageCreateError::InsufficientPermissions) => {
+                        fail!(from self, with BlackboardCreateError::InsufficientPermissions,
+                            "{} since the static service information could not be created due to insufficient permissions.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with BlackboardCreateError::InternalFailure,
+                            "{} since the static service information could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                self.calculate_entry_layout();
+                let blackboard_config = self.base.service_config.blackboard();
+
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_readers: blackboard_config.max_readers,
+                    number_of_writers: blackboard_config.max_writers,
+                    payload_size: blackboard_config.payload_size,
+                    payload_alignment: blackboard_config.payload_alignment,
+                };
+
+                let dynamic_config = match self.base.create_dynamic_config_storage(
+                    dynamic_config::MessagingPattern::Blackboard(
+                        dynamic_config::blackboard::DynamicConfig::new(&dynamic_config_setting),
+                    ),
+                    dynamic_config::blackboard::DynamicConfig::memory_size(&dynamic_config_setting),
+                    blackboard_config.max_nodes,
+                ) {
+                    Ok(dynamic_config) => dynamic_config,
+                    Err(DynamicStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with BlackboardCreateError::ServiceInCorruptedState,
+                            "{} since there exist an old dynamic config from a previous instance of the service.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with BlackboardCreateError::InternalFailure,
+                            "{} since the dynamic service segment could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                // the entries must be initialized before the static config is written,
+                // otherwise other instances could access uninitialized entries
+                let payload_start_address =
+                    dynamic_config.get().blackboard().payload_start_address();
+                for (details, entry) in blackboard_config.entries.iter().zip(self.entries.iter()) {
+                    unsafe {
+                        (payload_start_address.add(details.key_offset) as *mut KeyType)
+                            .write(entry.key);
+                        (entry.value_writer)(payload_start_address.add(details.value_offset));
+                    }
+                }
+
+                self.base.service_config.attributes = attributes.0.clone();
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                                            with BlackboardCreateError::ServiceInCorruptedState,
+                                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let mut unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with BlackboardCreateError::ServiceInCorruptedState,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                unlocked_static_details.release_ownership();
+                if let Some(mut service_tag) = service_tag {
+                    service_tag.release_ownership();
+                }
+
+                notify_discovery_listeners::<ServiceType>(
+                    self.base.shared_node.config(),
+                    DiscoveryTrigger::ServicesChanged,
+                );
+
+                Ok(blackboard::PortFactory::new(
+                    ServiceType::__internal_from_state(service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.shared_node.clone(),
+                        dynamic_config,
+                        unlocked_static_details,
+                    )),
+                ))
+            }
+            Some(_) => {
+                fail!(from self, with BlackboardCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+        }
+    }
+
+    fn calculate_entry_layout(&mut self) {
+        let key_layout = Layout::new::<KeyType>();
+        let mut payload_alignment = key_layout.align();
+        let mut position = 0;
+        let mut entries = vec![];
+
+        for entry in &self.entries {
+            let key_offset = align(position, key_layout.align());
+            position = key_offset + key_layout.size();
+            let value_offset = align(position, entry.value_layout.align());
+            position = value_offset + entry.value_layout.size();
+            payload_alignment = payload_alignment.max(entry.value_layout.align());
+
+            entries.push(EntryDetails {
+                value_type_details: entry.value_type_details.clone(),
+                key_offset,
+                value_offset,
+            });
+        }
+
+        let config = self.config_details();
+        config.entries = entries;
+        config.payload_size = position.max(1);
+        config.payload_alignment = payload_alignment;
+    }
+
+    fn adjust_attributes_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.blackboard_mut();
+
+        if settings.max_readers == 0 {
+            warn!(from origin, "Setting the maximum amount of readers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_readers = 1;
+        }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin, "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
+    }
+
+    fn verify_service_attributes(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<static_config::blackboard::StaticConfig, BlackboardOpenError> {
+        let msg = "Unable to open blackboard";
+
+        let existing_attributes = existing_settings.attributes();
+        if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
+        {
+            fail!(from self, with BlackboardOpenError::IncompatibleAttributes,
+                "{} due to incompatible service attribute key {}. The following attributes {:?} are required but the service has the attributes {:?}.",
+                msg, incompatible_key, required_attributes, existing_attributes);
+        }
+
+        let required_settings = self.base.service_config.blackboard();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            p => {
+                fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Blackboard is required.", msg, p);
+            }
+        };
+
+        if existing_settings.key_type_details != required_settings.key_type_details {
+            fail!(from self, with BlackboardOpenError::IncompatibleKeyType,
+                "{} since the service uses the key type {:?} but the key type {:?} is required.",
+                msg, existing_settings.key_type_details, required_settings.key_type_details);
+        }
+
+        if self.verify_max_readers && existing_settings.max_readers < required_settings.max_readers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
+                "{} since the blackboard supports only {} readers but a support of {} readers was requested.",
+                msg, existing_settings.max_readers, required_settings.max_readers);
+        }
+
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes,
+                "{} since the blackboard supports only {} nodes but {} are required.",
+                msg, existing_settings.max_nodes, required_settings.max_nodes);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
@@ -14,6 +14,7 @@
 //!
 //! See [`crate::service`]
 //!
+use crate::discovery::{notify_discovery_listeners, DiscoveryTrigger};
 pub use crate::port::event_id::EventId;
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::port_factory::event;
@@ -432,6 +433,11 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     service_tag.release_ownership();
                 }
 
+                notify_discovery_listeners::<ServiceType>(
+                    self.base.shared_node.config(),
+                    DiscoveryTrigger::ServicesChanged,
+                );
+
                 Ok(event::PortFactory::new(ServiceType::__internal_from_state(
                     service::ServiceState::new(
                         self.base.service_config.clone(),
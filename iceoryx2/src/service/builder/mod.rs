@@ -14,12 +14,22 @@
 //!
 //! See [`crate::service`]
 
+/// Builder for [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+pub mod blackboard;
+
 /// Builder for [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 pub mod event;
 
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// with serialized payloads
+pub mod publish_subscribe_serialized;
+
+/// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+pub mod request_response;
+
 use crate::node::SharedNode;
 use crate::service;
 use crate::service::dynamic_config::DynamicConfig;
@@ -29,10 +39,12 @@ use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::user::User;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
 use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::named_concept::NamedConceptConfiguration;
 use iceoryx2_cal::named_concept::NamedConceptDoesExistError;
 use iceoryx2_cal::named_concept::NamedConceptMgmt;
 use iceoryx2_cal::serialize::Serialize;
@@ -47,6 +59,9 @@ use super::config_scheme::static_config_storage_config;
 use super::service_name::ServiceName;
 use super::Service;
 
+pub use iceoryx2_bb_posix::permission::Permission;
+pub use iceoryx2_cal::access_policy::{AccessPolicy, AclEntry};
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ServiceState {
     IncompatibleMessagingPattern,
@@ -98,6 +113,7 @@ impl std::error::Error for ReadStaticStorageFailure {}
 pub struct Builder<S: Service> {
     name: ServiceName,
     shared_node: Arc<SharedNode<S>>,
+    access_policy: AccessPolicy,
     _phantom_s: PhantomData<S>,
 }
 
@@ -106,10 +122,50 @@ impl<S: Service> Builder<S> {
         Self {
             name: name.clone(),
             shared_node,
+            access_policy: AccessPolicy::default(),
             _phantom_s: PhantomData,
         }
     }
 
+    /// Defines the [`AccessPolicy`] of the [`Service`]. It is applied to all underlying
+    /// resources of the [`Service`] when it is created and every process that opens the
+    /// [`Service`] must be granted access by it, otherwise the open call fails with an
+    /// `InsufficientPermissions` error. When an existing [`Service`] is opened, its
+    /// [`AccessPolicy`] is used and the one defined here is ignored.
+    ///
+    /// The check when opening a [`Service`] only lets a process fail early, the access is
+    /// enforced by the file-system permissions of the underlying resources. Resources that
+    /// are created later by another user that is granted access, like the data segments and
+    /// connections of its ports, remain owned by this user when it is not privileged. The
+    /// owner and the owning group of the policy gain access to them via the access control list,
+    /// which requires the `acl` feature, without it creating these resources fails, see
+    /// [`AccessPolicy::applicable_by()`](iceoryx2_cal::access_policy::AccessPolicy::applicable_by()).
+    ///
+    /// By default, a [`Service`] is only accessible by the user that created it.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::builder::{AccessPolicy, Permission};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    ///
+    /// // every user can read and write the service
+    /// let service = node
+    ///     .service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .access_policy(
+    ///         &AccessPolicy::new().permission(Permission::OTHERS_READ | Permission::OTHERS_WRITE),
+    ///     )
+    ///     .event()
+    ///     .open_or_create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn access_policy(mut self, value: &AccessPolicy) -> Self {
+        self.access_policy = value.clone();
+        self
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
     pub fn publish_subscribe<PayloadType: Debug + ?Sized>(
@@ -121,19 +177,120 @@ impl<S: Service> Builder<S> {
                 self.shared_node.config(),
             ),
             self.shared_node,
+            self.access_policy,
         )
         .publish_subscribe()
     }
 
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// whose payload type is only known at runtime and described by the provided
+    /// [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail). The
+    /// ports loan and receive the payload as `[u8]`, one element of the described type
+    /// corresponds to [`TypeDetail::size`](crate::service::static_config::message_type_details::TypeDetail::size)
+    /// bytes. The same type compatibility checks as for the typed API apply when the
+    /// [`Service`] is opened. Fails when the
+    /// [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail)
+    /// describe an invalid type.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    ///
+    /// let payload_type = TypeDetail {
+    ///     variant: TypeVariant::FixedSize,
+    ///     type_name: "MyRuntimeType".to_string(),
+    ///     size: 16,
+    ///     alignment: 8,
+    ///     type_layout_hash: None,
+    /// };
+    ///
+    /// let service = node
+    ///     .service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe_with_type_details(&payload_type)?
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// let sample = publisher.loan_slice_uninit(1)?;
+    /// let sample = sample.write_from_fn(|byte| byte as u8);
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn publish_subscribe_with_type_details(
+        self,
+        payload_type_details: &message_type_details::TypeDetail,
+    ) -> Result<
+        publish_subscribe::Builder<[u8], publish_subscribe::CustomHeaderMarker, S>,
+        message_type_details::TypeDetailError,
+    > {
+        self.publish_subscribe::<[u8]>()
+            .user_header::<publish_subscribe::CustomHeaderMarker>()
+            .with_payload_type_details(payload_type_details)
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// whose `Payload` is serialized with the provided
+    /// [`Serialize`](iceoryx2_cal::serialize::Serialize) implementation.
+    pub fn publish_subscribe_serialized<
+        Payload: Debug + serde::Serialize + serde::de::DeserializeOwned,
+        Serializer: iceoryx2_cal::serialize::Serialize,
+    >(
+        self,
+    ) -> publish_subscribe_serialized::Builder<Payload, Serializer, S> {
+        publish_subscribe_serialized::Builder::new(
+            self.publish_subscribe::<[u8]>()
+                .user_header::<publish_subscribe::CustomHeaderMarker>(),
+        )
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    pub fn request_response<RequestPayload: Debug, ResponsePayload: Debug>(
+        self,
+    ) -> request_response::Builder<RequestPayload, ResponsePayload, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_request_response::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.shared_node,
+            self.access_policy,
+        )
+        .request_response()
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
         BuilderWithServiceType::new(
             StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
             self.shared_node,
+            self.access_policy,
         )
         .event()
     }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    pub fn blackboard<KeyType: Send + Sync + Eq + Copy + Debug + 'static>(
+        self,
+    ) -> blackboard::Builder<KeyType, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.shared_node,
+            self.access_policy,
+        )
+        .blackboard()
+    }
 }
 
 #[doc(hidden)]
@@ -145,7 +302,12 @@ pub struct BuilderWithServiceType<ServiceType: service::Service> {
 }
 
 impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
-    fn new(service_config: StaticConfig, shared_node: Arc<SharedNode<ServiceType>>) -> Self {
+    fn new(
+        mut service_config: StaticConfig,
+        shared_node: Arc<SharedNode<ServiceType>>,
+        access_policy: AccessPolicy,
+    ) -> Self {
+        service_config.access_policy = access_policy;
         Self {
             service_config,
             shared_node,
@@ -159,10 +321,22 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         publish_subscribe::Builder::new(self)
     }
 
+    fn request_response<RequestPayload: Debug, ResponsePayload: Debug>(
+        self,
+    ) -> request_response::Builder<RequestPayload, ResponsePayload, ServiceType> {
+        request_response::Builder::new(self)
+    }
+
     fn event(self) -> event::Builder<ServiceType> {
         event::Builder::new(self)
     }
 
+    fn blackboard<KeyType: Send + Sync + Eq + Copy + Debug + 'static>(
+        self,
+    ) -> blackboard::Builder<KeyType, ServiceType> {
+        blackboard::Builder::new(self)
+    }
+
     fn is_service_available(
         &self,
         msg: &str,
@@ -199,18 +373,13 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         }
                     };
 
-                let mut read_content =
-                    String::from_utf8(vec![b' '; storage.len() as usize]).expect("");
-                if storage
-                    .read(unsafe { read_content.as_mut_vec() }.as_mut_slice())
-                    .is_err()
-                {
+                let mut read_content = vec![0u8; storage.len() as usize];
+                if storage.read(read_content.as_mut_slice()).is_err() {
                     fail!(from self, with ServiceState::InsufficientPermissions,
                             "{} since it is not possible to read the services underlying static details. Is the service accessible?", msg);
                 }
 
-                let service_config = fail!(from self, when ServiceType::ConfigSerializer::deserialize::<StaticConfig>(unsafe {
-                                            read_content.as_mut_vec() }),
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::deserialize::<StaticConfig>(&read_content),
                                      with ServiceState::Corrupted, "Unable to deserialize the service config. Is the service corrupted?");
 
                 if service_config.service_id() != self.service_config.service_id() {
@@ -225,6 +394,11 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         msg, service_config.messaging_pattern(), self.service_config.messaging_pattern());
                 }
 
+                if !Self::is_access_granted(service_config.access_policy()) {
+                    fail!(from self, with ServiceState::InsufficientPermissions,
+                        "{} since the access policy of the service does not grant access to the current user.", msg);
+                }
+
                 Ok(Some((service_config, storage)))
             }
             Err(v) => {
@@ -234,6 +408,20 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         }
     }
 
+    // verifies the policy only inside the opening process so that it fails early with a
+    // meaningful error, the file-system permissions of the resources are the actual enforcement
+    fn is_access_granted(access_policy: &AccessPolicy) -> bool {
+        // the default policy is enforced by the file-system permissions alone
+        if access_policy.is_default() {
+            return true;
+        }
+
+        match User::from_self() {
+            Ok(user) => access_policy.grants_access_to(&user),
+            Err(_) => false,
+        }
+    }
+
     fn config_init_call(config: &mut DynamicConfig, allocator: &mut BumpAllocator) -> bool {
         unsafe { config.init(allocator) };
         true
@@ -252,7 +440,8 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         >>::Builder<'_> as NamedConceptBuilder<
             ServiceType::DynamicStorage,
         >>::new(&self.service_config.service_id().0.into())
-            .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
+            .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config())
+                .access_policy(self.service_config.access_policy()))
             .supplementary_size(additional_size + required_memory_size)
             .has_ownership(false)
             .initializer(Self::config_init_call)
@@ -331,17 +520,45 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         }
     }
 
+    // every process that opens the service verifies the access policy, therefore the
+    // creating user must be stated explicitly as owner and owning group
+    fn assign_creator_to_access_policy(&mut self) -> Result<(), StaticStorageCreateError> {
+        let access_policy = &self.service_config.access_policy;
+        if access_policy.is_default()
+            || (access_policy.get_owner().is_some() && access_policy.get_group().is_some())
+        {
+            return Ok(());
+        }
+
+        let user = fail!(from self, when User::from_self(),
+            with StaticStorageCreateError::InternalError,
+            "Unable to acquire the current user to complete the access policy of the service.");
+
+        let mut access_policy = self.service_config.access_policy.clone();
+        if access_policy.get_owner().is_none() {
+            access_policy = access_policy.owner(user.uid());
+        }
+        if access_policy.get_group().is_none() {
+            access_policy = access_policy.group(user.gid());
+        }
+        self.service_config.access_policy = access_policy;
+
+        Ok(())
+    }
+
     fn create_static_config_storage(
-        &self,
+        &mut self,
     ) -> Result<<ServiceType::StaticStorage as StaticStorage>::Locked, StaticStorageCreateError>
     {
+        self.assign_creator_to_access_policy()?;
+
         Ok(
             fail!(from self, when <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
                         ServiceType::StaticStorage,
                     >>::new(&self.service_config.service_id().0.into())
                     .config(&static_config_storage_config::<ServiceType>(
                         self.shared_node.config(),
-                    ))
+                    ).access_policy(self.service_config.access_policy()))
                     .has_ownership(true)
                     .create_locked(),
                     "Failed to create static service information since the underlying static storage could not be created."),
@@ -15,14 +15,19 @@
 //! See [`crate::service`]
 //!
 use std::marker::PhantomData;
+use std::time::Duration;
 
+use crate::discovery::{notify_discovery_listeners, DiscoveryTrigger};
 use crate::service;
+use crate::service::builder::event::{EventCreateError, EventOpenError, EventOpenOrCreateError};
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
-use crate::service::port_factory::publish_subscribe;
+use crate::service::port_factory::{event, publish_subscribe};
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::alignment::Alignment;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
@@ -30,13 +35,13 @@ use iceoryx2_cal::static_storage::StaticStorageLocked;
 
 use self::{
     attribute::{AttributeSpecifier, AttributeVerifier},
-    message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant},
+    message_type_details::{MessageTypeDetails, TypeDetail, TypeDetailError, TypeVariant},
 };
 
 use super::{OpenDynamicStorageFailure, ServiceState};
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, ZeroCopySend)]
 #[doc(hidden)]
 pub struct CustomHeaderMarker {}
 
@@ -67,6 +72,10 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required notify on send behavior is not compatible.
+    IncompatibleNotifyOnSendBehavior,
+    /// The [`Service`] required deadline is not compatible.
+    IncompatibleDeadline,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
@@ -204,6 +213,49 @@ impl std::fmt::Display for PublishSubscribeOpenOrCreateError {
 
 impl std::error::Error for PublishSubscribeOpenOrCreateError {}
 
+/// Returns the name of the companion event [`Service`] of a
+/// [`MessagingPattern::PublishSubscribe`] [`Service`] with the provided name. The name is
+/// reserved and cannot be used for services of the user.
+pub(crate) fn notify_on_send_service_name(service_name: &ServiceName) -> ServiceName {
+    service_name.internal("notify_on_send")
+}
+
+/// Opens the companion event [`Service`] of a [`MessagingPattern::PublishSubscribe`]
+/// [`Service`]. If it does not exist it is created with enough notifiers and listeners for
+/// all publishers and subscribers.
+pub(crate) fn open_or_create_notify_on_send_service<ServiceType: service::Service>(
+    service: &ServiceType,
+) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
+    let state = service.__internal_state();
+    let pubsub_config = state.static_config.publish_subscribe();
+    let event_name = notify_on_send_service_name(state.static_config.name());
+    let event_builder = || {
+        builder::Builder::new(&event_name, state.shared_node.clone())
+            .access_policy(state.static_config.access_policy())
+            .event()
+    };
+
+    loop {
+        match event_builder().open() {
+            Ok(factory) => return Ok(factory),
+            Err(EventOpenError::DoesNotExist) => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        match event_builder()
+            .max_notifiers(pubsub_config.max_publishers)
+            .max_listeners(pubsub_config.max_subscribers)
+            .max_nodes(pubsub_config.max_nodes)
+            .create()
+        {
+            Ok(factory) => return Ok(factory),
+            Err(EventCreateError::AlreadyExists)
+            | Err(EventCreateError::IsBeingCreatedByAnotherInstance) => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
 ///
 /// # Example
@@ -221,6 +273,8 @@ pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: serv
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_enable_notify_on_send: bool,
+    verify_deadline: bool,
     verify_max_nodes: bool,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -238,6 +292,8 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_enable_notify_on_send: false,
+            verify_deadline: false,
             verify_max_nodes: false,
             override_alignment: None,
             override_payload_type: None,
@@ -316,6 +372,31 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self
     }
 
+    /// If the [`Service`] is created, defines if every [`crate::port::publisher::Publisher`]
+    /// shall notify the companion event [`Service`] with the same name whenever a
+    /// [`crate::sample::Sample`] was sent. The [`crate::port::subscriber::Subscriber`] can then
+    /// wait for new samples with
+    /// [`Subscriber::blocking_receive()`](crate::port::subscriber::Subscriber::blocking_receive())
+    /// or [`Subscriber::timed_receive()`](crate::port::subscriber::Subscriber::timed_receive()).
+    /// If an existing [`Service`] is opened it requires the service to have the defined
+    /// notify on send behavior.
+    pub fn enable_notify_on_send(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notify_on_send = value;
+        self.verify_enable_notify_on_send = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines the maximum period between two
+    /// [`crate::sample::Sample`]s every [`crate::port::publisher::Publisher`] is expected to
+    /// send. A [`crate::port::subscriber::Subscriber`] reports a violation with
+    /// [`Subscriber::publisher_qos_events()`](crate::port::subscriber::Subscriber::publisher_qos_events()).
+    /// If an existing [`Service`] is opened it requires the service to have the defined deadline.
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.config_details_mut().deadline = Some(value);
+        self.verify_deadline = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -479,6 +560,20 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                                 msg);
         }
 
+        if self.verify_enable_notify_on_send
+            && existing_settings.enable_notify_on_send != required_settings.enable_notify_on_send
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior,
+                                "{} since the service has an incompatible notify on send behavior.",
+                                msg);
+        }
+
+        if self.verify_deadline && existing_settings.deadline != required_settings.deadline {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleDeadline,
+                                "{} since the service has an incompatible deadline of {:?} but {:?} is required.",
+                                msg, existing_settings.deadline, required_settings.deadline);
+        }
+
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
             fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
@@ -580,6 +675,11 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                     service_tag.release_ownership();
                 }
 
+                notify_discovery_listeners::<ServiceType>(
+                    self.base.shared_node.config(),
+                    DiscoveryTrigger::ServicesChanged,
+                );
+
                 Ok(publish_subscribe::PortFactory::new(
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
@@ -714,6 +814,27 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     }
 }
 
+impl<ServiceType: service::Service> Builder<[u8], CustomHeaderMarker, ServiceType> {
+    pub(crate) fn with_payload_type_details(
+        self,
+        value: &TypeDetail,
+    ) -> Result<Self, TypeDetailError> {
+        fail!(from self, when value.verify(),
+            "Unable to use the payload type details {:?} since they describe an invalid type.", value);
+
+        Ok(self.with_verified_payload_type_details(value))
+    }
+
+    pub(crate) fn with_verified_payload_type_details(mut self, value: &TypeDetail) -> Self {
+        let mut user_header = TypeDetail::__internal_new::<()>(TypeVariant::FixedSize);
+        user_header.type_layout_hash = Some(<() as ZeroCopySend>::type_layout_hash());
+
+        self.override_payload_type = Some(value.clone());
+        self.override_user_header_type = Some(user_header);
+        self
+    }
+}
+
 impl<UserHeader: Debug, ServiceType: service::Service> Builder<[u8], UserHeader, ServiceType> {
     #[doc(hidden)]
     pub unsafe fn __internal_set_payload_type_details(mut self, value: &TypeDetail) -> Self {
@@ -730,14 +851,31 @@ impl<Payload: Debug + ?Sized, ServiceType: service::Service>
         self.override_user_header_type = Some(value.clone());
         self
     }
+
+    /// Defines the user header type of a [`Service`] whose types are only known at runtime,
+    /// see [`crate::service::builder::Builder::publish_subscribe_with_type_details()`].
+    /// Without it the [`Service`] has no user header. Fails when the [`TypeDetail`] describe
+    /// an invalid type.
+    pub fn user_header_type_details(mut self, value: &TypeDetail) -> Result<Self, TypeDetailError> {
+        fail!(from self, when value.verify(),
+            "Unable to use the user header type details {:?} since they describe an invalid type.", value);
+
+        self.override_user_header_type = Some(value.clone());
+        Ok(self)
+    }
 }
 
-impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
-    Builder<Payload, UserHeader, ServiceType>
+impl<
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+        ServiceType: service::Service,
+    > Builder<Payload, UserHeader, ServiceType>
 {
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
-            MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::FixedSize);
+            MessageTypeDetails::from_zero_copy_send::<Header, UserHeader, Payload>(
+                TypeVariant::FixedSize,
+            );
 
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = details.clone();
@@ -822,12 +960,17 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
     }
 }
 
-impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
-    Builder<[Payload], UserHeader, ServiceType>
+impl<
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+        ServiceType: service::Service,
+    > Builder<[Payload], UserHeader, ServiceType>
 {
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
-            MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::Dynamic);
+            MessageTypeDetails::from_zero_copy_send::<Header, UserHeader, Payload>(
+                TypeVariant::Dynamic,
+            );
 
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = details.clone();
@@ -0,0 +1,260 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::builder::publish_subscribe_serialized::serialize::toml::Toml;
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct Settings {
+//!     name: String,
+//!     values: Vec<u64>,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<Settings, Toml>()
+//!     .history_size(1)
+//!     .subscriber_max_buffer_size(4)
+//!     .open_or_create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+
+use crate::service;
+use crate::service::attribute::{AttributeSpecifier, AttributeVerifier};
+use crate::service::port_factory::publish_subscribe_serialized;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+use super::publish_subscribe::{
+    self, CustomHeaderMarker, PublishSubscribeCreateError, PublishSubscribeOpenError,
+    PublishSubscribeOpenOrCreateError,
+};
+
+/// The serializers that can be used for the payload, see [`serialize::Serialize`].
+pub use iceoryx2_cal::serialize;
+
+/// Returns the [`TypeDetail`] of the serialized payload. Services are only compatible when
+/// the Rust type name and the [`serialize::Serialize::unique_id()`] match. The ids of custom
+/// serializers are not guaranteed to be unique, therefore their type name must match as well.
+pub(crate) fn serialized_type_details<Payload, Serializer: serialize::Serialize>() -> TypeDetail {
+    let unique_id = Serializer::unique_id();
+    let serializer = match serialize::RESERVED_UNIQUE_IDS.contains(&unique_id) {
+        true => format!("{}", unique_id),
+        false => format!("{} {}", unique_id, core::any::type_name::<Serializer>()),
+    };
+
+    TypeDetail {
+        variant: TypeVariant::Dynamic,
+        type_name: format!(
+            "{} (serializer {})",
+            core::any::type_name::<Payload>(),
+            serializer
+        ),
+        size: 1,
+        alignment: 1,
+        type_layout_hash: None,
+    }
+}
+
+/// Builder to create new
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based [`Service`](crate::service::Service)s whose payload is serialized with the
+/// `Serializer` instead of being shared in place. Therefore, the `Payload` can be any
+/// [`serde::Serialize`] type, even when it contains heap allocated members.
+pub struct Builder<
+    Payload: Debug + serde::Serialize + serde::de::DeserializeOwned,
+    Serializer: serialize::Serialize,
+    ServiceType: service::Service,
+> {
+    builder: publish_subscribe::Builder<[u8], CustomHeaderMarker, ServiceType>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<
+        Payload: Debug + serde::Serialize + serde::de::DeserializeOwned,
+        Serializer: serialize::Serialize,
+        ServiceType: service::Service,
+    > Debug for Builder<Payload, Serializer, ServiceType>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Builder<{}, {}, {}> {{ builder: {:?} }}",
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Serializer>(),
+            core::any::type_name::<ServiceType>(),
+            self.builder
+        )
+    }
+}
+
+impl<
+        Payload: Debug + serde::Serialize + serde::de::DeserializeOwned,
+        Serializer: serialize::Serialize,
+        ServiceType: service::Service,
+    > Builder<Payload, Serializer, ServiceType>
+{
+    pub(crate) fn new(
+        builder: publish_subscribe::Builder<[u8], CustomHeaderMarker, ServiceType>,
+    ) -> Self {
+        Self {
+            builder: builder
+                .with_verified_payload_type_details(
+                    &serialized_type_details::<Payload, Serializer>(),
+                ),
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    fn map<
+        F: FnOnce(
+            publish_subscribe::Builder<[u8], CustomHeaderMarker, ServiceType>,
+        ) -> publish_subscribe::Builder<[u8], CustomHeaderMarker, ServiceType>,
+    >(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.builder = f(self.builder);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::enable_safe_overflow()`]
+    pub fn enable_safe_overflow(self, value: bool) -> Self {
+        self.map(|b| b.enable_safe_overflow(value))
+    }
+
+    /// See [`publish_subscribe::Builder::enable_notify_on_send()`]
+    pub fn enable_notify_on_send(self, value: bool) -> Self {
+        self.map(|b| b.enable_notify_on_send(value))
+    }
+
+    /// See [`publish_subscribe::Builder::subscriber_max_borrowed_samples()`]
+    pub fn subscriber_max_borrowed_samples(self, value: usize) -> Self {
+        self.map(|b| b.subscriber_max_borrowed_samples(value))
+    }
+
+    /// See [`publish_subscribe::Builder::history_size()`]
+    pub fn history_size(self, value: usize) -> Self {
+        self.map(|b| b.history_size(value))
+    }
+
+    /// See [`publish_subscribe::Builder::subscriber_max_buffer_size()`]
+    pub fn subscriber_max_buffer_size(self, value: usize) -> Self {
+        self.map(|b| b.subscriber_max_buffer_size(value))
+    }
+
+    /// See [`publish_subscribe::Builder::max_subscribers()`]
+    pub fn max_subscribers(self, value: usize) -> Self {
+        self.map(|b| b.max_subscribers(value))
+    }
+
+    /// See [`publish_subscribe::Builder::max_publishers()`]
+    pub fn max_publishers(self, value: usize) -> Self {
+        self.map(|b| b.max_publishers(value))
+    }
+
+    /// See [`publish_subscribe::Builder::max_nodes()`]
+    pub fn max_nodes(self, value: usize) -> Self {
+        self.map(|b| b.max_nodes(value))
+    }
+
+    /// If the [`Service`](crate::service::Service) exists, it will be opened otherwise a new
+    /// [`Service`](crate::service::Service) will be created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`](crate::service::Service) exists, it will be opened otherwise a new
+    /// [`Service`](crate::service::Service) will be created. See
+    /// [`publish_subscribe::Builder::open_or_create_with_attributes()`].
+    pub fn open_or_create_with_attributes(
+        self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        let origin = format!("{:?}", self);
+        Ok(publish_subscribe_serialized::PortFactory::new(
+            fail!(from origin, when self.builder.open_or_create_with_attributes(required_attributes),
+                "Unable to open or create the serialized publish subscribe service."),
+        ))
+    }
+
+    /// Opens an existing [`Service`](crate::service::Service).
+    pub fn open(
+        self,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`](crate::service::Service) with attribute requirements. If
+    /// the defined attribute requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeOpenError,
+    > {
+        let origin = format!("{:?}", self);
+        Ok(publish_subscribe_serialized::PortFactory::new(
+            fail!(from origin, when self.builder.open_with_attributes(required_attributes),
+                "Unable to open the serialized publish subscribe service."),
+        ))
+    }
+
+    /// Creates a new [`Service`](crate::service::Service).
+    pub fn create(
+        self,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeCreateError,
+    > {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`](crate::service::Service) with a set of attributes.
+    pub fn create_with_attributes(
+        self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeCreateError,
+    > {
+        let origin = format!("{:?}", self);
+        Ok(publish_subscribe_serialized::PortFactory::new(
+            fail!(from origin, when self.builder.create_with_attributes(attributes),
+                "Unable to create the serialized publish subscribe service."),
+        ))
+    }
+}
@@ -0,0 +1,798 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use std::marker::PhantomData;
+
+use crate::discovery::{notify_discovery_listeners, DiscoveryTrigger};
+use crate::service;
+use crate::service::dynamic_config::request_response::DynamicConfigSettings;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::port_factory::request_response;
+use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::*;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::StaticStorageLocked;
+
+use self::{
+    attribute::{AttributeSpecifier, AttributeVerifier},
+    message_type_details::{MessageTypeDetails, TypeVariant},
+};
+
+use super::{OpenDynamicStorageFailure, ServiceState};
+
+/// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseOpenError {
+    /// Service could not be openen since it does not exist
+    DoesNotExist,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+    /// The [`Service`] has the wrong request payload type.
+    IncompatibleRequestType,
+    /// The [`Service`] has the wrong response payload type.
+    IncompatibleResponseType,
+    /// The [`Service`] has the wrong messaging pattern.
+    IncompatibleMessagingPattern,
+    /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
+    IncompatibleAttributes,
+    /// The [`Service`] supports less active requests per
+    /// [`Client`](crate::port::client::Client) than requested.
+    DoesNotSupportRequestedAmountOfActiveRequestsPerClient,
+    /// The [`Service`] has a lower response buffer size than requested.
+    DoesNotSupportRequestedResponseBufferSize,
+    /// The [`Service`] supports less borrowed responses per
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) than requested.
+    DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse,
+    /// The [`Service`] supports less [`Client`](crate::port::client::Client)s than requested.
+    DoesNotSupportRequestedAmountOfClients,
+    /// The [`Service`] supports less [`Server`](crate::port::server::Server)s than requested.
+    DoesNotSupportRequestedAmountOfServers,
+    /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
+    DoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] required overflow behavior for requests is not compatible.
+    IncompatibleOverflowBehaviorForRequests,
+    /// The [`Service`] required overflow behavior for responses is not compatible.
+    IncompatibleOverflowBehaviorForResponses,
+    /// The process has not enough permissions to open the [`Service`]
+    InsufficientPermissions,
+    /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
+    ServiceInCorruptedState,
+    /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
+    /// by a process that crashed during [`Service`] creation.
+    HangsInCreation,
+    /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
+    ExceedsMaxNumberOfNodes,
+    /// The [`Service`] is marked for destruction and currently cleaning up since no one is using it anymore.
+    /// When the call creation call is repeated with a little delay the [`Service`] should be
+    /// recreatable.
+    IsMarkedForDestruction,
+}
+
+impl std::fmt::Display for RequestResponseOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "RequestResponseOpenError::{:?}", self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenError {}
+
+impl From<ServiceAvailabilityState> for RequestResponseOpenError {
+    fn from(value: ServiceAvailabilityState) -> Self {
+        match value {
+            ServiceAvailabilityState::IncompatibleRequestType => {
+                RequestResponseOpenError::IncompatibleRequestType
+            }
+            ServiceAvailabilityState::IncompatibleResponseType => {
+                RequestResponseOpenError::IncompatibleResponseType
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
+                RequestResponseOpenError::IncompatibleMessagingPattern
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
+                RequestResponseOpenError::InsufficientPermissions
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::HangsInCreation) => {
+                RequestResponseOpenError::HangsInCreation
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::Corrupted) => {
+                RequestResponseOpenError::ServiceInCorruptedState
+            }
+        }
+    }
+}
+
+/// Errors that can occur when a new [`MessagingPattern::RequestResponse`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseCreateError {
+    /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
+    ServiceInCorruptedState,
+    /// The [`Service`] already exists.
+    AlreadyExists,
+    /// The process has insufficient permissions to create the [`Service`].
+    InsufficientPermissions,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+    /// Multiple processes are trying to create the same [`Service`].
+    IsBeingCreatedByAnotherInstance,
+    /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
+    /// by a process that crashed during [`Service`] creation.
+    HangsInCreation,
+}
+
+impl std::fmt::Display for RequestResponseCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "RequestResponseCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for RequestResponseCreateError {}
+
+impl From<ServiceAvailabilityState> for RequestResponseCreateError {
+    fn from(value: ServiceAvailabilityState) -> Self {
+        match value {
+            ServiceAvailabilityState::IncompatibleRequestType
+            | ServiceAvailabilityState::IncompatibleResponseType
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
+                RequestResponseCreateError::AlreadyExists
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
+                RequestResponseCreateError::InsufficientPermissions
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::HangsInCreation) => {
+                RequestResponseCreateError::HangsInCreation
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::Corrupted) => {
+                RequestResponseCreateError::ServiceInCorruptedState
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleRequestType,
+    IncompatibleResponseType,
+}
+
+/// Errors that can occur when a [`MessagingPattern::RequestResponse`] [`Service`] shall be
+/// created or opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseOpenOrCreateError {
+    /// Failures that can occur when an existing [`Service`] could not be opened.
+    RequestResponseOpenError(RequestResponseOpenError),
+    /// Failures that can occur when a [`Service`] could not be created.
+    RequestResponseCreateError(RequestResponseCreateError),
+}
+
+impl From<ServiceAvailabilityState> for RequestResponseOpenOrCreateError {
+    fn from(value: ServiceAvailabilityState) -> Self {
+        RequestResponseOpenOrCreateError::RequestResponseOpenError(value.into())
+    }
+}
+
+impl From<RequestResponseOpenError> for RequestResponseOpenOrCreateError {
+    fn from(value: RequestResponseOpenError) -> Self {
+        Self::RequestResponseOpenError(value)
+    }
+}
+
+impl From<RequestResponseCreateError> for RequestResponseOpenOrCreateError {
+    fn from(value: RequestResponseCreateError) -> Self {
+        Self::RequestResponseCreateError(value)
+    }
+}
+
+impl std::fmt::Display for RequestResponseOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "RequestResponseOpenOrCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<RequestPayload: Debug, ResponsePayload: Debug, ServiceType: service::Service> {
+    base: builder::BuilderWithServiceType<ServiceType>,
+    verify_number_of_clients: bool,
+    verify_number_of_servers: bool,
+    verify_max_active_requests_per_client: bool,
+    verify_max_response_buffer_size: bool,
+    verify_max_borrowed_responses_per_pending_response: bool,
+    verify_enable_safe_overflow_for_requests: bool,
+    verify_enable_safe_overflow_for_responses: bool,
+    verify_max_nodes: bool,
+    _request_payload: PhantomData<RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<RequestPayload: Debug, ResponsePayload: Debug, ServiceType: service::Service>
+    Builder<RequestPayload, ResponsePayload, ServiceType>
+{
+    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_clients: false,
+            verify_number_of_servers: false,
+            verify_max_active_requests_per_client: false,
+            verify_max_response_buffer_size: false,
+            verify_max_borrowed_responses_per_pending_response: false,
+            verify_enable_safe_overflow_for_requests: false,
+            verify_enable_safe_overflow_for_responses: false,
+            verify_max_nodes: false,
+            _request_payload: PhantomData,
+            _response_payload: PhantomData,
+        };
+
+        new_self.base.service_config.messaging_pattern = MessagingPattern::RequestResponse(
+            static_config::request_response::StaticConfig::new(new_self.base.shared_node.config()),
+        );
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available(error_msg) {
+            Ok(Some((config, storage))) => {
+                if !self
+                    .config_details()
+                    .request_message_type_details
+                    .is_compatible_to(&config.request_response().request_message_type_details)
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleRequestType,
+                        "{} since the service offers the request type \"{:?}\" which is not compatible to the requested request type \"{:?}\".",
+                        error_msg, &config.request_response().request_message_type_details, self.config_details().request_message_type_details);
+                }
+
+                if !self
+                    .config_details()
+                    .response_message_type_details
+                    .is_compatible_to(&config.request_response().response_message_type_details)
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleResponseType,
+                        "{} since the service offers the response type \"{:?}\" which is not compatible to the requested response type \"{:?}\".",
+                        error_msg, &config.request_response().response_message_type_details, self.config_details().response_message_type_details);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    /// If the [`Service`] is created, defines the overflow behavior of the service for requests.
+    /// If an existing [`Service`] is opened it requires the service to have the defined overflow
+    /// behavior.
+    pub fn enable_safe_overflow_for_requests(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_safe_overflow_for_requests = value;
+        self.verify_enable_safe_overflow_for_requests = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines the overflow behavior of the service for responses.
+    /// If an existing [`Service`] is opened it requires the service to have the defined overflow
+    /// behavior.
+    pub fn enable_safe_overflow_for_responses(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_safe_overflow_for_responses = value;
+        self.verify_enable_safe_overflow_for_responses = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many requests a
+    /// [`Client`](crate::port::client::Client) can have in flight at the same time. If an
+    /// existing [`Service`] is opened it defines the minimum required.
+    pub fn max_active_requests_per_client(mut self, value: usize) -> Self {
+        self.config_details_mut().max_active_requests_per_client = value;
+        self.verify_max_active_requests_per_client = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many responses fit into the buffer of a
+    /// [`PendingResponse`](crate::pending_response::PendingResponse). If an existing
+    /// [`Service`] is opened it defines the minimum required.
+    pub fn max_response_buffer_size(mut self, value: usize) -> Self {
+        self.config_details_mut().max_response_buffer_size = value;
+        self.verify_max_response_buffer_size = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`Response`](crate::response::Response)s
+    /// a [`PendingResponse`](crate::pending_response::PendingResponse) can borrow at most in
+    /// parallel. If an existing [`Service`] is opened it defines the minimum required.
+    pub fn max_borrowed_responses_per_pending_response(mut self, value: usize) -> Self {
+        self.config_details_mut()
+            .max_borrowed_responses_per_pending_response = value;
+        self.verify_max_borrowed_responses_per_pending_response = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::client::Client`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::client::Client`] must be at least supported.
+    pub fn max_clients(mut self, value: usize) -> Self {
+        self.config_details_mut().max_clients = value;
+        self.verify_number_of_clients = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::server::Server`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::server::Server`] must be at least supported.
+    pub fn max_servers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_servers = value;
+        self.verify_number_of_servers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`Node`](crate::node::Node)s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.config_details_mut().max_nodes = value;
+        self.verify_max_nodes = true;
+        self
+    }
+
+    fn adjust_attributes_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.request_response_mut();
+
+        if settings.max_active_requests_per_client == 0 {
+            warn!(from origin,
+                "Setting the maximum number of active requests per client to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_active_requests_per_client = 1;
+        }
+
+        if settings.max_response_buffer_size == 0 {
+            warn!(from origin,
+                "Setting the maximum response buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_response_buffer_size = 1;
+        }
+
+        if settings.max_borrowed_responses_per_pending_response == 0 {
+            warn!(from origin,
+                "Setting the maximum number of borrowed responses per pending response to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_borrowed_responses_per_pending_response = 1;
+        }
+
+        if settings.max_clients == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of clients to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_clients = 1;
+        }
+
+        if settings.max_servers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of servers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_servers = 1;
+        }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
+    }
+
+    fn verify_service_attributes(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<static_config::request_response::StaticConfig, RequestResponseOpenError> {
+        let msg = "Unable to open request response service";
+
+        let existing_attributes = existing_settings.attributes();
+        if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleAttributes,
+                "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
+                msg, incompatible_key, required_attributes, existing_attributes);
+        }
+
+        let required_settings = self.base.service_config.request_response();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            p => {
+                fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::RequestResponse is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_clients
+            && existing_settings.max_clients < required_settings.max_clients
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients,
+                                "{} since the service supports only {} clients but a support of {} clients was requested.",
+                                msg, existing_settings.max_clients, required_settings.max_clients);
+        }
+
+        if self.verify_number_of_servers
+            && existing_settings.max_servers < required_settings.max_servers
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers,
+                                "{} since the service supports only {} servers but a support of {} servers was requested.",
+                                msg, existing_settings.max_servers, required_settings.max_servers);
+        }
+
+        if self.verify_max_active_requests_per_client
+            && existing_settings.max_active_requests_per_client
+                < required_settings.max_active_requests_per_client
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient,
+                                "{} since the service supports only {} active requests per client but {} active requests were requested.",
+                                msg, existing_settings.max_active_requests_per_client, required_settings.max_active_requests_per_client);
+        }
+
+        if self.verify_max_response_buffer_size
+            && existing_settings.max_response_buffer_size
+                < required_settings.max_response_buffer_size
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize,
+                                "{} since the service supports only a response buffer size of {} but a buffer size of {} was requested.",
+                                msg, existing_settings.max_response_buffer_size, required_settings.max_response_buffer_size);
+        }
+
+        if self.verify_max_borrowed_responses_per_pending_response
+            && existing_settings.max_borrowed_responses_per_pending_response
+                < required_settings.max_borrowed_responses_per_pending_response
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse,
+                                "{} since the service supports only {} borrowed responses per pending response but {} borrowed responses were requested.",
+                                msg, existing_settings.max_borrowed_responses_per_pending_response, required_settings.max_borrowed_responses_per_pending_response);
+        }
+
+        if self.verify_enable_safe_overflow_for_requests
+            && existing_settings.enable_safe_overflow_for_requests
+                != required_settings.enable_safe_overflow_for_requests
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests,
+                                "{} since the service has an incompatible safe overflow behavior for requests.",
+                                msg);
+        }
+
+        if self.verify_enable_safe_overflow_for_responses
+            && existing_settings.enable_safe_overflow_for_responses
+                != required_settings.enable_safe_overflow_for_responses
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses,
+                                "{} since the service has an incompatible safe overflow behavior for responses.",
+                                msg);
+        }
+
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes,
+                                "{} since the service supports only {} nodes but {} are required.",
+                                msg, existing_settings.max_nodes, required_settings.max_nodes);
+        }
+
+        Ok(existing_settings.clone())
+    }
+
+    fn create_impl(
+        &mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseCreateError,
+    > {
+        self.adjust_attributes_to_meaningful_values();
+
+        let msg = "Unable to create request response service";
+
+        match self.is_service_available(msg)? {
+            None => {
+                let service_tag = self
+                    .base
+                    .create_node_service_tag(msg, RequestResponseCreateError::InternalFailure)?;
+
+                // create static config
+                let static_config = match self.base.create_static_config_storage() {
+                    Ok(c) => c,
+                    Err(StaticStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                           "{} since the service already exists.", msg);
+                    }
+                    Err(StaticStorageCreateError::Creation) => {
+                        fail!(from self, with RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                            "{} since the service is being created by another instance.", msg);
+                    }
+                    Err(StaticStorageCreateError::InsufficientPermissions) => {
+                        fail!(from self, with RequestResponseCreateError::InsufficientPermissions,
+                            "{} since the static service information could not be created due to insufficient permissions.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with RequestResponseCreateError::InternalFailure,
+                            "{} since the static service information could not be created due to an internal failure ({:?}).", msg, e);
+                    }
+                };
+
+                let request_response_config = self.base.service_config.request_response();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_clients: request_response_config.max_clients,
+                    number_of_servers: request_response_config.max_servers,
+                };
+
+                let dynamic_config = match self.base.create_dynamic_config_storage(
+                    dynamic_config::MessagingPattern::RequestResponse(
+                        dynamic_config::request_response::DynamicConfig::new(
+                            &dynamic_config_setting,
+                        ),
+                    ),
+                    dynamic_config::request_response::DynamicConfig::memory_size(
+                        &dynamic_config_setting,
+                    ),
+                    request_response_config.max_nodes,
+                ) {
+                    Ok(dynamic_config) => dynamic_config,
+                    Err(DynamicStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with RequestResponseCreateError::ServiceInCorruptedState,
+                            "{} since the dynamic config of a previous instance of the service still exists.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with RequestResponseCreateError::InternalFailure,
+                            "{} since the dynamic service segment could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                self.base.service_config.attributes = attributes.0.clone();
+                let service_config = fail!(from self,
+                            when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            with RequestResponseCreateError::ServiceInCorruptedState,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let mut unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with RequestResponseCreateError::ServiceInCorruptedState,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                unlocked_static_details.release_ownership();
+                if let Some(mut service_tag) = service_tag {
+                    service_tag.release_ownership();
+                }
+
+                notify_discovery_listeners::<ServiceType>(
+                    self.base.shared_node.config(),
+                    DiscoveryTrigger::ServicesChanged,
+                );
+
+                Ok(request_response::PortFactory::new(
+                    ServiceType::__internal_from_state(service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.shared_node.clone(),
+                        dynamic_config,
+                        unlocked_static_details,
+                    )),
+                ))
+            }
+            Some(_) => {
+                fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+        }
+    }
+
+    fn open_impl(
+        &mut self,
+        attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenError,
+    > {
+        const OPEN_RETRY_LIMIT: usize = 5;
+        let msg = "Unable to open request response service";
+
+        let mut service_open_retry_count = 0;
+        loop {
+            match self.is_service_available(msg)? {
+                None => {
+                    fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Some((static_config, static_storage)) => {
+                    let request_response_static_config =
+                        self.verify_service_attributes(&static_config, attributes)?;
+
+                    let service_tag = self
+                        .base
+                        .create_node_service_tag(msg, RequestResponseOpenError::InternalFailure)?;
+
+                    let dynamic_config = match self.base.open_dynamic_config_storage() {
+                        Ok(v) => v,
+                        Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
+                            fail!(from self, with RequestResponseOpenError::IsMarkedForDestruction,
+                                "{} since the service is marked for destruction.", msg);
+                        }
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with RequestResponseOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(e) => {
+                            if self.is_service_available(msg)?.is_none() {
+                                fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                                    "{} since the service does not exist.", msg);
+                            }
+
+                            service_open_retry_count += 1;
+
+                            if OPEN_RETRY_LIMIT < service_open_retry_count {
+                                fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                                "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
+                                msg, e);
+                            }
+
+                            continue;
+                        }
+                    };
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::RequestResponse(request_response_static_config);
+
+                    if let Some(mut service_tag) = service_tag {
+                        service_tag.release_ownership();
+                    }
+
+                    return Ok(request_response::PortFactory::new(
+                        ServiceType::__internal_from_state(service::ServiceState::new(
+                            static_config,
+                            self.base.shared_node.clone(),
+                            dynamic_config,
+                            static_storage,
+                        )),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn open_or_create_impl(
+        mut self,
+        attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenOrCreateError,
+    > {
+        let msg = "Unable to open or create request response service";
+
+        loop {
+            match self.is_service_available(msg)? {
+                Some(_) => match self.open_impl(attributes) {
+                    Ok(factory) => return Ok(factory),
+                    Err(RequestResponseOpenError::DoesNotExist) => continue,
+                    Err(e) => return Err(e.into()),
+                },
+                None => {
+                    match self.create_impl(&AttributeSpecifier(attributes.attributes().clone())) {
+                        Ok(factory) => return Ok(factory),
+                        Err(RequestResponseCreateError::AlreadyExists)
+                        | Err(RequestResponseCreateError::IsBeingCreatedByAnotherInstance) => {
+                            continue;
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+            }
+        }
+    }
+
+    fn prepare_config_details(&mut self) {
+        self.config_details_mut().request_message_type_details =
+            MessageTypeDetails::from::<RequestHeader, (), RequestPayload>(TypeVariant::FixedSize);
+        self.config_details_mut().response_message_type_details =
+            MessageTypeDetails::from::<ResponseHeader, (), ResponsePayload>(TypeVariant::FixedSize);
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes. If the [`Service`] already exists all attribute
+    /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
+    /// does not exist the required attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.prepare_config_details();
+        self.open_or_create_impl(required_attributes)
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseOpenError,
+    > {
+        self.prepare_config_details();
+        self.open_impl(required_attributes)
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseCreateError,
+    > {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        request_response::PortFactory<ServiceType, RequestPayload, ResponsePayload>,
+        RequestResponseCreateError,
+    > {
+        self.prepare_config_details();
+        self.create_impl(attributes)
+    }
+}
@@ -10,6 +10,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::fmt::Debug;
+
 use crate::{config, node::NodeId};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
@@ -105,3 +107,24 @@ pub(crate) fn service_tag_config<Service: crate::service::Service>(
         .suffix(&global_config.global.node.service_tag_suffix)
         .path_hint(&node_details_path(global_config, node_id))
 }
+
+pub(crate) fn port_statistics_config<
+    Service: crate::service::Service,
+    T: Send + Sync + Debug + 'static,
+>(
+    global_config: &config::Config,
+) -> <Service::PortStatisticsStorage<T> as NamedConceptMgmt>::Configuration {
+    <<Service::PortStatisticsStorage<T> as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.service.port_statistics_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
+pub(crate) fn discovery_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::Event as NamedConceptMgmt>::Configuration {
+    <<Service::Event as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.service.discovery_suffix)
+        .path_hint(global_config.global.root_path())
+}
@@ -0,0 +1,219 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, 123)
+//!     .open_or_create()?;
+//!
+//! println!("number of active readers:     {:?}", service.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", service.dynamic_config().number_of_writers());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+
+use iceoryx2_bb_elementary::allocator::BaseAllocator;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::{
+    node::NodeId,
+    port::port_identifiers::{UniquePortId, UniqueReaderId, UniqueWriterId},
+};
+
+use super::PortCleanupAction;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_readers: usize,
+    pub number_of_writers: usize,
+    pub payload_size: usize,
+    pub payload_alignment: usize,
+}
+
+/// Contains the details of a connected [`Reader`](crate::port::reader::Reader).
+#[derive(Debug, Clone, Copy)]
+pub struct ReaderDetails {
+    pub(crate) reader_id: UniqueReaderId,
+    pub(crate) node_id: NodeId,
+}
+
+impl ReaderDetails {
+    /// Returns the [`UniqueReaderId`] of the [`Reader`](crate::port::reader::Reader).
+    pub fn reader_id(&self) -> UniqueReaderId {
+        self.reader_id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`Reader`](crate::port::reader::Reader) was created.
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+}
+
+/// Contains the details of a connected [`Writer`](crate::port::writer::Writer).
+#[derive(Debug, Clone, Copy)]
+pub struct WriterDetails {
+    pub(crate) writer_id: UniqueWriterId,
+    pub(crate) node_id: NodeId,
+}
+
+impl WriterDetails {
+    /// Returns the [`UniqueWriterId`] of the [`Writer`](crate::port::writer::Writer).
+    pub fn writer_id(&self) -> UniqueWriterId {
+        self.writer_id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`Writer`](crate::port::writer::Writer) was created.
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains dynamic parameters like the connected endpoints and the
+/// memory of the blackboard entries.
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) readers: Container<ReaderDetails>,
+    pub(crate) writers: Container<WriterDetails>,
+    payload: RelocatablePointer<u8>,
+    payload_layout: Layout,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            readers: unsafe { Container::new_uninit(config.number_of_readers) },
+            writers: unsafe { Container::new_uninit(config.number_of_writers) },
+            payload: unsafe { RelocatablePointer::new_uninit() },
+            payload_layout: unsafe {
+                Layout::from_size_align_unchecked(config.payload_size, config.payload_alignment)
+            },
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from self,
+            when self.readers.init(allocator),
+            "This should never happen! Unable to initialize reader port id container.");
+        fatal_panic!(from self,
+            when self.writers.init(allocator),
+            "This should never happen! Unable to initialize writer port id container.");
+        let payload = fatal_panic!(from self,
+            when allocator.allocate(self.payload_layout),
+            "This should never happen! Unable to allocate the memory for the blackboard entries.");
+        self.payload.init(payload);
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<ReaderDetails>::memory_size(config.number_of_readers)
+            + Container::<WriterDetails>::memory_size(config.number_of_writers)
+            + config.payload_size
+            + config.payload_alignment
+            - 1
+    }
+
+    pub(crate) fn payload_start_address(&self) -> *mut u8 {
+        unsafe { self.payload.as_ptr() as *mut u8 }
+    }
+
+    /// Returns the how many [`crate::port::reader::Reader`] ports are currently connected.
+    pub fn number_of_readers(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns the how many [`crate::port::writer::Writer`] ports are currently connected.
+    pub fn number_of_writers(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Iterates over all connected [`crate::port::reader::Reader`] ports and calls the
+    /// provided callback with their [`ReaderDetails`] until the callback returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_readers<F: FnMut(&ReaderDetails) -> CallbackProgression>(&self, mut callback: F) {
+        let state = unsafe { self.readers.get_state() };
+        state.for_each(|_, details| callback(details));
+    }
+
+    /// Iterates over all connected [`crate::port::writer::Writer`] ports and calls the
+    /// provided callback with their [`WriterDetails`] until the callback returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_writers<F: FnMut(&WriterDetails) -> CallbackProgression>(&self, mut callback: F) {
+        let state = unsafe { self.writers.get_state() };
+        state.for_each(|_, details| callback(details));
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<
+        PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
+    >(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.readers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_reader| {
+                if registered_reader.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Reader(registered_reader.reader_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_reader_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.writers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_writer| {
+                if registered_writer.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Writer(registered_writer.writer_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_writer_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    pub(crate) fn add_reader_id(&self, id: ReaderDetails) -> Option<ContainerHandle> {
+        unsafe { self.readers.add(id).ok() }
+    }
+
+    pub(crate) fn release_reader_handle(&self, handle: ContainerHandle) {
+        unsafe { self.readers.remove(handle, ReleaseMode::Default) };
+    }
+
+    pub(crate) fn add_writer_id(&self, id: WriterDetails) -> Option<ContainerHandle> {
+        unsafe { self.writers.add(id).ok() }
+    }
+
+    pub(crate) fn release_writer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.writers.remove(handle, ReleaseMode::Default) };
+    }
+}
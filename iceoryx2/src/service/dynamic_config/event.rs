@@ -23,13 +23,21 @@
 //!
 //! println!("number of active listeners:   {:?}", event.dynamic_config().number_of_listeners());
 //! println!("number of active notifiers:   {:?}", event.dynamic_config().number_of_notifiers());
+//!
+//! event.dynamic_config().list_listeners(|details| {
+//!     println!("listener {:?} of node {:?}", details.listener_id(), details.node_id());
+//!     CallbackProgression::Continue
+//! });
 //! # Ok(())
 //! # }
 //! ```
+use std::sync::atomic::Ordering;
+
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::{
     node::NodeId,
@@ -52,18 +60,80 @@ pub struct DynamicConfig {
     pub(crate) notifiers: Container<NotifierDetails>,
 }
 
+/// Contains the details of a connected [`Listener`](crate::port::listener::Listener).
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct ListenerDetails {
+pub struct ListenerDetails {
     pub(crate) listener_id: UniqueListenerId,
     pub(crate) node_id: NodeId,
 }
 
+impl ListenerDetails {
+    /// Returns the [`UniqueListenerId`] of the [`Listener`](crate::port::listener::Listener).
+    pub fn listener_id(&self) -> UniqueListenerId {
+        self.listener_id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`Listener`](crate::port::listener::Listener) was created.
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+}
+
+/// Contains the details of a connected [`Notifier`](crate::port::notifier::Notifier).
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct NotifierDetails {
+pub struct NotifierDetails {
     pub(crate) notifier_id: UniqueNotifierId,
     pub(crate) node_id: NodeId,
 }
 
+impl NotifierDetails {
+    /// Returns the [`UniqueNotifierId`] of the [`Notifier`](crate::port::notifier::Notifier).
+    pub fn notifier_id(&self) -> UniqueNotifierId {
+        self.notifier_id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`Notifier`](crate::port::notifier::Notifier) was created.
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+}
+
+/// The counters a connected [`Notifier`](crate::port::notifier::Notifier) updates while it is
+/// in use. Acquired with
+/// [`PortFactory::list_notifier_statistics()`](crate::service::port_factory::event::PortFactory::list_notifier_statistics()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NotifierStatistics {
+    /// The total number of notifications the [`Notifier`](crate::port::notifier::Notifier)
+    /// has sent.
+    pub number_of_notifications: u64,
+}
+
+#[derive(Debug)]
+#[repr(C)]
+pub(crate) struct NotifierStatisticsEntry {
+    number_of_notifications: IoxAtomicU64,
+}
+
+impl NotifierStatisticsEntry {
+    pub(crate) fn new() -> Self {
+        Self {
+            number_of_notifications: IoxAtomicU64::new(0),
+        }
+    }
+
+    pub(crate) fn increment_notifications(&self) {
+        self.number_of_notifications.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn load(&self) -> NotifierStatistics {
+        NotifierStatistics {
+            number_of_notifications: self.number_of_notifications.load(Ordering::Relaxed),
+        }
+    }
+}
+
 impl DynamicConfig {
     pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
         Self {
@@ -96,6 +166,28 @@ impl DynamicConfig {
         self.notifiers.len()
     }
 
+    /// Iterates over all connected [`crate::port::listener::Listener`] ports and calls the
+    /// provided callback with their [`ListenerDetails`] until the callback returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_listeners<F: FnMut(&ListenerDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.listeners.get_state() };
+        state.for_each(|_, details| callback(details));
+    }
+
+    /// Iterates over all connected [`crate::port::notifier::Notifier`] ports and calls the
+    /// provided callback with their [`NotifierDetails`] until the callback returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_notifiers<F: FnMut(&NotifierDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.notifiers.get_state() };
+        state.for_each(|_, details| callback(details));
+    }
+
     #[doc(hidden)]
     pub fn __internal_listener_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
         let state = unsafe { self.listeners.get_state() };
@@ -10,6 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+pub mod blackboard;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 /// based service.
@@ -20,6 +25,11 @@ pub mod event;
 /// based service.
 pub mod publish_subscribe;
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service.
+pub mod request_response;
+
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::{
@@ -32,6 +42,43 @@ use std::fmt::Display;
 
 use crate::{node::NodeId, port::port_identifiers::UniquePortId};
 
+/// The details of a port that is connected to a [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Copy)]
+pub enum PortDetails {
+    /// A connected [`Publisher`](crate::port::publisher::Publisher)
+    Publisher(publish_subscribe::PublisherDetails),
+    /// A connected [`Subscriber`](crate::port::subscriber::Subscriber)
+    Subscriber(publish_subscribe::SubscriberDetails),
+    /// A connected [`Notifier`](crate::port::notifier::Notifier)
+    Notifier(event::NotifierDetails),
+    /// A connected [`Listener`](crate::port::listener::Listener)
+    Listener(event::ListenerDetails),
+    /// A connected [`Client`](crate::port::client::Client)
+    Client(request_response::ClientDetails),
+    /// A connected [`Server`](crate::port::server::Server)
+    Server(request_response::ServerDetails),
+    /// A connected [`Reader`](crate::port::reader::Reader)
+    Reader(blackboard::ReaderDetails),
+    /// A connected [`Writer`](crate::port::writer::Writer)
+    Writer(blackboard::WriterDetails),
+}
+
+impl PortDetails {
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) that owns the port.
+    pub fn node_id(&self) -> &NodeId {
+        match self {
+            PortDetails::Publisher(v) => &v.node_id,
+            PortDetails::Subscriber(v) => &v.node_id,
+            PortDetails::Notifier(v) => &v.node_id,
+            PortDetails::Listener(v) => &v.node_id,
+            PortDetails::Client(v) => &v.node_id,
+            PortDetails::Server(v) => &v.node_id,
+            PortDetails::Reader(v) => &v.node_id,
+            PortDetails::Writer(v) => &v.node_id,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum PortCleanupAction {
     RemovePort,
@@ -58,8 +105,16 @@ pub(crate) enum RemoveDeadNodeResult {
 pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
+    RequestResponse(request_response::DynamicConfig),
+    Blackboard(blackboard::DynamicConfig),
 }
 
+// Every process of the service maps the dynamic config read-write since ports register
+// themselves and the dead node cleanup removes foreign entries. Therefore, it contains only
+// details that are written once on registration, the statistics a port updates while it is in
+// use are stored in a segment owned by the port that is mapped read-only by all other processes.
+// The registration details themselves are not protected, a misbehaving process of the service
+// can still overwrite the entries of other ports.
 #[doc(hidden)]
 #[derive(Debug)]
 pub struct DynamicConfig {
@@ -98,6 +153,8 @@ impl DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v.init(allocator),
             MessagingPattern::Event(ref v) => v.init(allocator),
+            MessagingPattern::RequestResponse(ref v) => v.init(allocator),
+            MessagingPattern::Blackboard(ref v) => v.init(allocator),
         }
     }
 
@@ -113,6 +170,12 @@ impl DynamicConfig {
                 v.remove_dead_node_id(node_id, port_cleanup_callback)
             }
             MessagingPattern::Event(ref v) => v.remove_dead_node_id(node_id, port_cleanup_callback),
+            MessagingPattern::RequestResponse(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+            MessagingPattern::Blackboard(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
         };
 
         let mut ret_val = Err(RemoveDeadNodeResult::NodeNotRegistered);
@@ -153,6 +216,55 @@ impl DynamicConfig {
         state.for_each(|_, node_id| callback(node_id));
     }
 
+    pub(crate) fn list_ports<F: FnMut(PortDetails) -> CallbackProgression>(&self, mut callback: F) {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => {
+                let mut progression = CallbackProgression::Continue;
+                v.list_publishers(|details| {
+                    progression = callback(PortDetails::Publisher(*details));
+                    progression
+                });
+
+                if progression == CallbackProgression::Continue {
+                    v.list_subscribers(|details| callback(PortDetails::Subscriber(*details)));
+                }
+            }
+            MessagingPattern::Event(ref v) => {
+                let mut progression = CallbackProgression::Continue;
+                v.list_notifiers(|details| {
+                    progression = callback(PortDetails::Notifier(*details));
+                    progression
+                });
+
+                if progression == CallbackProgression::Continue {
+                    v.list_listeners(|details| callback(PortDetails::Listener(*details)));
+                }
+            }
+            MessagingPattern::RequestResponse(ref v) => {
+                let mut progression = CallbackProgression::Continue;
+                v.list_clients(|details| {
+                    progression = callback(PortDetails::Client(*details));
+                    progression
+                });
+
+                if progression == CallbackProgression::Continue {
+                    v.list_servers(|details| callback(PortDetails::Server(*details)));
+                }
+            }
+            MessagingPattern::Blackboard(ref v) => {
+                let mut progression = CallbackProgression::Continue;
+                v.list_writers(|details| {
+                    progression = callback(PortDetails::Writer(*details));
+                    progression
+                });
+
+                if progression == CallbackProgression::Continue {
+                    v.list_readers(|details| callback(PortDetails::Reader(*details)));
+                }
+            }
+        }
+    }
+
     pub(crate) fn is_marked_for_destruction(&self) -> bool {
         self.nodes.is_locked()
     }
@@ -184,4 +296,22 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access request_response::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access blackboard::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
 }
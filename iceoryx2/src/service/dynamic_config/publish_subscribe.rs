@@ -23,13 +23,23 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//!
+//! pubsub.dynamic_config().list_publishers(|details| {
+//!     println!("publisher {:?} of node {:?}", details.publisher_id(), details.node_id());
+//!     CallbackProgression::Continue
+//! });
 //! # Ok(())
 //! # }
 //! ```
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::{Time, TimeBuilder};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
 
 use crate::{
     node::NodeId,
@@ -44,19 +54,186 @@ pub(crate) struct DynamicConfigSettings {
     pub number_of_publishers: usize,
 }
 
+/// Contains the communication settings of the connected
+/// [`Publisher`](crate::port::publisher::Publisher).
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct PublisherDetails {
+pub struct PublisherDetails {
     pub(crate) publisher_id: UniquePublisherId,
     pub(crate) node_id: NodeId,
     pub(crate) number_of_samples: usize,
     pub(crate) max_slice_len: usize,
+    pub(crate) max_number_of_segments: u8,
+    pub(crate) max_loaned_samples: usize,
+    pub(crate) liveliness_lease_duration: Option<Duration>,
 }
 
+impl PublisherDetails {
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher).
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`Publisher`](crate::port::publisher::Publisher) was created.
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the total number of samples available in the
+    /// [`Publisher`](crate::port::publisher::Publisher)s data segment.
+    pub fn number_of_samples(&self) -> usize {
+        self.number_of_samples
+    }
+
+    /// Returns the current maximum length of a slice.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Returns the maximum number of data segments the
+    /// [`Publisher`](crate::port::publisher::Publisher) can create.
+    pub fn max_number_of_segments(&self) -> u8 {
+        self.max_number_of_segments
+    }
+
+    /// Returns the maximum number of samples the
+    /// [`Publisher`](crate::port::publisher::Publisher) can loan in parallel.
+    pub fn max_loaned_samples(&self) -> usize {
+        self.max_loaned_samples
+    }
+
+    /// Returns the maximum period in which the [`Publisher`](crate::port::publisher::Publisher)
+    /// has to send a sample or assert its liveliness. If no lease duration is defined the
+    /// liveliness of the [`Publisher`](crate::port::publisher::Publisher) is not monitored.
+    pub fn liveliness_lease_duration(&self) -> Option<Duration> {
+        self.liveliness_lease_duration
+    }
+}
+
+/// The counters a connected [`Publisher`](crate::port::publisher::Publisher) updates while it
+/// is in use. Acquired with
+/// [`PortFactory::list_publisher_statistics()`](crate::service::port_factory::publish_subscribe::PortFactory::list_publisher_statistics()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PublisherStatistics {
+    /// The total number of samples the [`Publisher`](crate::port::publisher::Publisher) has
+    /// sent.
+    pub number_of_sent_samples: u64,
+    /// The number of samples the [`Publisher`](crate::port::publisher::Publisher) currently
+    /// loans.
+    pub number_of_loaned_samples: usize,
+    /// The point in time when the [`Publisher`](crate::port::publisher::Publisher) sent its last
+    /// sample. When no sample was sent yet, it is the point in time of its creation.
+    pub last_sample_timestamp: Time,
+    /// The point in time when the [`Publisher`](crate::port::publisher::Publisher) sent its last
+    /// sample or asserted its liveliness.
+    pub last_liveliness_timestamp: Time,
+}
+
+#[derive(Debug)]
+#[repr(C)]
+pub(crate) struct PublisherStatisticsEntry {
+    number_of_sent_samples: IoxAtomicU64,
+    number_of_loaned_samples: IoxAtomicUsize,
+    last_sample_timestamp: IoxAtomicU64,
+    last_liveliness_timestamp: IoxAtomicU64,
+}
+
+// timestamps are stored as nanoseconds of the default clock, zero means that no valid timestamp
+// could be acquired
+fn now_in_nanoseconds() -> u64 {
+    Time::now().map(|t| to_nanoseconds(&t)).unwrap_or(0)
+}
+
+fn to_nanoseconds(time: &Time) -> u64 {
+    time.as_duration().as_nanos() as u64
+}
+
+fn time_from_nanoseconds(value: u64) -> Time {
+    let value = Duration::from_nanos(value);
+    TimeBuilder::new()
+        .seconds(value.as_secs())
+        .nanoseconds(value.subsec_nanos())
+        .create()
+}
+
+impl PublisherStatisticsEntry {
+    pub(crate) fn new() -> Self {
+        let now = now_in_nanoseconds();
+        Self {
+            number_of_sent_samples: IoxAtomicU64::new(0),
+            number_of_loaned_samples: IoxAtomicUsize::new(0),
+            last_sample_timestamp: IoxAtomicU64::new(now),
+            last_liveliness_timestamp: IoxAtomicU64::new(now),
+        }
+    }
+
+    // sending a sample asserts the liveliness as well, the liveliness timestamp is therefore
+    // only updated by assert_liveliness() and combined with the sample timestamp in load()
+    pub(crate) fn increment_sent_samples(&self, timestamp: &Time) {
+        self.number_of_sent_samples.fetch_add(1, Ordering::Relaxed);
+        self.last_sample_timestamp
+            .store(to_nanoseconds(timestamp), Ordering::Relaxed);
+    }
+
+    pub(crate) fn assert_liveliness(&self) {
+        self.last_liveliness_timestamp
+            .store(now_in_nanoseconds(), Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_loaned_samples(&self, value: usize) {
+        self.number_of_loaned_samples
+            .store(value, Ordering::Relaxed);
+    }
+
+    pub(crate) fn load(&self) -> PublisherStatistics {
+        let last_sample_timestamp = self.last_sample_timestamp.load(Ordering::Relaxed);
+        let last_liveliness_timestamp = self.last_liveliness_timestamp.load(Ordering::Relaxed);
+        PublisherStatistics {
+            number_of_sent_samples: self.number_of_sent_samples.load(Ordering::Relaxed),
+            number_of_loaned_samples: self.number_of_loaned_samples.load(Ordering::Relaxed),
+            last_sample_timestamp: time_from_nanoseconds(last_sample_timestamp),
+            last_liveliness_timestamp: time_from_nanoseconds(
+                last_sample_timestamp.max(last_liveliness_timestamp),
+            ),
+        }
+    }
+}
+
+/// Contains the communication settings of the connected
+/// [`Subscriber`](crate::port::subscriber::Subscriber).
 #[derive(Debug, Copy, Clone)]
-pub(crate) struct SubscriberDetails {
+pub struct SubscriberDetails {
     pub(crate) subscriber_id: UniqueSubscriberId,
     pub(crate) node_id: NodeId,
     pub(crate) buffer_size: usize,
+    pub(crate) history_size: usize,
+}
+
+impl SubscriberDetails {
+    /// Returns the [`UniqueSubscriberId`] of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) was created.
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the receive buffer size of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Returns the number of history samples the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) receives when it connects to a
+    /// [`Publisher`](crate::port::publisher::Publisher).
+    pub fn history_size(&self) -> usize {
+        self.history_size
+    }
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -133,6 +310,28 @@ impl DynamicConfig {
         self.subscribers.len()
     }
 
+    /// Iterates over all connected [`crate::port::publisher::Publisher`] ports and calls the
+    /// provided callback with their [`PublisherDetails`] until the callback returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_publishers<F: FnMut(&PublisherDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.publishers.get_state() };
+        state.for_each(|_, details| callback(details));
+    }
+
+    /// Iterates over all connected [`crate::port::subscriber::Subscriber`] ports and calls the
+    /// provided callback with their [`SubscriberDetails`] until the callback returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_subscribers<F: FnMut(&SubscriberDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.subscribers.get_state() };
+        state.for_each(|_, details| callback(details));
+    }
+
     #[doc(hidden)]
     pub fn __internal_subscriber_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
         let state = unsafe { self.subscribers.get_state() };
@@ -0,0 +1,98 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//! let client = service.client_builder().create()?;
+//!
+//! let pending_response = client.send_copy(1234)?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("request header: {:?}", active_request.header());
+//!     active_request.send_copy(5678)?;
+//! }
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("response header: {:?}", response.header());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+
+/// Request header used by
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct RequestHeader {
+    client_port_id: UniqueClientId,
+    request_id: u64,
+}
+
+impl RequestHeader {
+    pub(crate) fn new(client_port_id: UniqueClientId, request_id: u64) -> Self {
+        Self {
+            client_port_id,
+            request_id,
+        }
+    }
+
+    /// Returns the [`UniqueClientId`] of the source [`crate::port::client::Client`].
+    pub fn client_id(&self) -> UniqueClientId {
+        self.client_port_id
+    }
+
+    /// Returns the id of the request. It is unique for every request a
+    /// [`crate::port::client::Client`] has sent.
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
+
+/// Response header used by
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct ResponseHeader {
+    server_port_id: UniqueServerId,
+    request_id: u64,
+}
+
+impl ResponseHeader {
+    pub(crate) fn new(server_port_id: UniqueServerId, request_id: u64) -> Self {
+        Self {
+            server_port_id,
+            request_id,
+        }
+    }
+
+    /// Returns the [`UniqueServerId`] of the source [`crate::port::server::Server`].
+    pub fn server_id(&self) -> UniqueServerId {
+        self.server_port_id
+    }
+
+    /// Returns the id of the request this response belongs to.
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
@@ -32,7 +32,7 @@
 //!
 //! See [`Service`](crate::service) for more detailed examples.
 
-use std::sync::Arc;
+use std::{fmt::Debug, sync::Arc};
 
 use crate::service::dynamic_config::DynamicConfig;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
@@ -50,11 +50,17 @@ impl crate::service::Service for Service {
     type StaticStorage = static_storage::file::Storage;
     type ConfigSerializer = serialize::toml::Toml;
     type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type PortStatisticsStorage<T: Send + Sync + Debug + 'static> =
+        dynamic_storage::posix_shared_memory::Storage<T>;
     type ServiceNameHasher = hash::sha1::Sha1;
     type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::posix_shared_memory::Connection;
     type Event = event::unix_datagram_socket::EventImpl;
     type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    #[cfg(target_os = "linux")]
+    type Reactor = reactor::linux_epoll::Reactor;
+    #[cfg(not(target_os = "linux"))]
+    type Reactor = reactor::posix_select::Reactor;
 }
 
 impl crate::service::internal::ServiceInternal<Service> for Service {
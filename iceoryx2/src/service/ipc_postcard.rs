@@ -0,0 +1,61 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Identical to [`ipc`](crate::service::ipc) but stores the
+//! [`StaticConfig`](crate::service::static_config::StaticConfig) with the compact
+//! binary [`Postcard`](iceoryx2_cal::serialize::postcard::Postcard) serializer.
+//! It exists to verify that services work with a binary
+//! [`Service::ConfigSerializer`](crate::service::Service::ConfigSerializer).
+
+use std::{fmt::Debug, sync::Arc};
+
+use crate::service::dynamic_config::DynamicConfig;
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::*;
+
+use super::ServiceState;
+
+/// Defines a zero copy inter-process communication setup based on posix mechanisms
+/// that serializes the static service config with postcard.
+#[derive(Debug)]
+pub struct Service {
+    state: Arc<ServiceState<Self>>,
+}
+
+impl crate::service::Service for Service {
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::postcard::Postcard;
+    type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type PortStatisticsStorage<T: Send + Sync + Debug + 'static> =
+        dynamic_storage::posix_shared_memory::Storage<T>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
+    type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    type Event = event::unix_datagram_socket::EventImpl;
+    type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    #[cfg(target_os = "linux")]
+    type Reactor = reactor::linux_epoll::Reactor;
+    #[cfg(not(target_os = "linux"))]
+    type Reactor = reactor::posix_select::Reactor;
+}
+
+impl crate::service::internal::ServiceInternal<Service> for Service {
+    fn __internal_from_state(state: ServiceState<Self>) -> Self {
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    fn __internal_state(&self) -> &Arc<ServiceState<Self>> {
+        &self.state
+    }
+}
@@ -31,6 +31,20 @@
 //! [`Listener`](crate::port::listener::Listener)s.
 //!
 //! **Note:** This does **not** send or receive POSIX signals nor is it based on them.
+//!
+//! ### Request-Response
+//!
+//! Bi-directional communication where `n` [`Client`](crate::port::client::Client)s send
+//! requests to `m` [`Server`](crate::port::server::Server)s. Every request can be answered by
+//! every [`Server`](crate::port::server::Server) with a stream of responses that are delivered
+//! only to the [`Client`](crate::port::client::Client) that sent the request.
+//!
+//! ### Blackboard
+//!
+//! A key-value store where one [`Writer`](crate::port::writer::Writer) updates a fixed set of
+//! typed entries and `m` [`Reader`](crate::port::reader::Reader)s read the latest value of an
+//! entry. Values are not queued, a [`Reader`](crate::port::reader::Reader) always sees the
+//! most recently written value.
 
 /// Identifies the kind of messaging pattern the [`Service`](crate::service::Service) will use.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -46,4 +60,14 @@ pub enum MessagingPattern {
     /// ability to sleep until a signal/event arrives.
     /// Building block to realize push-notifications.
     Event,
+
+    /// Bidirectional communication pattern where the [`Client`](crate::port::client::Client)
+    /// sends arbitrary requests to the [`Server`](crate::port::server::Server) which can
+    /// respond to them with a stream of arbitrary responses.
+    RequestResponse,
+
+    /// Key-value communication pattern where one [`Writer`](crate::port::writer::Writer)
+    /// updates the entries of a shared key-value store and the
+    /// [`Reader`](crate::port::reader::Reader)s read the latest value of an entry.
+    Blackboard,
 }
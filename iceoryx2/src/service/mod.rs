@@ -155,12 +155,18 @@ pub mod static_config;
 /// Represents static features of a service that can be set when a [`Service`] is created.
 pub mod attribute;
 
+/// Selects [`Service`]s by name patterns, [`MessagingPattern`] and attributes.
+pub mod query;
+
 /// A configuration when communicating within a single process or single address space.
 pub mod local;
 
 /// A configuration when communicating between different processes using posix mechanisms.
 pub mod ipc;
 
+#[doc(hidden)]
+pub mod ipc_postcard;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -169,14 +175,17 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::config;
+use crate::discovery::{notify_discovery_listeners, DiscoveryTrigger, ServiceRemovalNotifier};
 use crate::node::{NodeId, NodeListFailure, NodeState, SharedNode};
 use crate::service::config_scheme::dynamic_config_storage_config;
-use crate::service::dynamic_config::DynamicConfig;
+use crate::service::dynamic_config::{DynamicConfig, PortDetails};
 use crate::service::static_config::*;
 use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_log::{debug, fail, trace, warn};
+use iceoryx2_bb_posix::user::User;
+use iceoryx2_cal::access_policy::AccessPolicy;
 use iceoryx2_cal::dynamic_storage::{
     DynamicStorage, DynamicStorageBuilder, DynamicStorageOpenError,
 };
@@ -185,6 +194,7 @@ use iceoryx2_cal::hash::*;
 use iceoryx2_cal::monitoring::Monitoring;
 use iceoryx2_cal::named_concept::NamedConceptListError;
 use iceoryx2_cal::named_concept::*;
+use iceoryx2_cal::reactor::Reactor;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::shared_memory::SharedMemory;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
@@ -194,6 +204,7 @@ use service_id::ServiceId;
 
 use self::dynamic_config::DeregisterNodeState;
 use self::messaging_pattern::MessagingPattern;
+use self::query::ServiceQuery;
 use self::service_name::ServiceName;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -260,6 +271,9 @@ impl std::error::Error for ServiceListError {}
 pub struct ServiceDynamicDetails<S: Service> {
     /// A list of all [`Node`](crate::node::Node)s that a registered at the [`Service`]
     pub nodes: Vec<NodeState<S>>,
+    /// A list of all ports that are connected to the [`Service`], the owning
+    /// [`Node`](crate::node::Node) is provided by [`PortDetails::node_id()`].
+    pub ports: Vec<PortDetails>,
 }
 
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`].
@@ -279,6 +293,12 @@ pub struct ServiceState<S: Service> {
     pub(crate) shared_node: Arc<SharedNode<S>>,
     pub(crate) dynamic_storage: S::DynamicStorage,
     pub(crate) static_storage: S::StaticStorage,
+    // the access policy of the service as the current user can apply it to the resources of
+    // its ports
+    pub(crate) port_access_policy: AccessPolicy,
+    // must be the last member so that the discovery listeners are notified after the
+    // underlying resources are removed
+    removal_notifier: ServiceRemovalNotifier<S>,
 }
 
 impl<S: Service> ServiceState<S> {
@@ -288,11 +308,22 @@ impl<S: Service> ServiceState<S> {
         dynamic_storage: S::DynamicStorage,
         static_storage: S::StaticStorage,
     ) -> Self {
+        let port_access_policy = match User::from_self() {
+            Ok(user) => static_config.access_policy().applicable_by(&user),
+            Err(e) => {
+                warn!(from "Service::open()",
+                    "Unable to acquire the current user ({:?}), the access policy of the service is applied unchanged to the resources of its ports.", e);
+                static_config.access_policy().clone()
+            }
+        };
+
         let new_self = Self {
             static_config,
             shared_node,
             dynamic_storage,
             static_storage,
+            port_access_policy,
+            removal_notifier: ServiceRemovalNotifier::new(),
         };
         trace!(from "Service::open()", "open service: {} ({:?})",
             new_self.static_config.name(), new_self.static_config.service_id());
@@ -304,7 +335,11 @@ impl<S: Service> Drop for ServiceState<S> {
     fn drop(&mut self) {
         let origin = "ServiceState::drop()";
         let id = self.static_config.service_id();
+        let mut is_removed = false;
         self.shared_node.registered_services().remove(id, |handle| {
+            // the node no longer uses the service, no publisher can take over the history
+            self.shared_node.retained_histories().remove(id);
+
             if let Err(e) = remove_service_tag::<S>(self.shared_node.id(), id, self.shared_node.config())
             {
                 debug!(from origin, "The service tag could not be removed from the node {:?} ({:?}).",
@@ -319,11 +354,16 @@ impl<S: Service> Drop for ServiceState<S> {
                 DeregisterNodeState::NoMoreOwners => {
                     self.static_storage.acquire_ownership();
                     self.dynamic_storage.acquire_ownership();
+                    is_removed = true;
                     trace!(from origin, "close and remove service: {} ({:?})",
                             self.static_config.name(), id);
                 }
             }
         });
+
+        if is_removed {
+            self.removal_notifier.arm(self.shared_node.clone());
+        }
     }
 }
 
@@ -334,12 +374,17 @@ pub(crate) mod internal {
     use crate::{
         node::NodeId,
         port::{
+            client::{
+                remove_data_segment_of_client, remove_request_response_port_from_all_connections,
+            },
             listener::remove_connection_of_listener,
+            notifier::remove_statistics_of_notifier,
             port_identifiers::UniquePortId,
             publisher::{
                 remove_data_segment_of_publisher, remove_publisher_from_all_connections,
-                remove_subscriber_from_all_connections,
+                remove_statistics_of_publisher, remove_subscriber_from_all_connections,
             },
+            server::remove_data_segment_of_server,
         },
     };
 
@@ -389,6 +434,11 @@ pub(crate) mod internal {
                             debug!(from origin, "Failed to remove the publishers ({:?}) data segment ({:?}).", id, e);
                             return PortCleanupAction::SkipPort;
                         }
+
+                        if let Err(e) = unsafe { remove_statistics_of_publisher::<S>(id, config) } {
+                            debug!(from origin, "Failed to remove the publishers ({:?}) statistics ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
                     }
                     UniquePortId::Subscriber(ref id) => {
                         if let Err(e) =
@@ -398,13 +448,51 @@ pub(crate) mod internal {
                             return PortCleanupAction::SkipPort;
                         }
                     }
-                    UniquePortId::Notifier(_) => (),
+                    UniquePortId::Notifier(ref id) => {
+                        if let Err(e) = unsafe { remove_statistics_of_notifier::<S>(id, config) } {
+                            debug!(from origin, "Failed to remove the notifiers ({:?}) statistics ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
                     UniquePortId::Listener(ref id) => {
                         if let Err(e) = unsafe { remove_connection_of_listener::<S>(id, config) } {
                             debug!(from origin, "Failed to remove the listeners ({:?}) connection ({:?}).", id, e);
                             return PortCleanupAction::SkipPort;
                         }
                     }
+                    UniquePortId::Client(ref id) => {
+                        if let Err(e) = unsafe {
+                            remove_request_response_port_from_all_connections::<S>(
+                                id.0.value(),
+                                config,
+                            )
+                        } {
+                            debug!(from origin, "Failed to remove the client ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+
+                        if let Err(e) = unsafe { remove_data_segment_of_client::<S>(id, config) } {
+                            debug!(from origin, "Failed to remove the clients ({:?}) data segment ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::Server(ref id) => {
+                        if let Err(e) = unsafe {
+                            remove_request_response_port_from_all_connections::<S>(
+                                id.0.value(),
+                                config,
+                            )
+                        } {
+                            debug!(from origin, "Failed to remove the server ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+
+                        if let Err(e) = unsafe { remove_data_segment_of_server::<S>(id, config) } {
+                            debug!(from origin, "Failed to remove the servers ({:?}) data segment ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::Reader(_) | UniquePortId::Writer(_) => (),
                 };
 
                 debug!(from origin, "Remove port {:?} from service.", port_id);
@@ -432,7 +520,8 @@ pub(crate) mod internal {
                 } {
                     Ok(_) => {
                         debug!(from origin, "Remove unused service.");
-                        dynamic_config.acquire_ownership()
+                        dynamic_config.acquire_ownership();
+                        notify_discovery_listeners::<S>(config, DiscoveryTrigger::ServicesChanged);
                     }
                     Err(e) => {
                         warn!(from origin, "Unable to remove static config of unused service ({:?}).",
@@ -466,6 +555,10 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     /// contains for instance all endpoints and other dynamic details.
     type DynamicStorage: DynamicStorage<DynamicConfig>;
 
+    /// Defines the construct used to store the statistics a port updates while it is in use.
+    /// Only the process of the port writes to it, all other processes map it read-only.
+    type PortStatisticsStorage<T: Send + Sync + Debug + 'static>: DynamicStorage<T>;
+
     /// The memory used to store the payload.
     type SharedMemory: SharedMemory<PoolAllocator>;
 
@@ -478,6 +571,9 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     /// Monitoring mechanism to detect dead processes.
     type Monitoring: Monitoring;
 
+    /// Event multiplexing mechanism to wait on multiple events.
+    type Reactor: Reactor;
+
     /// Checks if a service under a given [`config::Config`] does exist
     ///
     /// # Example
@@ -583,6 +679,42 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
 
         Ok(())
     }
+
+    /// Returns all services created under a given [`config::Config`] that match the
+    /// [`ServiceQuery`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2::service::query::{ServiceNamePattern, ServiceQuery};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let query = ServiceQuery::new()
+    ///     .name(ServiceNamePattern::Prefix("Robot/Arm/".into()))
+    ///     .attributes(&AttributeVerifier::new().require("sensor", "lidar"));
+    ///
+    /// ipc::Service::query(Config::global_config(), &query, |service| {
+    ///     println!("\n{:#?}", &service);
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn query<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
+        config: &config::Config,
+        query: &ServiceQuery,
+        mut callback: F,
+    ) -> Result<(), ServiceListError> {
+        Self::list(config, |service_details| {
+            if query.matches(&service_details.static_details) {
+                callback(service_details)
+            } else {
+                CallbackProgression::Continue
+            }
+        })
+    }
 }
 
 fn details<S: Service>(
@@ -610,22 +742,21 @@ fn details<S: Service>(
         }
     };
 
-    let mut content = String::from_utf8(vec![b' '; reader.len() as usize]).unwrap();
-    if let Err(e) = reader.read(unsafe { content.as_mut_vec().as_mut_slice() }) {
+    let mut content = vec![0u8; reader.len() as usize];
+    if let Err(e) = reader.read(content.as_mut_slice()) {
         fail!(from origin, with ServiceDetailsError::FailedToReadStaticServiceInfo,
                 "{} since the static service info \"{}\" could not be read ({:?}).",
                 msg, uuid, e );
     }
 
-    let service_config =
-        match S::ConfigSerializer::deserialize::<StaticConfig>(unsafe { content.as_mut_vec() }) {
-            Ok(service_config) => service_config,
-            Err(e) => {
-                fail!(from origin, with ServiceDetailsError::FailedToDeserializeStaticServiceInfo,
+    let service_config = match S::ConfigSerializer::deserialize::<StaticConfig>(&content) {
+        Ok(service_config) => service_config,
+        Err(e) => {
+            fail!(from origin, with ServiceDetailsError::FailedToDeserializeStaticServiceInfo,
                     "{} since the static service info \"{}\" could not be deserialized ({:?}).",
                        msg, uuid, e );
-            }
-        };
+        }
+    };
 
     if uuid.as_bytes() != service_config.service_id().0.as_bytes() {
         fail!(from origin, with ServiceDetailsError::ServiceInInconsistentState,
@@ -648,7 +779,14 @@ fn details<S: Service>(
             };
             CallbackProgression::Continue
         });
-        Some(ServiceDynamicDetails { nodes })
+
+        let mut ports = vec![];
+        d.get().list_ports(|details| {
+            ports.push(details);
+            CallbackProgression::Continue
+        });
+
+        Some(ServiceDynamicDetails { nodes, ports })
     } else {
         None
     };
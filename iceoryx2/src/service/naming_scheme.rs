@@ -10,10 +10,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::port::port_identifiers::{
+    UniqueClientId, UniqueListenerId, UniquePublisherId, UniqueServerId, UniqueSubscriberId,
+};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::shm_allocator::SegmentId;
 
 pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
     let msg = "The system does not support the required file name length for the listeners event concept name.";
@@ -51,12 +55,77 @@ pub(crate) fn extract_subscriber_id_from_connection(connection: &FileName) -> Un
     unsafe { core::mem::transmute::<u128, UniqueSubscriberId>(value) }
 }
 
-pub(crate) fn data_segment_name(publisher_id: &UniquePublisherId) -> FileName {
+pub(crate) fn data_segment_name(
+    publisher_id: &UniquePublisherId,
+    segment_id: SegmentId,
+) -> FileName {
     let msg = "The system does not support the required file name length for the publishers data segment.";
     let origin = "data_segment_name()";
 
     let mut file = fatal_panic!(from origin, when FileName::new(publisher_id.0.pid().to_string().as_bytes()), "{}", msg);
     fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
     fatal_panic!(from origin, when file.push_bytes(publisher_id.0.value().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(segment_id.value().to_string().as_bytes()), "{}", msg);
+    file
+}
+
+pub(crate) fn request_connection_name(
+    client_id: UniqueClientId,
+    server_id: UniqueServerId,
+) -> FileName {
+    let mut file = FileName::new(client_id.0.value().to_string().as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(server_id.0.value().to_string().as_bytes())
+        .unwrap();
+    file
+}
+
+pub(crate) fn response_connection_name(
+    server_id: UniqueServerId,
+    client_id: UniqueClientId,
+) -> FileName {
+    let mut file = FileName::new(server_id.0.value().to_string().as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(client_id.0.value().to_string().as_bytes())
+        .unwrap();
+    file
+}
+
+pub(crate) fn is_port_part_of_connection(connection: &FileName, port_id_value: u128) -> bool {
+    let name = core::str::from_utf8(connection.as_bytes()).unwrap();
+    name.split('_')
+        .any(|id| id.parse::<u128>() == Ok(port_id_value))
+}
+
+pub(crate) fn client_data_segment_name(client_id: &UniqueClientId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the clients data segment.";
+    let origin = "client_data_segment_name()";
+
+    let mut file = fatal_panic!(from origin, when FileName::new(client_id.0.pid().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(client_id.0.value().to_string().as_bytes()), "{}", msg);
+    file
+}
+
+pub(crate) fn server_data_segment_name(server_id: &UniqueServerId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the servers data segment.";
+    let origin = "server_data_segment_name()";
+
+    let mut file = fatal_panic!(from origin, when FileName::new(server_id.0.pid().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(server_id.0.value().to_string().as_bytes()), "{}", msg);
+    file
+}
+
+pub(crate) fn port_statistics_name(port_id: &UniqueSystemId) -> FileName {
+    let msg = "The system does not support the required file name length for the ports statistics.";
+    let origin = "port_statistics_name()";
+
+    let mut file = fatal_panic!(from origin, when FileName::new(port_id.pid().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(port_id.value().to_string().as_bytes()), "{}", msg);
     file
 }
@@ -0,0 +1,178 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .open_or_create()?;
+//!
+//! println!("name:                         {:?}", blackboard.name());
+//! println!("service id:                   {:?}", blackboard.service_id());
+//! println!("number of entries:            {:?}", blackboard.static_config().number_of_entries());
+//! println!("max readers:                  {:?}", blackboard.static_config().max_readers());
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//!
+//! let writer = blackboard.writer_builder().create()?;
+//! let reader = blackboard.reader_builder().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::NodeListFailure;
+use crate::service::attribute::AttributeSet;
+use crate::service::builder::AccessPolicy;
+use crate::service::service_id::ServiceId;
+use crate::service::{self, static_config};
+use crate::service::{dynamic_config, ServiceName};
+
+use super::nodes;
+use super::reader::PortFactoryReader;
+use super::writer::PortFactoryWriter;
+
+/// The factory for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::reader::Reader`] or [`crate::port::writer::Writer`] ports.
+#[derive(Debug)]
+pub struct PortFactory<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+> {
+    pub(crate) service: Service,
+    _key: PhantomData<KeyType>,
+}
+
+unsafe impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static> Send
+    for PortFactory<Service, KeyType>
+{
+}
+unsafe impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static> Sync
+    for PortFactory<Service, KeyType>
+{
+}
+
+impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static>
+    crate::service::port_factory::PortFactory for PortFactory<Service, KeyType>
+{
+    type Service = Service;
+    type StaticConfig = static_config::blackboard::StaticConfig;
+    type DynamicConfig = dynamic_config::blackboard::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.service.__internal_state().static_config.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.service.__internal_state().static_config.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.service.__internal_state().static_config.attributes()
+    }
+
+    fn access_policy(&self) -> &AccessPolicy {
+        self.service
+            .__internal_state()
+            .static_config
+            .access_policy()
+    }
+
+    fn static_config(&self) -> &static_config::blackboard::StaticConfig {
+        self.service.__internal_state().static_config.blackboard()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::blackboard::DynamicConfig {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .blackboard()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        nodes(
+            self.service.__internal_state().dynamic_storage.get(),
+            self.service.__internal_state().shared_node.config(),
+            callback,
+        )
+    }
+}
+
+impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static>
+    PortFactory<Service, KeyType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _key: PhantomData,
+        }
+    }
+
+    /// Returns a [`PortFactoryWriter`] to create a new [`crate::port::writer::Writer`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .blackboard::<u64>()
+    ///     .add::<i32>(0, -1)
+    ///     .open_or_create()?;
+    ///
+    /// let writer = blackboard.writer_builder().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn writer_builder(&self) -> PortFactoryWriter<'_, Service, KeyType> {
+        PortFactoryWriter { factory: self }
+    }
+
+    /// Returns a [`PortFactoryReader`] to create a new [`crate::port::reader::Reader`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .blackboard::<u64>()
+    ///     .add::<i32>(0, -1)
+    ///     .open_or_create()?;
+    ///
+    /// let reader = blackboard.reader_builder().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reader_builder(&self) -> PortFactoryReader<'_, Service, KeyType> {
+        PortFactoryReader { factory: self }
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder()
+//!                     .max_loaned_requests(6)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use super::request_response::PortFactory;
+use crate::{
+    port::client::{Client, ClientCreateError},
+    service,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LocalClientConfig {
+    pub(crate) max_loaned_requests: usize,
+}
+
+/// Factory to create a new [`Client`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based communication.
+#[derive(Debug)]
+pub struct PortFactoryClient<
+    'factory,
+    Service: service::Service,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    config: LocalClientConfig,
+    pub(crate) factory: &'factory PortFactory<Service, RequestPayload, ResponsePayload>,
+}
+
+impl<'factory, Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    PortFactoryClient<'factory, Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<Service, RequestPayload, ResponsePayload>,
+    ) -> Self {
+        Self {
+            config: LocalClientConfig {
+                max_loaned_requests: factory
+                    .service
+                    .__internal_state()
+                    .shared_node
+                    .config()
+                    .defaults
+                    .request_response
+                    .client_max_loaned_requests,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::request_mut::RequestMut`] the [`Client`] can loan with
+    /// [`Client::loan()`] or [`Client::loan_uninit()`] in parallel.
+    pub fn max_loaned_requests(mut self, value: usize) -> Self {
+        self.config.max_loaned_requests = value;
+        self
+    }
+
+    /// Creates a new [`Client`] or returns a [`ClientCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<Client<Service, RequestPayload, ResponsePayload>, ClientCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(
+            fail!(from origin, when Client::new(&self.factory.service, self.factory.service.__internal_state().static_config.request_response(), self.config),
+                "Failed to create new Client port."),
+        )
+    }
+}
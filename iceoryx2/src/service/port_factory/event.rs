@@ -30,14 +30,27 @@
 //!
 //! let listener = event.listener_builder().create()?;
 //! let notifier = event.notifier_builder().create()?;
+//!
+//! event.list_notifier_statistics(|details, statistics| {
+//!     println!("notifier {:?} sent {} notifications", details.notifier_id(),
+//!         statistics.number_of_notifications);
+//!     CallbackProgression::Continue
+//! });
 //! # Ok(())
 //! # }
 //! ```
+use std::time::Duration;
+
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
+use crate::port::details::port_statistics::PortStatistics;
 use crate::service::attribute::AttributeSet;
+use crate::service::builder::AccessPolicy;
+use crate::service::dynamic_config::event::{
+    NotifierDetails, NotifierStatistics, NotifierStatisticsEntry,
+};
 use crate::service::service_id::ServiceId;
 use crate::service::{self, static_config};
 use crate::service::{dynamic_config, ServiceName};
@@ -75,6 +88,13 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.__internal_state().static_config.attributes()
     }
 
+    fn access_policy(&self) -> &AccessPolicy {
+        self.service
+            .__internal_state()
+            .static_config
+            .access_policy()
+    }
+
     fn static_config(&self) -> &static_config::event::StaticConfig {
         self.service.__internal_state().static_config.event()
     }
@@ -145,4 +165,33 @@ impl<Service: service::Service> PortFactory<Service> {
     pub fn listener_builder(&self) -> PortFactoryListener<Service> {
         PortFactoryListener { factory: self }
     }
+
+    /// Iterates over all connected [`crate::port::notifier::Notifier`] ports and calls the
+    /// provided callback with their [`NotifierDetails`] and current [`NotifierStatistics`]
+    /// until the callback returns [`CallbackProgression::Stop`]. The statistics are owned by
+    /// the process of the [`crate::port::notifier::Notifier`] and are mapped read-only.
+    pub fn list_notifier_statistics<
+        F: FnMut(&NotifierDetails, &NotifierStatistics) -> CallbackProgression,
+    >(
+        &self,
+        mut callback: F,
+    ) {
+        let state = self.service.__internal_state();
+        let config = state.shared_node.config();
+        state
+            .dynamic_storage
+            .get()
+            .event()
+            .list_notifiers(|details| {
+                match PortStatistics::<Service, NotifierStatisticsEntry>::open_read_only(
+                    &details.notifier_id.0,
+                    config,
+                    Duration::ZERO,
+                ) {
+                    Ok(statistics) => callback(details, &statistics.get().load()),
+                    // the notifier was removed in the meantime
+                    Err(_) => CallbackProgression::Continue,
+                }
+            });
+    }
 }
@@ -15,10 +15,22 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use crate::config::Config;
 use crate::node::{NodeListFailure, NodeState};
 
+use super::builder::AccessPolicy;
 use super::dynamic_config::DynamicConfig;
 use super::service_id::ServiceId;
 use super::{attribute::AttributeSet, service_name::ServiceName};
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
+/// communication and to acquire static and dynamic service information
+pub mod blackboard;
+
+/// Factory to create a [`Reader`](crate::port::reader::Reader)
+pub mod reader;
+
+/// Factory to create a [`Writer`](crate::port::writer::Writer)
+pub mod writer;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication and to acquire static and dynamic service information
@@ -35,12 +47,28 @@ pub mod notifier;
 /// communication and to acquire static and dynamic service information
 pub mod publish_subscribe;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication with serialized payloads
+pub mod publish_subscribe_serialized;
+
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication and to acquire static and dynamic service information
+pub mod request_response;
+
+/// Factory to create a [`Client`](crate::port::client::Client)
+pub mod client;
+
+/// Factory to create a [`Server`](crate::port::server::Server)
+pub mod server;
+
 /// The trait that contains the interface of all port factories for any kind of
 /// [`crate::service::messaging_pattern::MessagingPattern`].
 pub trait PortFactory {
@@ -64,6 +92,9 @@ pub trait PortFactory {
     /// Returns the attributes defined in the [`crate::service::Service`]
     fn attributes(&self) -> &AttributeSet;
 
+    /// Returns the [`AccessPolicy`] of the [`crate::service::Service`]
+    fn access_policy(&self) -> &AccessPolicy;
+
     /// Returns the StaticConfig of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     fn static_config(&self) -> &Self::StaticConfig;
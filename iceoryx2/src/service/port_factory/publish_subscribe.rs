@@ -36,17 +36,39 @@
 //! let publisher = pubsub.publisher_builder().create()?;
 //! let subscriber = pubsub.subscriber_builder().create()?;
 //!
+//! pubsub.list_publisher_statistics(|details, statistics| {
+//!     println!("publisher {:?} sent {} samples", details.publisher_id(),
+//!         statistics.number_of_sent_samples);
+//!     CallbackProgression::Continue
+//! });
+//!
+//! pubsub.list_connection_statistics(|statistics| {
+//!     println!("queued samples of {:?}: {}", statistics.subscriber_id,
+//!         statistics.number_of_queued_samples);
+//!     CallbackProgression::Continue
+//! });
+//!
 //! # Ok(())
 //! # }
 //! ```
 
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
 
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
 
 use crate::node::NodeListFailure;
+use crate::port::details::port_statistics::PortStatistics;
+use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use crate::service::attribute::AttributeSet;
+use crate::service::builder::publish_subscribe::notify_on_send_service_name;
+use crate::service::builder::AccessPolicy;
+use crate::service::config_scheme::connection_config;
+use crate::service::dynamic_config::publish_subscribe::{
+    PublisherDetails, PublisherStatistics, PublisherStatisticsEntry,
+};
+use crate::service::naming_scheme::connection_name;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
@@ -54,6 +76,36 @@ use crate::service::{self, dynamic_config, static_config};
 use super::nodes;
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 
+/// The counters of the connection between a [`crate::port::publisher::Publisher`] and a
+/// [`crate::port::subscriber::Subscriber`]. Acquired with
+/// [`PortFactory::list_connection_statistics()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionStatistics {
+    /// The [`UniquePublisherId`] of the sending [`crate::port::publisher::Publisher`]
+    pub publisher_id: UniquePublisherId,
+    /// The [`UniqueSubscriberId`] of the receiving [`crate::port::subscriber::Subscriber`]
+    pub subscriber_id: UniqueSubscriberId,
+    /// The capacity of the receive buffer of the [`crate::port::subscriber::Subscriber`]
+    pub buffer_size: usize,
+    /// The number of samples that were sent but not yet received
+    pub number_of_queued_samples: usize,
+    /// The maximum number of samples the [`crate::port::subscriber::Subscriber`] can borrow
+    /// in parallel
+    pub max_borrowed_samples: usize,
+    /// The number of samples the [`crate::port::subscriber::Subscriber`] currently borrows
+    pub number_of_borrowed_samples: usize,
+    /// The total number of samples sent via the connection
+    pub number_of_sent_samples: u64,
+    /// The total number of samples received via the connection
+    pub number_of_received_samples: u64,
+    /// The sum of the latencies of all samples received via the connection, the time from
+    /// [`Header::timestamp()`](crate::service::header::publish_subscribe::Header::timestamp())
+    /// until the sample was received
+    pub accumulated_latency: Duration,
+    /// The latency of the sample that was received last via the connection
+    pub last_latency: Duration,
+}
+
 /// The factory for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
 /// It can acquire dynamic and static service informations and create
@@ -94,6 +146,13 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.service.__internal_state().static_config.attributes()
     }
 
+    fn access_policy(&self) -> &AccessPolicy {
+        self.service
+            .__internal_state()
+            .static_config
+            .access_policy()
+    }
+
     fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
         self.service
             .__internal_state()
@@ -181,4 +240,107 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     pub fn publisher_builder(&self) -> PortFactoryPublisher<Service, Payload, UserHeader> {
         PortFactoryPublisher::new(self)
     }
+
+    /// Returns the [`ServiceName`] of the companion event [`Service`](crate::service::Service)
+    /// that is notified on every send when the service was created with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notify_on_send()`],
+    /// otherwise [`None`]. The name is reserved for iceoryx2 and can only be used to open the
+    /// companion event service.
+    pub fn notify_on_send_service_name(&self) -> Option<ServiceName> {
+        let state = self.service.__internal_state();
+        match state
+            .static_config
+            .publish_subscribe()
+            .enable_notify_on_send
+        {
+            true => Some(notify_on_send_service_name(state.static_config.name())),
+            false => None,
+        }
+    }
+
+    /// Iterates over all connected [`crate::port::publisher::Publisher`] ports and calls the
+    /// provided callback with their [`PublisherDetails`] and current [`PublisherStatistics`]
+    /// until the callback returns [`CallbackProgression::Stop`]. The statistics are owned by
+    /// the process of the [`crate::port::publisher::Publisher`] and are mapped read-only.
+    pub fn list_publisher_statistics<
+        F: FnMut(&PublisherDetails, &PublisherStatistics) -> CallbackProgression,
+    >(
+        &self,
+        mut callback: F,
+    ) {
+        let state = self.service.__internal_state();
+        let config = state.shared_node.config();
+        state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .list_publishers(|details| {
+                match PortStatistics::<Service, PublisherStatisticsEntry>::open_read_only(
+                    &details.publisher_id.0,
+                    config,
+                    Duration::ZERO,
+                ) {
+                    Ok(statistics) => callback(details, &statistics.get().load()),
+                    // the publisher was removed in the meantime
+                    Err(_) => CallbackProgression::Continue,
+                }
+            });
+    }
+
+    /// Iterates over the connections between all connected
+    /// [`crate::port::publisher::Publisher`]s and [`crate::port::subscriber::Subscriber`]s and
+    /// calls the provided callback with their [`ConnectionStatistics`] until the callback
+    /// returns [`CallbackProgression::Stop`]. The statistics are acquired without
+    /// connecting to the [`Service`](crate::service::Service) as a port.
+    pub fn list_connection_statistics<F: FnMut(&ConnectionStatistics) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let dynamic_config = self
+            .service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe();
+        let config =
+            connection_config::<Service>(self.service.__internal_state().shared_node.config());
+
+        let mut publisher_ids = vec![];
+        dynamic_config.list_publishers(|details| {
+            publisher_ids.push(details.publisher_id);
+            CallbackProgression::Continue
+        });
+
+        let mut subscriber_ids = vec![];
+        dynamic_config.list_subscribers(|details| {
+            subscriber_ids.push(details.subscriber_id);
+            CallbackProgression::Continue
+        });
+
+        for publisher_id in &publisher_ids {
+            for subscriber_id in &subscriber_ids {
+                let name = connection_name(*publisher_id, *subscriber_id);
+                if let Ok(Some(statistics)) =
+                    <Service::Connection as ZeroCopyConnection>::statistics(&name, &config)
+                {
+                    let statistics = ConnectionStatistics {
+                        publisher_id: *publisher_id,
+                        subscriber_id: *subscriber_id,
+                        buffer_size: statistics.buffer_size,
+                        number_of_queued_samples: statistics.number_of_queued_samples,
+                        max_borrowed_samples: statistics.max_borrowed_samples,
+                        number_of_borrowed_samples: statistics.number_of_borrowed_samples,
+                        number_of_sent_samples: statistics.number_of_sent_samples,
+                        number_of_received_samples: statistics.number_of_received_samples,
+                        accumulated_latency: statistics.accumulated_latency,
+                        last_latency: statistics.last_latency,
+                    };
+
+                    if callback(&statistics) == CallbackProgression::Stop {
+                        return;
+                    }
+                }
+            }
+        }
+    }
 }
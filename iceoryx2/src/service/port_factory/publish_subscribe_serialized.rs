@@ -0,0 +1,286 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::builder::publish_subscribe_serialized::serialize::cdr::Cdr;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<Vec<String>, Cdr>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                             {:?}", pubsub.name());
+//! println!("service id:                       {:?}", pubsub.service_id());
+//! println!("type details:                     {:?}", pubsub.static_config().message_type_details());
+//! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
+//!
+//! let publisher = pubsub.publisher_builder().create()?;
+//! let subscriber = pubsub.subscriber_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::Serialize;
+
+use crate::node::NodeListFailure;
+use crate::port::publisher::PublisherCreateError;
+use crate::port::serialized_publisher::SerializedPublisher;
+use crate::port::serialized_subscriber::SerializedSubscriber;
+use crate::port::subscriber::SubscriberCreateError;
+use crate::service::attribute::AttributeSet;
+use crate::service::builder::publish_subscribe::CustomHeaderMarker;
+use crate::service::builder::AccessPolicy;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::publish_subscribe;
+use super::publisher::{AllocationStrategy, PortFactoryPublisher, UnableToDeliverStrategy};
+use super::subscriber::PortFactorySubscriber;
+
+/// The factory for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// services whose payload is serialized. It can acquire dynamic and static service
+/// informations and create [`SerializedPublisher`] or [`SerializedSubscriber`] ports.
+pub struct PortFactory<Service: service::Service, Payload: Debug, Serializer: Serialize> {
+    pub(crate) factory: publish_subscribe::PortFactory<Service, [u8], CustomHeaderMarker>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<Service: service::Service, Payload: Debug, Serializer: Serialize> Debug
+    for PortFactory<Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PortFactory<{}, {}, {}> {{ factory: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Serializer>(),
+            self.factory
+        )
+    }
+}
+
+impl<Service: service::Service, Payload: Debug, Serializer: Serialize>
+    crate::service::port_factory::PortFactory for PortFactory<Service, Payload, Serializer>
+{
+    type Service = Service;
+    type StaticConfig = static_config::publish_subscribe::StaticConfig;
+    type DynamicConfig = dynamic_config::publish_subscribe::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.factory.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.factory.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.factory.attributes()
+    }
+
+    fn access_policy(&self) -> &AccessPolicy {
+        self.factory.access_policy()
+    }
+
+    fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
+        self.factory.static_config()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::publish_subscribe::DynamicConfig {
+        self.factory.dynamic_config()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        self.factory.nodes(callback)
+    }
+}
+
+impl<Service: service::Service, Payload: Debug, Serializer: Serialize>
+    PortFactory<Service, Payload, Serializer>
+{
+    pub(crate) fn new(
+        factory: publish_subscribe::PortFactory<Service, [u8], CustomHeaderMarker>,
+    ) -> Self {
+        Self {
+            factory,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Returns a [`PortFactorySerializedPublisher`] to create a new [`SerializedPublisher`]
+    /// port.
+    pub fn publisher_builder(
+        &self,
+    ) -> PortFactorySerializedPublisher<'_, Service, Payload, Serializer> {
+        PortFactorySerializedPublisher {
+            builder: self
+                .factory
+                .publisher_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo),
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Returns a [`PortFactorySerializedSubscriber`] to create a new [`SerializedSubscriber`]
+    /// port.
+    pub fn subscriber_builder(
+        &self,
+    ) -> PortFactorySerializedSubscriber<'_, Service, Payload, Serializer> {
+        PortFactorySerializedSubscriber {
+            builder: self.factory.subscriber_builder(),
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+}
+
+/// Factory to create a new [`SerializedPublisher`] port/endpoint. The memory for the serialized
+/// payload grows on demand, [`PortFactorySerializedPublisher::initial_max_payload_size()`]
+/// defines the initially reserved number of bytes per sample.
+pub struct PortFactorySerializedPublisher<
+    'factory,
+    Service: service::Service,
+    Payload: Debug,
+    Serializer: Serialize,
+> {
+    builder: PortFactoryPublisher<'factory, Service, [u8], CustomHeaderMarker>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<Service: service::Service, Payload: Debug, Serializer: Serialize> Debug
+    for PortFactorySerializedPublisher<'_, Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PortFactorySerializedPublisher<{}, {}, {}> {{ builder: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Serializer>(),
+            self.builder
+        )
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + serde::Serialize, Serializer: Serialize>
+    PortFactorySerializedPublisher<'_, Service, Payload, Serializer>
+{
+    /// Defines how many samples the [`SerializedPublisher`] can hold in parallel until they
+    /// are delivered.
+    pub fn max_loaned_samples(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_loaned_samples(value);
+        self
+    }
+
+    /// Sets the [`UnableToDeliverStrategy`].
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.builder = self.builder.unable_to_deliver_strategy(value);
+        self
+    }
+
+    /// Defines the number of bytes of the serialized payload that fit into the initially
+    /// created data segment. Larger payloads create a new and larger data segment.
+    pub fn initial_max_payload_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_slice_len(value);
+        self
+    }
+
+    /// Creates a new [`SerializedPublisher`] or returns a [`PublisherCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<SerializedPublisher<Service, Payload, Serializer>, PublisherCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(SerializedPublisher::new(
+            fail!(from origin, when self.builder.create(),
+                "Failed to create new SerializedPublisher port."),
+        ))
+    }
+}
+
+/// Factory to create a new [`SerializedSubscriber`] port/endpoint.
+pub struct PortFactorySerializedSubscriber<
+    'factory,
+    Service: service::Service,
+    Payload: Debug,
+    Serializer: Serialize,
+> {
+    builder: PortFactorySubscriber<'factory, Service, [u8], CustomHeaderMarker>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<Service: service::Service, Payload: Debug, Serializer: Serialize> Debug
+    for PortFactorySerializedSubscriber<'_, Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PortFactorySerializedSubscriber<{}, {}, {}> {{ builder: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Serializer>(),
+            self.builder
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::de::DeserializeOwned,
+        Serializer: Serialize,
+    > PortFactorySerializedSubscriber<'_, Service, Payload, Serializer>
+{
+    /// Defines the buffer size of the [`SerializedSubscriber`]. Smallest possible value is `1`.
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.buffer_size(value);
+        self
+    }
+
+    /// Defines how many samples of the history the [`SerializedSubscriber`] requests when it
+    /// connects to a [`SerializedPublisher`].
+    pub fn history_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.history_size(value);
+        self
+    }
+
+    /// Creates a new [`SerializedSubscriber`] or returns a [`SubscriberCreateError`] on
+    /// failure.
+    pub fn create(
+        self,
+    ) -> Result<SerializedSubscriber<Service, Payload, Serializer>, SubscriberCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(SerializedSubscriber::new(
+            fail!(from origin, when self.builder.create(),
+                "Failed to create new SerializedSubscriber port."),
+        ))
+    }
+}
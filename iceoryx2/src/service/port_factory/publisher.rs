@@ -55,8 +55,10 @@
 //! ```
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use iceoryx2_bb_log::fail;
+pub use iceoryx2_cal::shm_allocator::AllocationStrategy;
 use serde::{de::Visitor, Deserialize, Serialize};
 
 use super::publish_subscribe::PortFactory;
@@ -131,6 +133,8 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
     pub(crate) max_slice_len: usize,
+    pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) liveliness_lease_duration: Option<Duration>,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -155,6 +159,8 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
             config: LocalPublisherConfig {
                 degration_callback: None,
                 max_slice_len: 1,
+                allocation_strategy: AllocationStrategy::Static,
+                liveliness_lease_duration: None,
                 max_loaned_samples: factory
                     .service
                     .__internal_state()
@@ -190,6 +196,15 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         self
     }
 
+    /// Defines the maximum period in which the [`Publisher`] has to either send a sample or
+    /// call [`Publisher::assert_liveliness()`]. Otherwise the connected
+    /// [`crate::port::subscriber::Subscriber`]s consider it as
+    /// [`PublisherQosEvent::LivelinessLost`](crate::port::subscriber::PublisherQosEvent::LivelinessLost).
+    pub fn liveliness_lease_duration(mut self, value: Duration) -> Self {
+        self.config.liveliness_lease_duration = Some(value);
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -231,4 +246,18 @@ impl<'factory, Service: service::Service, Payload: Debug, UserHeader: Debug>
         self.config.max_slice_len = value;
         self
     }
+
+    /// Defines how the [`Publisher`] reacts when a slice is loaned that is longer than the
+    /// current maximum slice length. With [`AllocationStrategy::Static`] the loan fails,
+    /// otherwise a new and larger data segment is created and
+    /// [`PortFactoryPublisher::max_slice_len()`] defines only the initial slice length.
+    ///
+    /// A [`Publisher`] can create at most 32 data segments during its lifetime. The ids of
+    /// released segments are not reused since subscribers keep the segments mapped by their
+    /// id, therefore a loan that requires another segment fails afterwards with
+    /// [`PublisherLoanError::ExceedsMaxLoanSize`](crate::port::publisher::PublisherLoanError::ExceedsMaxLoanSize).
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.config.allocation_strategy = value;
+        self
+    }
 }
@@ -0,0 +1,58 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .open_or_create()?;
+//!
+//! let reader = blackboard.reader_builder().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use crate::port::reader::{Reader, ReaderCreateError};
+use iceoryx2_bb_log::fail;
+
+use crate::service;
+
+use super::blackboard::PortFactory;
+
+/// Factory to create a new [`Reader`] port/endpoint for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based communication.
+#[derive(Debug)]
+pub struct PortFactoryReader<
+    'factory,
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Copy + Debug + 'static,
+> {
+    pub(crate) factory: &'factory PortFactory<Service, KeyType>,
+}
+
+impl<Service: service::Service, KeyType: Send + Sync + Eq + Copy + Debug + 'static>
+    PortFactoryReader<'_, Service, KeyType>
+{
+    /// Creates a new [`Reader`] port or returns a [`ReaderCreateError`] on failure.
+    pub fn create(self) -> Result<Reader<Service, KeyType>, ReaderCreateError> {
+        Ok(fail!(from self, when Reader::new(&self.factory.service),
+                    "Failed to create new Reader port."))
+    }
+}
@@ -47,6 +47,7 @@ use super::publish_subscribe::PortFactory;
 #[derive(Debug)]
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
+    pub(crate) history_size: Option<usize>,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
 }
 
@@ -71,6 +72,7 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         Self {
             config: SubscriberConfig {
                 buffer_size: None,
+                history_size: None,
                 degration_callback: None,
             },
             factory,
@@ -83,6 +85,14 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         self
     }
 
+    /// Defines how many samples of the history the [`Subscriber`] receives when it connects
+    /// to a publisher. Must not exceed the history size of the service, which is also the
+    /// default. `0` disables the history for this [`Subscriber`].
+    pub fn history_size(mut self, value: usize) -> Self {
+        self.config.history_size = Some(value);
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
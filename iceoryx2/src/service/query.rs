@@ -0,0 +1,188 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::Config;
+//! use iceoryx2::service::query::{ServiceNamePattern, ServiceQuery};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let query = ServiceQuery::new()
+//!     // all services directly under "Robot/Arm/" ...
+//!     .name(ServiceNamePattern::Glob("Robot/Arm/*".into()))
+//!     // ... that use publish-subscribe ...
+//!     .messaging_pattern(MessagingPattern::PublishSubscribe)
+//!     // ... and were created with the attribute "sensor" = "lidar"
+//!     .attributes(&AttributeVerifier::new().require("sensor", "lidar"));
+//!
+//! ipc::Service::query(Config::global_config(), &query, |service| {
+//!     println!("{}", service.static_details.name());
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{
+    attribute::AttributeVerifier, messaging_pattern::MessagingPattern, service_name::ServiceName,
+    static_config::StaticConfig,
+};
+
+/// Defines which [`ServiceName`]s are matched by a [`ServiceQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceNamePattern {
+    /// Matches only the given [`ServiceName`].
+    Exact(ServiceName),
+    /// Matches all [`ServiceName`]s that start with the given string, e.g. `Robot/Arm/`
+    /// matches `Robot/Arm/Left` and `Robot/Arm/Left/Gripper`.
+    Prefix(String),
+    /// Matches all [`ServiceName`]s that fit the glob pattern, where `/` separates the
+    /// segments of a name.
+    ///
+    ///  * `?` matches exactly one character that is not `/`
+    ///  * `*` matches any sequence of characters that does not contain `/`
+    ///  * `**` matches any sequence of characters, including `/`
+    ///
+    /// `Robot/Arm/*` matches `Robot/Arm/Left` but not `Robot/Arm/Left/Gripper`,
+    /// `Robot/**/Gripper` matches `Robot/Arm/Left/Gripper`.
+    Glob(String),
+}
+
+impl ServiceNamePattern {
+    /// Returns true when the [`ServiceName`] matches the pattern, otherwise false.
+    pub fn matches(&self, service_name: &ServiceName) -> bool {
+        match self {
+            ServiceNamePattern::Exact(name) => name == service_name,
+            ServiceNamePattern::Prefix(prefix) => {
+                service_name.as_str().starts_with(prefix.as_str())
+            }
+            ServiceNamePattern::Glob(pattern) => glob_matches(pattern, service_name.as_str()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GlobToken {
+    AnySequence,
+    SegmentSequence,
+    SegmentCharacter,
+    Character(char),
+}
+
+/// Matches the name iteratively in O(pattern.len() * name.len()). Every pattern token
+/// computes from the previous row, which name prefixes the pattern prefix matches, so that
+/// patterns like `a*a*a*a*b` cannot cause exponential backtracking.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    const SEPARATOR: char = '/';
+
+    let mut tokens = vec![];
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                GlobToken::AnySequence
+            }
+            '*' => GlobToken::SegmentSequence,
+            '?' => GlobToken::SegmentCharacter,
+            c => GlobToken::Character(c),
+        });
+    }
+
+    let name: Vec<char> = name.chars().collect();
+    // previous[n] is true when the already processed tokens match the first n characters
+    let mut previous = vec![false; name.len() + 1];
+    let mut current = vec![false; name.len() + 1];
+    previous[0] = true;
+
+    for token in tokens {
+        current[0] = match token {
+            GlobToken::AnySequence | GlobToken::SegmentSequence => previous[0],
+            GlobToken::SegmentCharacter | GlobToken::Character(_) => false,
+        };
+
+        for (n, c) in name.iter().enumerate() {
+            current[n + 1] = match token {
+                GlobToken::AnySequence => previous[n + 1] || current[n],
+                GlobToken::SegmentSequence => previous[n + 1] || (current[n] && *c != SEPARATOR),
+                GlobToken::SegmentCharacter => previous[n] && *c != SEPARATOR,
+                GlobToken::Character(p) => previous[n] && *c == p,
+            };
+        }
+
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[name.len()]
+}
+
+/// Selects [`Service`](crate::service::Service)s by their [`ServiceName`],
+/// [`MessagingPattern`] and [`AttributeSet`](crate::service::attribute::AttributeSet).
+/// Used with [`Service::query()`](crate::service::Service::query()). An empty query matches
+/// every [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Default)]
+pub struct ServiceQuery {
+    name: Option<ServiceNamePattern>,
+    messaging_pattern: Option<MessagingPattern>,
+    attributes: AttributeVerifier,
+}
+
+impl ServiceQuery {
+    /// Creates a new [`ServiceQuery`] that matches every [`Service`](crate::service::Service).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches only [`Service`](crate::service::Service)s whose [`ServiceName`] fits the
+    /// [`ServiceNamePattern`].
+    pub fn name(mut self, pattern: ServiceNamePattern) -> Self {
+        self.name = Some(pattern);
+        self
+    }
+
+    /// Matches only [`Service`](crate::service::Service)s with the given
+    /// [`MessagingPattern`].
+    pub fn messaging_pattern(mut self, messaging_pattern: MessagingPattern) -> Self {
+        self.messaging_pattern = Some(messaging_pattern);
+        self
+    }
+
+    /// Matches only [`Service`](crate::service::Service)s whose
+    /// [`AttributeSet`](crate::service::attribute::AttributeSet) satisfies the
+    /// [`AttributeVerifier`].
+    pub fn attributes(mut self, attributes: &AttributeVerifier) -> Self {
+        self.attributes = attributes.clone();
+        self
+    }
+
+    /// Returns true when the [`Service`](crate::service::Service) with the given
+    /// [`StaticConfig`] is selected by the query, otherwise false.
+    pub fn matches(&self, service: &StaticConfig) -> bool {
+        if let Some(pattern) = &self.name {
+            if !pattern.matches(service.name()) {
+                return false;
+            }
+        }
+
+        if let Some(messaging_pattern) = self.messaging_pattern {
+            if service.messaging_pattern().pattern_type() != messaging_pattern {
+                return false;
+            }
+        }
+
+        self.attributes
+            .verify_requirements(service.attributes())
+            .is_ok()
+    }
+}
@@ -25,6 +25,11 @@
 use iceoryx2_bb_container::semantic_string::SemanticStringError;
 use serde::{de::Visitor, Deserialize, Serialize};
 
+// services that are created by iceoryx2 itself, like the companion event service of a
+// publish-subscribe service with notify on send, use this prefix so that they cannot collide
+// with the services of the user
+const INTERNAL_SERVICE_NAME_PREFIX: &str = "__iox2_internal__/";
+
 /// The name of a [`Service`](crate::service::Service).
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ServiceName {
@@ -32,15 +37,31 @@ pub struct ServiceName {
 }
 
 impl ServiceName {
-    /// Creates a new [`ServiceName`]. The name is not allowed to be empty.
+    /// Creates a new [`ServiceName`]. The name is not allowed to be empty and is not allowed
+    /// to start with the prefix `__iox2_internal__/` that is reserved for services iceoryx2
+    /// creates internally.
     pub fn new(name: &str) -> Result<Self, SemanticStringError> {
-        if name.is_empty() {
+        if name.is_empty() || name.starts_with(INTERNAL_SERVICE_NAME_PREFIX) {
             return Err(SemanticStringError::InvalidContent);
         }
 
         Ok(Self { value: name.into() })
     }
 
+    /// Returns the name of the internal [`Service`](crate::service::Service) with the
+    /// provided purpose that belongs to this [`ServiceName`].
+    pub(crate) fn internal(&self, purpose: &str) -> Self {
+        Self {
+            value: format!("{}{}/{}", INTERNAL_SERVICE_NAME_PREFIX, purpose, self.value),
+        }
+    }
+
+    /// Returns true if the [`ServiceName`] belongs to a service that iceoryx2 created
+    /// internally.
+    pub fn is_internal(&self) -> bool {
+        self.value.starts_with(INTERNAL_SERVICE_NAME_PREFIX)
+    }
+
     /// Returns a str reference to the [`ServiceName`]
     pub fn as_str(&self) -> &str {
         &self.value
@@ -94,6 +115,11 @@ impl<'de> Visitor<'de> for ServiceNameVisitor {
     where
         E: serde::de::Error,
     {
+        // the names of internal services are stored in their static config as well
+        if v.starts_with(INTERNAL_SERVICE_NAME_PREFIX) {
+            return Ok(ServiceName { value: v.into() });
+        }
+
         match ServiceName::new(v) {
             Ok(v) => Ok(v),
             Err(v) => Err(E::custom(format!("invalid service name provided {:?}.", v))),
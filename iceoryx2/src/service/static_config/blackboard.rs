@@ -0,0 +1,129 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, 123)
+//!     .add::<f64>(1, 4.56)
+//!     .open_or_create()?;
+//!
+//! println!("key type details:   {:?}", service.static_config().key_type_details());
+//! println!("number of entries:  {:?}", service.static_config().number_of_entries());
+//! println!("max readers:        {:?}", service.static_config().max_readers());
+//! println!("max nodes:          {:?}", service.static_config().max_nodes());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::message_type_details::TypeDetail;
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// Describes the type and the memory location of a single entry of the blackboard.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct EntryDetails {
+    pub(crate) value_type_details: TypeDetail,
+    pub(crate) key_offset: usize,
+    pub(crate) value_offset: usize,
+}
+
+impl EntryDetails {
+    /// Returns the [`TypeDetail`] of the value of the entry
+    pub fn value_type_details(&self) -> &TypeDetail {
+        &self.value_type_details
+    }
+}
+
+/// The static configuration of an
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_readers: usize,
+    pub(crate) max_writers: usize,
+    pub(crate) max_nodes: usize,
+    pub(crate) key_type_details: TypeDetail,
+    pub(crate) entries: Vec<EntryDetails>,
+    pub(crate) payload_size: usize,
+    pub(crate) payload_alignment: usize,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_readers: config.defaults.blackboard.max_readers,
+            max_writers: 1,
+            max_nodes: config.defaults.blackboard.max_nodes,
+            key_type_details: TypeDetail::default(),
+            entries: vec![],
+            payload_size: 0,
+            payload_alignment: 1,
+        }
+    }
+
+    /// Returns the maximum supported amount of [`Node`](crate::node::Node)s that can open the
+    /// [`Service`](crate::service::Service) in parallel.
+    pub fn max_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::reader::Reader`] ports
+    pub fn max_readers(&self) -> usize {
+        self.max_readers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::writer::Writer`] ports. A
+    /// blackboard supports always exactly one writer.
+    pub fn max_writers(&self) -> usize {
+        self.max_writers
+    }
+
+    /// Returns the [`TypeDetail`] of the key type
+    pub fn key_type_details(&self) -> &TypeDetail {
+        &self.key_type_details
+    }
+
+    /// Returns the number of entries stored in the blackboard
+    pub fn number_of_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the [`EntryDetails`] of all entries stored in the blackboard
+    pub fn entries(&self) -> &[EntryDetails] {
+        &self.entries
+    }
+
+    /// Returns the index of the entry with the provided key.
+    ///
+    /// # Safety
+    ///
+    ///  * `payload_start_address` must point to the initialized blackboard entries
+    ///  * `KeyType` must be the key type the blackboard was created with
+    pub(crate) unsafe fn entry_index<KeyType: Eq>(
+        &self,
+        payload_start_address: *const u8,
+        key: &KeyType,
+    ) -> Option<usize> {
+        self.entries.iter().position(|entry| {
+            *(payload_start_address.add(entry.key_offset) as *const KeyType) == *key
+        })
+    }
+}
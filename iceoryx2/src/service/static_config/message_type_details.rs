@@ -13,6 +13,7 @@
 use std::alloc::Layout;
 
 use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use serde::{Deserialize, Serialize};
 
 /// Defines if the type is a slice with a runtime-size ([`TypeVariant::Dynamic`])
@@ -26,6 +27,45 @@ pub enum TypeVariant {
     Dynamic,
 }
 
+// Formats like TOML support only signed 64-bit integers, therefore the hash is stored as
+// hex string.
+mod type_layout_hash_serializer {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.serialize_some(&format!("{:016x}", v)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(v) => u64::from_str_radix(&v, 16)
+                .map(Some)
+                .map_err(serde::de::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Errors that can occur when [`TypeDetail`]s that are only known at runtime are provided.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TypeDetailError {
+    /// The alignment is not a power of two or the size is not a multiple of the alignment.
+    InvalidSizeOrAlignmentValue,
+}
+
+impl std::fmt::Display for TypeDetailError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "TypeDetailError::{:?}", self)
+    }
+}
+
+impl std::error::Error for TypeDetailError {}
+
 /// Contains all type details required to connect to a [`crate::service::Service`]
 #[derive(Default, Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct TypeDetail {
@@ -37,6 +77,11 @@ pub struct TypeDetail {
     pub size: usize,
     /// The alignment of the underlying type.
     pub alignment: usize,
+    /// The structural layout hash of the type provided by
+    /// [`ZeroCopySend::type_layout_hash()`]. When both sides provide it, it is compared
+    /// instead of the `type_name` so that identical types with different names are compatible.
+    #[serde(default, with = "type_layout_hash_serializer")]
+    pub type_layout_hash: Option<u64>,
 }
 
 impl TypeDetail {
@@ -47,6 +92,21 @@ impl TypeDetail {
             type_name: core::any::type_name::<T>().to_string(),
             size: core::mem::size_of::<T>(),
             alignment: core::mem::align_of::<T>(),
+            type_layout_hash: None,
+        }
+    }
+
+    pub(crate) fn verify(&self) -> Result<(), TypeDetailError> {
+        match Layout::from_size_align(self.size, self.alignment) {
+            Ok(layout) if layout.size() % layout.align() == 0 => Ok(()),
+            _ => Err(TypeDetailError::InvalidSizeOrAlignmentValue),
+        }
+    }
+
+    fn is_same_type_as(&self, rhs: &Self) -> bool {
+        match (self.type_layout_hash, rhs.type_layout_hash) {
+            (Some(lhs_hash), Some(rhs_hash)) => lhs_hash == rhs_hash,
+            _ => self.type_name == rhs.type_name,
         }
     }
 }
@@ -72,6 +132,15 @@ impl MessageTypeDetails {
         }
     }
 
+    pub(crate) fn from_zero_copy_send<Header, UserHeader: ZeroCopySend, Payload: ZeroCopySend>(
+        variant: TypeVariant,
+    ) -> Self {
+        let mut details = Self::from::<Header, UserHeader, Payload>(variant);
+        details.user_header.type_layout_hash = Some(UserHeader::type_layout_hash());
+        details.payload.type_layout_hash = Some(Payload::type_layout_hash());
+        details
+    }
+
     pub(crate) fn payload_ptr_from_header(&self, header: *const u8) -> *const u8 {
         let user_header = self.user_header_ptr_from_header(header) as usize;
         let payload_start = align(user_header + self.user_header.size, self.payload.alignment);
@@ -110,11 +179,11 @@ impl MessageTypeDetails {
 
     pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
         self.header == rhs.header
-            && self.user_header.type_name == rhs.user_header.type_name
+            && self.user_header.is_same_type_as(&rhs.user_header)
             && self.user_header.variant == rhs.user_header.variant
             && self.user_header.size == rhs.user_header.size
             && self.user_header.alignment <= rhs.user_header.alignment
-            && self.payload.type_name == rhs.payload.type_name
+            && self.payload.is_same_type_as(&rhs.payload)
             && self.payload.variant == rhs.payload.variant
             && self.payload.size == rhs.payload.size
             && self.payload.alignment <= rhs.payload.alignment
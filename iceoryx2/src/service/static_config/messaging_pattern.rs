@@ -13,15 +13,16 @@
 //! Stores the [`Service`](crate::service::Service) messaging pattern specific static configuration.
 use std::fmt::Display;
 
+use crate::service::static_config::blackboard;
 use crate::service::static_config::event;
 use crate::service::static_config::publish_subscribe;
-use serde::{Deserialize, Serialize};
+use crate::service::static_config::request_response;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Contains the static config of the corresponding
 /// [`service::MessagingPattern`](crate::service::messaging_pattern::MessagingPattern).
 #[non_exhaustive]
-#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "messaging_pattern")]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum MessagingPattern {
     /// Stores the static config of the
     /// [`service::MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
@@ -30,6 +31,94 @@ pub enum MessagingPattern {
     /// Stores the static config of the
     /// [`service::MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
     Event(event::StaticConfig),
+
+    /// Stores the static config of the
+    /// [`service::MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+    RequestResponse(request_response::StaticConfig),
+
+    /// Stores the static config of the
+    /// [`service::MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+    Blackboard(blackboard::StaticConfig),
+}
+
+/// Human readable formats, like TOML, store the [`MessagingPattern`] as table with an
+/// additional `messaging_pattern` entry. Compact binary formats are not self-describing and
+/// cannot deserialize internally tagged enums, therefore they store the externally tagged
+/// [`BinaryMessagingPattern`].
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "messaging_pattern")]
+enum TaggedMessagingPattern {
+    PublishSubscribe(publish_subscribe::StaticConfig),
+    Event(event::StaticConfig),
+    RequestResponse(request_response::StaticConfig),
+    Blackboard(blackboard::StaticConfig),
+}
+
+#[derive(Serialize, Deserialize)]
+enum BinaryMessagingPattern {
+    PublishSubscribe(publish_subscribe::StaticConfig),
+    Event(event::StaticConfig),
+    RequestResponse(request_response::StaticConfig),
+    Blackboard(blackboard::StaticConfig),
+}
+
+impl From<TaggedMessagingPattern> for MessagingPattern {
+    fn from(value: TaggedMessagingPattern) -> Self {
+        match value {
+            TaggedMessagingPattern::PublishSubscribe(v) => MessagingPattern::PublishSubscribe(v),
+            TaggedMessagingPattern::Event(v) => MessagingPattern::Event(v),
+            TaggedMessagingPattern::RequestResponse(v) => MessagingPattern::RequestResponse(v),
+            TaggedMessagingPattern::Blackboard(v) => MessagingPattern::Blackboard(v),
+        }
+    }
+}
+
+impl From<BinaryMessagingPattern> for MessagingPattern {
+    fn from(value: BinaryMessagingPattern) -> Self {
+        match value {
+            BinaryMessagingPattern::PublishSubscribe(v) => MessagingPattern::PublishSubscribe(v),
+            BinaryMessagingPattern::Event(v) => MessagingPattern::Event(v),
+            BinaryMessagingPattern::RequestResponse(v) => MessagingPattern::RequestResponse(v),
+            BinaryMessagingPattern::Blackboard(v) => MessagingPattern::Blackboard(v),
+        }
+    }
+}
+
+impl Serialize for MessagingPattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self.clone();
+        if serializer.is_human_readable() {
+            match value {
+                MessagingPattern::PublishSubscribe(v) => {
+                    TaggedMessagingPattern::PublishSubscribe(v)
+                }
+                MessagingPattern::Event(v) => TaggedMessagingPattern::Event(v),
+                MessagingPattern::RequestResponse(v) => TaggedMessagingPattern::RequestResponse(v),
+                MessagingPattern::Blackboard(v) => TaggedMessagingPattern::Blackboard(v),
+            }
+            .serialize(serializer)
+        } else {
+            match value {
+                MessagingPattern::PublishSubscribe(v) => {
+                    BinaryMessagingPattern::PublishSubscribe(v)
+                }
+                MessagingPattern::Event(v) => BinaryMessagingPattern::Event(v),
+                MessagingPattern::RequestResponse(v) => BinaryMessagingPattern::RequestResponse(v),
+                MessagingPattern::Blackboard(v) => BinaryMessagingPattern::Blackboard(v),
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MessagingPattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            Ok(TaggedMessagingPattern::deserialize(deserializer)?.into())
+        } else {
+            Ok(BinaryMessagingPattern::deserialize(deserializer)?.into())
+        }
+    }
 }
 
 impl Display for MessagingPattern {
@@ -37,11 +126,30 @@ impl Display for MessagingPattern {
         match self {
             MessagingPattern::Event(_) => write!(f, "Event"),
             MessagingPattern::PublishSubscribe(_) => write!(f, "PublishSubscribe"),
+            MessagingPattern::RequestResponse(_) => write!(f, "RequestResponse"),
+            MessagingPattern::Blackboard(_) => write!(f, "Blackboard"),
         }
     }
 }
 
 impl MessagingPattern {
+    pub(crate) fn pattern_type(&self) -> crate::service::messaging_pattern::MessagingPattern {
+        match self {
+            MessagingPattern::PublishSubscribe(_) => {
+                crate::service::messaging_pattern::MessagingPattern::PublishSubscribe
+            }
+            MessagingPattern::Event(_) => {
+                crate::service::messaging_pattern::MessagingPattern::Event
+            }
+            MessagingPattern::RequestResponse(_) => {
+                crate::service::messaging_pattern::MessagingPattern::RequestResponse
+            }
+            MessagingPattern::Blackboard(_) => {
+                crate::service::messaging_pattern::MessagingPattern::Blackboard
+            }
+        }
+    }
+
     pub(crate) fn is_same_pattern(&self, rhs: &MessagingPattern) -> bool {
         match self {
             MessagingPattern::PublishSubscribe(_) => {
@@ -50,6 +158,12 @@ impl MessagingPattern {
             MessagingPattern::Event(_) => {
                 matches!(rhs, MessagingPattern::Event(_))
             }
+            MessagingPattern::RequestResponse(_) => {
+                matches!(rhs, MessagingPattern::RequestResponse(_))
+            }
+            MessagingPattern::Blackboard(_) => {
+                matches!(rhs, MessagingPattern::Blackboard(_))
+            }
         }
     }
 
@@ -10,6 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The static service configuration of an
+/// [`MessagingPattern::Blackboard`]
+/// based service.
+pub mod blackboard;
+
 /// The static service configuration of an
 /// [`MessagingPattern::Event`]
 /// based service.
@@ -20,6 +25,11 @@ pub mod event;
 /// based service.
 pub mod publish_subscribe;
 
+/// The static service configuration of an
+/// [`MessagingPattern::RequestResponse`]
+/// based service.
+pub mod request_response;
+
 /// Contains the size, alignment and name of the header and payload type
 /// and the type variant
 pub mod message_type_details;
@@ -27,6 +37,7 @@ pub mod message_type_details;
 pub mod messaging_pattern;
 
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_cal::access_policy::AccessPolicy;
 use iceoryx2_cal::hash::Hash;
 use serde::{Deserialize, Serialize};
 
@@ -43,6 +54,7 @@ pub struct StaticConfig {
     service_name: ServiceName,
     pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
+    pub(crate) access_policy: AccessPolicy,
 }
 
 pub(crate) fn create_uuid<Hasher: Hash>(
@@ -67,6 +79,7 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            access_policy: AccessPolicy::default(),
         }
     }
 
@@ -84,6 +97,42 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            access_policy: AccessPolicy::default(),
+        }
+    }
+
+    pub(crate) fn new_request_response<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern =
+            MessagingPattern::RequestResponse(request_response::StaticConfig::new(config));
+        Self {
+            service_id: ServiceId::new::<Hasher>(
+                service_name,
+                crate::service::messaging_pattern::MessagingPattern::RequestResponse,
+            ),
+            service_name: service_name.clone(),
+            messaging_pattern,
+            attributes: AttributeSet::new(),
+            access_policy: AccessPolicy::default(),
+        }
+    }
+
+    pub(crate) fn new_blackboard<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern = MessagingPattern::Blackboard(blackboard::StaticConfig::new(config));
+        Self {
+            service_id: ServiceId::new::<Hasher>(
+                service_name,
+                crate::service::messaging_pattern::MessagingPattern::Blackboard,
+            ),
+            service_name: service_name.clone(),
+            messaging_pattern,
+            attributes: AttributeSet::new(),
+            access_policy: AccessPolicy::default(),
         }
     }
 
@@ -107,6 +156,11 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns the [`AccessPolicy`] of the [`crate::service::Service`]
+    pub fn access_policy(&self) -> &AccessPolicy {
+        &self.access_policy
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
@@ -149,4 +203,42 @@ impl StaticConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn request_response_mut(&mut self) -> &mut request_response::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard_mut(&mut self) -> &mut blackboard::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
 }
@@ -28,11 +28,15 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("notify on send:                   {:?}", pubsub.static_config().has_notify_on_send());
+//! println!("deadline:                         {:?}", pubsub.static_config().deadline());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use std::time::Duration;
+
 use super::message_type_details::MessageTypeDetails;
 use crate::config;
 use serde::{Deserialize, Serialize};
@@ -50,6 +54,10 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    #[serde(default)]
+    pub(crate) enable_notify_on_send: bool,
+    #[serde(default)]
+    pub(crate) deadline: Option<Duration>,
     pub(crate) message_type_details: MessageTypeDetails,
 }
 
@@ -69,6 +77,8 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            enable_notify_on_send: false,
+            deadline: None,
             message_type_details: MessageTypeDetails::default(),
         }
     }
@@ -113,6 +123,20 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns true if every [`crate::port::publisher::Publisher`] notifies the companion
+    /// event [`crate::service::Service`] with the same name after a
+    /// [`crate::sample::Sample`] was sent, otherwise false.
+    pub fn has_notify_on_send(&self) -> bool {
+        self.enable_notify_on_send
+    }
+
+    /// Returns the maximum period between two [`crate::sample::Sample`]s a
+    /// [`crate::port::publisher::Publisher`] is expected to send. If no deadline is defined
+    /// it returns [`None`].
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
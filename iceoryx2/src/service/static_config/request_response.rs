@@ -0,0 +1,172 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! println!("request type details:             {:?}", service.static_config().request_message_type_details());
+//! println!("response type details:            {:?}", service.static_config().response_message_type_details());
+//! println!("max clients:                      {:?}", service.static_config().max_clients());
+//! println!("max servers:                      {:?}", service.static_config().max_servers());
+//! println!("max active requests per client:   {:?}", service.static_config().max_active_requests_per_client());
+//! println!("max response buffer size:         {:?}", service.static_config().max_response_buffer_size());
+//! println!("max borrowed responses:           {:?}", service.static_config().max_borrowed_responses_per_pending_response());
+//! println!("safe overflow for requests:       {:?}", service.static_config().has_safe_overflow_for_requests());
+//! println!("safe overflow for responses:      {:?}", service.static_config().has_safe_overflow_for_responses());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::message_type_details::MessageTypeDetails;
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_clients: usize,
+    pub(crate) max_servers: usize,
+    pub(crate) max_nodes: usize,
+    pub(crate) max_active_requests_per_client: usize,
+    pub(crate) max_response_buffer_size: usize,
+    pub(crate) max_borrowed_responses_per_pending_response: usize,
+    pub(crate) enable_safe_overflow_for_requests: bool,
+    pub(crate) enable_safe_overflow_for_responses: bool,
+    pub(crate) request_message_type_details: MessageTypeDetails,
+    pub(crate) response_message_type_details: MessageTypeDetails,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        let defaults = &config.defaults.request_response;
+        Self {
+            max_clients: defaults.max_clients,
+            max_servers: defaults.max_servers,
+            max_nodes: defaults.max_nodes,
+            max_active_requests_per_client: defaults.max_active_requests_per_client,
+            max_response_buffer_size: defaults.max_response_buffer_size,
+            max_borrowed_responses_per_pending_response: defaults
+                .max_borrowed_responses_per_pending_response,
+            enable_safe_overflow_for_requests: defaults.enable_safe_overflow_for_requests,
+            enable_safe_overflow_for_responses: defaults.enable_safe_overflow_for_responses,
+            request_message_type_details: MessageTypeDetails::default(),
+            response_message_type_details: MessageTypeDetails::default(),
+        }
+    }
+
+    /// Returns the maximum supported amount of [`Node`](crate::node::Node)s that can open the
+    /// [`Service`](crate::service::Service) in parallel.
+    pub fn max_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::client::Client`] ports
+    pub fn max_clients(&self) -> usize {
+        self.max_clients
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::server::Server`] ports
+    pub fn max_servers(&self) -> usize {
+        self.max_servers
+    }
+
+    /// Returns how many requests a [`crate::port::client::Client`] can have in flight at the
+    /// same time. A request is active as long as its
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) is alive.
+    pub fn max_active_requests_per_client(&self) -> usize {
+        self.max_active_requests_per_client
+    }
+
+    /// Returns how many responses can be buffered for a single
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) per
+    /// [`crate::port::server::Server`].
+    pub fn max_response_buffer_size(&self) -> usize {
+        self.max_response_buffer_size
+    }
+
+    /// Returns how many [`crate::response::Response`]s a
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) can borrow in parallel.
+    pub fn max_borrowed_responses_per_pending_response(&self) -> usize {
+        self.max_borrowed_responses_per_pending_response
+    }
+
+    /// Returns true if the request buffer of the [`crate::port::server::Server`] safely
+    /// overflows, otherwise false. Safe overflow means that the
+    /// [`crate::port::client::Client`] will recycle the oldest request from the
+    /// [`crate::port::server::Server`] when its buffer is full.
+    pub fn has_safe_overflow_for_requests(&self) -> bool {
+        self.enable_safe_overflow_for_requests
+    }
+
+    /// Returns true if the response buffer of the [`crate::port::client::Client`] safely
+    /// overflows, otherwise false. Safe overflow means that the
+    /// [`crate::port::server::Server`] will recycle the oldest response from the
+    /// [`crate::port::client::Client`] when its buffer is full.
+    pub fn has_safe_overflow_for_responses(&self) -> bool {
+        self.enable_safe_overflow_for_responses
+    }
+
+    /// Returns the request type details of the [`crate::service::Service`].
+    pub fn request_message_type_details(&self) -> &MessageTypeDetails {
+        &self.request_message_type_details
+    }
+
+    /// Returns the response type details of the [`crate::service::Service`].
+    pub fn response_message_type_details(&self) -> &MessageTypeDetails {
+        &self.response_message_type_details
+    }
+
+    pub(crate) fn request_buffer_size(&self) -> usize {
+        self.max_active_requests_per_client
+    }
+
+    pub(crate) fn max_borrowed_requests(&self) -> usize {
+        self.max_active_requests_per_client
+    }
+
+    pub(crate) fn response_buffer_size(&self) -> usize {
+        self.max_active_requests_per_client * self.max_response_buffer_size
+    }
+
+    pub(crate) fn max_borrowed_responses(&self) -> usize {
+        self.max_active_requests_per_client
+            * (self.max_response_buffer_size + self.max_borrowed_responses_per_pending_response)
+    }
+
+    pub(crate) fn required_amount_of_requests_per_data_segment(
+        &self,
+        client_max_loaned_requests: usize,
+    ) -> usize {
+        self.max_servers * (self.request_buffer_size() + self.max_borrowed_requests())
+            + client_max_loaned_requests
+    }
+
+    pub(crate) fn required_amount_of_responses_per_data_segment(
+        &self,
+        server_max_loaned_responses: usize,
+    ) -> usize {
+        self.max_clients * (self.response_buffer_size() + self.max_borrowed_responses())
+            + server_max_loaned_responses
+    }
+}
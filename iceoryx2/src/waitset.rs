@@ -0,0 +1,679 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ## Multiplexing Listeners
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::waitset::WaitSetBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event_1 = node.service_builder(&"MyEventName_1".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//! let event_2 = node.service_builder(&"MyEventName_2".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener_1 = event_1.listener_builder().create()?;
+//! let listener_2 = event_2.listener_builder().create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! let guard_1 = waitset.attach_notification(&listener_1)?;
+//! let guard_2 = waitset.attach_deadline(&listener_2, core::time::Duration::from_secs(1))?;
+//! let tick_guard = waitset.attach_interval(core::time::Duration::from_millis(100))?;
+//!
+//! waitset.wait_and_process(|attachment_id| {
+//!     if attachment_id.has_event_from(&guard_1) {
+//!         while let Ok(Some(event_id)) = listener_1.try_wait_one() {
+//!             println!("listener 1 received {:?}", event_id);
+//!         }
+//!     } else if attachment_id.has_event_from(&guard_2) {
+//!         while let Ok(Some(event_id)) = listener_2.try_wait_one() {
+//!             println!("listener 2 received {:?}", event_id);
+//!         }
+//!     } else if attachment_id.has_missed_deadline(&guard_2) {
+//!         println!("listener 2 missed its deadline");
+//!     } else if attachment_id.has_event_from(&tick_guard) {
+//!         println!("tick");
+//!     }
+//!
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Waiting Only Once
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::waitset::WaitSetBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! let guard = waitset.attach_interval(core::time::Duration::from_millis(100))?;
+//!
+//! waitset.wait_and_process_once(|attachment_id| {
+//!     if attachment_id.has_event_from(&guard) {
+//!         println!("tick");
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{file_descriptor_set::SynchronousMultiplexing, signal::SignalHandler};
+use iceoryx2_cal::reactor::{Reactor, ReactorAttachError, ReactorBuilder, ReactorWaitError};
+
+use crate::port::subscriber::Subscriber;
+use crate::service;
+
+/// States why the [`WaitSet::wait_and_process()`] and its variants returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitSetRunResult {
+    /// A termination signal `SIGTERM` or `SIGINT` was received.
+    TerminationRequest,
+    /// An interrupt signal was received while waiting.
+    Interrupt,
+    /// The user callback returned [`CallbackProgression::Stop`].
+    StopRequest,
+    /// All events were handled.
+    AllEventsHandled,
+}
+
+/// Defines the failures that can occur when attaching something with
+/// [`WaitSet::attach_notification()`], [`WaitSet::attach_deadline()`] or
+/// [`WaitSet::attach_interval()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitSetAttachmentError {
+    /// The [`WaitSet`]s capacity is exceeded.
+    InsufficientCapacity,
+    /// The attachment is already attached.
+    AlreadyAttached,
+    /// Neither the service of the [`Subscriber`] defines a deadline nor one of its connected
+    /// [`Publisher`](crate::port::publisher::Publisher)s a liveliness lease duration.
+    PublisherQosIsNotDefined,
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetAttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WaitSetAttachmentError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WaitSetAttachmentError {}
+
+/// Defines the failures that can occur when calling [`WaitSet::wait_and_process()`] or one of
+/// its variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitSetRunError {
+    /// The process has not sufficient permissions to wait on the attachments.
+    InsufficientPermissions,
+    /// An internal error has occurred.
+    InternalError,
+    /// Waiting on an empty [`WaitSet`] would lead to a deadlock therefore it causes an error.
+    NoAttachments,
+}
+
+impl std::fmt::Display for WaitSetRunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WaitSetRunError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WaitSetRunError {}
+
+/// Defines the failures that can occur when calling [`WaitSetBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitSetCreateError {
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WaitSetCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WaitSetCreateError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AttachmentIdType {
+    Tick(u64),
+    Deadline(i32, u64),
+    Notification(i32),
+    MissedDeadline(i32, u64),
+}
+
+/// Represents an attachment to the [`WaitSet`]. It is provided to the user callback in
+/// [`WaitSet::wait_and_process()`] and identifies the origin of the event.
+pub struct WaitSetAttachmentId<Service: service::Service> {
+    attachment: AttachmentIdType,
+    _data: PhantomData<Service>,
+}
+
+impl<Service: service::Service> Debug for WaitSetAttachmentId<Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WaitSetAttachmentId<{}> {{ attachment: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.attachment
+        )
+    }
+}
+
+impl<Service: service::Service> Clone for WaitSetAttachmentId<Service> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Service: service::Service> Copy for WaitSetAttachmentId<Service> {}
+
+impl<Service: service::Service> PartialEq for WaitSetAttachmentId<Service> {
+    fn eq(&self, other: &Self) -> bool {
+        self.attachment == other.attachment
+    }
+}
+
+impl<Service: service::Service> Eq for WaitSetAttachmentId<Service> {}
+
+impl<Service: service::Service> Hash for WaitSetAttachmentId<Service> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.attachment.hash(state)
+    }
+}
+
+impl<Service: service::Service> WaitSetAttachmentId<Service> {
+    fn new(attachment: AttachmentIdType) -> Self {
+        Self {
+            attachment,
+            _data: PhantomData,
+        }
+    }
+
+    /// Creates a [`WaitSetAttachmentId`] from a [`WaitSetGuard`] that was returned via
+    /// [`WaitSet::attach_interval()`], [`WaitSet::attach_notification()`] or
+    /// [`WaitSet::attach_deadline()`].
+    pub fn from_guard(guard: &WaitSetGuard<Service>) -> Self {
+        Self::new(guard.attachment)
+    }
+
+    /// Returns true if an event was emitted from a notification or deadline attachment
+    /// corresponding to [`WaitSetGuard`] or if the interval of an interval attachment
+    /// has passed.
+    pub fn has_event_from(&self, other: &WaitSetGuard<Service>) -> bool {
+        match (self.attachment, other.attachment) {
+            (AttachmentIdType::MissedDeadline(..), _) => false,
+            (lhs, rhs) => lhs == rhs,
+        }
+    }
+
+    /// Returns true if the deadline of the deadline attachment corresponding to the
+    /// [`WaitSetGuard`] was missed.
+    pub fn has_missed_deadline(&self, other: &WaitSetGuard<Service>) -> bool {
+        match (self.attachment, other.attachment) {
+            (
+                AttachmentIdType::MissedDeadline(fd, id),
+                AttachmentIdType::Deadline(other_fd, other_id),
+            ) => fd == other_fd && id == other_id,
+            _ => false,
+        }
+    }
+}
+
+/// Is returned when something is attached to the [`WaitSet`]. As soon as it goes out
+/// of scope, the attachment is detached.
+pub struct WaitSetGuard<'waitset, 'attachment, Service: service::Service>
+where
+    Service::Reactor: 'waitset,
+{
+    waitset: &'waitset WaitSet<Service>,
+    reactor_guard: Option<<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>>,
+    attachment: AttachmentIdType,
+}
+
+impl<Service: service::Service> Debug for WaitSetGuard<'_, '_, Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WaitSetGuard<{}> {{ attachment: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.attachment
+        )
+    }
+}
+
+impl<Service: service::Service> Drop for WaitSetGuard<'_, '_, Service> {
+    fn drop(&mut self) {
+        match self.attachment {
+            AttachmentIdType::Tick(id) => self.waitset.remove_timer(id),
+            AttachmentIdType::Deadline(fd, id) => {
+                self.waitset.remove_timer(id);
+                self.waitset.remove_file_descriptor(fd);
+            }
+            AttachmentIdType::Notification(fd) => self.waitset.remove_file_descriptor(fd),
+            AttachmentIdType::MissedDeadline(..) => (),
+        }
+
+        // detaches the file descriptor from the reactor
+        self.reactor_guard.take();
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Timer {
+    period: Duration,
+    next_activation: Instant,
+    file_descriptor: Option<i32>,
+}
+
+/// The builder for the [`WaitSet`].
+#[derive(Debug, Default)]
+pub struct WaitSetBuilder {}
+
+impl WaitSetBuilder {
+    /// Creates a new [`WaitSetBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the [`WaitSet`].
+    pub fn create<Service: service::Service>(self) -> Result<WaitSet<Service>, WaitSetCreateError> {
+        match <Service::Reactor as Reactor>::Builder::new().create() {
+            Ok(reactor) => Ok(WaitSet {
+                reactor,
+                file_descriptors: RefCell::new(BTreeMap::new()),
+                timers: RefCell::new(BTreeMap::new()),
+                timer_id_counter: Cell::new(0),
+            }),
+            Err(e) => {
+                fail!(from self, with WaitSetCreateError::InternalError,
+                    "Unable to create WaitSet since the underlying reactor could not be created ({:?}).",
+                    e);
+            }
+        }
+    }
+}
+
+/// The [`WaitSet`] implements a reactor pattern and allows to wait on multiple events in one
+/// single call [`WaitSet::wait_and_process()`] until an interrupt or termination signal was
+/// received or the user callback requested to stop.
+///
+/// Attachments that are based on a file descriptor, like a
+/// [`Listener`](crate::port::listener::Listener) of an [`ipc::Service`](crate::service::ipc::Service),
+/// can be attached via [`WaitSet::attach_notification()`] or [`WaitSet::attach_deadline()`].
+/// Periodic wake ups can be attached via [`WaitSet::attach_interval()`].
+///
+/// The user callback must consume all pending events of a notification attachment, otherwise
+/// the [`WaitSet`] will wake up immediately again.
+pub struct WaitSet<Service: service::Service> {
+    reactor: Service::Reactor,
+    file_descriptors: RefCell<BTreeMap<i32, Option<u64>>>,
+    timers: RefCell<BTreeMap<u64, Timer>>,
+    timer_id_counter: Cell<u64>,
+}
+
+impl<Service: service::Service> Debug for WaitSet<Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WaitSet<{}> {{ file_descriptors: {:?}, timers: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.file_descriptors.borrow(),
+            self.timers.borrow()
+        )
+    }
+}
+
+impl<Service: service::Service> WaitSet<Service> {
+    fn remove_timer(&self, id: u64) {
+        self.timers.borrow_mut().remove(&id);
+    }
+
+    fn remove_file_descriptor(&self, fd: i32) {
+        self.file_descriptors.borrow_mut().remove(&fd);
+    }
+
+    fn add_timer(&self, period: Duration, file_descriptor: Option<i32>) -> u64 {
+        let id = self.timer_id_counter.get();
+        self.timer_id_counter.set(id + 1);
+        self.timers.borrow_mut().insert(
+            id,
+            Timer {
+                period,
+                next_activation: Instant::now() + period,
+                file_descriptor,
+            },
+        );
+        id
+    }
+
+    fn attach_to_reactor<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>, WaitSetAttachmentError>
+    {
+        let msg = "Unable to attach object to internal reactor";
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+        if self.file_descriptors.borrow().contains_key(&fd) {
+            fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
+                "{} {:?} since it is already attached.", msg, attachment);
+        }
+
+        match self.reactor.attach(attachment) {
+            Ok(guard) => Ok(guard),
+            Err(ReactorAttachError::CapacityExceeded) => {
+                fail!(from self, with WaitSetAttachmentError::InsufficientCapacity,
+                    "{} {:?} since it would exceed the capacity of {} of the waitset.",
+                    msg, attachment, self.capacity());
+            }
+            Err(ReactorAttachError::UnknownError(e)) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{} {:?} due to an internal error ({}).", msg, attachment, e);
+            }
+        }
+    }
+
+    /// Attaches an object as notification to the [`WaitSet`]. Whenever an event is received on
+    /// the object the [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] to handle
+    /// the event. The object must implement [`SynchronousMultiplexing`] and cannot be attached
+    /// twice.
+    pub fn attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        let reactor_guard = self.attach_to_reactor(attachment)?;
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+        self.file_descriptors.borrow_mut().insert(fd, None);
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            reactor_guard: Some(reactor_guard),
+            attachment: AttachmentIdType::Notification(fd),
+        })
+    }
+
+    /// Attaches an object as deadline to the [`WaitSet`]. Whenever the event is received or the
+    /// deadline is hit, the user is informed in [`WaitSet::wait_and_process()`].
+    /// The object must implement [`SynchronousMultiplexing`] and cannot be attached twice.
+    /// Whenever the object emits an event the deadline is reset by the [`WaitSet`].
+    pub fn attach_deadline<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+        deadline: Duration,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        let reactor_guard = self.attach_to_reactor(attachment)?;
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+        let id = self.add_timer(deadline, Some(fd));
+        self.file_descriptors.borrow_mut().insert(fd, Some(id));
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            reactor_guard: Some(reactor_guard),
+            attachment: AttachmentIdType::Deadline(fd, id),
+        })
+    }
+
+    /// Attaches a tick event to the [`WaitSet`]. Whenever the timeout is reached the [`WaitSet`]
+    /// informs the user in [`WaitSet::wait_and_process()`].
+    pub fn attach_interval(
+        &self,
+        interval: Duration,
+    ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
+        let id = self.add_timer(interval, None);
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            reactor_guard: None,
+            attachment: AttachmentIdType::Tick(id),
+        })
+    }
+
+    /// Attaches the quality of service checks of a [`Subscriber`] to the [`WaitSet`]. The
+    /// [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] with the period of
+    /// [`Subscriber::publisher_qos_check_interval()`], acquired when it is attached, and the
+    /// user acquires the violations with [`Subscriber::publisher_qos_events()`].
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::waitset::WaitSetBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .deadline(Duration::from_millis(100))
+    ///     .open_or_create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_publisher_qos(&subscriber)?;
+    ///
+    /// waitset.wait_and_process(|attachment_id| {
+    ///     if attachment_id.has_event_from(&guard) {
+    ///         subscriber.publisher_qos_events(|event| {
+    ///             println!("{:?}", event);
+    ///             CallbackProgression::Continue
+    ///         }).ok();
+    ///     }
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_publisher_qos<Payload: Debug + ?Sized, UserHeader: Debug>(
+        &self,
+        subscriber: &Subscriber<Service, Payload, UserHeader>,
+    ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
+        match subscriber.publisher_qos_check_interval() {
+            Some(interval) => self.attach_interval(interval),
+            None => {
+                fail!(from self, with WaitSetAttachmentError::PublisherQosIsNotDefined,
+                    "Unable to attach the quality of service checks of the subscriber {:?} since neither a deadline nor a liveliness lease duration is defined.",
+                    subscriber.id());
+            }
+        }
+    }
+
+    /// Waits until an event arrives on the [`WaitSet`], then collects the events corresponding
+    /// to the attachments and calls the provided callback with the [`WaitSetAttachmentId`] as
+    /// input argument. It repeats this until either a termination or interrupt signal was
+    /// received or the callback returned [`CallbackProgression::Stop`].
+    pub fn wait_and_process<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        mut fn_call: F,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        loop {
+            match self.wait_and_process_once_impl(&mut fn_call, None)? {
+                WaitSetRunResult::AllEventsHandled => (),
+                v => return Ok(v),
+            }
+        }
+    }
+
+    /// Waits until an event arrives on the [`WaitSet`], then collects the events corresponding
+    /// to the attachments, calls the provided callback with the [`WaitSetAttachmentId`] as
+    /// input argument and returns.
+    pub fn wait_and_process_once<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        mut fn_call: F,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.wait_and_process_once_impl(&mut fn_call, None)
+    }
+
+    /// Waits until an event arrives on the [`WaitSet`] or the provided timeout has passed,
+    /// then collects the events corresponding to the attachments, calls the provided callback
+    /// with the [`WaitSetAttachmentId`] as input argument and returns.
+    pub fn wait_and_process_once_with_timeout<
+        F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression,
+    >(
+        &self,
+        mut fn_call: F,
+        timeout: Duration,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.wait_and_process_once_impl(&mut fn_call, Some(timeout))
+    }
+
+    /// Returns the capacity of the [`WaitSet`] for file descriptor based attachments.
+    pub fn capacity(&self) -> usize {
+        Service::Reactor::capacity()
+    }
+
+    /// Returns the number of attachments.
+    pub fn len(&self) -> usize {
+        let number_of_intervals = self
+            .timers
+            .borrow()
+            .values()
+            .filter(|timer| timer.file_descriptor.is_none())
+            .count();
+
+        self.file_descriptors.borrow().len() + number_of_intervals
+    }
+
+    /// Returns true if the [`WaitSet`] has no attachments, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn time_until_next_timer(&self, now: Instant) -> Option<Duration> {
+        self.timers
+            .borrow()
+            .values()
+            .map(|timer| timer.next_activation.saturating_duration_since(now))
+            .min()
+    }
+
+    fn wait_and_process_once_impl<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        fn_call: &mut F,
+        timeout: Option<Duration>,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        let msg = "Unable to wait and process events";
+        if SignalHandler::termination_requested() {
+            return Ok(WaitSetRunResult::TerminationRequest);
+        }
+
+        if self.is_empty() {
+            fail!(from self, with WaitSetRunError::NoAttachments,
+                "{} since the WaitSet has no attachments, therefore the call would end up in a deadlock.",
+                msg);
+        }
+
+        let wait_time = match (self.time_until_next_timer(Instant::now()), timeout) {
+            (Some(t1), Some(t2)) => Some(t1.min(t2)),
+            (Some(t), None) | (None, Some(t)) => Some(t),
+            (None, None) => None,
+        };
+
+        let mut triggered_fds = vec![];
+        let fd_callback = |fd: &iceoryx2_bb_posix::file_descriptor::FileDescriptor| {
+            triggered_fds.push(unsafe { fd.native_handle() })
+        };
+
+        let wait_result = match wait_time {
+            Some(wait_time) => self.reactor.timed_wait(fd_callback, wait_time),
+            None => self.reactor.blocking_wait(fd_callback),
+        };
+
+        match wait_result {
+            Ok(()) => (),
+            Err(ReactorWaitError::Interrupt) => {
+                if SignalHandler::termination_requested() {
+                    return Ok(WaitSetRunResult::TerminationRequest);
+                }
+                return Ok(WaitSetRunResult::Interrupt);
+            }
+            Err(ReactorWaitError::InsufficientPermissions) => {
+                fail!(from self, with WaitSetRunError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(ReactorWaitError::UnknownError) => {
+                fail!(from self, with WaitSetRunError::InternalError,
+                    "{} due to an internal error.", msg);
+            }
+        }
+
+        // collect all events before calling the user so that the callback is able to
+        // attach or detach elements
+        let now = Instant::now();
+        let mut triggered_attachments = vec![];
+        {
+            let file_descriptors = self.file_descriptors.borrow();
+            let mut timers = self.timers.borrow_mut();
+            for fd in triggered_fds {
+                match file_descriptors.get(&fd) {
+                    Some(Some(id)) => {
+                        if let Some(timer) = timers.get_mut(id) {
+                            timer.next_activation = now + timer.period;
+                        }
+                        triggered_attachments.push(AttachmentIdType::Deadline(fd, *id));
+                    }
+                    Some(None) => triggered_attachments.push(AttachmentIdType::Notification(fd)),
+                    None => (),
+                }
+            }
+
+            for (id, timer) in timers.iter_mut() {
+                if now < timer.next_activation {
+                    continue;
+                }
+
+                match timer.file_descriptor {
+                    Some(fd) => {
+                        timer.next_activation = now + timer.period;
+                        triggered_attachments.push(AttachmentIdType::MissedDeadline(fd, *id));
+                    }
+                    None => {
+                        if timer.period.is_zero() {
+                            timer.next_activation = now;
+                        }
+                        while timer.next_activation <= now && !timer.period.is_zero() {
+                            timer.next_activation += timer.period;
+                        }
+                        triggered_attachments.push(AttachmentIdType::Tick(*id));
+                    }
+                }
+            }
+        }
+
+        for attachment in triggered_attachments {
+            if let CallbackProgression::Stop = fn_call(WaitSetAttachmentId::new(attachment)) {
+                return Ok(WaitSetRunResult::StopRequest);
+            }
+        }
+
+        Ok(WaitSetRunResult::AllEventsHandled)
+    }
+}
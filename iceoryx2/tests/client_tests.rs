@@ -0,0 +1,355 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod client {
+    use iceoryx2::port::client::RequestSendError;
+    use iceoryx2::port::{LoanError, ReceiveError, SendError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "client_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    const COMPLEX_TYPE_DEFAULT_VALUE: u64 = 872379237;
+
+    #[derive(Debug)]
+    #[repr(C)]
+    struct ComplexType {
+        data: u64,
+    }
+
+    impl Default for ComplexType {
+        fn default() -> Self {
+            ComplexType {
+                data: COMPLEX_TYPE_DEFAULT_VALUE,
+            }
+        }
+    }
+
+    #[test]
+    fn client_loan_initializes_request_with_default<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<ComplexType, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        let request = sut.loan().unwrap();
+
+        assert_that!(request.payload().data, eq COMPLEX_TYPE_DEFAULT_VALUE);
+        assert_that!(request.header().client_id(), eq sut.id());
+    }
+
+    #[test]
+    fn client_can_loan_at_most_max_loaned_requests<Sut: Service>() {
+        const MAX_LOANED_REQUESTS: usize = 3;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .client_builder()
+            .max_loaned_requests(MAX_LOANED_REQUESTS)
+            .create()
+            .unwrap();
+
+        let mut requests = vec![];
+        for _ in 0..MAX_LOANED_REQUESTS {
+            requests.push(sut.loan_uninit().unwrap());
+        }
+
+        assert_that!(sut.loan_uninit().err(), eq Some(LoanError::ExceedsMaxLoans));
+
+        requests.pop();
+        assert_that!(sut.loan_uninit(), is_ok);
+    }
+
+    #[test]
+    fn request_ids_are_unique<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+
+        let pending_response_1 = sut.send_copy(1).unwrap();
+        let pending_response_2 = sut.send_copy(2).unwrap();
+
+        assert_that!(pending_response_1.request_id(), ne pending_response_2.request_id());
+    }
+
+    #[test]
+    fn client_cannot_exceed_max_active_requests<Sut: Service>() {
+        const MAX_ACTIVE_REQUESTS: usize = 2;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(MAX_ACTIVE_REQUESTS)
+            .create()
+            .unwrap();
+
+        let _server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let mut pending_responses = vec![];
+        for i in 0..MAX_ACTIVE_REQUESTS {
+            pending_responses.push(sut.send_copy(i as u64).unwrap());
+        }
+
+        assert_that!(sut.send_copy(0).err(), eq Some(RequestSendError::ExceedsMaxActiveRequests));
+
+        pending_responses.pop();
+        assert_that!(sut.send_copy(0), is_ok);
+    }
+
+    #[test]
+    fn responses_of_dropped_pending_response_are_discarded<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(1)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let pending_response = sut.send_copy(1).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        drop(pending_response);
+
+        active_request.send_copy(2).unwrap();
+        drop(active_request);
+
+        let pending_response = sut.send_copy(3).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_copy(4).unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 4);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_without_servers_has_no_server_connections<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        let pending_response = sut.send_copy(1).unwrap();
+
+        assert_that!(pending_response.number_of_server_connections(), eq 0);
+        assert_that!(pending_response.has_response().unwrap(), eq false);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_without_servers_is_not_an_active_request<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(1)
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        let _pending_response_1 = sut.send_copy(1).unwrap();
+        let pending_response_2 = sut.send_copy(2);
+
+        assert_that!(pending_response_2, is_ok);
+    }
+
+    #[test]
+    fn has_response_ignores_responses_of_other_requests<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let pending_response_1 = sut.send_copy(1).unwrap();
+        let pending_response_2 = sut.send_copy(2).unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 1);
+        active_request.send_copy(10).unwrap();
+
+        assert_that!(pending_response_2.has_response().unwrap(), eq false);
+        assert_that!(pending_response_1.has_response().unwrap(), eq true);
+
+        let response = pending_response_1.receive().unwrap().unwrap();
+        assert_that!(*response, eq 10);
+    }
+
+    #[test]
+    fn unconsumed_responses_of_other_requests_do_not_starve_pending_response<Sut: Service>() {
+        const RESPONSE_BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(2)
+            .max_response_buffer_size(RESPONSE_BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let pending_response_1 = sut.send_copy(1).unwrap();
+        let pending_response_2 = sut.send_copy(2).unwrap();
+        let active_request_1 = server.receive().unwrap().unwrap();
+        let active_request_2 = server.receive().unwrap().unwrap();
+
+        for i in 0..10 {
+            for _ in 0..RESPONSE_BUFFER_SIZE {
+                active_request_1.send_copy(i).unwrap();
+            }
+            assert_that!(pending_response_2.receive().unwrap(), is_none);
+        }
+
+        active_request_2.send_copy(20).unwrap();
+        let response = pending_response_2.receive().unwrap().unwrap();
+        assert_that!(*response, eq 20);
+
+        // only the latest responses are kept for the pending response
+        let mut responses = vec![];
+        while let Some(response) = pending_response_1.receive().unwrap() {
+            responses.push(*response);
+        }
+        assert_that!(responses, len RESPONSE_BUFFER_SIZE);
+        assert_that!(responses, contains 9);
+    }
+
+    #[test]
+    fn full_response_buffer_without_safe_overflow_blocks_server<Sut: Service>() {
+        const MAX_NUMBER_OF_SENT_RESPONSES: u64 = 100;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(2)
+            .max_response_buffer_size(2)
+            .enable_safe_overflow_for_responses(false)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let pending_response_1 = sut.send_copy(1).unwrap();
+        let pending_response_2 = sut.send_copy(2).unwrap();
+        let active_request_1 = server.receive().unwrap().unwrap();
+        let _active_request_2 = server.receive().unwrap().unwrap();
+
+        let mut number_of_sent_responses = 0;
+        for i in 0..MAX_NUMBER_OF_SENT_RESPONSES {
+            match active_request_1.send_copy(i) {
+                Ok(()) => number_of_sent_responses += 1,
+                Err(e) => {
+                    assert_that!(e, eq SendError::ReceiveBufferFull);
+                    break;
+                }
+            }
+            assert_that!(pending_response_2.receive().unwrap(), is_none);
+        }
+        assert_that!(number_of_sent_responses, lt MAX_NUMBER_OF_SENT_RESPONSES);
+
+        // no response is lost
+        let mut responses = vec![];
+        while let Some(response) = pending_response_1.receive().unwrap() {
+            responses.push(*response);
+        }
+        assert_that!(
+            responses,
+            eq(0..number_of_sent_responses).collect::<Vec<u64>>()
+        );
+    }
+
+    #[test]
+    fn pending_response_can_borrow_at_most_max_borrowed_responses<Sut: Service>() {
+        const MAX_BORROWED_RESPONSES: usize = 3;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(MAX_BORROWED_RESPONSES + 1)
+            .max_borrowed_responses_per_pending_response(MAX_BORROWED_RESPONSES)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let pending_response = sut.send_copy(1).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        for i in 0..MAX_BORROWED_RESPONSES as u64 + 1 {
+            active_request.send_copy(i).unwrap();
+        }
+
+        let mut responses = vec![];
+        for _ in 0..MAX_BORROWED_RESPONSES {
+            responses.push(pending_response.receive().unwrap().unwrap());
+        }
+
+        let result = pending_response.receive();
+        assert_that!(result.err(), eq Some(ReceiveError::ExceedsMaxBorrows));
+
+        responses.pop();
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq MAX_BORROWED_RESPONSES as u64);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
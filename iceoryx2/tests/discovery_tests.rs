@@ -0,0 +1,419 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod discovery {
+    use std::sync::Barrier;
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::config::Config;
+    use iceoryx2::discovery::{DiscoveryEvent, DiscoveryListener, DiscoveryListenerBuilder};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::query::ServiceNamePattern;
+    use iceoryx2::service::static_config::StaticConfig;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "discovery_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    // every test uses its own prefix so that the services and nodes of tests that run in
+    // parallel are not discovered
+    fn generate_isolated_config() -> Config {
+        let mut config = Config::default();
+        config.global.prefix =
+            FileName::new(format!("disc_{:x}_", UniqueSystemId::new().unwrap().value()).as_bytes())
+                .unwrap();
+        config
+    }
+
+    fn collect_events<S: Service>(sut: &DiscoveryListener<S>) -> Vec<DiscoveryEvent> {
+        let mut events = vec![];
+        sut.try_wait(|event| events.push(event)).unwrap();
+        events
+    }
+
+    fn added_services(events: &[DiscoveryEvent]) -> Vec<StaticConfig> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                DiscoveryEvent::ServiceAdded(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn removed_services(events: &[DiscoveryEvent]) -> Vec<StaticConfig> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                DiscoveryEvent::ServiceRemoved(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn first_wait_reports_existing_services_and_nodes<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        let events = collect_events(&sut);
+        assert_that!(events, len 2);
+        let added = added_services(&events);
+        assert_that!(added, len 1);
+        assert_that!(*added[0].name(), eq service_name);
+        assert_that!(events, contains DiscoveryEvent::NodeJoined(*node.id()));
+
+        assert_that!(collect_events(&sut), is_empty);
+    }
+
+    #[test]
+    fn created_and_removed_services_are_reported<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        collect_events(&sut);
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let events = collect_events(&sut);
+        assert_that!(events, len 1);
+        let added = added_services(&events);
+        assert_that!(*added[0].name(), eq service_name);
+
+        drop(service);
+
+        let events = collect_events(&sut);
+        assert_that!(events, len 1);
+        let removed = removed_services(&events);
+        assert_that!(*removed[0].name(), eq service_name);
+
+        assert_that!(collect_events(&sut), is_empty);
+    }
+
+    #[test]
+    fn opening_a_service_is_not_reported<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        collect_events(&sut);
+
+        let opened_service = node.service_builder(&service_name).event().open().unwrap();
+        drop(opened_service);
+
+        assert_that!(collect_events(&sut), is_empty);
+    }
+
+    #[test]
+    fn joining_and_leaving_nodes_are_reported<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        assert_that!(collect_events(&sut), is_empty);
+
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node_id = *node.id();
+
+        let events = collect_events(&sut);
+        assert_that!(events, eq vec![DiscoveryEvent::NodeJoined(node_id)]);
+
+        drop(node);
+
+        let events = collect_events(&sut);
+        assert_that!(events, eq vec![DiscoveryEvent::NodeLeft(node_id)]);
+    }
+
+    #[test]
+    fn services_are_filtered_by_messaging_pattern<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .messaging_pattern(MessagingPattern::Event)
+            .create::<S>()
+            .unwrap();
+        collect_events(&sut);
+
+        let _pubsub_service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(collect_events(&sut), is_empty);
+
+        let event_name = generate_name();
+        let _event_service = node.service_builder(&event_name).event().create().unwrap();
+
+        let events = collect_events(&sut);
+        assert_that!(events, len 1);
+        let added = added_services(&events);
+        assert_that!(*added[0].name(), eq event_name);
+    }
+
+    #[test]
+    fn services_are_filtered_by_attributes<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .attributes(&AttributeVerifier::new().require("camera", "front"))
+            .create::<S>()
+            .unwrap();
+        collect_events(&sut);
+
+        let _rear_camera = node
+            .service_builder(&generate_name())
+            .event()
+            .create_with_attributes(&AttributeSpecifier::new().define("camera", "rear"))
+            .unwrap();
+        assert_that!(collect_events(&sut), is_empty);
+
+        let front_camera_name = generate_name();
+        let front_camera = node
+            .service_builder(&front_camera_name)
+            .publish_subscribe::<u64>()
+            .create_with_attributes(
+                &AttributeSpecifier::new()
+                    .define("camera", "front")
+                    .define("resolution", "4k"),
+            )
+            .unwrap();
+
+        let events = collect_events(&sut);
+        assert_that!(events, len 1);
+        let added = added_services(&events);
+        assert_that!(added[0].name(), eq & front_camera_name);
+        assert_that!(added[0].attributes().get("resolution"), eq vec!["4k"]);
+
+        drop(front_camera);
+        let events = collect_events(&sut);
+        assert_that!(removed_services(&events), len 1);
+    }
+
+    #[test]
+    fn services_are_filtered_by_name_pattern<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .name(ServiceNamePattern::Prefix("Robot/Arm/".into()))
+            .create::<S>()
+            .unwrap();
+        collect_events(&sut);
+
+        let _leg = node
+            .service_builder(&"Robot/Leg/Left".try_into().unwrap())
+            .event()
+            .create()
+            .unwrap();
+        assert_that!(collect_events(&sut), is_empty);
+
+        let _arm = node
+            .service_builder(&"Robot/Arm/Left".try_into().unwrap())
+            .event()
+            .create()
+            .unwrap();
+
+        let events = collect_events(&sut);
+        assert_that!(events, len 1);
+        let added = added_services(&events);
+        assert_that!(added[0].name().as_str(), eq "Robot/Arm/Left");
+    }
+
+    #[test]
+    fn blocking_wait_wakes_up_when_a_service_is_created<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        collect_events(&sut);
+        let barrier_start = Barrier::new(2);
+        let barrier_end = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            let service_name = &service_name;
+            let barrier_start = &barrier_start;
+            let barrier_end = &barrier_end;
+            s.spawn(move || {
+                barrier_start.wait();
+                std::thread::sleep(TIMEOUT);
+                let _service = node.service_builder(service_name).event().create().unwrap();
+                barrier_end.wait();
+            });
+
+            barrier_start.wait();
+            let start = Instant::now();
+            let mut events = vec![];
+            while events.is_empty() {
+                sut.blocking_wait(|event| events.push(event)).unwrap();
+            }
+            barrier_end.wait();
+
+            assert_that!(start.elapsed(), ge TIMEOUT);
+            let added = added_services(&events);
+            assert_that!(*added[0].name(), eq * service_name);
+        });
+    }
+
+    #[test]
+    fn timed_wait_without_changes_reports_nothing<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        collect_events(&sut);
+
+        let start = Instant::now();
+        let mut events = vec![];
+        sut.timed_wait(|event| events.push(event), TIMEOUT).unwrap();
+
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(events, is_empty);
+    }
+
+    #[test]
+    fn multiple_discovery_listeners_are_notified<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut_1 = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let sut_2 = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        collect_events(&sut_1);
+        collect_events(&sut_2);
+
+        let _service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        for sut in [&sut_1, &sut_2] {
+            let mut events = vec![];
+            sut.timed_wait(|event| events.push(event), TIMEOUT).unwrap();
+            let added = added_services(&events);
+            assert_that!(*added[0].name(), eq service_name);
+        }
+    }
+
+    #[test]
+    fn discovery_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", iceoryx2::discovery::DiscoveryListenerWaitError::InterruptSignal),
+            eq "DiscoveryListenerWaitError::InterruptSignal"
+        );
+        assert_that!(
+            format!("{}", iceoryx2::discovery::DiscoveryListenerCreateError::InternalError),
+            eq "DiscoveryListenerCreateError::InternalError"
+        );
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
+
+mod discovery_ipc {
+    use std::os::unix::net::UnixDatagram;
+    use std::path::PathBuf;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::discovery::DiscoveryListenerBuilder;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn stale_socket_of_crashed_discovery_listener_is_removed() {
+        let mut config = Config::default();
+        config.global.prefix =
+            FileName::new(format!("disc_{:x}_", UniqueSystemId::new().unwrap().value()).as_bytes())
+                .unwrap();
+        // creates the root path and verifies that the listener is notified
+        let _sut = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        // a crashed listener leaves its socket behind without anyone receiving on it
+        let stale_socket = PathBuf::from(format!(
+            "{}/{}crashed{}",
+            config.global.root_path(),
+            config.global.prefix,
+            config.global.service.discovery_suffix
+        ));
+        drop(UnixDatagram::bind(&stale_socket).unwrap());
+        assert_that!(stale_socket.exists(), eq true);
+
+        let _node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        assert_that!(stale_socket.exists(), eq false);
+    }
+}
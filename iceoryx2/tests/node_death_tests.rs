@@ -15,12 +15,16 @@ mod node_death_tests {
     use std::sync::atomic::{AtomicU32, Ordering};
 
     use iceoryx2::config::Config;
+    use iceoryx2::discovery::{DiscoveryEvent, DiscoveryListenerBuilder};
     use iceoryx2::node::testing::__internal_node_staged_death;
     use iceoryx2::node::{CleanupState, NodeState};
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
+    use iceoryx2_bb_posix::shared_memory::SharedMemory;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::watchdog::Watchdog;
     use iceoryx2_bb_testing::{assert_that, test_fail};
 
@@ -196,6 +200,157 @@ mod node_death_tests {
         }
     }
 
+    #[test]
+    fn samples_of_dead_subscriber_are_returned_to_publisher<S: Test>() {
+        let _watchdog = Watchdog::new();
+        const BUFFER_SIZE: usize = 1;
+        const MAX_BORROWED_SAMPLES: usize = 2;
+        const MAX_LOANED_SAMPLES: usize = 2;
+
+        let mut config = Config::global_config().clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let service_name = generate_name();
+        let mut bad_node = S::create_test_node(&config).node;
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+
+        let create_service = |node: &Node<S::Service>| {
+            node.service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .max_subscribers(1)
+                .history_size(0)
+                .subscriber_max_buffer_size(BUFFER_SIZE)
+                .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+                .open_or_create()
+                .unwrap()
+        };
+
+        let good_service = create_service(&good_node);
+        let publisher = good_service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()
+            .unwrap();
+
+        let bad_service = create_service(&bad_node);
+        let bad_subscriber = bad_service.subscriber_builder().create().unwrap();
+
+        let mut bad_samples = vec![];
+        for n in 0..MAX_BORROWED_SAMPLES {
+            publisher.send_copy(n as u64).unwrap();
+            bad_samples.push(bad_subscriber.receive().unwrap().unwrap());
+        }
+        publisher.send_copy(1234).unwrap();
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(bad_samples);
+        core::mem::forget(bad_subscriber);
+        core::mem::forget(bad_service);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        // the new subscriber acquires all the samples it can hold, therefore the publisher
+        // can only loan its samples when the samples of the dead subscriber were returned
+        let subscriber = good_service.subscriber_builder().create().unwrap();
+        let mut samples = vec![];
+        for n in 0..MAX_BORROWED_SAMPLES {
+            publisher.send_copy(n as u64).unwrap();
+            samples.push(subscriber.receive().unwrap().unwrap());
+        }
+        publisher.send_copy(5678).unwrap();
+
+        let mut loans = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            let sample = publisher.loan_uninit();
+            assert_that!(sample, is_ok);
+            loans.push(sample);
+        }
+    }
+
+    #[test]
+    fn dead_node_is_removed_from_request_response_service<S: Test>() {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_BAD_NODES: usize = 3;
+        const NUMBER_OF_GOOD_NODES: usize = 4;
+        const NUMBER_OF_SERVICES: usize = 5;
+        const NUMBER_OF_CLIENTS: usize = NUMBER_OF_BAD_NODES + NUMBER_OF_GOOD_NODES;
+        const NUMBER_OF_SERVERS: usize = NUMBER_OF_BAD_NODES + NUMBER_OF_GOOD_NODES;
+
+        let mut config = Config::global_config().clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut bad_nodes = vec![];
+        let mut good_nodes = vec![];
+
+        for _ in 0..NUMBER_OF_BAD_NODES {
+            bad_nodes.push(S::create_test_node(&config).node);
+        }
+
+        for _ in 0..NUMBER_OF_GOOD_NODES {
+            good_nodes.push(
+                NodeBuilder::new()
+                    .config(&config)
+                    .create::<S::Service>()
+                    .unwrap(),
+            );
+        }
+
+        let mut services = vec![];
+        let mut bad_clients = vec![];
+        let mut bad_servers = vec![];
+        let mut good_clients = vec![];
+        let mut good_servers = vec![];
+
+        for _ in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_name();
+
+            for node in &bad_nodes {
+                let service = node
+                    .service_builder(&service_name)
+                    .request_response::<u64, u64>()
+                    .max_clients(NUMBER_OF_CLIENTS)
+                    .max_servers(NUMBER_OF_SERVERS)
+                    .open_or_create()
+                    .unwrap();
+                bad_clients.push(service.client_builder().create().unwrap());
+                bad_servers.push(service.server_builder().create().unwrap());
+
+                services.push(service);
+            }
+
+            for node in &good_nodes {
+                let service = node
+                    .service_builder(&service_name)
+                    .request_response::<u64, u64>()
+                    .max_clients(NUMBER_OF_CLIENTS)
+                    .max_servers(NUMBER_OF_SERVERS)
+                    .open_or_create()
+                    .unwrap();
+                good_clients.push(service.client_builder().create().unwrap());
+                good_servers.push(service.server_builder().create().unwrap());
+                services.push(service);
+            }
+        }
+
+        for _ in 0..NUMBER_OF_BAD_NODES {
+            let mut node = bad_nodes.pop().unwrap();
+            S::staged_death(&mut node);
+        }
+
+        core::mem::forget(bad_clients);
+        core::mem::forget(bad_servers);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(Config::global_config()), eq CleanupState { cleanups: NUMBER_OF_BAD_NODES, failed_cleanups: 0});
+
+        for service in &services {
+            assert_that!(service.dynamic_config().number_of_clients(), eq NUMBER_OF_CLIENTS - NUMBER_OF_BAD_NODES);
+            assert_that!(service.dynamic_config().number_of_servers(), eq NUMBER_OF_SERVERS - NUMBER_OF_BAD_NODES);
+        }
+    }
+
     #[test]
     fn dead_node_is_removed_from_event_service<S: Test>() {
         set_log_level(LogLevel::Error);
@@ -345,6 +500,80 @@ mod node_death_tests {
         );
     }
 
+    #[test]
+    fn statistics_of_ports_of_dead_node_are_removed<S: Test>() {
+        let service_name = generate_name();
+        let mut config = Config::global_config().clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.prefix = FileName::new(
+            format!("stats_{}_", UniqueSystemId::new().unwrap().value() as u32).as_bytes(),
+        )
+        .unwrap();
+
+        // the statistics are stored in shared memory whose name starts with the prefix and
+        // ends with the suffix
+        let number_of_statistics = || {
+            SharedMemory::list()
+                .iter()
+                .filter(|name| {
+                    let name = name.as_bytes();
+                    name.starts_with(config.global.prefix.as_bytes())
+                        && name.ends_with(config.global.service.port_statistics_suffix.as_bytes())
+                })
+                .count()
+        };
+
+        let mut bad_node = S::create_test_node(&config).node;
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+
+        let bad_pubsub = bad_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let bad_event = bad_node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let bad_publisher = bad_pubsub.publisher_builder().create().unwrap();
+        let bad_notifier = bad_event.notifier_builder().create().unwrap();
+
+        let good_pubsub = good_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let good_event = good_node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let _good_publisher = good_pubsub.publisher_builder().create().unwrap();
+        let _good_notifier = good_event.notifier_builder().create().unwrap();
+
+        assert_that!(number_of_statistics(), eq 4);
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(bad_publisher);
+        core::mem::forget(bad_notifier);
+        core::mem::forget(bad_pubsub);
+        core::mem::forget(bad_event);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        assert_that!(number_of_statistics(), eq 2);
+        let mut number_of_publishers = 0;
+        good_pubsub.list_publisher_statistics(|_, _| {
+            number_of_publishers += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(number_of_publishers, eq 1);
+    }
+
     #[test]
     fn node_cleanup_option_works_on_node_creation<S: Test>() {
         let mut config = Config::global_config().clone();
@@ -429,6 +658,50 @@ mod node_death_tests {
         assert_that!(number_of_nodes(), eq 0);
     }
 
+    #[test]
+    fn cleanup_of_dead_node_is_reported_to_discovery_listener<S: Test>() {
+        let mut config = Config::global_config().clone();
+        config.global.prefix = FileName::new(
+            format!("death_{:x}_", UniqueSystemId::new().unwrap().value()).as_bytes(),
+        )
+        .unwrap();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_name();
+
+        let mut sut = S::create_test_node(&config);
+        let dead_node_id = *sut.node.id();
+        let service = sut
+            .node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let discovery = DiscoveryListenerBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        discovery.try_wait(|_| {}).unwrap();
+
+        core::mem::forget(service);
+        S::staged_death(&mut sut.node);
+        core::mem::forget(sut.node);
+
+        let cleanup_state = Node::<S::Service>::cleanup_dead_nodes(&config);
+        assert_that!(cleanup_state.cleanups, eq 1);
+
+        let mut events = vec![];
+        discovery.try_wait(|event| events.push(event)).unwrap();
+
+        assert_that!(events, len 2);
+        assert_that!(events, contains DiscoveryEvent::NodeLeft(dead_node_id));
+        let removed_service = events.iter().find_map(|event| match event {
+            DiscoveryEvent::ServiceRemoved(service) => Some(service.name().clone()),
+            _ => None,
+        });
+        assert_that!(removed_service, eq Some(service_name));
+    }
+
     #[instantiate_tests(<ZeroCopy>)]
     mod ipc {}
 }
@@ -351,3 +351,204 @@ mod node {
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
 }
+
+mod node_with_attachments {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::node::NodeAttachmentError;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(25);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "node_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn error_display_works() {
+        assert_that!(
+            format!("{}", NodeAttachmentError::AlreadyAttached), eq "NodeAttachmentError::AlreadyAttached");
+    }
+
+    #[test]
+    fn attaching_listener_twice_fails() {
+        let sut = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = sut
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+
+        let _guard = sut.attach(&listener).unwrap();
+        let result = sut.attach(&listener);
+        assert_that!(result.err(), eq Some(NodeAttachmentError::AlreadyAttached));
+    }
+
+    #[test]
+    fn wait_returns_tick_when_attachment_has_no_event() {
+        let sut = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = sut
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let _guard = sut.attach(&listener).unwrap();
+
+        let start = Instant::now();
+        let event = sut.wait(TIMEOUT);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(event, eq NodeEvent::Tick);
+    }
+
+    #[test]
+    fn wait_returns_attachment_with_pending_event() {
+        let sut = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service_1 = sut
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let service_2 = sut
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener_1 = service_1.listener_builder().create().unwrap();
+        let listener_2 = service_2.listener_builder().create().unwrap();
+        let notifier = service_2.notifier_builder().create().unwrap();
+
+        let guard_1 = sut.attach(&listener_1).unwrap();
+        let guard_2 = sut.attach(&listener_2).unwrap();
+
+        notifier.notify().unwrap();
+
+        match sut.wait(TIMEOUT * 1000) {
+            NodeEvent::Attachment(id) => {
+                assert_that!(id.is_from(&guard_1), eq false);
+                assert_that!(id.is_from(&guard_2), eq true);
+                assert_that!(id, eq guard_2.id());
+            }
+            event => panic!("expected an attachment event but got {:?}", event),
+        }
+
+        assert_that!(listener_2.try_wait_one().unwrap(), is_some);
+        assert_that!(sut.wait(TIMEOUT), eq NodeEvent::Tick);
+    }
+
+    #[test]
+    fn all_attachments_with_pending_events_are_returned() {
+        let sut = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service_1 = sut
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let service_2 = sut
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener_1 = service_1.listener_builder().create().unwrap();
+        let listener_2 = service_2.listener_builder().create().unwrap();
+        let notifier_1 = service_1.notifier_builder().create().unwrap();
+        let notifier_2 = service_2.notifier_builder().create().unwrap();
+
+        let guard_1 = sut.attach(&listener_1).unwrap();
+        let guard_2 = sut.attach(&listener_2).unwrap();
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+
+        let mut received = vec![];
+        for _ in 0..2 {
+            if let NodeEvent::Attachment(id) = sut.wait(TIMEOUT * 1000) {
+                received.push(id);
+            }
+        }
+
+        assert_that!(received, len 2);
+        assert_that!(received, contains guard_1.id());
+        assert_that!(received, contains guard_2.id());
+    }
+
+    #[test]
+    fn attaching_subscriber_without_notify_on_send_fails() {
+        let sut = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = sut
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let result = sut.attach_subscriber(&subscriber);
+        assert_that!(result.err(), eq Some(NodeAttachmentError::NotifyOnSendIsDisabled));
+    }
+
+    #[test]
+    fn wait_returns_attached_subscriber_until_all_samples_are_received() {
+        let sut = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = sut
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let guard = sut.attach_subscriber(&subscriber).unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        match sut.wait(TIMEOUT * 1000) {
+            NodeEvent::Attachment(id) => assert_that!(id.is_from(&guard), eq true),
+            event => panic!("expected an attachment event but got {:?}", event),
+        }
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        drop(sample);
+
+        // a sample is still available, the subscriber is reported again
+        let event = sut.wait(TIMEOUT);
+        assert_that!(event, eq NodeEvent::Attachment(guard.id()));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(sut.wait(TIMEOUT), eq NodeEvent::Tick);
+    }
+
+    #[test]
+    fn detached_listener_does_not_wake_up_node() {
+        let sut = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = sut
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        let guard = sut.attach(&listener).unwrap();
+        drop(guard);
+
+        notifier.notify().unwrap();
+
+        let start = Instant::now();
+        assert_that!(sut.wait(TIMEOUT), eq NodeEvent::Tick);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    }
+}
@@ -39,7 +39,7 @@ mod publisher {
 
     const COMPLEX_TYPE_DEFAULT_VALUE: u64 = 872379237;
 
-    #[derive(Debug)]
+    #[derive(Debug, ZeroCopySend)]
     #[repr(C)]
     struct ComplexType {
         data: u64,
@@ -156,6 +156,97 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_with_best_fit_strategy_can_loan_slices_larger_than_max_slice_len<Sut: Service>(
+    ) -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[ComplexType]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()?;
+
+        let small_sample = publisher.loan_slice(NUMBER_OF_ELEMENTS)?;
+        let sut = publisher.loan_slice(NUMBER_OF_ELEMENTS * 10)?;
+        assert_that!(sut.payload(), len NUMBER_OF_ELEMENTS * 10);
+        for element in sut.payload() {
+            assert_that!(element.data, eq COMPLEX_TYPE_DEFAULT_VALUE);
+        }
+        assert_that!(small_sample.payload(), len NUMBER_OF_ELEMENTS);
+        drop(small_sample);
+        drop(sut);
+
+        let sut = publisher.loan_slice(NUMBER_OF_ELEMENTS * 10 + 1)?;
+        assert_that!(sut.payload(), len NUMBER_OF_ELEMENTS * 10 + 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_power_of_two_strategy_can_loan_slices_larger_than_max_slice_len<
+        Sut: Service,
+    >() -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 3;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[ComplexType]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+
+        for n in 0..=1000 {
+            let sut = publisher.loan_slice(n)?;
+            assert_that!(sut.payload(), len n);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_dynamic_strategy_fails_when_all_data_segments_are_used<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()?;
+
+        let mut slice_len = 1;
+        let result = loop {
+            slice_len += 1;
+            match publisher.loan_slice(slice_len) {
+                Ok(_) => (),
+                Err(e) => break e,
+            }
+        };
+
+        assert_that!(result, eq PublisherLoanError::ExceedsMaxLoanSize);
+        assert_that!(slice_len, gt 2);
+        assert_that!(publisher.loan_slice(slice_len - 1), is_ok);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -167,6 +167,72 @@ mod sample {
         assert_that!(*sample_2, eq PAYLOAD_2);
     }
 
+    #[test]
+    fn sequence_numbers_are_consecutive_per_publisher<Sut: Service>() {
+        let test_context = TestContext::<Sut>::new();
+
+        for i in 0..3 {
+            assert_that!(test_context.publisher_1.send_copy(1), eq Ok(1));
+            let sample = test_context.subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().sequence_number(), eq i);
+        }
+
+        for i in 0..3 {
+            assert_that!(test_context.publisher_2.send_copy(2), eq Ok(1));
+            let sample = test_context.subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().sequence_number(), eq i);
+        }
+
+        assert_that!(test_context.subscriber.number_of_missed_samples(), eq 0);
+    }
+
+    #[test]
+    fn timestamps_are_set_and_do_not_decrease<Sut: Service>() {
+        let test_context = TestContext::<Sut>::new();
+
+        assert_that!(test_context.publisher_1.send_copy(1), eq Ok(1));
+        assert_that!(test_context.publisher_1.send_copy(2), eq Ok(1));
+
+        let sample_1 = test_context.subscriber.receive().unwrap().unwrap();
+        let sample_2 = test_context.subscriber.receive().unwrap().unwrap();
+        let timestamp_1 = sample_1.header().timestamp().as_duration();
+        let timestamp_2 = sample_2.header().timestamp().as_duration();
+
+        assert_that!(timestamp_1, gt std::time::Duration::ZERO);
+        assert_that!(timestamp_2, ge timestamp_1);
+    }
+
+    #[test]
+    fn overflowed_samples_are_reported_as_missed<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
+        const NUMBER_OF_SAMPLES: u64 = 5;
+
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(0), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap(), is_some);
+
+        for i in 1..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+        }
+
+        while let Some(sample) = subscriber.receive().unwrap() {
+            assert_that!(*sample, eq sample.header().sequence_number());
+        }
+
+        assert_that!(subscriber.number_of_missed_samples(), eq NUMBER_OF_SAMPLES - 1 - BUFFER_SIZE as u64);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -0,0 +1,160 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod server {
+    use iceoryx2::port::{LoanError, SendError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "server_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn server_without_clients_has_no_requests<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+
+        assert_that!(sut.has_requests().unwrap(), eq false);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn server_receives_requests_of_all_clients<Sut: Service>() {
+        const NUMBER_OF_CLIENTS: usize = 3;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(NUMBER_OF_CLIENTS)
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+
+        let mut clients = vec![];
+        let mut pending_responses = vec![];
+        for i in 0..NUMBER_OF_CLIENTS {
+            let client = service.client_builder().create().unwrap();
+            pending_responses.push(client.send_copy(i as u64).unwrap());
+            clients.push(client);
+        }
+
+        assert_that!(sut.has_requests().unwrap(), eq true);
+
+        let mut received = vec![];
+        while let Some(active_request) = sut.receive().unwrap() {
+            received.push(*active_request);
+        }
+
+        assert_that!(received, len NUMBER_OF_CLIENTS);
+        for i in 0..NUMBER_OF_CLIENTS {
+            assert_that!(received, contains i as u64);
+        }
+    }
+
+    #[test]
+    fn server_can_loan_at_most_max_loaned_responses<Sut: Service>() {
+        const MAX_LOANED_RESPONSES: usize = 2;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .server_builder()
+            .max_loaned_responses(MAX_LOANED_RESPONSES)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let _pending_response = client.send_copy(1).unwrap();
+
+        let active_request = sut.receive().unwrap().unwrap();
+
+        let mut responses = vec![];
+        for _ in 0..MAX_LOANED_RESPONSES {
+            responses.push(active_request.loan_uninit().unwrap());
+        }
+
+        assert_that!(active_request.loan_uninit().err(), eq Some(LoanError::ExceedsMaxLoans));
+
+        responses.pop();
+        assert_that!(active_request.loan_uninit(), is_ok);
+    }
+
+    #[test]
+    fn response_header_contains_server_id_and_request_id<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+        let pending_response = client.send_copy(1).unwrap();
+
+        let active_request = sut.receive().unwrap().unwrap();
+        let response = active_request.loan().unwrap();
+
+        assert_that!(response.header().server_id(), eq sut.id());
+        assert_that!(response.header().request_id(), eq pending_response.request_id());
+    }
+
+    #[test]
+    fn sending_response_to_disconnected_client_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+        let pending_response = client.send_copy(1).unwrap();
+
+        let active_request = sut.receive().unwrap().unwrap();
+
+        drop(pending_response);
+        drop(client);
+
+        assert_that!(active_request.send_copy(2).err(), eq Some(SendError::ConnectionBrokenSinceReceiverNoLongerExists));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
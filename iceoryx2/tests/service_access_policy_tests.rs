@@ -0,0 +1,272 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_access_policy {
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::event::EventOpenError;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::builder::{AccessPolicy, Permission};
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
+
+    const FOREIGN_UID: u32 = 4711;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "access_policy_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn generate_isolated_config() -> Config {
+        let mut config = Config::default();
+        config.global.prefix =
+            FileName::new(format!("acl_{:x}_", UniqueSystemId::new().unwrap().value()).as_bytes())
+                .unwrap();
+        config
+    }
+
+    // transferring the ownership of the underlying resources to another user requires
+    // a privileged process
+    fn can_transfer_ownership() -> bool {
+        User::from_self().unwrap().uid() == 0
+    }
+
+    fn open_event_with_policy<S: Service>(
+        access_policy: &AccessPolicy,
+    ) -> Result<(), EventOpenError> {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .access_policy(access_policy)
+            .event()
+            .create()
+            .unwrap();
+
+        node.service_builder(&service_name)
+            .event()
+            .open()
+            .map(|_| ())
+    }
+
+    #[test]
+    fn service_with_default_policy_can_be_opened<S: Service>() {
+        let sut = open_event_with_policy::<S>(&AccessPolicy::new());
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn creator_is_assigned_as_owner_when_no_owner_is_defined<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let user = User::from_self().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .access_policy(&AccessPolicy::new().permission(Permission::GROUP_READ))
+            .event()
+            .create()
+            .unwrap();
+
+        let access_policy = sut.access_policy();
+        assert_that!(access_policy.get_owner(), eq Some(user.uid()));
+        assert_that!(access_policy.get_group(), eq Some(user.gid()));
+        assert_that!(access_policy.get_permission(), eq Permission::GROUP_READ);
+
+        let sut = node.service_builder(&service_name).event().open();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn policy_of_existing_service_is_used_when_opening<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .access_policy(&AccessPolicy::new().permission(Permission::OTHERS_READ))
+            .event()
+            .open()
+            .unwrap();
+
+        assert_that!(sut.access_policy().is_default(), eq true);
+    }
+
+    #[test]
+    fn service_of_other_owner_cannot_be_opened<S: Service>() {
+        test_requires!(can_transfer_ownership());
+
+        let sut = open_event_with_policy::<S>(&AccessPolicy::new().owner(FOREIGN_UID));
+
+        assert_that!(sut.err(), eq Some(EventOpenError::InsufficientPermissions));
+    }
+
+    #[test]
+    fn publish_subscribe_service_of_other_owner_cannot_be_opened<S: Service>() {
+        test_requires!(can_transfer_ownership());
+
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .access_policy(&AccessPolicy::new().owner(FOREIGN_UID))
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::InsufficientPermissions));
+    }
+
+    #[test]
+    fn service_of_other_owner_with_others_access_can_be_opened<S: Service>() {
+        test_requires!(can_transfer_ownership());
+
+        let sut = open_event_with_policy::<S>(
+            &AccessPolicy::new()
+                .owner(FOREIGN_UID)
+                .permission(Permission::OTHERS_READ | Permission::OTHERS_WRITE),
+        );
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn service_of_other_owner_with_read_only_access_cannot_be_opened<S: Service>() {
+        test_requires!(can_transfer_ownership());
+
+        let sut = open_event_with_policy::<S>(
+            &AccessPolicy::new()
+                .owner(FOREIGN_UID)
+                .permission(Permission::OTHERS_READ),
+        );
+
+        assert_that!(sut.err(), eq Some(EventOpenError::InsufficientPermissions));
+    }
+
+    #[test]
+    fn service_of_other_owner_with_group_access_can_be_opened_by_group_member<S: Service>() {
+        test_requires!(can_transfer_ownership());
+
+        let gid = User::from_self().unwrap().gid();
+        let sut = open_event_with_policy::<S>(
+            &AccessPolicy::new()
+                .owner(FOREIGN_UID)
+                .group(gid)
+                .permission(Permission::GROUP_READ | Permission::GROUP_WRITE),
+        );
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn service_of_other_owner_without_group_access_cannot_be_opened_by_group_member<S: Service>() {
+        test_requires!(can_transfer_ownership());
+
+        let gid = User::from_self().unwrap().gid();
+        let sut = open_event_with_policy::<S>(&AccessPolicy::new().owner(FOREIGN_UID).group(gid));
+
+        assert_that!(sut.err(), eq Some(EventOpenError::InsufficientPermissions));
+    }
+
+    #[test]
+    #[cfg(feature = "acl")]
+    fn service_of_other_owner_can_be_opened_by_allowed_user<S: Service>() {
+        test_requires!(can_transfer_ownership());
+
+        let uid = User::from_self().unwrap().uid();
+        let sut =
+            open_event_with_policy::<S>(&AccessPolicy::new().owner(FOREIGN_UID).allow_user(uid));
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    #[cfg(feature = "acl")]
+    fn service_of_other_owner_can_be_opened_by_member_of_allowed_group<S: Service>() {
+        test_requires!(can_transfer_ownership());
+
+        let gid = User::from_self().unwrap().gid();
+        let sut =
+            open_event_with_policy::<S>(&AccessPolicy::new().owner(FOREIGN_UID).allow_group(gid));
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
+
+#[cfg(not(feature = "acl"))]
+mod service_access_policy_without_acl_support {
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::AccessPolicy;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn service_with_allowed_user_cannot_be_created() {
+        let mut config = Config::default();
+        config.global.prefix =
+            FileName::new(format!("acl_{:x}_", UniqueSystemId::new().unwrap().value()).as_bytes())
+                .unwrap();
+        let service_name = ServiceName::new(&format!(
+            "access_policy_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let uid = User::from_self().unwrap().uid();
+
+        let sut = node
+            .service_builder(&service_name)
+            .access_policy(&AccessPolicy::new().allow_user(uid))
+            .event()
+            .create();
+
+        assert_that!(sut, is_err);
+    }
+}
@@ -0,0 +1,464 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_blackboard {
+    use iceoryx2::port::reader::{EntryHandleError, ReaderCreateError};
+    use iceoryx2::port::writer::{EntryHandleMutError, WriterCreateError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
+    use iceoryx2::service::dynamic_config::PortDetails;
+    use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_service_without_entries_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .create();
+
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::NoEntriesProvided));
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+        assert_that!(sut2.err(), eq Some(BlackboardCreateError::AlreadyExists));
+    }
+
+    #[test]
+    fn recreate_after_drop_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        drop(sut);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .open();
+
+        assert_that!(sut.err(), eq Some(BlackboardOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn open_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .add::<i32>(1, -1)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut2.static_config().number_of_entries(), eq 2);
+        assert_that!(sut.service_id(), eq sut2.service_id());
+    }
+
+    #[test]
+    fn open_with_incompatible_key_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u32>()
+            .open();
+        assert_that!(sut2.err(), eq Some(BlackboardOpenError::IncompatibleKeyType));
+    }
+
+    #[test]
+    fn open_with_more_readers_than_supported_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .max_readers(2)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .max_readers(3)
+            .open();
+        assert_that!(sut2.err(), eq Some(BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .max_readers(1)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn only_one_writer_can_be_created<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create();
+        assert_that!(writer, is_ok);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+
+        let writer2 = sut.writer_builder().create();
+        assert_that!(writer2.err(), eq Some(WriterCreateError::ExceedsMaxSupportedWriters));
+
+        drop(writer);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+
+        let writer2 = sut.writer_builder().create();
+        assert_that!(writer2, is_ok);
+    }
+
+    #[test]
+    fn number_of_readers_is_limited<Sut: Service>() {
+        const MAX_READERS: usize = 4;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .max_readers(MAX_READERS)
+            .create()
+            .unwrap();
+
+        let mut readers = vec![];
+        for _ in 0..MAX_READERS {
+            let reader = sut.reader_builder().create();
+            assert_that!(reader, is_ok);
+            readers.push(reader.unwrap());
+        }
+        assert_that!(sut.dynamic_config().number_of_readers(), eq MAX_READERS);
+
+        let reader = sut.reader_builder().create();
+        assert_that!(reader.err(), eq Some(ReaderCreateError::ExceedsMaxSupportedReaders));
+
+        readers.pop();
+        let reader = sut.reader_builder().create();
+        assert_that!(reader, is_ok);
+    }
+
+    #[test]
+    fn reader_reads_initial_value<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 12)
+            .add::<i32>(1, -34)
+            .add::<u64>(2, 56)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u8>(&0).unwrap().get(), eq 12);
+        assert_that!(reader.entry::<i32>(&1).unwrap().get(), eq - 34);
+        assert_that!(reader.entry::<u64>(&2).unwrap().get(), eq 56);
+    }
+
+    #[test]
+    fn reader_reads_latest_written_value<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .add::<[u64; 8]>(1, [0; 8])
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_mut = writer.entry::<[u64; 8]>(&1).unwrap();
+        let entry = reader.entry::<[u64; 8]>(&1).unwrap();
+
+        for i in 0..10 {
+            entry_mut.update_with_copy([i; 8]);
+            assert_that!(entry.get(), eq[i; 8]);
+        }
+        assert_that!(reader.entry::<u8>(&0).unwrap().get(), eq 0);
+    }
+
+    #[test]
+    fn reader_of_other_node_reads_written_value<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let node2 = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(7, 0)
+            .create()
+            .unwrap();
+        let sut2 = node2
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .open()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut2.reader_builder().create().unwrap();
+
+        writer.entry::<u32>(&7).unwrap().update_with_copy(8912);
+        assert_that!(reader.entry::<u32>(&7).unwrap().get(), eq 8912);
+    }
+
+    #[test]
+    fn entry_handle_mut_can_be_acquired_only_once<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .add::<u8>(1, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let entry_mut = writer.entry::<u8>(&0);
+        assert_that!(entry_mut, is_ok);
+
+        let entry_mut2 = writer.entry::<u8>(&0);
+        assert_that!(entry_mut2.err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+
+        let other_entry_mut = writer.entry::<u8>(&1);
+        assert_that!(other_entry_mut, is_ok);
+
+        drop(entry_mut);
+        let entry_mut2 = writer.entry::<u8>(&0);
+        assert_that!(entry_mut2, is_ok);
+    }
+
+    #[test]
+    fn recreated_writer_can_acquire_entry_handle_mut<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let entry_mut = writer.entry::<u8>(&0).unwrap();
+        entry_mut.update_with_copy(5);
+        drop(entry_mut);
+        drop(writer);
+
+        let writer = sut.writer_builder().create().unwrap();
+        let entry_mut = writer.entry::<u8>(&0);
+        assert_that!(entry_mut, is_ok);
+        entry_mut.unwrap().update_with_copy(6);
+
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u8>(&0).unwrap().get(), eq 6);
+    }
+
+    #[test]
+    fn entry_with_non_existing_key_cannot_be_acquired<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(writer.entry::<u8>(&1).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(reader.entry::<u8>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn entry_with_incompatible_value_type_cannot_be_acquired<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(writer.entry::<u16>(&0).err(), eq Some(EntryHandleMutError::IncompatibleValueType));
+        assert_that!(reader.entry::<i8>(&0).err(), eq Some(EntryHandleError::IncompatibleValueType));
+    }
+
+    #[test]
+    fn adding_same_key_twice_replaces_entry<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .add::<u32>(0, 42)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().number_of_entries(), eq 1);
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u32>(&0).unwrap().get(), eq 42);
+    }
+
+    #[test]
+    fn details_list_connected_readers_and_writers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let mut readers = vec![];
+        sut.dynamic_config().list_readers(|details| {
+            readers.push(*details);
+            CallbackProgression::Continue
+        });
+        assert_that!(readers, len 1);
+        assert_that!(readers[0].reader_id(), eq reader.id());
+
+        let details = Sut::details(
+            &service_name,
+            Config::global_config(),
+            MessagingPattern::Blackboard,
+        )
+        .unwrap()
+        .unwrap();
+
+        let ports = details.dynamic_details.unwrap().ports;
+        assert_that!(ports, len 2);
+
+        let mut found_reader = false;
+        let mut found_writer = false;
+        for port in ports {
+            assert_that!(*port.node_id(), eq * node.id());
+            match port {
+                PortDetails::Reader(details) => {
+                    assert_that!(details.reader_id(), eq reader.id());
+                    found_reader = true;
+                }
+                PortDetails::Writer(details) => {
+                    assert_that!(details.writer_id(), eq writer.id());
+                    found_writer = true;
+                }
+                _ => assert_that!(true, eq false),
+            }
+        }
+
+        assert_that!(found_reader, eq true);
+        assert_that!(found_writer, eq true);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -22,6 +22,7 @@ mod service_event {
     use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
+    use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -470,6 +471,48 @@ mod service_event {
         }
     }
 
+    #[test]
+    fn dynamic_config_lists_connected_notifiers_and_listeners<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let node2 = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut2 = node2.service_builder(&service_name).event().open().unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut2.listener_builder().create().unwrap();
+
+        let mut notifiers = vec![];
+        sut2.dynamic_config().list_notifiers(|details| {
+            notifiers.push(*details);
+            CallbackProgression::Continue
+        });
+        assert_that!(notifiers, len 1);
+        assert_that!(notifiers[0].notifier_id(), eq notifier.id());
+        assert_that!(*notifiers[0].node_id(), eq * node.id());
+
+        let mut listeners = vec![];
+        sut.dynamic_config().list_listeners(|details| {
+            listeners.push(*details);
+            CallbackProgression::Continue
+        });
+        assert_that!(listeners, len 1);
+        assert_that!(listeners[0].listener_id(), eq listener.id());
+        assert_that!(*listeners[0].node_id(), eq * node2.id());
+
+        let mut counter = 0;
+        sut.dynamic_config().list_listeners(|_| {
+            counter += 1;
+            CallbackProgression::Stop
+        });
+        assert_that!(counter, eq 1);
+    }
+
     #[test]
     fn number_of_notifiers_works<Sut: Service>() {
         let service_name = generate_name();
@@ -1091,6 +1134,34 @@ mod service_event {
             format!("{}", EventCreateError::IsBeingCreatedByAnotherInstance), eq "EventCreateError::IsBeingCreatedByAnotherInstance");
     }
 
+    #[test]
+    fn notifier_statistics_track_number_of_notifications<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let _listener = sut.listener_builder().create().unwrap();
+
+        for _ in 0..5 {
+            assert_that!(notifier.notify(), is_ok);
+        }
+
+        let mut counter = 0;
+        sut.list_notifier_statistics(|details, statistics| {
+            assert_that!(details.notifier_id(), eq notifier.id());
+            assert_that!(statistics.number_of_notifications, eq 5);
+            counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(counter, eq 1);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -0,0 +1,326 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_publish_subscribe_serialized {
+    use std::collections::HashMap;
+
+    use iceoryx2::port::serialized_publisher::SerializedPublisherSendError;
+    use iceoryx2::port::serialized_subscriber::SerializedSubscriberReceiveError;
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::builder::publish_subscribe_serialized::serialize::{
+        cdr::Cdr, postcard::Postcard, toml::Toml, DeserializeError, Serialize, SerializeError,
+    };
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Settings {
+        name: String,
+        values: Vec<u64>,
+        entries: Vec<(String, String)>,
+    }
+
+    fn settings(name: &str, number_of_values: u64) -> Settings {
+        let entries = vec![("key".to_string(), format!("value of {}", name))];
+        Settings {
+            name: name.to_string(),
+            values: (0..number_of_values).collect(),
+            entries,
+        }
+    }
+
+    // relies on the default Serialize::unique_id()
+    struct CustomSerializer;
+
+    impl Serialize for CustomSerializer {
+        fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
+            Postcard::serialize(value)
+        }
+
+        fn deserialize<T: serde::de::DeserializeOwned>(
+            bytes: &[u8],
+        ) -> Result<T, DeserializeError> {
+            Postcard::deserialize(bytes)
+        }
+    }
+
+    struct OtherCustomSerializer;
+
+    impl Serialize for OtherCustomSerializer {
+        fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
+            Postcard::serialize(value)
+        }
+
+        fn deserialize<T: serde::de::DeserializeOwned>(
+            bytes: &[u8],
+        ) -> Result<T, DeserializeError> {
+            Postcard::deserialize(bytes)
+        }
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn heap_allocated_payload_can_be_sent_and_received<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, Ser>()
+            .subscriber_max_buffer_size(2)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(publisher.send(&settings("first", 3)), eq Ok(1));
+        assert_that!(publisher.send(&settings("second", 5)), eq Ok(1));
+
+        assert_that!(subscriber.receive().unwrap(), eq Some(settings("first", 3)));
+        assert_that!(subscriber.receive().unwrap(), eq Some(settings("second", 5)));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn hash_map_payload_can_be_sent_and_received<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        // CDR does not support maps, therefore TOML is used
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<HashMap<String, Settings>, Toml>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut value = HashMap::new();
+        value.insert("first".to_string(), settings("first", 2));
+        value.insert("second".to_string(), settings("second", 4));
+
+        assert_that!(publisher.send(&value), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap(), eq Some(value));
+    }
+
+    #[test]
+    fn payload_larger_than_initial_size_can_be_sent<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, Ser>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_payload_size(16)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for number_of_values in [1, 100, 10000] {
+            let value = settings("growing", number_of_values);
+            assert_that!(publisher.send(&value), eq Ok(1));
+            assert_that!(subscriber.receive().unwrap(), eq Some(value));
+        }
+    }
+
+    #[test]
+    fn history_is_delivered<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, Ser>()
+            .history_size(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send(&settings("history", 2)), eq Ok(0));
+
+        let subscriber = sut.subscriber_builder().history_size(1).create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        assert_that!(subscriber.receive().unwrap(), eq Some(settings("history", 2)));
+    }
+
+    #[test]
+    fn open_with_same_payload_and_serializer_works<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, Ser>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, Ser>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_with_different_payload_type_fails<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, Ser>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<HashMap<String, String>, Ser>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_with_different_serializer_fails<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, Ser>()
+            .create()
+            .unwrap();
+
+        let result = if Ser::unique_id() == Cdr::unique_id() {
+            node.service_builder(&service_name)
+                .publish_subscribe_serialized::<Settings, Toml>()
+                .open()
+                .err()
+        } else {
+            node.service_builder(&service_name)
+                .publish_subscribe_serialized::<Settings, Cdr>()
+                .open()
+                .err()
+        };
+        assert_that!(result, eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn custom_serializer_without_unique_id_is_incompatible_to_other_serializers<
+        Sut: Service,
+        Ser: Serialize,
+    >() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, OtherCustomSerializer>()
+            .create()
+            .unwrap();
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Settings, Ser>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn typed_service_is_incompatible_to_serialized_service<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<u64, Ser>()
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn payload_that_cannot_be_serialized_is_not_sent<Sut: Service, Ser: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        // TOML documents require a table on the top level
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Vec<u64>, Toml>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send(&vec![1, 2, 3]).err(), eq Some(SerializedPublisherSendError::SerializationFailed));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn error_display_works<Sut: Service, Ser: Serialize>() {
+        assert_that!(
+            format!("{}", SerializedPublisherSendError::SerializationFailed), eq "SerializedPublisherSendError::SerializationFailed");
+        assert_that!(
+            format!("{}", SerializedSubscriberReceiveError::DeserializationFailed), eq "SerializedSubscriberReceiveError::DeserializationFailed");
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, Cdr>)]
+    mod ipc_cdr {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, Toml>)]
+    mod ipc_toml {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, Postcard>)]
+    mod ipc_postcard {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, CustomSerializer>)]
+    mod ipc_custom {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service, Cdr>)]
+    mod local_cdr {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service, Toml>)]
+    mod local_toml {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service, Postcard>)]
+    mod local_postcard {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service, CustomSerializer>)]
+    mod local_custom {}
+}
@@ -15,19 +15,27 @@ mod service_publish_subscribe {
     use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use std::sync::{Barrier, Mutex};
     use std::thread;
+    use std::time::{Duration, Instant};
 
     use iceoryx2::config::Config;
     use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
-    use iceoryx2::port::subscriber::SubscriberCreateError;
+    use iceoryx2::port::subscriber::{SubscriberCreateError, SubscriberReceiveError};
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::CustomHeaderMarker;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::dynamic_config::PortDetails;
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
-    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2::service::static_config::message_type_details::{
+        TypeDetail, TypeDetailError, TypeVariant,
+    };
     use iceoryx2::service::{Service, ServiceDetails};
+    use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+    use iceoryx2_bb_container::hash_map::FixedSizeHashMap;
+    use iceoryx2_bb_container::list::FixedSizeList;
+    use iceoryx2_bb_container::set::FixedSizeSet;
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
@@ -35,11 +43,43 @@ mod service_publish_subscribe {
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
 
-    #[derive(Debug)]
+    const TIMEOUT: Duration = Duration::from_millis(25);
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
     struct SomeUserHeader {
         value: [u64; 1024],
     }
 
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct SomePayload {
+        _value_1: u32,
+        _value_2: u32,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct RenamedSomePayload {
+        _renamed_value_1: u32,
+        _renamed_value_2: u32,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct SomePayloadWithOtherLayout {
+        _value_1: i32,
+        _value_2: u32,
+    }
+
+    #[derive(Debug, Default, PlacementDefault, ZeroCopySend)]
+    #[repr(C)]
+    struct PayloadWithContainers {
+        map: FixedSizeHashMap<FixedSizeByteString<8>, u64, 8>,
+        set: FixedSizeSet<u64, 8>,
+        list: FixedSizeList<u64, 8>,
+    }
+
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
             "service_tests_{}",
@@ -203,6 +243,75 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn open_succeeds_when_type_is_renamed_but_has_same_layout<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<SomePayload>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<RenamedSomePayload>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[RenamedSomePayload]>()
+            .open();
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_fails_when_type_has_same_size_but_different_layout<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<SomePayload>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<SomePayloadWithOtherLayout>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_fails_when_user_header_has_different_layout<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomePayload>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomePayloadWithOtherLayout>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<RenamedSomePayload>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_max_nodes_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -323,6 +432,224 @@ mod service_publish_subscribe {
         );
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_notify_on_send_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(false)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior
+        );
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_deadline_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(Duration::from_millis(100))
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(Duration::from_millis(200))
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleDeadline
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let deadline = sut3.static_config().deadline();
+        assert_that!(deadline, eq Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn blocking_and_timed_receive_fail_when_notify_on_send_is_disabled<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().has_notify_on_send(), eq false);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let result = subscriber.blocking_receive();
+        assert_that!(result.err(), eq Some(SubscriberReceiveError::NotifyOnSendIsDisabled));
+
+        let result = subscriber.timed_receive(TIMEOUT);
+        assert_that!(result.err(), eq Some(SubscriberReceiveError::NotifyOnSendIsDisabled));
+    }
+
+    #[test]
+    fn timed_receive_returns_none_when_nothing_was_sent<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().has_notify_on_send(), eq true);
+
+        let _publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let start = Instant::now();
+        let result = subscriber.timed_receive(TIMEOUT);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result.unwrap(), is_none);
+    }
+
+    #[test]
+    fn timed_receive_returns_sent_sample<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(8129), eq Ok(1));
+
+        let sample = subscriber.timed_receive(TIMEOUT * 1000).unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8129);
+    }
+
+    #[test]
+    fn blocking_receive_wakes_up_when_sample_is_sent<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let barrier = Barrier::new(2);
+        let has_received_sample = AtomicBool::new(false);
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let subscriber = sut.subscriber_builder().create().unwrap();
+                barrier.wait();
+
+                let sample = subscriber.blocking_receive().unwrap();
+                assert_that!(sample, is_some);
+                assert_that!(*sample.unwrap(), eq 4711);
+                has_received_sample.store(true, Ordering::Relaxed);
+            });
+
+            barrier.wait();
+            std::thread::sleep(TIMEOUT);
+            assert_that!(has_received_sample.load(Ordering::Relaxed), eq false);
+            assert_that!(publisher.send_copy(4711), eq Ok(1));
+        });
+
+        assert_that!(has_received_sample.load(Ordering::Relaxed), eq true);
+    }
+
+    #[test]
+    fn send_notifies_companion_event_service<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let event_name = sut.notify_on_send_service_name().unwrap();
+        let event = node.service_builder(&event_name).event().open().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(listener.timed_wait_one(TIMEOUT * 1000).unwrap(), is_some);
+    }
+
+    #[test]
+    fn companion_event_service_does_not_use_service_name<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(1)
+            .create()
+            .unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .max_publishers(2)
+            .create()
+            .unwrap();
+        let _publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+
+        let event_name = sut.notify_on_send_service_name().unwrap();
+        assert_that!(event_name, ne service_name);
+        assert_that!(event_name.is_internal(), eq true);
+
+        assert_that!(publisher_2.send_copy(1), is_ok);
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn companion_event_service_name_cannot_be_created_by_user<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+
+        let event_name = sut.notify_on_send_service_name().unwrap();
+        assert_that!(ServiceName::new(event_name.as_str()), is_err);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -697,6 +1024,101 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn dynamic_config_lists_connected_publishers_and_subscribers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let node2 = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node2
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().buffer_size(1).create().unwrap();
+
+        let mut publishers = vec![];
+        sut2.dynamic_config().list_publishers(|details| {
+            publishers.push(*details);
+            CallbackProgression::Continue
+        });
+        assert_that!(publishers, len 1);
+        assert_that!(publishers[0].publisher_id(), eq publisher.id());
+        assert_that!(*publishers[0].node_id(), eq * node.id());
+
+        let mut subscribers = vec![];
+        sut.dynamic_config().list_subscribers(|details| {
+            subscribers.push(*details);
+            CallbackProgression::Continue
+        });
+        assert_that!(subscribers, len 1);
+        assert_that!(subscribers[0].subscriber_id(), eq subscriber.id());
+        assert_that!(*subscribers[0].node_id(), eq * node2.id());
+        assert_that!(subscribers[0].buffer_size(), eq 1);
+
+        drop(publisher);
+        let mut counter = 0;
+        sut2.dynamic_config().list_publishers(|_| {
+            counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(counter, eq 0);
+    }
+
+    #[test]
+    fn service_details_contain_connected_ports<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let details = Sut::details(
+            &service_name,
+            Config::global_config(),
+            MessagingPattern::PublishSubscribe,
+        )
+        .unwrap()
+        .unwrap();
+
+        let ports = details.dynamic_details.unwrap().ports;
+        assert_that!(ports, len 2);
+
+        let mut found_publisher = false;
+        let mut found_subscriber = false;
+        for port in ports {
+            assert_that!(*port.node_id(), eq * node.id());
+            match port {
+                PortDetails::Publisher(details) => {
+                    assert_that!(details.publisher_id(), eq publisher.id());
+                    found_publisher = true;
+                }
+                PortDetails::Subscriber(details) => {
+                    assert_that!(details.subscriber_id(), eq subscriber.id());
+                    found_subscriber = true;
+                }
+                _ => assert_that!(true, eq false),
+            }
+        }
+
+        assert_that!(found_publisher, eq true);
+        assert_that!(found_subscriber, eq true);
+    }
+
     #[test]
     fn type_informations_are_correct<Sut: Service>() {
         type Header = iceoryx2::service::header::publish_subscribe::Header;
@@ -725,6 +1147,8 @@ mod service_publish_subscribe {
         assert_that!(d.payload.type_name, eq core::any::type_name::<PayloadType>());
         assert_that!(d.payload.size, eq std::mem::size_of::<PayloadType>());
         assert_that!(d.payload.alignment, eq std::mem::align_of::<PayloadType>());
+        assert_that!(d.user_header.type_layout_hash, eq Some(SomeUserHeader::type_layout_hash()));
+        assert_that!(d.payload.type_layout_hash, eq Some(PayloadType::type_layout_hash()));
     }
 
     #[test]
@@ -1402,64 +1826,273 @@ mod service_publish_subscribe {
             .subscriber_max_buffer_size(11)
             .create();
 
-        assert_that!(sut, is_err);
-        assert_that!(
-            sut.err().unwrap(), eq
-            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
-        );
+        assert_that!(sut, is_err);
+        assert_that!(
+            sut.err().unwrap(), eq
+            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
+        );
+    }
+
+    #[test]
+    fn publish_history_is_delivered_on_subscription<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(3)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(32), is_ok);
+        assert_that!(sut_publisher.send_copy(35), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        for i in 0..BUFFER_SIZE {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
+        }
+    }
+
+    #[test]
+    fn publish_history_of_zero_works<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_none);
+    }
+
+    #[test]
+    fn publish_history_is_limited_by_subscriber_history_size<Sut: Service>() {
+        const HISTORY_SIZE: usize = 3;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        for i in 0..HISTORY_SIZE {
+            assert_that!(sut_publisher.send_copy(i), is_ok);
+        }
+
+        let sut_subscriber = sut.subscriber_builder().history_size(1).create().unwrap();
+        let sut_subscriber_without_history =
+            sut.subscriber_builder().history_size(0).create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_some);
+        assert_that!(*data.unwrap(), eq HISTORY_SIZE - 1);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+
+        assert_that!(sut_subscriber_without_history.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publish_history_survives_publisher_recreation_with_same_node<Sut: Service>() {
+        const HISTORY_SIZE: usize = 2;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(12), is_ok);
+        assert_that!(sut_publisher.send_copy(34), is_ok);
+        assert_that!(sut_publisher.send_copy(56), is_ok);
+        drop(sut_publisher);
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        for value in [34, 56] {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            let data = data.unwrap();
+            assert_that!(*data, eq value);
+            assert_that!(data.header().publisher_id(), eq sut_publisher.id());
+        }
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publish_histories_of_publishers_with_same_node_are_merged<Sut: Service>() {
+        const HISTORY_SIZE: usize = 3;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let sut_publisher_1 = sut.publisher_builder().create().unwrap();
+        let sut_publisher_2 = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher_1.send_copy(12), is_ok);
+        assert_that!(sut_publisher_1.send_copy(34), is_ok);
+        assert_that!(sut_publisher_2.send_copy(56), is_ok);
+        assert_that!(sut_publisher_2.send_copy(78), is_ok);
+        drop(sut_publisher_1);
+        drop(sut_publisher_2);
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        for value in [34, 56, 78] {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq value);
+        }
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publish_history_is_discarded_when_node_releases_service<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let other_node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(1)
+            .create()
+            .unwrap();
+        let _sut2 = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(78), is_ok);
+        drop(sut_publisher);
+        drop(sut);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open()
+            .unwrap();
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
     }
 
     #[test]
-    fn publish_history_is_delivered_on_subscription<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    fn publish_history_of_slices_survives_publisher_recreation_with_same_node<Sut: Service>() {
+        const SLICE_LEN: usize = 5;
         let service_name = generate_name();
         let node = NodeBuilder::new().create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .history_size(3)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<[u64]>()
+            .history_size(1)
             .create()
             .unwrap();
 
-        let sut_publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(sut_publisher.send_copy(29), is_ok);
-        assert_that!(sut_publisher.send_copy(32), is_ok);
-        assert_that!(sut_publisher.send_copy(35), is_ok);
+        let sut_publisher = sut
+            .publisher_builder()
+            .max_slice_len(SLICE_LEN)
+            .create()
+            .unwrap();
+        let sample = sut_publisher
+            .loan_slice_uninit(SLICE_LEN)
+            .unwrap()
+            .write_from_fn(|i| i as u64 * 3);
+        assert_that!(sample.send(), is_ok);
+        drop(sut_publisher);
 
+        let sut_publisher = sut
+            .publisher_builder()
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()
+            .unwrap();
         let sut_subscriber = sut.subscriber_builder().create().unwrap();
-        assert_that!(sut_publisher.update_connections(), is_ok);
+        let sample = sut_publisher.loan_slice_uninit(1).unwrap();
+        assert_that!(sample.write_from_fn(|_| 1).send(), is_ok);
 
-        for i in 0..BUFFER_SIZE {
-            let data = sut_subscriber.receive().unwrap();
-            assert_that!(data, is_some);
-            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
+        let data = sut_subscriber.receive().unwrap();
+        assert_that!(data, is_some);
+        let data = data.unwrap();
+        assert_that!(data.payload(), len SLICE_LEN);
+        for (i, value) in data.payload().iter().enumerate() {
+            assert_that!(*value, eq i as u64 * 3);
         }
     }
 
     #[test]
-    fn publish_history_of_zero_works<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    fn publish_history_does_not_survive_publisher_recreation_with_other_node<Sut: Service>() {
         let service_name = generate_name();
         let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let other_node = NodeBuilder::new().create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<usize>()
-            .history_size(0)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .history_size(1)
             .create()
             .unwrap();
+        let sut2 = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open()
+            .unwrap();
 
         let sut_publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(78), is_ok);
+        drop(sut_publisher);
 
+        let sut_publisher = sut2.publisher_builder().create().unwrap();
         let sut_subscriber = sut.subscriber_builder().create().unwrap();
         assert_that!(sut_publisher.update_connections(), is_ok);
 
-        let data = sut_subscriber.receive().unwrap();
-        assert_that!(data, is_none);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
     }
 
     #[test]
@@ -2192,6 +2825,28 @@ mod service_publish_subscribe {
         assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
     }
 
+    #[test]
+    fn subscriber_creation_fails_when_history_size_exceeds_service_history_size<Sut: Service>() {
+        const HISTORY_SIZE: usize = 3;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .history_size(HISTORY_SIZE + 1)
+            .create();
+        assert_that!(subscriber, is_err);
+        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::HistorySizeExceedsHistorySizeOfService);
+    }
+
     #[test]
     fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
         let service_name = generate_name();
@@ -2237,6 +2892,54 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn sliced_service_with_growing_publisher_works<Sut: Service>() {
+        const INITIAL_MAX_ELEMENTS: usize = 4;
+        const MAX_ELEMENTS: usize = 512;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_borrowed_samples(2)
+            .history_size(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_slice_len(INITIAL_MAX_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut previous_sample = None;
+        for n in (0..=MAX_ELEMENTS).step_by(7) {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            sample.write_from_fn(|i| (i + n) as u64).send().unwrap();
+
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq(i + n) as u64);
+            }
+
+            // keep a sample of a previous data segment alive while the publisher grows
+            previous_sample = Some(recv_sample);
+        }
+
+        // the history is delivered to the late subscriber on the next send
+        let late_subscriber = sut.subscriber_builder().create().unwrap();
+        let sample = publisher.loan_slice_uninit(1).unwrap();
+        sample.write_from_fn(|_| 0).send().unwrap();
+
+        let history_sample = late_subscriber.receive().unwrap().unwrap();
+        let previous_sample = previous_sample.unwrap();
+        assert_that!(history_sample.payload(), eq previous_sample.payload());
+    }
+
     #[test]
     fn slice_aligned_service_works<Sut: Service>() {
         const MAX_ELEMENTS: usize = 91;
@@ -2637,6 +3340,295 @@ mod service_publish_subscribe {
         assert_that!(*sample, eq 456);
     }
 
+    #[test]
+    fn publisher_statistics_track_sent_and_loaned_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(4)
+            .create()
+            .unwrap();
+        let _subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample_1 = publisher.loan().unwrap();
+        let sample_2 = publisher.loan().unwrap();
+
+        let mut counter = 0;
+        sut.list_publisher_statistics(|details, statistics| {
+            assert_that!(details.publisher_id(), eq publisher.id());
+            assert_that!(details.max_loaned_samples(), eq 4);
+            assert_that!(statistics.number_of_loaned_samples, eq 2);
+            assert_that!(statistics.number_of_sent_samples, eq 0);
+            counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(counter, eq 1);
+
+        *sample_1.payload_mut() = 1;
+        assert_that!(sample_1.send(), is_ok);
+        drop(sample_2);
+        assert_that!(publisher.send_copy(2), is_ok);
+
+        sut.list_publisher_statistics(|_, statistics| {
+            assert_that!(statistics.number_of_loaned_samples, eq 0);
+            assert_that!(statistics.number_of_sent_samples, eq 2);
+            CallbackProgression::Continue
+        });
+    }
+
+    #[test]
+    fn publisher_statistics_can_be_acquired_by_other_nodes<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let other_node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut2 = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(publisher.send_copy(2), is_ok);
+
+        let mut counter = 0;
+        sut2.list_publisher_statistics(|details, statistics| {
+            assert_that!(details.publisher_id(), eq publisher.id());
+            assert_that!(statistics.number_of_sent_samples, eq 2);
+            counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(counter, eq 1);
+
+        drop(publisher);
+
+        sut2.list_publisher_statistics(|_, _| {
+            counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(counter, eq 1);
+    }
+
+    #[test]
+    fn connection_statistics_track_queued_and_borrowed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .subscriber_max_borrowed_samples(3)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..3 {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+
+        let mut counter = 0;
+        sut.list_connection_statistics(|statistics| {
+            assert_that!(statistics.publisher_id, eq publisher.id());
+            assert_that!(statistics.subscriber_id, eq subscriber.id());
+            assert_that!(statistics.buffer_size, eq 5);
+            assert_that!(statistics.max_borrowed_samples, eq 3);
+            assert_that!(statistics.number_of_queued_samples, eq 2);
+            assert_that!(statistics.number_of_borrowed_samples, eq 1);
+            assert_that!(statistics.number_of_sent_samples, eq 3);
+            assert_that!(statistics.number_of_received_samples, eq 1);
+            counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(counter, eq 1);
+
+        drop(sample);
+
+        sut.list_connection_statistics(|statistics| {
+            assert_that!(statistics.number_of_borrowed_samples, eq 0);
+            CallbackProgression::Continue
+        });
+    }
+
+    fn runtime_type_details_of<T>() -> TypeDetail {
+        TypeDetail {
+            variant: TypeVariant::FixedSize,
+            type_name: core::any::type_name::<T>().to_string(),
+            size: core::mem::size_of::<T>(),
+            alignment: core::mem::align_of::<T>(),
+            type_layout_hash: None,
+        }
+    }
+
+    #[test]
+    fn service_with_runtime_type_details_communicates_with_typed_service<Sut: Service>() {
+        const VALUE: u64 = 0x0011223344556677;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let typed = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_with_type_details(&runtime_type_details_of::<u64>())
+            .unwrap()
+            .open();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+
+        let typed_publisher = typed.publisher_builder().create().unwrap();
+        let typed_subscriber = typed.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(typed_publisher.send_copy(VALUE), is_ok);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq VALUE.to_ne_bytes());
+
+        let sample = publisher.loan_slice_uninit(1).unwrap();
+        assert_that!(sample.payload(), len core::mem::size_of::<u64>());
+        let bytes = VALUE.to_ne_bytes();
+        let sample = sample.write_from_fn(|n| bytes[n]);
+        assert_that!(sample.send(), is_ok);
+
+        let sample = typed_subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq VALUE);
+    }
+
+    #[test]
+    fn service_with_runtime_type_details_and_user_header_can_be_opened<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _typed = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u32>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_with_type_details(&runtime_type_details_of::<u32>())
+            .unwrap()
+            .open();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_with_type_details(&runtime_type_details_of::<u32>())
+            .unwrap()
+            .user_header_type_details(&runtime_type_details_of::<u64>())
+            .unwrap()
+            .open();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn service_with_incompatible_runtime_type_details_cannot_be_opened<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _typed = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut type_details = runtime_type_details_of::<u64>();
+        type_details.size = 16;
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_with_type_details(&type_details)
+            .unwrap()
+            .open();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_with_type_details(&runtime_type_details_of::<i64>())
+            .unwrap()
+            .open();
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn invalid_runtime_type_details_are_rejected<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let mut type_details = runtime_type_details_of::<u64>();
+        type_details.alignment = 3;
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_with_type_details(&type_details);
+        assert_that!(sut.err().unwrap(), eq TypeDetailError::InvalidSizeOrAlignmentValue);
+
+        let mut type_details = runtime_type_details_of::<u64>();
+        type_details.size = 12;
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_with_type_details(&runtime_type_details_of::<u64>())
+            .unwrap()
+            .user_header_type_details(&type_details);
+        assert_that!(sut.err().unwrap(), eq TypeDetailError::InvalidSizeOrAlignmentValue);
+    }
+
+    #[test]
+    fn payload_with_fixed_size_containers_can_be_sent_and_received<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<PayloadWithContainers>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_uninit().unwrap();
+        unsafe { PayloadWithContainers::placement_default(sample.payload_mut().as_mut_ptr()) };
+        let mut sample = unsafe { sample.assume_init() };
+
+        let key = FixedSizeByteString::from_bytes(b"answer").unwrap();
+        let payload = sample.payload_mut();
+        assert_that!(payload.map.insert(key, 42), eq Ok(None));
+        assert_that!(payload.set.insert(7), eq Ok(true));
+        assert_that!(payload.list.push_back(1), eq true);
+        assert_that!(payload.list.push_front(0), eq true);
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let payload = sample.payload();
+        assert_that!(payload.map.get(&key), eq Some(&42));
+        assert_that!(payload.set.contains(&7), eq true);
+        let list: Vec<u64> = payload.list.iter().copied().collect();
+        assert_that!(list, eq vec![0, 1]);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -0,0 +1,285 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_name_pattern {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::query::ServiceNamePattern;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn matches(pattern: &ServiceNamePattern, name: &str) -> bool {
+        pattern.matches(&ServiceName::new(name).unwrap())
+    }
+
+    #[test]
+    fn exact_pattern_matches_only_same_name() {
+        let sut = ServiceNamePattern::Exact(ServiceName::new("Robot/Arm").unwrap());
+
+        assert_that!(matches(&sut, "Robot/Arm"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm/Left"), eq false);
+        assert_that!(matches(&sut, "Robot"), eq false);
+    }
+
+    #[test]
+    fn prefix_pattern_matches_all_names_starting_with_prefix() {
+        let sut = ServiceNamePattern::Prefix("Robot/Arm/".into());
+
+        assert_that!(matches(&sut, "Robot/Arm/Left"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm/Left/Gripper"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm"), eq false);
+        assert_that!(matches(&sut, "Robot/Leg/Left"), eq false);
+    }
+
+    #[test]
+    fn glob_star_does_not_cross_segments() {
+        let sut = ServiceNamePattern::Glob("Robot/Arm/*".into());
+
+        assert_that!(matches(&sut, "Robot/Arm/Left"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm/"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm/Left/Gripper"), eq false);
+        assert_that!(matches(&sut, "Robot/Leg/Left"), eq false);
+
+        let sut = ServiceNamePattern::Glob("Robot/*/Gripper".into());
+        assert_that!(matches(&sut, "Robot/Arm/Gripper"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm/Left/Gripper"), eq false);
+    }
+
+    #[test]
+    fn glob_double_star_crosses_segments() {
+        let sut = ServiceNamePattern::Glob("Robot/**/Gripper".into());
+
+        assert_that!(matches(&sut, "Robot/Arm/Gripper"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm/Left/Gripper"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm/Left/Camera"), eq false);
+
+        let sut = ServiceNamePattern::Glob("**".into());
+        assert_that!(matches(&sut, "a/b/c"), eq true);
+    }
+
+    #[test]
+    fn glob_question_mark_matches_single_character() {
+        let sut = ServiceNamePattern::Glob("Camera?".into());
+
+        assert_that!(matches(&sut, "Camera1"), eq true);
+        assert_that!(matches(&sut, "Camera"), eq false);
+        assert_that!(matches(&sut, "Camera12"), eq false);
+        assert_that!(matches(&sut, "Camera/"), eq false);
+    }
+
+    #[test]
+    fn glob_with_many_stars_does_not_backtrack_exponentially() {
+        let sut = ServiceNamePattern::Glob("a*a*a*a*a*a*a*a*a*a*a*a*b".into());
+        let name = "a".repeat(200);
+
+        let start = std::time::Instant::now();
+        assert_that!(matches(&sut, &name), eq false);
+        assert_that!(matches(&sut, &format!("{name}b")), eq true);
+
+        let sut = ServiceNamePattern::Glob("**a**a**a**a**a**a**a**a**a**a**b".into());
+        assert_that!(matches(&sut, &name), eq false);
+        assert_that!(start.elapsed(), lt std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn glob_mixes_segment_and_any_wildcards() {
+        let sut = ServiceNamePattern::Glob("**/*Gripper".into());
+
+        assert_that!(matches(&sut, "Robot/Arm/LeftGripper"), eq true);
+        assert_that!(matches(&sut, "Robot/LeftGripper/Camera"), eq false);
+
+        let sut = ServiceNamePattern::Glob("Robot/*/**".into());
+        assert_that!(matches(&sut, "Robot/Arm/"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm/Left/Gripper"), eq true);
+        assert_that!(matches(&sut, "Robot/Arm"), eq false);
+    }
+
+    #[test]
+    fn glob_without_wildcards_matches_only_same_name() {
+        let sut = ServiceNamePattern::Glob("Robot/Arm".into());
+
+        assert_that!(matches(&sut, "Robot/Arm"), eq true);
+        assert_that!(matches(&sut, "Robot/Arms"), eq false);
+    }
+}
+
+#[generic_tests::define]
+mod service_query {
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::query::{ServiceNamePattern, ServiceQuery};
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+
+    // every test uses its own prefix so that the services of tests that run in parallel
+    // are not found
+    fn generate_isolated_config() -> Config {
+        let mut config = Config::default();
+        config.global.prefix = FileName::new(
+            format!("query_{:x}_", UniqueSystemId::new().unwrap().value()).as_bytes(),
+        )
+        .unwrap();
+        config
+    }
+
+    fn query_names<S: Service>(config: &Config, query: &ServiceQuery) -> Vec<String> {
+        let mut names = vec![];
+        S::query(config, query, |service| {
+            names.push(service.static_details.name().to_string());
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn empty_query_returns_all_services<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _a = node
+            .service_builder(&"Robot/Arm".try_into().unwrap())
+            .event()
+            .create()
+            .unwrap();
+        let _b = node
+            .service_builder(&"Robot/Leg".try_into().unwrap())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(query_names::<S>(&config, &ServiceQuery::new()), eq vec!["Robot/Arm", "Robot/Leg"]);
+    }
+
+    #[test]
+    fn query_by_prefix_works<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut services = vec![];
+        for name in ["Robot/Arm/Left", "Robot/Arm/Left/Gripper", "Robot/Leg/Left"] {
+            services.push(
+                node.service_builder(&name.try_into().unwrap())
+                    .event()
+                    .create()
+                    .unwrap(),
+            );
+        }
+
+        let query = ServiceQuery::new().name(ServiceNamePattern::Prefix("Robot/Arm/".into()));
+
+        assert_that!(query_names::<S>(&config, &query), eq vec!["Robot/Arm/Left", "Robot/Arm/Left/Gripper"]);
+    }
+
+    #[test]
+    fn query_by_glob_works<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut services = vec![];
+        for name in ["Robot/Arm/Left", "Robot/Arm/Left/Gripper", "Robot/Leg/Left"] {
+            services.push(
+                node.service_builder(&name.try_into().unwrap())
+                    .event()
+                    .create()
+                    .unwrap(),
+            );
+        }
+
+        let query = ServiceQuery::new().name(ServiceNamePattern::Glob("Robot/*/Left".into()));
+
+        assert_that!(query_names::<S>(&config, &query), eq vec!["Robot/Arm/Left", "Robot/Leg/Left"]);
+    }
+
+    #[test]
+    fn query_by_messaging_pattern_and_attributes_works<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _lidar_pubsub = node
+            .service_builder(&"Front".try_into().unwrap())
+            .publish_subscribe::<u64>()
+            .create_with_attributes(&AttributeSpecifier::new().define("sensor", "lidar"))
+            .unwrap();
+        let _lidar_event = node
+            .service_builder(&"Front".try_into().unwrap())
+            .event()
+            .create_with_attributes(&AttributeSpecifier::new().define("sensor", "lidar"))
+            .unwrap();
+        let _camera_pubsub = node
+            .service_builder(&"Rear".try_into().unwrap())
+            .publish_subscribe::<u64>()
+            .create_with_attributes(&AttributeSpecifier::new().define("sensor", "camera"))
+            .unwrap();
+
+        let query = ServiceQuery::new()
+            .messaging_pattern(MessagingPattern::PublishSubscribe)
+            .attributes(&AttributeVerifier::new().require("sensor", "lidar"));
+
+        let mut services = vec![];
+        S::query(&config, &query, |service| {
+            services.push(service);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(services, len 1);
+        assert_that!(services[0].static_details.name().as_str(), eq "Front");
+        assert_that!(services[0].static_details.attributes().get("sensor"), eq vec!["lidar"]);
+
+        let query = ServiceQuery::new().attributes(&AttributeVerifier::new().require_key("sensor"));
+        assert_that!(query_names::<S>(&config, &query), len 3);
+    }
+
+    #[test]
+    fn query_without_matches_returns_nothing<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _service = node
+            .service_builder(&"Robot/Arm".try_into().unwrap())
+            .event()
+            .create()
+            .unwrap();
+
+        let query =
+            ServiceQuery::new().name(ServiceNamePattern::Exact("Robot/Leg".try_into().unwrap()));
+
+        assert_that!(query_names::<S>(&config, &query), is_empty);
+    }
+
+    #[test]
+    fn query_stops_when_callback_progression_states_stop<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut services = vec![];
+        for name in ["a", "b", "c"] {
+            services.push(
+                node.service_builder(&name.try_into().unwrap())
+                    .event()
+                    .create()
+                    .unwrap(),
+            );
+        }
+
+        let mut counter = 0;
+        S::query(&config, &ServiceQuery::new(), |_| {
+            counter += 1;
+            CallbackProgression::Stop
+        })
+        .unwrap();
+
+        assert_that!(counter, eq 1);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,455 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_request_response {
+    use iceoryx2::port::client::ClientCreateError;
+    use iceoryx2::port::server::ServerCreateError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::request_response::{
+        RequestResponseCreateError, RequestResponseOpenError,
+    };
+    use iceoryx2::service::dynamic_config::PortDetails;
+    use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn open_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut = sut.unwrap();
+        let sut2 = sut2.unwrap();
+        assert_that!(sut.name(), eq sut2.name());
+        assert_that!(sut.service_id(), eq sut2.service_id());
+    }
+
+    #[test]
+    fn creating_existing_service_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create();
+        assert_that!(sut2.err(), eq Some(RequestResponseCreateError::AlreadyExists));
+    }
+
+    #[test]
+    fn open_non_existing_service_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn open_fails_when_request_type_is_incompatible<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<i32, u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(RequestResponseOpenError::IncompatibleRequestType));
+    }
+
+    #[test]
+    fn open_fails_when_response_type_is_incompatible<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, i32>()
+            .open();
+        assert_that!(sut2.err(), eq Some(RequestResponseOpenError::IncompatibleResponseType));
+    }
+
+    #[test]
+    fn services_with_same_name_but_different_messaging_pattern_are_independent<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(RequestResponseOpenError::DoesNotExist));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_qos_settings<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(4)
+            .max_servers(3)
+            .max_nodes(5)
+            .max_active_requests_per_client(6)
+            .max_response_buffer_size(7)
+            .max_borrowed_responses_per_pending_response(8)
+            .enable_safe_overflow_for_requests(false)
+            .enable_safe_overflow_for_responses(true)
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(5)
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(4)
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_nodes(6)
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(7)
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(8)
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_borrowed_responses_per_pending_response(9)
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_safe_overflow_for_requests(true)
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_safe_overflow_for_responses(false)
+            .open();
+        assert_that!(sut.err(), eq Some(RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(3)
+            .max_servers(2)
+            .max_active_requests_per_client(1)
+            .open();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        let static_config = sut.static_config();
+        assert_that!(static_config.max_clients(), eq 4);
+        assert_that!(static_config.max_servers(), eq 3);
+        assert_that!(static_config.max_nodes(), eq 5);
+        assert_that!(static_config.max_active_requests_per_client(), eq 6);
+        assert_that!(static_config.max_response_buffer_size(), eq 7);
+        assert_that!(static_config.max_borrowed_responses_per_pending_response(), eq 8);
+        assert_that!(static_config.has_safe_overflow_for_requests(), eq false);
+        assert_that!(static_config.has_safe_overflow_for_responses(), eq true);
+    }
+
+    #[test]
+    fn service_has_request_response_messaging_pattern<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(Sut::does_exist(&service_name, Config::global_config(), MessagingPattern::RequestResponse), eq Ok(true));
+        assert_that!(Sut::does_exist(&service_name, Config::global_config(), MessagingPattern::PublishSubscribe), eq Ok(false));
+    }
+
+    #[test]
+    fn number_of_clients_and_servers_is_tracked<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(2)
+            .max_servers(1)
+            .create()
+            .unwrap();
+
+        let client_1 = sut.client_builder().create().unwrap();
+        let client_2 = sut.client_builder().create().unwrap();
+        let server = sut.server_builder().create().unwrap();
+
+        assert_that!(sut.dynamic_config().number_of_clients(), eq 2);
+        assert_that!(sut.dynamic_config().number_of_servers(), eq 1);
+
+        let client_3 = sut.client_builder().create();
+        assert_that!(client_3.err(), eq Some(ClientCreateError::ExceedsMaxSupportedClients));
+        let server_2 = sut.server_builder().create();
+        assert_that!(server_2.err(), eq Some(ServerCreateError::ExceedsMaxSupportedServers));
+
+        drop(client_1);
+        drop(server);
+        assert_that!(sut.dynamic_config().number_of_clients(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_servers(), eq 0);
+
+        drop(client_2);
+        assert_that!(sut.dynamic_config().number_of_clients(), eq 0);
+    }
+
+    #[test]
+    fn request_and_responses_are_delivered<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(123).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 1);
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 123);
+        assert_that!(active_request.origin(), eq client.id());
+        assert_that!(active_request.header().client_id(), eq client.id());
+        assert_that!(active_request.header().request_id(), eq pending_response.request_id());
+
+        for i in 0..3 {
+            active_request.send_copy(*active_request + i).unwrap();
+        }
+
+        for i in 0..3 {
+            let response = pending_response.receive().unwrap().unwrap();
+            assert_that!(*response, eq 123 + i);
+            assert_that!(response.origin(), eq server.id());
+            assert_that!(response.header().request_id(), eq pending_response.request_id());
+        }
+
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn responses_are_routed_to_the_corresponding_pending_response<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response_1 = client.send_copy(1).unwrap();
+        let pending_response_2 = client.send_copy(2).unwrap();
+
+        while let Some(active_request) = server.receive().unwrap() {
+            active_request.send_copy(*active_request * 10).unwrap();
+        }
+
+        let response = pending_response_2.receive().unwrap().unwrap();
+        assert_that!(*response, eq 20);
+        assert_that!(pending_response_2.receive().unwrap(), is_none);
+
+        let response = pending_response_1.receive().unwrap().unwrap();
+        assert_that!(*response, eq 10);
+        assert_that!(pending_response_1.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_is_delivered_to_all_servers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let server_1 = sut.server_builder().create().unwrap();
+        let server_2 = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(5).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 2);
+
+        for server in [&server_1, &server_2] {
+            let active_request = server.receive().unwrap().unwrap();
+            assert_that!(*active_request, eq 5);
+            active_request.send_copy(6).unwrap();
+        }
+
+        let mut origins = vec![];
+        while let Some(response) = pending_response.receive().unwrap() {
+            assert_that!(*response, eq 6);
+            origins.push(response.origin());
+        }
+
+        assert_that!(origins, len 2);
+        assert_that!(origins, contains server_1.id());
+        assert_that!(origins, contains server_2.id());
+    }
+
+    #[test]
+    fn details_list_connected_clients_and_servers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = sut.client_builder().create().unwrap();
+        let server = sut.server_builder().create().unwrap();
+
+        let mut clients = vec![];
+        sut.dynamic_config().list_clients(|details| {
+            clients.push(*details);
+            CallbackProgression::Continue
+        });
+        assert_that!(clients, len 1);
+        assert_that!(clients[0].client_id(), eq client.id());
+
+        let details = Sut::details(
+            &service_name,
+            Config::global_config(),
+            MessagingPattern::RequestResponse,
+        )
+        .unwrap()
+        .unwrap();
+
+        let ports = details.dynamic_details.unwrap().ports;
+        assert_that!(ports, len 2);
+
+        let mut found_client = false;
+        let mut found_server = false;
+        for port in ports {
+            assert_that!(*port.node_id(), eq * node.id());
+            match port {
+                PortDetails::Client(details) => {
+                    assert_that!(details.client_id(), eq client.id());
+                    found_client = true;
+                }
+                PortDetails::Server(details) => {
+                    assert_that!(details.server_id(), eq server.id());
+                    found_server = true;
+                }
+                _ => assert_that!(true, eq false),
+            }
+        }
+
+        assert_that!(found_client, eq true);
+        assert_that!(found_server, eq true);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
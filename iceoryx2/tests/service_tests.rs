@@ -937,6 +937,39 @@ mod service {
         assert_that!(sut.service_id(), eq sut2.service_id());
     }
 
+    #[test]
+    fn static_config_can_be_serialized_with_binary_and_text_serializers<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        use iceoryx2::service::static_config::StaticConfig;
+        use iceoryx2_cal::serialize::{postcard::Postcard, toml::Toml, Serialize};
+
+        fn roundtrip<S: Serialize>(static_config: &StaticConfig) -> StaticConfig {
+            S::deserialize(&S::serialize(static_config).unwrap()).unwrap()
+        }
+
+        let test = Factory::new();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let static_config = Sut::details(
+            &service_name,
+            Config::global_config(),
+            Factory::messaging_pattern(),
+        )
+        .unwrap()
+        .unwrap()
+        .static_details;
+
+        assert_that!(roundtrip::<Toml>(&static_config), eq static_config);
+        assert_that!(roundtrip::<Postcard>(&static_config), eq static_config);
+    }
+
     mod ipc {
         use iceoryx2::service::ipc::Service;
 
@@ -947,6 +980,16 @@ mod service {
         mod publish_subscribe {}
     }
 
+    mod ipc_postcard {
+        use iceoryx2::service::ipc_postcard::Service;
+
+        #[instantiate_tests(<Service, crate::service::EventTests::<Service>>)]
+        mod event {}
+
+        #[instantiate_tests(<Service, crate::service::PubSubTests::<Service>>)]
+        mod publish_subscribe {}
+    }
+
     mod local {
         use iceoryx2::service::local::Service;
 
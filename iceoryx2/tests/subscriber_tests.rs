@@ -13,10 +13,12 @@
 #[generic_tests::define]
 mod subscriber {
     use std::collections::HashSet;
+    use std::time::Duration;
 
     use iceoryx2::{
         node::NodeBuilder,
-        port::subscriber::{SubscriberCreateError, SubscriberReceiveError},
+        port::subscriber::{PublisherQosEvent, SubscriberCreateError, SubscriberReceiveError},
+        prelude::CallbackProgression,
         service::{service_name::ServiceName, Service},
     };
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -42,6 +44,8 @@ mod subscriber {
             format!("{}", SubscriberCreateError::ExceedsMaxSupportedSubscribers), eq "SubscriberCreateError::ExceedsMaxSupportedSubscribers");
         assert_that!(
             format!("{}", SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService), eq "SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService");
+        assert_that!(
+            format!("{}", SubscriberCreateError::HistorySizeExceedsHistorySizeOfService), eq "SubscriberCreateError::HistorySizeExceedsHistorySizeOfService");
     }
 
     #[test]
@@ -67,6 +71,195 @@ mod subscriber {
         }
     }
 
+    const QOS_PERIOD: Duration = Duration::from_millis(50);
+
+    fn collect_qos_events<S: Service>(
+        subscriber: &iceoryx2::port::subscriber::Subscriber<S, u64, ()>,
+    ) -> Vec<PublisherQosEvent> {
+        let mut events = vec![];
+        subscriber
+            .publisher_qos_events(|event| {
+                events.push(event);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        events
+    }
+
+    #[test]
+    fn publisher_qos_events_are_empty_without_deadline_and_lease<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let _publisher = sut.publisher_builder().create().unwrap();
+
+        std::thread::sleep(QOS_PERIOD * 2);
+
+        assert_that!(collect_qos_events(&subscriber), len 0);
+    }
+
+    #[test]
+    fn missed_deadline_is_reported_once_until_publisher_sends_again<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(QOS_PERIOD)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(collect_qos_events(&subscriber), len 0);
+
+        std::thread::sleep(QOS_PERIOD * 2);
+        let events = collect_qos_events(&subscriber);
+        assert_that!(events, eq vec![PublisherQosEvent::DeadlineMissed(publisher.id())]);
+        assert_that!(collect_qos_events(&subscriber), len 0);
+
+        publisher.send_copy(123).unwrap();
+        assert_that!(collect_qos_events(&subscriber), len 0);
+
+        std::thread::sleep(QOS_PERIOD * 2);
+        let events = collect_qos_events(&subscriber);
+        assert_that!(events, eq vec![PublisherQosEvent::DeadlineMissed(publisher.id())]);
+    }
+
+    #[test]
+    fn publisher_qos_check_interval_is_smallest_deadline_or_lease_duration<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(QOS_PERIOD * 4)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.publisher_qos_check_interval(), eq Some(QOS_PERIOD * 4));
+
+        let _publisher_1 = sut
+            .publisher_builder()
+            .liveliness_lease_duration(QOS_PERIOD * 2)
+            .create()
+            .unwrap();
+        let _publisher_2 = sut
+            .publisher_builder()
+            .liveliness_lease_duration(QOS_PERIOD * 8)
+            .create()
+            .unwrap();
+        assert_that!(subscriber.publisher_qos_check_interval(), eq Some(QOS_PERIOD * 2));
+    }
+
+    #[test]
+    fn publisher_qos_check_interval_is_none_without_deadline_and_lease_duration<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let _publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(subscriber.publisher_qos_check_interval(), eq None);
+    }
+
+    #[test]
+    fn lost_liveliness_is_reported_when_lease_expires<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .liveliness_lease_duration(QOS_PERIOD)
+            .create()
+            .unwrap();
+        assert_that!(collect_qos_events(&subscriber), len 0);
+
+        std::thread::sleep(QOS_PERIOD * 2);
+        let events = collect_qos_events(&subscriber);
+        assert_that!(events, eq vec![PublisherQosEvent::LivelinessLost(publisher.id())]);
+        assert_that!(collect_qos_events(&subscriber), len 0);
+    }
+
+    #[test]
+    fn asserted_liveliness_renews_lease_but_not_deadline<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(QOS_PERIOD)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .liveliness_lease_duration(QOS_PERIOD)
+            .create()
+            .unwrap();
+
+        std::thread::sleep(QOS_PERIOD * 2);
+        publisher.assert_liveliness();
+
+        let events = collect_qos_events(&subscriber);
+        assert_that!(events, eq vec![PublisherQosEvent::DeadlineMissed(publisher.id())]);
+    }
+
+    #[test]
+    fn publisher_qos_events_stop_when_callback_returns_stop<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .deadline(QOS_PERIOD)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let _publisher_1 = sut.publisher_builder().create().unwrap();
+        let _publisher_2 = sut.publisher_builder().create().unwrap();
+
+        std::thread::sleep(QOS_PERIOD * 2);
+
+        let mut counter = 0;
+        subscriber
+            .publisher_qos_events(|_| {
+                counter += 1;
+                CallbackProgression::Stop
+            })
+            .unwrap();
+        assert_that!(counter, eq 1);
+
+        // the event of the remaining publisher is reported with the next call
+        assert_that!(collect_qos_events(&subscriber), len 1);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
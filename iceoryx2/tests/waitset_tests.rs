@@ -0,0 +1,342 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod waitset {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::port::subscriber::PublisherQosEvent;
+    use iceoryx2::prelude::*;
+    use iceoryx2::waitset::{
+        WaitSetAttachmentError, WaitSetAttachmentId, WaitSetBuilder, WaitSetRunError,
+        WaitSetRunResult,
+    };
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "waitset_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn newly_created_waitset_is_empty<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.len(), eq 0);
+        assert_that!(sut.capacity(), ge 1);
+    }
+
+    #[test]
+    fn waiting_on_empty_waitset_fails<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let result = sut.wait_and_process_once(|_| CallbackProgression::Continue);
+        assert_that!(result.err(), eq Some(WaitSetRunError::NoAttachments));
+
+        let result = sut.wait_and_process(|_| CallbackProgression::Continue);
+        assert_that!(result.err(), eq Some(WaitSetRunError::NoAttachments));
+    }
+
+    #[test]
+    fn attaching_and_detaching_intervals_adjusts_len<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let guard_1 = sut.attach_interval(TIMEOUT).unwrap();
+        assert_that!(sut.len(), eq 1);
+        let guard_2 = sut.attach_interval(TIMEOUT).unwrap();
+        assert_that!(sut.len(), eq 2);
+        assert_that!(sut.is_empty(), eq false);
+
+        drop(guard_1);
+        assert_that!(sut.len(), eq 1);
+        drop(guard_2);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn interval_wakes_up_waitset<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let guard = sut.attach_interval(TIMEOUT).unwrap();
+        let other_guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
+
+        let start = Instant::now();
+        let mut callback_called = false;
+        let result = sut
+            .wait_and_process_once(|id| {
+                assert_that!(id.has_event_from(&guard), eq true);
+                assert_that!(id.has_event_from(&other_guard), eq false);
+                assert_that!(id, eq WaitSetAttachmentId::from_guard(&guard));
+                callback_called = true;
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(callback_called, eq true);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+    }
+
+    #[test]
+    fn attached_publisher_qos_wakes_up_waitset_to_report_missed_deadline<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .deadline(TIMEOUT)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let guard = sut.attach_publisher_qos(&subscriber).unwrap();
+        assert_that!(sut.len(), eq 1);
+
+        let mut events = vec![];
+        let start = Instant::now();
+        while events.is_empty() && start.elapsed() < TIMEOUT * 100 {
+            sut.wait_and_process_once(|id| {
+                assert_that!(id.has_event_from(&guard), eq true);
+                subscriber
+                    .publisher_qos_events(|event| {
+                        events.push(event);
+                        CallbackProgression::Continue
+                    })
+                    .unwrap();
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        }
+
+        assert_that!(events, eq vec![PublisherQosEvent::DeadlineMissed(publisher.id())]);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+    }
+
+    #[test]
+    fn attaching_publisher_qos_without_deadline_and_lease_duration_fails<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let result = sut.attach_publisher_qos(&subscriber);
+
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::PublisherQosIsNotDefined));
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn wait_and_process_returns_when_callback_requests_stop<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let _guard = sut.attach_interval(Duration::from_millis(1)).unwrap();
+
+        let mut counter = 0;
+        let result = sut
+            .wait_and_process(|_| {
+                counter += 1;
+                if counter == 3 {
+                    CallbackProgression::Stop
+                } else {
+                    CallbackProgression::Continue
+                }
+            })
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::StopRequest);
+        assert_that!(counter, eq 3);
+    }
+
+    #[test]
+    fn wait_with_timeout_returns_when_no_event_arrived<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let _guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
+
+        let start = Instant::now();
+        let mut callback_called = false;
+        let result = sut
+            .wait_and_process_once_with_timeout(
+                |_| {
+                    callback_called = true;
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(callback_called, eq false);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
+
+mod waitset_with_listener {
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::waitset::{
+        WaitSetAttachmentError, WaitSetBuilder, WaitSetRunError, WaitSetRunResult,
+    };
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "waitset_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn error_display_works() {
+        assert_that!(
+            format!("{}", WaitSetAttachmentError::AlreadyAttached), eq "WaitSetAttachmentError::AlreadyAttached");
+        assert_that!(
+            format!("{}", WaitSetRunError::NoAttachments), eq "WaitSetRunError::NoAttachments");
+    }
+
+    #[test]
+    fn attaching_listener_twice_fails() {
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let sut = WaitSetBuilder::new().create::<ipc::Service>().unwrap();
+
+        let _guard = sut.attach_notification(&listener).unwrap();
+        assert_that!(sut.len(), eq 1);
+
+        let result = sut.attach_notification(&listener);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+        let result = sut.attach_deadline(&listener, TIMEOUT);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+    }
+
+    #[test]
+    fn notification_wakes_up_waitset() {
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service_1 = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let service_2 = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener_1 = service_1.listener_builder().create().unwrap();
+        let listener_2 = service_2.listener_builder().create().unwrap();
+        let notifier = service_2.notifier_builder().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create::<ipc::Service>().unwrap();
+        let guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let guard_2 = sut.attach_notification(&listener_2).unwrap();
+        assert_that!(sut.len(), eq 2);
+
+        notifier.notify().unwrap();
+
+        let mut callback_called = false;
+        let result = sut
+            .wait_and_process_once(|id| {
+                assert_that!(id.has_event_from(&guard_1), eq false);
+                assert_that!(id.has_event_from(&guard_2), eq true);
+                assert_that!(listener_2.try_wait_one().unwrap(), is_some);
+                callback_called = true;
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(callback_called, eq true);
+
+        drop(guard_1);
+        drop(guard_2);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn missed_deadline_is_reported() {
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create::<ipc::Service>().unwrap();
+        let guard = sut.attach_deadline(&listener, TIMEOUT).unwrap();
+
+        let mut missed_deadline = false;
+        sut.wait_and_process_once(|id| {
+            assert_that!(id.has_event_from(&guard), eq false);
+            missed_deadline = id.has_missed_deadline(&guard);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadline, eq true);
+    }
+
+    #[test]
+    fn notification_on_deadline_attachment_is_reported_as_event() {
+        let node = NodeBuilder::new().create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create::<ipc::Service>().unwrap();
+        let guard = sut.attach_deadline(&listener, TIMEOUT * 1000).unwrap();
+
+        notifier.notify().unwrap();
+
+        let mut has_event = false;
+        let mut missed_deadline = false;
+        sut.wait_and_process_once(|id| {
+            has_event |= id.has_event_from(&guard);
+            missed_deadline |= id.has_missed_deadline(&guard);
+            while let Ok(Some(_)) = listener.try_wait_one() {}
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(has_event, eq true);
+        assert_that!(missed_deadline, eq false);
+    }
+}